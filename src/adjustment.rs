@@ -0,0 +1,156 @@
+//! adjustment contains two-phase balance updates.
+//!
+//! A [`PendingAdjustment`] stages a change to a balance: the new balance is computed up front,
+//! but is only obtained by explicitly calling [`PendingAdjustment::commit`], e.g. after the change is persisted.
+//! [`PendingAdjustment::abort`] gives the untouched balance back.
+
+use std::fmt::Debug;
+
+use crate::{BaseMoney, Currency, Money, MoneyError};
+
+/// Staged change of `delta` to `target` balance, resulting in `resulting` balance.
+///
+/// It must be either committed or aborted, and is not `Clone` so it is applied at most once.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{money, adjustment::PendingAdjustment};
+///
+/// let balance = money!(USD, 100);
+///
+/// let pending = PendingAdjustment::new(balance, money!(USD, -30)).unwrap();
+/// assert_eq!(pending.resulting(), money!(USD, 70));
+///
+/// // persist the change, then commit.
+/// let balance = pending.commit();
+/// assert_eq!(balance, money!(USD, 70));
+///
+/// // persisting failed, abort.
+/// let pending = PendingAdjustment::new(balance, money!(USD, 50)).unwrap();
+/// let balance = pending.abort();
+/// assert_eq!(balance, money!(USD, 70));
+/// ```
+#[must_use = "pending adjustment must be committed or aborted"]
+pub struct PendingAdjustment<C: Currency> {
+    target: Money<C>,
+    delta: Money<C>,
+    resulting: Money<C>,
+}
+
+impl<C: Currency> PendingAdjustment<C> {
+    /// Stages `delta` to `target` balance.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::OverflowError`] if the resulting balance overflows.
+    pub fn new(target: Money<C>, delta: Money<C>) -> Result<Self, MoneyError> {
+        let resulting = target
+            .amount()
+            .checked_add(delta.amount())
+            .ok_or(MoneyError::OverflowError)?;
+        Ok(Self {
+            target,
+            delta,
+            resulting: Money::from_decimal(resulting),
+        })
+    }
+
+    /// Stages change of `target` balance into `resulting` balance.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::OverflowError`] if the delta overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, adjustment::PendingAdjustment};
+    ///
+    /// let pending = PendingAdjustment::to(money!(USD, 100), money!(USD, 80)).unwrap();
+    /// assert_eq!(pending.delta(), money!(USD, -20));
+    /// # let _ = pending.commit();
+    /// ```
+    pub fn to(target: Money<C>, resulting: Money<C>) -> Result<Self, MoneyError> {
+        let delta = resulting
+            .amount()
+            .checked_sub(target.amount())
+            .ok_or(MoneyError::OverflowError)?;
+        Ok(Self {
+            target,
+            delta: Money::from_decimal(delta),
+            resulting,
+        })
+    }
+
+    /// Returns the balance the adjustment is applied to.
+    #[inline]
+    pub fn target(&self) -> Money<C> {
+        self.target.clone()
+    }
+
+    /// Returns the change applied to the balance.
+    #[inline]
+    pub fn delta(&self) -> Money<C> {
+        self.delta.clone()
+    }
+
+    /// Returns the balance after the adjustment.
+    #[inline]
+    pub fn resulting(&self) -> Money<C> {
+        self.resulting.clone()
+    }
+
+    /// Commits the adjustment, returning the resulting balance.
+    #[inline]
+    pub fn commit(self) -> Money<C> {
+        self.resulting
+    }
+
+    /// Aborts the adjustment, returning the untouched target balance.
+    #[inline]
+    pub fn abort(self) -> Money<C> {
+        self.target
+    }
+
+    /// Commits the adjustment if `current` balance is still the target, e.g. nothing else changed it since staging.
+    ///
+    /// # Errors
+    ///
+    /// Returns the adjustment back if `current` is not the target, so it can be aborted or staged again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, adjustment::PendingAdjustment};
+    ///
+    /// let pending = PendingAdjustment::new(money!(USD, 100), money!(USD, 5)).unwrap();
+    /// let pending = pending.commit_if(&money!(USD, 90)).unwrap_err();
+    /// assert_eq!(pending.commit_if(&money!(USD, 100)).unwrap(), money!(USD, 105));
+    /// ```
+    pub fn commit_if(self, current: &Money<C>) -> Result<Money<C>, Self> {
+        if *current == self.target {
+            Ok(self.resulting)
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<C: Currency> PartialEq for PendingAdjustment<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.target == other.target && self.delta == other.delta
+    }
+}
+
+impl<C: Currency> Eq for PendingAdjustment<C> {}
+
+impl<C: Currency> Debug for PendingAdjustment<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PendingAdjustment")
+            .field("target", &self.target)
+            .field("delta", &self.delta)
+            .field("resulting", &self.resulting)
+            .finish()
+    }
+}
@@ -0,0 +1,65 @@
+use crate::adjustment::PendingAdjustment;
+use crate::macros::money;
+use crate::{BaseMoney, Decimal, Money, MoneyError, iso::USD};
+
+#[test]
+fn test_new() {
+    let pending = PendingAdjustment::new(money!(USD, 100), money!(USD, 25.5)).unwrap();
+    assert_eq!(pending.target(), money!(USD, 100));
+    assert_eq!(pending.delta(), money!(USD, 25.5));
+    assert_eq!(pending.resulting(), money!(USD, 125.5));
+
+    let pending = PendingAdjustment::new(money!(USD, 10), money!(USD, -25)).unwrap();
+    assert_eq!(pending.resulting(), money!(USD, -15));
+    let _ = pending.abort();
+}
+
+#[test]
+fn test_new_overflow() {
+    let max = Money::<USD>::from_decimal(Decimal::MAX);
+    let ret = PendingAdjustment::new(max, money!(USD, 1));
+    assert!(matches!(ret, Err(MoneyError::OverflowError)));
+}
+
+#[test]
+fn test_to() {
+    let pending = PendingAdjustment::to(money!(USD, 100), money!(USD, 130.25)).unwrap();
+    assert_eq!(pending.delta(), money!(USD, 30.25));
+    assert_eq!(
+        pending,
+        PendingAdjustment::new(money!(USD, 100), money!(USD, 30.25)).unwrap()
+    );
+    let _ = pending.commit();
+
+    let min = Money::<USD>::from_decimal(Decimal::MIN);
+    let ret = PendingAdjustment::to(money!(USD, 1), min);
+    assert!(matches!(ret, Err(MoneyError::OverflowError)));
+}
+
+#[test]
+fn test_commit_abort() {
+    let pending = PendingAdjustment::new(money!(USD, 100), money!(USD, -40)).unwrap();
+    assert_eq!(pending.commit(), money!(USD, 60));
+
+    let pending = PendingAdjustment::new(money!(USD, 100), money!(USD, -40)).unwrap();
+    assert_eq!(pending.abort(), money!(USD, 100));
+}
+
+#[test]
+fn test_commit_if() {
+    let pending = PendingAdjustment::new(money!(USD, 100), money!(USD, -40)).unwrap();
+    let pending = pending.commit_if(&money!(USD, 99.99)).unwrap_err();
+    assert_eq!(pending.target(), money!(USD, 100));
+    assert_eq!(pending.commit_if(&money!(USD, 100)), Ok(money!(USD, 60)));
+}
+
+#[cfg(not(feature = "redact_debug"))]
+#[test]
+fn test_debug() {
+    let pending = PendingAdjustment::new(money!(USD, 100), money!(USD, -40)).unwrap();
+    assert_eq!(
+        format!("{:?}", pending),
+        "PendingAdjustment { target: Money(USD, 100), delta: Money(USD, -40), resulting: Money(USD, 60) }"
+    );
+    let _ = pending.abort();
+}
@@ -0,0 +1,227 @@
+//! balance contains account balance with non-negative invariant.
+//!
+//! [`AccountBalance`] never goes below zero, or below its overdraft limit if it has one.
+//! Debits that would break it fail with [`BalanceError::InsufficientFunds`] and leave the balance untouched.
+
+use std::error::Error;
+use std::fmt::{Debug, Display};
+
+use crate::error::ERROR_PREFIX;
+use crate::{BaseMoney, Currency, Money};
+
+/// Error of [`AccountBalance`] operations.
+#[non_exhaustive]
+pub enum BalanceError<C: Currency> {
+    /// Debit is more than available funds by `missing`.
+    InsufficientFunds { missing: Money<C> },
+
+    /// Amount credited/debited or overdraft limit is negative.
+    NegativeAmount(Money<C>),
+
+    /// Balance overflowed.
+    OverflowError,
+}
+
+impl<C: Currency> Display for BalanceError<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BalanceError::InsufficientFunds { missing } => {
+                write!(f, "{ERROR_PREFIX} insufficient funds: missing {}", missing)
+            }
+            BalanceError::NegativeAmount(amount) => {
+                write!(f, "{ERROR_PREFIX} negative amount: {}", amount)
+            }
+            BalanceError::OverflowError => write!(f, "{ERROR_PREFIX} got overflowed"),
+        }
+    }
+}
+
+impl<C: Currency> Debug for BalanceError<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BalanceError::InsufficientFunds { missing } => f
+                .debug_struct("InsufficientFunds")
+                .field("missing", missing)
+                .finish(),
+            BalanceError::NegativeAmount(amount) => {
+                f.debug_tuple("NegativeAmount").field(amount).finish()
+            }
+            BalanceError::OverflowError => f.write_str("OverflowError"),
+        }
+    }
+}
+
+impl<C: Currency> Error for BalanceError<C> {}
+
+/// Account balance that never goes below zero, or below `-overdraft_limit`.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{money, balance::{AccountBalance, BalanceError}};
+///
+/// let mut wallet = AccountBalance::new(money!(USD, 100)).unwrap();
+/// wallet.credit(money!(USD, 50)).unwrap();
+/// wallet.debit(money!(USD, 120)).unwrap();
+/// assert_eq!(wallet.balance(), money!(USD, 30));
+///
+/// let err = wallet.debit(money!(USD, 50)).unwrap_err();
+/// assert!(matches!(err, BalanceError::InsufficientFunds { missing } if missing == money!(USD, 20)));
+/// assert_eq!(wallet.balance(), money!(USD, 30));
+///
+/// // With overdraft.
+/// let mut account = AccountBalance::with_overdraft(money!(USD, 30), money!(USD, 100)).unwrap();
+/// account.debit(money!(USD, 50)).unwrap();
+/// assert_eq!(account.balance(), money!(USD, -20));
+/// assert_eq!(account.available(), money!(USD, 80));
+/// ```
+pub struct AccountBalance<C: Currency> {
+    balance: Money<C>,
+    overdraft_limit: Money<C>,
+}
+
+impl<C: Currency> AccountBalance<C> {
+    /// Creates account balance without overdraft.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BalanceError::InsufficientFunds`] if `balance` is negative.
+    pub fn new(balance: Money<C>) -> Result<Self, BalanceError<C>> {
+        Self::with_overdraft(balance, Money::default())
+    }
+
+    /// Creates account balance allowed to go down to `-overdraft_limit`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BalanceError::NegativeAmount`] if `overdraft_limit` is negative,
+    /// and [`BalanceError::InsufficientFunds`] if `balance` is below `-overdraft_limit`.
+    pub fn with_overdraft(
+        balance: Money<C>,
+        overdraft_limit: Money<C>,
+    ) -> Result<Self, BalanceError<C>> {
+        if overdraft_limit.is_negative() {
+            return Err(BalanceError::NegativeAmount(overdraft_limit));
+        }
+        let available = balance
+            .amount()
+            .checked_add(overdraft_limit.amount())
+            .ok_or(BalanceError::OverflowError)?;
+        if available.is_sign_negative() && !available.is_zero() {
+            return Err(BalanceError::InsufficientFunds {
+                missing: Money::from_decimal(-available),
+            });
+        }
+        Ok(Self {
+            balance,
+            overdraft_limit,
+        })
+    }
+
+    /// Returns current balance.
+    #[inline]
+    pub fn balance(&self) -> Money<C> {
+        self.balance.clone()
+    }
+
+    /// Returns overdraft limit, zero if there's no overdraft.
+    #[inline]
+    pub fn overdraft_limit(&self) -> Money<C> {
+        self.overdraft_limit.clone()
+    }
+
+    /// Returns funds available for debit, balance plus overdraft limit.
+    #[inline]
+    pub fn available(&self) -> Money<C> {
+        // Checked on creation and every operation.
+        Money::from_decimal(
+            self.balance
+                .amount()
+                .saturating_add(self.overdraft_limit.amount()),
+        )
+    }
+
+    /// Returns `true` if `amount` can be debited.
+    pub fn can_debit(&self, amount: &Money<C>) -> bool {
+        !amount.is_negative() && *amount <= self.available()
+    }
+
+    /// Adds `amount` to the balance, returning the new balance.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BalanceError::NegativeAmount`] if `amount` is negative,
+    /// and [`BalanceError::OverflowError`] if the balance overflows.
+    pub fn credit(&mut self, amount: Money<C>) -> Result<Money<C>, BalanceError<C>> {
+        if amount.is_negative() {
+            return Err(BalanceError::NegativeAmount(amount));
+        }
+        let balance = self
+            .balance
+            .amount()
+            .checked_add(amount.amount())
+            .ok_or(BalanceError::OverflowError)?;
+        // Keep available funds representable.
+        balance
+            .checked_add(self.overdraft_limit.amount())
+            .ok_or(BalanceError::OverflowError)?;
+        self.balance = Money::from_decimal(balance);
+        Ok(self.balance())
+    }
+
+    /// Subtracts `amount` from the balance, returning the new balance.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BalanceError::NegativeAmount`] if `amount` is negative,
+    /// and [`BalanceError::InsufficientFunds`] with the missing amount if `amount` is more than [`available`](Self::available).
+    /// The balance is untouched on error.
+    pub fn debit(&mut self, amount: Money<C>) -> Result<Money<C>, BalanceError<C>> {
+        if amount.is_negative() {
+            return Err(BalanceError::NegativeAmount(amount));
+        }
+        let available = self.available();
+        if amount > available {
+            let missing = amount
+                .amount()
+                .checked_sub(available.amount())
+                .ok_or(BalanceError::OverflowError)?;
+            return Err(BalanceError::InsufficientFunds {
+                missing: Money::from_decimal(missing),
+            });
+        }
+        let balance = self
+            .balance
+            .amount()
+            .checked_sub(amount.amount())
+            .ok_or(BalanceError::OverflowError)?;
+        self.balance = Money::from_decimal(balance);
+        Ok(self.balance())
+    }
+}
+
+impl<C: Currency> Clone for AccountBalance<C> {
+    fn clone(&self) -> Self {
+        Self {
+            balance: self.balance.clone(),
+            overdraft_limit: self.overdraft_limit.clone(),
+        }
+    }
+}
+
+impl<C: Currency> PartialEq for AccountBalance<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.balance == other.balance && self.overdraft_limit == other.overdraft_limit
+    }
+}
+
+impl<C: Currency> Eq for AccountBalance<C> {}
+
+impl<C: Currency> Debug for AccountBalance<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountBalance")
+            .field("balance", &self.balance)
+            .field("overdraft_limit", &self.overdraft_limit)
+            .finish()
+    }
+}
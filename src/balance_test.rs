@@ -0,0 +1,131 @@
+use crate::balance::{AccountBalance, BalanceError};
+use crate::macros::money;
+use crate::{BaseMoney, Decimal, Money, iso::USD};
+
+#[test]
+fn test_new() {
+    let account = AccountBalance::new(money!(USD, 100)).unwrap();
+    assert_eq!(account.balance(), money!(USD, 100));
+    assert_eq!(account.overdraft_limit(), money!(USD, 0));
+    assert_eq!(account.available(), money!(USD, 100));
+
+    let account = AccountBalance::new(money!(USD, 0)).unwrap();
+    assert_eq!(account.available(), money!(USD, 0));
+
+    let err = AccountBalance::new(money!(USD, -0.01)).unwrap_err();
+    assert!(
+        matches!(err, BalanceError::InsufficientFunds { missing } if missing == money!(USD, 0.01))
+    );
+}
+
+#[test]
+fn test_with_overdraft() {
+    let account = AccountBalance::with_overdraft(money!(USD, -50), money!(USD, 100)).unwrap();
+    assert_eq!(account.balance(), money!(USD, -50));
+    assert_eq!(account.overdraft_limit(), money!(USD, 100));
+    assert_eq!(account.available(), money!(USD, 50));
+
+    assert!(AccountBalance::with_overdraft(money!(USD, -100), money!(USD, 100)).is_ok());
+
+    let err = AccountBalance::with_overdraft(money!(USD, -150), money!(USD, 100)).unwrap_err();
+    assert!(
+        matches!(err, BalanceError::InsufficientFunds { missing } if missing == money!(USD, 50))
+    );
+
+    let err = AccountBalance::with_overdraft(money!(USD, 10), money!(USD, -1)).unwrap_err();
+    assert!(matches!(err, BalanceError::NegativeAmount(amount) if amount == money!(USD, -1)));
+
+    let max = Money::<USD>::from_decimal(Decimal::MAX);
+    let err = AccountBalance::with_overdraft(max, money!(USD, 1)).unwrap_err();
+    assert!(matches!(err, BalanceError::OverflowError));
+}
+
+#[test]
+fn test_credit() {
+    let mut account = AccountBalance::new(money!(USD, 10)).unwrap();
+    assert_eq!(account.credit(money!(USD, 5.5)).unwrap(), money!(USD, 15.5));
+    assert_eq!(account.credit(money!(USD, 0)).unwrap(), money!(USD, 15.5));
+
+    let err = account.credit(money!(USD, -1)).unwrap_err();
+    assert!(matches!(err, BalanceError::NegativeAmount(_)));
+    assert_eq!(account.balance(), money!(USD, 15.5));
+
+    let max = Money::<USD>::from_decimal(Decimal::MAX);
+    let err = account.credit(max).unwrap_err();
+    assert!(matches!(err, BalanceError::OverflowError));
+    assert_eq!(account.balance(), money!(USD, 15.5));
+}
+
+#[test]
+fn test_debit() {
+    let mut account = AccountBalance::new(money!(USD, 100)).unwrap();
+    assert_eq!(account.debit(money!(USD, 40)).unwrap(), money!(USD, 60));
+    assert_eq!(account.debit(money!(USD, 60)).unwrap(), money!(USD, 0));
+
+    let err = account.debit(money!(USD, 0.01)).unwrap_err();
+    assert!(
+        matches!(err, BalanceError::InsufficientFunds { missing } if missing == money!(USD, 0.01))
+    );
+    assert_eq!(account.balance(), money!(USD, 0));
+
+    let err = account.debit(money!(USD, -5)).unwrap_err();
+    assert!(matches!(err, BalanceError::NegativeAmount(_)));
+    assert_eq!(account.balance(), money!(USD, 0));
+}
+
+#[test]
+fn test_debit_overdraft() {
+    let mut account = AccountBalance::with_overdraft(money!(USD, 20), money!(USD, 50)).unwrap();
+    assert_eq!(account.debit(money!(USD, 60)).unwrap(), money!(USD, -40));
+    assert_eq!(account.available(), money!(USD, 10));
+
+    let err = account.debit(money!(USD, 25)).unwrap_err();
+    assert!(
+        matches!(err, BalanceError::InsufficientFunds { missing } if missing == money!(USD, 15))
+    );
+    assert_eq!(account.balance(), money!(USD, -40));
+
+    assert_eq!(account.credit(money!(USD, 100)).unwrap(), money!(USD, 60));
+    assert_eq!(account.available(), money!(USD, 110));
+}
+
+#[test]
+fn test_can_debit() {
+    let account = AccountBalance::with_overdraft(money!(USD, 20), money!(USD, 10)).unwrap();
+    assert!(account.can_debit(&money!(USD, 30)));
+    assert!(!account.can_debit(&money!(USD, 30.01)));
+    assert!(!account.can_debit(&money!(USD, -1)));
+}
+
+#[test]
+fn test_error_display() {
+    let err = BalanceError::InsufficientFunds {
+        missing: money!(USD, 15),
+    };
+    assert!(
+        err.to_string()
+            .starts_with("[MONEYLIB] insufficient funds: missing")
+    );
+    #[cfg(not(feature = "redact_debug"))]
+    assert_eq!(
+        format!("{:?}", err),
+        "InsufficientFunds { missing: Money(USD, 15) }"
+    );
+
+    let err = BalanceError::NegativeAmount(money!(USD, -1));
+    assert!(err.to_string().starts_with("[MONEYLIB] negative amount:"));
+
+    let err = BalanceError::<USD>::OverflowError;
+    assert_eq!(err.to_string(), "[MONEYLIB] got overflowed");
+}
+
+#[test]
+fn test_clone_eq_debug() {
+    let account = AccountBalance::with_overdraft(money!(USD, 20), money!(USD, 10)).unwrap();
+    assert_eq!(account.clone(), account);
+    #[cfg(not(feature = "redact_debug"))]
+    assert_eq!(
+        format!("{:?}", account),
+        "AccountBalance { balance: Money(USD, 20), overdraft_limit: Money(USD, 10) }"
+    );
+}
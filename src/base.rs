@@ -1,13 +1,11 @@
 use crate::Currency;
 use crate::Decimal;
 use crate::MoneyError;
-use crate::dec;
 use crate::fmt::format_with_separator;
 use crate::fmt::{CODE_FORMAT, CODE_FORMAT_MINOR, SYMBOL_FORMAT, SYMBOL_FORMAT_MINOR, format};
 use crate::split_alloc_ops::Split;
-use rust_decimal::MathematicalOps;
 use rust_decimal::RoundingStrategy as DecimalRoundingStrategy;
-use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use std::fmt::Debug;
 use std::ops::Neg;
 use std::str::FromStr;
@@ -151,17 +149,112 @@ pub trait BaseMoney<C: Currency>: Clone {
     #[inline]
     fn from_minor(minor_amount: i128) -> Result<Self, MoneyError> {
         Ok(Self::from_decimal(
-            Decimal::from_i128(minor_amount)
-                .ok_or(MoneyError::OverflowError)?
-                .checked_div(
-                    dec!(10)
-                        .checked_powu(C::MINOR_UNIT.into())
-                        .ok_or(MoneyError::OverflowError)?,
-                )
+            crate::decimal::from_scaled_i128(minor_amount, C::MINOR_UNIT.into())
                 .ok_or(MoneyError::OverflowError)?,
         ))
     }
 
+    /// Creates a new money from separate `major` units and `minor` subunits, e.g. `(12, 34)` is `12.34` for USD.
+    ///
+    /// For amounts delivered as separate fields, e.g. receipt data and fixed-format bank files.
+    /// The sign is taken from `major`, so `(-12, 34)` is `-12.34`, use [`from_minor`](Self::from_minor) for amounts
+    /// between -1 and 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::MinorUnitError`] if `minor` is not less than `10^minor_unit`, e.g. 100 for USD and 1 for JPY,
+    /// and [`MoneyError::OverflowError`] if the amount is too large for `Decimal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{Money, BaseMoney, macros::dec, iso::{BHD, JPY, USD}};
+    ///
+    /// assert_eq!(Money::<USD>::from_major_minor(12, 34).unwrap().amount(), dec!(12.34));
+    /// assert_eq!(Money::<USD>::from_major_minor(12, 5).unwrap().amount(), dec!(12.05));
+    /// assert_eq!(Money::<USD>::from_major_minor(-12, 34).unwrap().amount(), dec!(-12.34));
+    /// assert_eq!(Money::<BHD>::from_major_minor(1, 500).unwrap().amount(), dec!(1.500));
+    /// assert_eq!(Money::<JPY>::from_major_minor(1_000, 0).unwrap().amount(), dec!(1000));
+    ///
+    /// assert!(Money::<USD>::from_major_minor(12, 100).is_err());
+    /// assert!(Money::<JPY>::from_major_minor(1_000, 5).is_err());
+    /// ```
+    fn from_major_minor(major: i128, minor: u32) -> Result<Self, MoneyError> {
+        let scale = crate::decimal::pow10(C::MINOR_UNIT.into())
+            .and_then(|scale| scale.to_i128())
+            .ok_or(MoneyError::OverflowError)?;
+        let minor = i128::from(minor);
+        if minor >= scale {
+            return Err(MoneyError::MinorUnitError(
+                format!(
+                    "minor {} must be less than {} for {}'s minor unit of {}",
+                    minor,
+                    scale,
+                    C::CODE,
+                    C::MINOR_UNIT
+                )
+                .into(),
+            ));
+        }
+
+        let major_in_minor = major.checked_mul(scale).ok_or(MoneyError::OverflowError)?;
+        let minor_amount = if major.is_negative() {
+            major_in_minor.checked_sub(minor)
+        } else {
+            major_in_minor.checked_add(minor)
+        }
+        .ok_or(MoneyError::OverflowError)?;
+        Self::from_minor(minor_amount)
+    }
+
+    /// Creates a new money from string of digits with implied decimals, e.g. `"123456"` is `1234.56` for USD.
+    ///
+    /// The last `C::MINOR_UNIT` digits are the decimals, as in telecom CDRs and fixed-width legacy feeds.
+    /// Strings shorter than the minor unit are left-padded with zeros, e.g. `"5"` is `0.05` for USD.
+    /// Surrounding whitespace and a leading `-` are accepted, nothing else than ASCII digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ParseStrError`] if `digits` is empty or has non-digit characters,
+    /// [`MoneyError::InputTooLong`] if it's longer than parsers accept,
+    /// and [`MoneyError::OverflowError`] if it's too large for `Decimal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{Money, BaseMoney, macros::dec, iso::{BHD, JPY, USD}};
+    ///
+    /// assert_eq!(Money::<USD>::from_implied_decimal_str("123456").unwrap().amount(), dec!(1234.56));
+    /// assert_eq!(Money::<USD>::from_implied_decimal_str("-0005").unwrap().amount(), dec!(-0.05));
+    /// assert_eq!(Money::<BHD>::from_implied_decimal_str("123456").unwrap().amount(), dec!(123.456));
+    /// assert_eq!(Money::<JPY>::from_implied_decimal_str("123456").unwrap().amount(), dec!(123456));
+    ///
+    /// assert!(Money::<USD>::from_implied_decimal_str("1234.56").is_err());
+    /// assert!(Money::<USD>::from_implied_decimal_str("").is_err());
+    /// ```
+    fn from_implied_decimal_str(digits: &str) -> Result<Self, MoneyError> {
+        crate::parse::check_input_len(digits.as_bytes())?;
+        let trimmed = digits.trim();
+        let (unsigned, is_negative) = match trimmed.strip_prefix('-') {
+            Some(unsigned) => (unsigned, true),
+            None => (trimmed, false),
+        };
+        if unsigned.is_empty() || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(MoneyError::ParseStrError(
+                format!("implied decimal amount must be ASCII digits: {:?}", digits).into(),
+            ));
+        }
+
+        let mut amount =
+            Decimal::from_str_exact(unsigned).map_err(|_| MoneyError::OverflowError)?;
+        amount
+            .set_scale(C::MINOR_UNIT.into())
+            .map_err(|_| MoneyError::OverflowError)?;
+        amount.set_sign_negative(is_negative);
+
+        Ok(Self::from_decimal(amount))
+    }
+
     /// Rounds the money amount using bankers rounding rule to the scale of the currency's minor unit.
     ///
     /// # Examples
@@ -197,10 +290,168 @@ pub trait BaseMoney<C: Currency>: Clone {
     /// ```
     #[inline]
     fn round_with(self, decimal_points: u32, strategy: RoundingStrategy) -> Self {
-        Self::from_decimal(
-            self.amount()
-                .round_dp_with_strategy(decimal_points, strategy.into()),
-        )
+        Self::from_decimal(strategy.round_dp(self.amount(), decimal_points))
+    }
+
+    /// Rounds the money amount to a multiple of any `step` using the given strategy, e.g. `0.25`, `0.10` or `1000`.
+    ///
+    /// Useful for bucketing budgets or rounding payroll to quarter hours. For [`Money`](crate::Money), `step` should
+    /// be a multiple of the currency's minor unit, as the result is kept in the minor unit.
+    ///
+    /// Returns `None` if `step` is not positive, or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RoundingStrategy, money, macros::dec};
+    ///
+    /// let budget = money!(USD, 12_345.67);
+    /// assert_eq!(budget.round_to_nearest(dec!(1000), RoundingStrategy::HalfUp).unwrap().amount(), dec!(12_000));
+    /// assert_eq!(budget.round_to_nearest(dec!(1000), RoundingStrategy::Up).unwrap().amount(), dec!(13_000));
+    /// assert_eq!(budget.round_to_nearest(dec!(0.25), RoundingStrategy::HalfUp).unwrap().amount(), dec!(12_345.75));
+    ///
+    /// assert!(budget.round_to_nearest(dec!(-1), RoundingStrategy::HalfUp).is_none());
+    /// ```
+    fn round_to_nearest(&self, step: Decimal, strategy: RoundingStrategy) -> Option<Self> {
+        self.round_to_nearest_with_offset(step, Decimal::ZERO, strategy)
+    }
+
+    /// Rounds the money amount to a multiple of `step` plus `offset` using the given strategy,
+    /// e.g. to prices ending in `.99` with step `1` and offset `0.99`.
+    ///
+    /// Same as [`round_to_nearest`](Self::round_to_nearest) with the grid of results shifted by `offset`.
+    ///
+    /// Returns `None` if `step` is not positive, or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RoundingStrategy, money, macros::dec};
+    ///
+    /// let price = money!(USD, 12.40);
+    /// assert_eq!(
+    ///     price.round_to_nearest_with_offset(dec!(1), dec!(0.99), RoundingStrategy::HalfUp).unwrap().amount(),
+    ///     dec!(11.99)
+    /// );
+    /// assert_eq!(
+    ///     price.round_to_nearest_with_offset(dec!(1), dec!(0.99), RoundingStrategy::Up).unwrap().amount(),
+    ///     dec!(12.99)
+    /// );
+    /// assert_eq!(
+    ///     price.round_to_nearest_with_offset(dec!(5), dec!(-0.01), RoundingStrategy::HalfUp).unwrap().amount(),
+    ///     dec!(9.99)
+    /// );
+    /// ```
+    fn round_to_nearest_with_offset(
+        &self,
+        step: Decimal,
+        offset: Decimal,
+        strategy: RoundingStrategy,
+    ) -> Option<Self> {
+        if step <= Decimal::ZERO {
+            return None;
+        }
+        let steps = strategy.round_dp(self.amount().checked_sub(offset)?.checked_div(step)?, 0);
+        Some(Self::from_decimal(
+            steps.checked_mul(step)?.checked_add(offset)?,
+        ))
+    }
+
+    /// Rounds the money amount to a multiple of `step` using the given strategy, e.g. to `0.05` for cash totals in CHF.
+    ///
+    /// Same as [`round_to_nearest`](Self::round_to_nearest) with `step` of the smallest coin cash is paid with,
+    /// e.g. `0.05`, `0.10`, `1` or `5`.
+    ///
+    /// Returns `None` if `step` is not positive, or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RoundingStrategy, money, macros::dec};
+    ///
+    /// let total = money!(CHF, 12.33);
+    /// assert_eq!(total.round_cash(dec!(0.05), RoundingStrategy::HalfUp).unwrap().amount(), dec!(12.35));
+    /// assert_eq!(total.round_cash(dec!(0.05), RoundingStrategy::Floor).unwrap().amount(), dec!(12.30));
+    ///
+    /// let total = money!(HUF, -1237);
+    /// assert_eq!(total.round_cash(dec!(5), RoundingStrategy::HalfUp).unwrap().amount(), dec!(-1235));
+    ///
+    /// assert!(total.round_cash(dec!(0), RoundingStrategy::HalfUp).is_none());
+    /// ```
+    fn round_cash(&self, step: Decimal, strategy: RoundingStrategy) -> Option<Self> {
+        self.round_to_nearest(step, strategy)
+    }
+
+    /// Rounds the money amount to the currency's cash rounding step using the given strategy.
+    ///
+    /// Same as [`round_cash`](Self::round_cash) with [`CurrencyFormat::cash_rounding`](crate::CurrencyFormat::cash_rounding),
+    /// or rounds to the currency's minor unit if cash is paid in it.
+    ///
+    /// Returns `None` if the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RoundingStrategy, money, raw, macros::dec};
+    ///
+    /// assert_eq!(money!(CHF, 9.97).round_cash_default(RoundingStrategy::HalfUp).unwrap().amount(), dec!(9.95));
+    /// assert_eq!(money!(SEK, 99.50).round_cash_default(RoundingStrategy::HalfUp).unwrap().amount(), dec!(100));
+    /// assert_eq!(raw!(USD, 9.975).round_cash_default(RoundingStrategy::HalfUp).unwrap().amount(), dec!(9.98));
+    /// ```
+    fn round_cash_default(&self, strategy: RoundingStrategy) -> Option<Self> {
+        let step = <C as crate::CurrencyFormat>::cash_rounding()
+            .or_else(|| Decimal::try_new(1, C::MINOR_UNIT.into()).ok())?;
+        self.round_cash(step, strategy)
+    }
+
+    /// Same as [`round`](Self::round), also returning the rounding delta, `rounded - original`.
+    ///
+    /// The delta is positive if rounding added to the amount and negative if it dropped from it,
+    /// so summing deltas gives the total rounding adjustment, e.g. for billing statement disclosures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, raw, macros::dec};
+    ///
+    /// let (rounded, delta) = raw!(USD, 10.235).round_returning_delta();
+    /// assert_eq!(rounded.amount(), dec!(10.24));
+    /// assert_eq!(delta, dec!(0.005));
+    /// ```
+    #[inline]
+    fn round_returning_delta(self) -> (Self, Decimal) {
+        let original = self.amount();
+        let rounded = self.round();
+        let delta = rounded.amount() - original;
+        (rounded, delta)
+    }
+
+    /// Same as [`round_with`](Self::round_with), also returning the rounding delta, `rounded - original`.
+    ///
+    /// See [`round_returning_delta`](Self::round_returning_delta).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RoundingStrategy, money, macros::dec};
+    ///
+    /// let bills = [money!(USD, 10.40), money!(USD, 20.75), money!(USD, 5.50)];
+    /// let total_adjustment: moneylib::Decimal = bills
+    ///     .into_iter()
+    ///     .map(|bill| bill.round_with_returning_delta(0, RoundingStrategy::HalfUp).1)
+    ///     .sum();
+    /// assert_eq!(total_adjustment, dec!(0.35));
+    /// ```
+    #[inline]
+    fn round_with_returning_delta(
+        self,
+        decimal_points: u32,
+        strategy: RoundingStrategy,
+    ) -> (Self, Decimal) {
+        let original = self.amount();
+        let rounded = self.round_with(decimal_points, strategy);
+        let delta = rounded.amount() - original;
+        (rounded, delta)
     }
 
     /// Truncates the money amount removing the fraction.
@@ -237,6 +488,120 @@ pub trait BaseMoney<C: Currency>: Clone {
         Self::from_decimal(self.amount().trunc_with_scale(scale))
     }
 
+    /// Returns the whole part of money, same as [`truncate`](Self::truncate), so `trunc() + fract()` is the money itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, money};
+    ///
+    /// assert_eq!(money!(USD, 12.34).trunc(), money!(USD, 12));
+    /// assert_eq!(money!(USD, -12.34).trunc(), money!(USD, -12));
+    /// ```
+    #[inline]
+    fn trunc(&self) -> Self {
+        self.truncate()
+    }
+
+    /// Returns the fractional part of money as money, with the sign of the money, e.g. `0.34` of `12.34`.
+    ///
+    /// Same as [`fraction`](Self::fraction) as money rather than `Decimal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, money, raw};
+    ///
+    /// assert_eq!(money!(USD, 12.34).fract(), money!(USD, 0.34));
+    /// assert_eq!(money!(USD, -12.34).fract(), money!(USD, -0.34));
+    /// assert_eq!(raw!(USD, 12.3456).fract(), raw!(USD, 0.3456));
+    /// ```
+    #[inline]
+    fn fract(&self) -> Self {
+        Self::from_decimal(self.amount().fract())
+    }
+
+    /// Returns money as whole major units and the remainder in minor units, e.g. `(12, 34)` of `12.34` USD.
+    ///
+    /// Amount is rounded into minor unit first with bankers rounding, like [`minor_amount`](Self::minor_amount).
+    /// The sign is carried by major units, so check [`is_negative`](Self::is_negative) for amounts between -1 and 0,
+    /// the inverse of [`from_major_minor`](Self::from_major_minor).
+    ///
+    /// Returns `None` if the minor remainder doesn't fit `u32`, i.e. minor unit is more than 9.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, money, raw};
+    ///
+    /// assert_eq!(money!(USD, 12.34).into_parts(), Some((12, 34)));
+    /// assert_eq!(money!(USD, 12.05).into_parts(), Some((12, 5)));
+    /// assert_eq!(money!(USD, -12.34).into_parts(), Some((-12, 34)));
+    /// assert_eq!(money!(JPY, 1_234).into_parts(), Some((1_234, 0)));
+    /// assert_eq!(raw!(USD, 12.999).into_parts(), Some((13, 0)));
+    ///
+    /// let negative_cents = money!(USD, -0.34);
+    /// assert_eq!(negative_cents.into_parts(), Some((0, 34)));
+    /// assert!(negative_cents.is_negative());
+    /// ```
+    fn into_parts(self) -> Option<(i128, u32)> {
+        let rounded = self.amount().round_dp(C::MINOR_UNIT.into());
+        let major = rounded.trunc();
+        let minor = rounded
+            .checked_sub(major)?
+            .abs()
+            .checked_mul(crate::decimal::pow10(C::MINOR_UNIT.into())?)?
+            .to_u32()?;
+        Some((major.to_i128()?, minor))
+    }
+
+    /// Applies `f` to the amount and returns the result as money of the same currency, re-rounded like
+    /// [`from_decimal`](Self::from_decimal), for custom `Decimal` math without unwrapping and rewrapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, money, raw, macros::dec};
+    ///
+    /// assert_eq!(money!(USD, 10).map_amount(|d| d / dec!(3)), money!(USD, 3.33));
+    /// assert_eq!(money!(USD, 19.99).map_amount(|d| d.floor()), money!(USD, 19));
+    /// assert_eq!(raw!(USD, 10).map_amount(|d| d / dec!(8)), raw!(USD, 1.25));
+    /// ```
+    #[inline]
+    fn map_amount<F>(&self, f: F) -> Self
+    where
+        F: FnOnce(Decimal) -> Decimal,
+    {
+        Self::from_decimal(f(self.amount()))
+    }
+
+    /// Applies fallible `f` to the amount like [`map_amount`](Self::map_amount), e.g. with `Decimal`'s `checked_*`
+    /// methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::OverflowError`] if `f` returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, money, macros::dec, iso::USD};
+    ///
+    /// let fee = money!(USD, 100).try_map_amount(|d| d.checked_mul(dec!(0.029))?.checked_add(dec!(0.30)));
+    /// assert_eq!(fee.unwrap(), money!(USD, 3.20));
+    ///
+    /// assert!(Money::<USD>::MAX.try_map_amount(|d| d.checked_mul(dec!(2))).is_err());
+    /// ```
+    #[inline]
+    fn try_map_amount<F>(&self, f: F) -> Result<Self, MoneyError>
+    where
+        F: FnOnce(Decimal) -> Option<Decimal>,
+    {
+        f(self.amount())
+            .map(Self::from_decimal)
+            .ok_or(MoneyError::OverflowError)
+    }
+
     /// Returns the full name of the currency.
     ///
     /// # Examples
@@ -500,6 +865,37 @@ pub trait BaseMoney<C: Currency>: Clone {
         self.amount().scale()
     }
 
+    /// Returns money with the same value in its canonical representation, without trailing zeros and negative zero.
+    ///
+    /// Amounts equal by value may differ in scale, e.g. `1.5` and `1.50`, which shows in [`scale`](Self::scale),
+    /// [`mantissa`](Self::mantissa), `Display` and serialized forms. Normalizing makes them identical.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RawMoney, money, iso::USD, dec};
+    ///
+    /// let raw = RawMoney::<USD>::from_decimal(dec!(1234.5000));
+    /// assert_eq!(raw.scale(), 4);
+    /// assert_eq!(raw.mantissa(), 12345000);
+    ///
+    /// let normalized = raw.normalize();
+    /// assert_eq!(normalized, raw);
+    /// assert_eq!(normalized.scale(), 1);
+    /// assert_eq!(normalized.mantissa(), 12345);
+    ///
+    /// let money = money!(USD, 100.00);
+    /// assert_eq!(money.normalize().scale(), 0);
+    ///
+    /// let negative_zero = RawMoney::<USD>::from_decimal(-dec!(0.00));
+    /// assert!(negative_zero.amount().is_sign_negative());
+    /// assert!(!negative_zero.normalize().amount().is_sign_negative());
+    /// ```
+    #[inline]
+    fn normalize(&self) -> Self {
+        Self::from_decimal(self.amount().normalize())
+    }
+
     /// Formats money with currency code along with thousands and decimal separators.
     ///
     /// This uses currency's locale separators.
@@ -701,6 +1097,170 @@ pub trait BaseOps<C: Currency>: BaseMoney<C> + Neg<Output = Self> {
         Self::from_decimal(self.amount().abs())
     }
 
+    /// Returns the non-negative difference between this money and `other`, e.g. for reconciliation tolerances.
+    ///
+    /// Computed and rounded once, instead of subtracting then taking absolute value.
+    /// Returns `None` if the difference overflows, e.g. between [`Decimal::MAX`] and [`Decimal::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseOps, Money, money, raw, iso::USD};
+    ///
+    /// assert_eq!(money!(USD, 100).checked_abs_diff(money!(USD, 120.50)), Some(money!(USD, 20.50)));
+    /// assert_eq!(money!(USD, 120.50).checked_abs_diff(money!(USD, 100)), Some(money!(USD, 20.50)));
+    /// assert_eq!(money!(USD, -10).checked_abs_diff(money!(USD, 10)), Some(money!(USD, 20)));
+    /// assert_eq!(Money::<USD>::MAX.checked_abs_diff(Money::MIN), None);
+    ///
+    /// assert_eq!(raw!(USD, 0.125).checked_abs_diff(raw!(USD, 0.1)), Some(raw!(USD, 0.025)));
+    /// ```
+    #[inline]
+    fn checked_abs_diff(&self, other: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.amount(), other.amount());
+        let diff = if lhs >= rhs {
+            lhs.checked_sub(rhs)?
+        } else {
+            rhs.checked_sub(lhs)?
+        };
+        Some(Self::from_decimal(diff))
+    }
+
+    /// Restricts this money into `[min, max]`, accepting bounds either as money of the same currency or as amounts.
+    ///
+    /// Unlike [`Ord::clamp`] which only takes `Self` and panics on inverted bounds, returns `None` if `min > max`
+    /// or a bound cannot be converted into `Decimal`, e.g. `f64::NAN`. A bound returned as the result is rounded
+    /// like [`from_decimal`](BaseMoney::from_decimal).
+    ///
+    /// # Argument
+    /// - `min`/`max: impl Amount<C>` accepts: `BaseMoney<C>`(`Money<C>`/`RawMoney<C>`), `Decimal`, `f64`, `i32`, `i64`, `i128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseOps, money, macros::dec};
+    ///
+    /// let budget = money!(USD, 1_250);
+    /// assert_eq!(budget.checked_clamp(money!(USD, 500), money!(USD, 1_000)), Some(money!(USD, 1_000)));
+    /// assert_eq!(budget.checked_clamp(dec!(500), dec!(2_000)), Some(budget));
+    /// assert_eq!(budget.checked_clamp(1_500, 2_000), Some(money!(USD, 1_500)));
+    ///
+    /// assert_eq!(budget.checked_clamp(2_000, 500), None);
+    /// ```
+    fn checked_clamp<B>(&self, min: B, max: B) -> Option<Self>
+    where
+        B: Amount<C>,
+    {
+        let (min, max) = (min.get_decimal()?, max.get_decimal()?);
+        if min > max {
+            return None;
+        }
+        Some(Self::from_decimal(self.amount().clamp(min, max)))
+    }
+
+    /// Returns the proportion of this money to `other` as `Decimal`, without rounding into currency's minor unit.
+    ///
+    /// For weights, progress percentages and allocation keys, e.g. `25 / 100 = 0.25`.
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::DivisionByZeroError`] if `other` is zero.
+    /// - [`MoneyError::OverflowError`] if the division overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseOps, money, macros::dec};
+    ///
+    /// assert_eq!(money!(USD, 25).ratio_to(money!(USD, 100)).unwrap(), dec!(0.25));
+    /// assert_eq!(money!(USD, 1).ratio_to(money!(USD, 3)).unwrap().round_dp(4), dec!(0.3333));
+    /// assert_eq!(money!(USD, 150).ratio_to(money!(USD, -100)).unwrap(), dec!(-1.5));
+    ///
+    /// assert!(money!(USD, 1).ratio_to(money!(USD, 0)).is_err());
+    /// ```
+    fn ratio_to(&self, other: Self) -> Result<Decimal, MoneyError> {
+        let divisor = other.amount();
+        if divisor.is_zero() {
+            return Err(MoneyError::DivisionByZeroError);
+        }
+        self.amount()
+            .checked_div(divisor)
+            .ok_or(MoneyError::OverflowError)
+    }
+
+    /// Multiplies this money by `factor` then adds `addend` at full precision, rounding only once at the end.
+    ///
+    /// Computing `self * factor + addend` in one step avoids rounding the product first, e.g. for tax-and-total
+    /// calculations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::OverflowError`] if the multiplication or addition overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseOps, money, macros::dec};
+    ///
+    /// // 10% tax on 0.125 plus the price: 0.0125 + 0.125 = 0.1375, rounded once into 0.138
+    /// let price = money!(BHD, 0.125);
+    /// assert_eq!(price.mul_add(dec!(0.1), price).unwrap(), money!(BHD, 0.138));
+    ///
+    /// // rounding the tax first loses a fils: 0.012 + 0.125 = 0.137
+    /// let tax = price.checked_mul(dec!(0.1)).unwrap();
+    /// assert_eq!(tax.checked_add(price).unwrap(), money!(BHD, 0.137));
+    /// ```
+    fn mul_add(&self, factor: Decimal, addend: Self) -> Result<Self, MoneyError> {
+        self.amount()
+            .checked_mul(factor)
+            .and_then(|product| product.checked_add(addend.amount()))
+            .map(Self::from_decimal)
+            .ok_or(MoneyError::OverflowError)
+    }
+
+    /// Divides this money by `divisor` into the per-part amount in currency's minor unit and the leftover,
+    /// such that `quotient * divisor + remainder` is exactly this money.
+    ///
+    /// The quotient is truncated toward zero and the remainder keeps the sign of this money, like `/` and `%` on integers.
+    /// For remainder distributed across parts, use [`split`](Self::split) instead.
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::DivisionByZeroError`] if `divisor` is zero.
+    /// - [`MoneyError::OverflowError`] if the division overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseOps, money};
+    ///
+    /// // 3 installments of 33.33 leaving 0.01
+    /// assert_eq!(money!(USD, 100).div_rem(3).unwrap(), (money!(USD, 33.33), money!(USD, 0.01)));
+    /// assert_eq!(money!(USD, -100).div_rem(3).unwrap(), (money!(USD, -33.33), money!(USD, -0.01)));
+    /// assert_eq!(money!(JPY, 1000).div_rem(7).unwrap(), (money!(JPY, 142), money!(JPY, 6)));
+    ///
+    /// assert!(money!(USD, 100).div_rem(0).is_err());
+    /// ```
+    fn div_rem(&self, divisor: i64) -> Result<(Self, Self), MoneyError> {
+        if divisor == 0 {
+            return Err(MoneyError::DivisionByZeroError);
+        }
+        let divisor = Decimal::from(divisor);
+        let unit = Decimal::new(1, crate::decimal::minor_scale(C::MINOR_UNIT));
+        let amount = self.amount();
+
+        // remainder of division by `divisor` units is exact, leaving a multiple of `divisor` in minor unit.
+        let remainder = divisor
+            .checked_mul(unit)
+            .and_then(|units| amount.checked_rem(units))
+            .ok_or(MoneyError::OverflowError)?;
+        let quotient = amount
+            .checked_sub(remainder)
+            .and_then(|whole| whole.checked_div(divisor))
+            .ok_or(MoneyError::OverflowError)?;
+
+        Ok((Self::from_decimal(quotient), Self::from_decimal(remainder)))
+    }
+
     /// Adds another money value to this one.
     ///
     /// # Argument
@@ -804,7 +1364,12 @@ pub trait BaseOps<C: Currency>: BaseMoney<C> + Neg<Output = Self> {
         ))
     }
 
-    /// Get remainder of self % rhs.
+    /// Get remainder of self % rhs, e.g. how much is left after whole units of a coupon denomination.
+    ///
+    /// Returns `None` if `rhs` is zero or can't be converted into `Decimal`.
+    ///
+    /// # Argument
+    /// - `rhs: impl DecimalNumber` accepts: `Decimal`, `f64`, `i32`, `i64`, `i128`.
     ///
     /// # Examples
     /// ```
@@ -813,6 +1378,11 @@ pub trait BaseOps<C: Currency>: BaseMoney<C> + Neg<Output = Self> {
     /// let money = money!(USD, 100);
     /// let rem = money.checked_rem(3).unwrap();
     /// assert_eq!(rem.amount(), dec!(1));
+    ///
+    /// // 97.50 paid with 25.00 coupons leaves 22.50
+    /// assert_eq!(money!(USD, 97.50).checked_rem(dec!(25)).unwrap(), money!(USD, 22.50));
+    ///
+    /// assert!(money.checked_rem(0).is_none());
     /// ```
     fn checked_rem<RHS>(&self, rhs: RHS) -> Option<Self>
     where
@@ -823,20 +1393,101 @@ pub trait BaseOps<C: Currency>: BaseMoney<C> + Neg<Output = Self> {
         ))
     }
 
-    /// Split money without losing a single penny.
+    /// Adds another money value to this one, clamping at [`Decimal::MAX`]/[`Decimal::MIN`] instead of overflowing.
     ///
-    /// `P` is the number of split or ratios, supporting `u32` or `impl AsRef<[D]>` respectively.
+    /// Returns `None` only if `rhs` cannot be converted into `Decimal`, e.g. `f64::NAN`.
     ///
-    /// `R` is return type supporting `Option<(M, M)>` or `Option<Vec<M>>`.
+    /// # Argument
+    /// - `rhs: impl Amount<C>` accepts: `BaseMoney<C>`(`Money<C>`/`RawMoney<C>`), `Decimal`, `f64`, `i32`, `i64`, `i128`.
     ///
-    /// 3 modes:
-    /// - `P: u32` -> `R: (M, M)` => split into `P` equal parts(0) with a remainder(1) if any.
-    /// - `P: u32` -> `R: Vec<M>` => split into vector of `P` parts where remainder(if any) is distributed equally across parts.
-    /// - `P: impl AsRef<[D]>` -> `R: Vec<M>` => split by ratios where remainder(if any) is distributed equally across parts.
+    /// # Examples
     ///
-    /// Where:
-    /// - `M`: `BaseMoney<C>`
-    /// - `D`: `DecimalNumber`
+    /// ```
+    /// use moneylib::{Money, BaseMoney, BaseOps, Decimal, money, dec, iso::USD};
+    ///
+    /// let m = money!(USD, 100);
+    /// assert_eq!(m.saturating_add(money!(USD, 50)).unwrap().amount(), dec!(150));
+    ///
+    /// let max = Money::<USD>::from_decimal(Decimal::MAX);
+    /// assert_eq!(max.saturating_add(m).unwrap().amount(), Decimal::MAX);
+    /// ```
+    fn saturating_add<RHS>(&self, rhs: RHS) -> Option<Self>
+    where
+        RHS: Amount<C>,
+    {
+        Some(Self::from_decimal(
+            self.amount().saturating_add(rhs.get_decimal()?),
+        ))
+    }
+
+    /// Subtracts another money value from this one, clamping at [`Decimal::MAX`]/[`Decimal::MIN`] instead of overflowing.
+    ///
+    /// Returns `None` only if `rhs` cannot be converted into `Decimal`, e.g. `f64::NAN`.
+    ///
+    /// # Argument
+    /// - `rhs: impl Amount<C>` accepts: `BaseMoney<C>`(`Money<C>`/`RawMoney<C>`), `Decimal`, `f64`, `i32`, `i64`, `i128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{Money, BaseMoney, BaseOps, Decimal, money, dec, iso::USD};
+    ///
+    /// let m = money!(USD, 100);
+    /// assert_eq!(m.saturating_sub(money!(USD, 150)).unwrap().amount(), dec!(-50));
+    ///
+    /// let min = Money::<USD>::from_decimal(Decimal::MIN);
+    /// assert_eq!(min.saturating_sub(m).unwrap().amount(), Decimal::MIN);
+    /// ```
+    fn saturating_sub<RHS>(&self, rhs: RHS) -> Option<Self>
+    where
+        RHS: Amount<C>,
+    {
+        Some(Self::from_decimal(
+            self.amount().saturating_sub(rhs.get_decimal()?),
+        ))
+    }
+
+    /// Multiplies this money value by another value, clamping at [`Decimal::MAX`]/[`Decimal::MIN`] instead of overflowing.
+    ///
+    /// Returns `None` only if `rhs` cannot be converted into `Decimal`, e.g. `f64::NAN`.
+    ///
+    /// # Argument
+    /// - `rhs: impl DecimalNumber` accepts: `Decimal`, `f64`, `i32`, `i64`, `i128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{Money, BaseMoney, BaseOps, Decimal, money, dec, iso::USD};
+    ///
+    /// let m = money!(USD, 10);
+    /// assert_eq!(m.saturating_mul(3).unwrap().amount(), dec!(30));
+    ///
+    /// let big = Money::<USD>::from_decimal(Decimal::MAX);
+    /// assert_eq!(big.saturating_mul(-2).unwrap().amount(), Decimal::MIN);
+    /// ```
+    fn saturating_mul<RHS>(&self, rhs: RHS) -> Option<Self>
+    where
+        RHS: DecimalNumber,
+    {
+        Some(Self::from_decimal(
+            self.amount().saturating_mul(rhs.get_decimal()?),
+        ))
+    }
+
+    /// Split money without losing a single penny.
+    ///
+    /// `P` is the number of split or ratios, supporting `u32` or `impl AsRef<[D]>` respectively.
+    ///
+    /// `R` is return type supporting `Option<(M, M)>` or `Option<Vec<M>>`.
+    ///
+    /// 3 modes:
+    /// - `P: u32` -> `R: (M, M)` => split into `P` equal parts(0) with a remainder(1) if any.
+    /// - `P: u32` -> `R: Vec<M>` => split into vector of `P` parts where remainder(if any) is distributed equally across parts.
+    /// - `P: impl AsRef<[D]>` -> `R: Vec<M>` => split by ratios where remainder(if any) is distributed equally across parts.
+    ///
+    /// Where:
+    /// - `M`: `BaseMoney<C>`
+    /// - `D`: `DecimalNumber`
     ///
     /// # Examples
     /// ```rust
@@ -1139,6 +1790,9 @@ impl DecimalNumber for i128 {
 /// Different rounding strategies can produce different results when rounding values that fall
 /// exactly between two possible rounded values (e.g., 2.5 rounded to one decimal place).
 ///
+/// Rounding to steps, e.g. Swedish rounding to `0.05` cash, is done by [`BaseMoney::round_to_nearest`] and
+/// [`BaseMoney::round_cash`] with any of these strategies. New strategies may be added, so matches need a wildcard arm.
+///
 /// # Examples
 ///
 /// ```
@@ -1157,6 +1811,7 @@ impl DecimalNumber for i128 {
 /// assert_eq!(half_up.amount(), dec!(3));  // Always rounds up at halfway
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
 pub enum RoundingStrategy {
     /// Banker's Rounding (Round Half to Even).
     ///
@@ -1231,9 +1886,10 @@ pub enum RoundingStrategy {
     /// ```
     HalfDown,
 
-    /// Rounds away from zero (toward positive/negative infinity).
+    /// Rounds away from zero.
     ///
     /// Always rounds to the next number away from zero, regardless of the fractional part.
+    /// Negative values round toward negative infinity, use [`Up`](Self::Up) to round toward positive infinity.
     ///
     /// # Examples
     ///
@@ -1257,6 +1913,7 @@ pub enum RoundingStrategy {
     /// Rounds toward zero (truncates).
     ///
     /// Always rounds to the next number closer to zero, effectively truncating the decimal part.
+    /// Negative values round toward positive infinity, use [`Down`](Self::Down) to round toward negative infinity.
     ///
     /// # Examples
     ///
@@ -1276,6 +1933,48 @@ pub enum RoundingStrategy {
     /// assert_eq!(rounded.amount(), dec!(-2));
     /// ```
     Floor,
+
+    /// Rounds toward positive infinity.
+    ///
+    /// Unlike [`Ceil`](Self::Ceil), negative values round toward zero, e.g. for amounts owed to a customer
+    /// which must never be understated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RoundingStrategy, raw, macros::dec};
+    ///
+    /// // 2.1 rounds to 3
+    /// assert_eq!(raw!(USD, 2.1).round_with(0, RoundingStrategy::Up).amount(), dec!(3));
+    ///
+    /// // -2.9 rounds to -2
+    /// assert_eq!(raw!(USD, -2.9).round_with(0, RoundingStrategy::Up).amount(), dec!(-2));
+    /// ```
+    Up,
+
+    /// Rounds toward negative infinity.
+    ///
+    /// Unlike [`Floor`](Self::Floor), negative values round away from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RoundingStrategy, raw, macros::dec};
+    ///
+    /// // 2.9 rounds to 2
+    /// assert_eq!(raw!(USD, 2.9).round_with(0, RoundingStrategy::Down).amount(), dec!(2));
+    ///
+    /// // -2.1 rounds to -3
+    /// assert_eq!(raw!(USD, -2.1).round_with(0, RoundingStrategy::Down).amount(), dec!(-3));
+    /// ```
+    Down,
+}
+
+impl RoundingStrategy {
+    /// Returns `amount` rounded into `decimal_points` with this strategy.
+    pub(crate) fn round_dp(self, amount: Decimal, decimal_points: u32) -> Decimal {
+        amount.round_dp_with_strategy(decimal_points, self.into())
+    }
 }
 
 impl From<RoundingStrategy> for DecimalRoundingStrategy {
@@ -1286,6 +1985,8 @@ impl From<RoundingStrategy> for DecimalRoundingStrategy {
             RoundingStrategy::HalfDown => DecimalRoundingStrategy::MidpointTowardZero,
             RoundingStrategy::Ceil => DecimalRoundingStrategy::AwayFromZero,
             RoundingStrategy::Floor => DecimalRoundingStrategy::ToZero,
+            RoundingStrategy::Up => DecimalRoundingStrategy::ToPositiveInfinity,
+            RoundingStrategy::Down => DecimalRoundingStrategy::ToNegativeInfinity,
         }
     }
 }
@@ -1300,6 +2001,9 @@ impl From<RoundingStrategy> for DecimalRoundingStrategy {
 /// - Code format: `"<CODE> <AMOUNT>"` — e.g. `"USD 1,234.56"`
 /// - Symbol format: `"<SYMBOL><AMOUNT>"` — e.g. `"$1,234.56"` or `"-$1,234.56"`
 ///
+/// Inputs longer than 256 bytes or with more than 64 digits are rejected with [`MoneyError::InputTooLong`] before
+/// parsing, by every parser including [`FromStr`](std::str::FromStr), so untrusted inputs fail fast.
+///
 /// # Examples
 ///
 /// ```
@@ -1321,6 +2025,9 @@ pub trait MoneyParser<C: Currency>: BaseMoney<C> {
     /// may use `thousand_separator` to group digits and `decimal_separator` to separate the
     /// integer and fractional parts.
     ///
+    /// Negative amounts have either `-` before the amount, or parentheses around the amount or the whole string,
+    /// e.g. `"USD (1,234.56)"` of accounting exports.
+    ///
     /// # Arguments
     ///
     /// * `amount_str` - Input string in `"<CODE> <AMOUNT>"` format (e.g. `"USD 1,234.56"`)
@@ -1368,7 +2075,8 @@ pub trait MoneyParser<C: Currency>: BaseMoney<C> {
     /// The `<SYMBOL>` must match the currency's symbol (e.g. `"$"` for USD) and the `<AMOUNT>`
     /// may use `thousand_separator` to group digits and `decimal_separator` to separate the
     /// integer and fractional parts. Negative amounts may be prefixed with `"-"` before the
-    /// symbol (e.g. `"-$1,234.56"`).
+    /// symbol (e.g. `"-$1,234.56"`), or have parentheses around the amount or the whole string
+    /// (e.g. `"($1,234.56)"`) of accounting exports.
     ///
     /// # Arguments
     ///
@@ -1412,6 +2120,89 @@ pub trait MoneyParser<C: Currency>: BaseMoney<C> {
         Ok(Self::from_decimal(amount))
     }
 
+    /// Parse money from a string in `"<CODE> <AMOUNT>"` format with explicit separators and digit groups.
+    ///
+    /// Same as [`Self::from_str_code_with`], except digits between `thousand_separator` must be grouped by `grouping`,
+    /// e.g. [`Grouping::INDIAN`](crate::Grouping::INDIAN) for `"INR 1,23,456.78"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatchError`] if the code in the string does not match
+    /// the expected currency. Returns [`MoneyError::ParseStrError`] for any other malformed input,
+    /// including digit groups not matching `grouping`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Grouping, Money, MoneyParser, macros::dec, iso::{CNY, INR}};
+    ///
+    /// let m = Money::<INR>::from_str_code_with_grouping("INR 1,23,456.78", ",", ".", Grouping::INDIAN).unwrap();
+    /// assert_eq!(m.amount(), dec!(123456.78));
+    ///
+    /// let m = Money::<CNY>::from_str_code_with_grouping("CNY 12,3456.78", ",", ".", Grouping::MYRIADS).unwrap();
+    /// assert_eq!(m.amount(), dec!(123456.78));
+    ///
+    /// assert!(Money::<INR>::from_str_code_with_grouping("INR 123,456.78", ",", ".", Grouping::INDIAN).is_err());
+    /// ```
+    fn from_str_code_with_grouping(
+        money_str: &str,
+        thousand_separator: &str,
+        decimal_separator: &str,
+        grouping: crate::Grouping,
+    ) -> Result<Self, MoneyError> {
+        let amount = Decimal::from_str(&crate::parse::parse_str_code_with(
+            money_str,
+            C::CODE,
+            thousand_separator,
+            decimal_separator,
+            grouping,
+        )?)
+        .map_err(|err| {
+            MoneyError::ParseStrError(format!("failed parsing {} into decimal", err).into())
+        })?;
+
+        Ok(Self::from_decimal(amount))
+    }
+
+    /// Parse money from a string in `"<SYMBOL><AMOUNT>"` format with explicit separators and digit groups.
+    ///
+    /// Same as [`Self::from_str_symbol_with`], except digits between `thousand_separator` must be grouped by `grouping`,
+    /// e.g. [`Grouping::INDIAN`](crate::Grouping::INDIAN) for `"₹1,23,456.78"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatchError`] if the symbol in the string does not match
+    /// the expected currency. Returns [`MoneyError::ParseStrError`] for any other malformed input,
+    /// including digit groups not matching `grouping`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Grouping, Money, MoneyParser, macros::dec, iso::INR};
+    ///
+    /// let m = Money::<INR>::from_str_symbol_with_grouping("-₹12,34,567.89", ",", ".", Grouping::INDIAN).unwrap();
+    /// assert_eq!(m.amount(), dec!(-1234567.89));
+    /// ```
+    fn from_str_symbol_with_grouping(
+        money_str: &str,
+        thousand_separator: &str,
+        decimal_separator: &str,
+        grouping: crate::Grouping,
+    ) -> Result<Self, MoneyError> {
+        let amount = Decimal::from_str(&crate::parse::parse_str_symbol_with(
+            money_str,
+            C::SYMBOL,
+            thousand_separator,
+            decimal_separator,
+            grouping,
+        )?)
+        .map_err(|err| {
+            MoneyError::ParseStrError(format!("failed parsing {} into decimal", err).into())
+        })?;
+
+        Ok(Self::from_decimal(amount))
+    }
+
     /// Parse money from a string in `"<CODE> <AMOUNT>"` format using the currency's locale separators.
     ///
     /// This is a convenience wrapper around [`Self::from_str_code_with`] that automatically
@@ -1436,6 +2227,10 @@ pub trait MoneyParser<C: Currency>: BaseMoney<C> {
     ///
     /// // Negative amount
     /// let m = Money::<USD>::from_str_code("USD -1,234.56").unwrap();
+    ///
+    /// // Negative amount in parentheses, as in accounting exports
+    /// assert_eq!(Money::<USD>::from_str_code("USD (1,234.56)").unwrap(), m);
+    /// assert_eq!(Money::<USD>::from_str_code("(USD 1,234.56)").unwrap(), m);
     /// ```
     fn from_str_code(money_str: &str) -> Result<Self, MoneyError> {
         let amount = Decimal::from_str(&crate::parse::parse_str_code::<C>(
@@ -1474,6 +2269,10 @@ pub trait MoneyParser<C: Currency>: BaseMoney<C> {
     ///
     /// // Negative amount
     /// let m = Money::<USD>::from_str_symbol("-$1,234.56").unwrap();
+    ///
+    /// // Negative amount in parentheses, as in accounting exports
+    /// assert_eq!(Money::<USD>::from_str_symbol("($1,234.56)").unwrap(), m);
+    /// assert_eq!(Money::<USD>::from_str_symbol("$(1,234.56)").unwrap(), m);
     /// ```
     fn from_str_symbol(money_str: &str) -> Result<Self, MoneyError> {
         let amount = Decimal::from_str(&crate::parse::parse_str_symbol::<C>(
@@ -1487,6 +2286,216 @@ pub trait MoneyParser<C: Currency>: BaseMoney<C> {
 
         Ok(Self::from_decimal(amount))
     }
+
+    /// Parse money from a string formatted with `format_str` with explicit separators.
+    ///
+    /// This is the reverse of [`MoneyFormatter::format_with_separator`], format symbols are the same:
+    /// - 'a': amount, may use `thousand_separator` and `decimal_separator`
+    /// - 'c': currency code, must match the currency's code
+    /// - 's': currency symbol, must match the currency's symbol
+    /// - 'm': minor symbol, the amount is parsed as minor amount
+    /// - 'n': optional negative sign (-)
+    /// - 'p': optional plus sign (+)
+    ///
+    /// Escaped symbols, literal blocks and any other characters must appear as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `money_str` - Input string, e.g. `"1,234.56 USD"`
+    /// * `format_str` - Format of the input, e.g. `"a c"`
+    /// * `thousand_separator` - Character(s) used to group digits (e.g. `","` or `"."`)
+    /// * `decimal_separator` - Character(s) separating integer and fractional parts (e.g. `"."` or `","`)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatchError`] if the code or symbol in the string does not match
+    /// the expected currency. Returns [`MoneyError::ParseStrError`] for any other malformed input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyParser, macros::dec, iso::EUR};
+    ///
+    /// let m = Money::<EUR>::from_str_format_with("1,234.56 EUR", "a c", ",", ".").unwrap();
+    /// assert_eq!(m.amount(), dec!(1234.56));
+    ///
+    /// let m = Money::<EUR>::from_str_format_with("(EUR) -1.234,56", "(c) na", ".", ",").unwrap();
+    /// assert_eq!(m.amount(), dec!(-1234.56));
+    /// ```
+    fn from_str_format_with(
+        money_str: &str,
+        format_str: &str,
+        thousand_separator: &str,
+        decimal_separator: &str,
+    ) -> Result<Self, MoneyError> {
+        let amount = crate::parse::parse_str_format::<C>(
+            money_str,
+            format_str,
+            thousand_separator,
+            decimal_separator,
+        )?;
+
+        Ok(Self::from_decimal(amount))
+    }
+
+    /// Parse money from a string formatted with `format_str` using the currency's locale separators.
+    ///
+    /// This is a convenience wrapper around [`Self::from_str_format_with`] that automatically
+    /// uses [`Currency::THOUSAND_SEPARATOR`] and [`Currency::DECIMAL_SEPARATOR`] for the
+    /// currency type `C`. It parses strings produced by [`MoneyFormatter::format`] with the same `format_str`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatchError`] if the code or symbol in the string does not match
+    /// the expected currency. Returns [`MoneyError::ParseStrError`] for any other malformed input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, MoneyParser, macros::dec, iso::USD};
+    ///
+    /// let m = Money::<USD>::from_str_format("1,234.56 USD", "a c").unwrap();
+    /// assert_eq!(m.amount(), dec!(1234.56));
+    ///
+    /// let m = Money::<USD>::from_str_format("-123,456 ¢", "na m").unwrap();
+    /// assert_eq!(m.amount(), dec!(-1234.56));
+    ///
+    /// let m = Money::<USD>::from_decimal(dec!(-42.5));
+    /// assert_eq!(Money::<USD>::from_str_format(&m.format("sna"), "sna").unwrap(), m);
+    /// ```
+    fn from_str_format(money_str: &str, format_str: &str) -> Result<Self, MoneyError> {
+        Self::from_str_format_with(
+            money_str,
+            format_str,
+            C::THOUSAND_SEPARATOR,
+            C::DECIMAL_SEPARATOR,
+        )
+    }
+
+    /// Parse money from a string typed in number convention `locale`, regardless of the currency's own separators.
+    ///
+    /// The amount may have currency code or symbol before or after it, e.g. `"1 234,56"`, `"EUR 1 234,56"` and
+    /// `"1 234,56 €"` with [`ParseLocale::French`](crate::ParseLocale::French). Negative amounts have `-` or parentheses
+    /// around the amount or everything, e.g. `"(1 234,56) €"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatchError`] if the amount has other currency's code or symbol,
+    /// and [`MoneyError::ParseStrError`] if the amount doesn't follow `locale`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyParser, ParseLocale, macros::dec, iso::{EUR, INR, USD}};
+    ///
+    /// let m = Money::<EUR>::parse_locale("-1 234,56 €", ParseLocale::French).unwrap();
+    /// assert_eq!(m.amount(), dec!(-1234.56));
+    ///
+    /// let m = Money::<USD>::parse_locale("USD 1.234,5", ParseLocale::German).unwrap();
+    /// assert_eq!(m.amount(), dec!(1234.5));
+    ///
+    /// let m = Money::<INR>::parse_locale("₹12,34,567.89", ParseLocale::Indian).unwrap();
+    /// assert_eq!(m.amount(), dec!(1234567.89));
+    ///
+    /// // thousand separators must group digits
+    /// assert!(Money::<USD>::parse_locale("1,23,456.78", ParseLocale::English).is_err());
+    /// ```
+    fn parse_locale(money_str: &str, locale: crate::ParseLocale) -> Result<Self, MoneyError> {
+        let amount = Decimal::from_str(&crate::parse::parse_locale::<C>(money_str, locale)?)
+            .map_err(|err| {
+                MoneyError::ParseStrError(format!("failed parsing {} into decimal", err).into())
+            })?;
+
+        Ok(Self::from_decimal(amount))
+    }
+
+    /// Parse money from a string in code format `"<CODE> <AMOUNT>"`, symbol format `"<SYMBOL><AMOUNT>"` or plain
+    /// amount, as accepted by `options`.
+    ///
+    /// One entry point composing the forms, separators, digit groups and strictness of the other parsers,
+    /// see [`ParseOptions`](crate::ParseOptions).
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::CurrencyMismatchError`] if the code in the string does not match the expected currency.
+    /// - [`MoneyError::MinorUnitError`] if strict and the amount has more decimal places than the currency's minor unit.
+    /// - [`MoneyError::ParseStrError`] for any other malformed input, including forms not accepted by `options`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Grouping, Money, MoneyParser, ParseOptions, macros::dec, iso::{INR, USD}};
+    ///
+    /// let options = ParseOptions::new().grouping(Grouping::INDIAN);
+    /// let m = Money::<INR>::parse_with("INR 12,34,567.89", &options).unwrap();
+    /// assert_eq!(m.amount(), dec!(1234567.89));
+    ///
+    /// let options = ParseOptions::new().allow_symbol(false).allow_parentheses(false);
+    /// assert_eq!(Money::<USD>::parse_with("-1,234.56", &options).unwrap().amount(), dec!(-1234.56));
+    /// assert!(Money::<USD>::parse_with("$1,234.56", &options).is_err());
+    /// assert!(Money::<USD>::parse_with("USD (1,234.56)", &options).is_err());
+    /// ```
+    fn parse_with(money_str: &str, options: &crate::ParseOptions) -> Result<Self, MoneyError> {
+        crate::parse::parse_with_options::<C>(money_str, options).map(Self::from_decimal)
+    }
+
+    /// Parse money from ASCII bytes of plain amount, e.g. `b"-1234.56"`, without UTF-8 validation or allocation.
+    ///
+    /// For fields of CSV or FIX feeds arriving as `&[u8]`. The amount has ASCII digits, optional `.` decimal point
+    /// and no thousand separators, negated with `-` or parentheses, e.g. `b"(1234.56)"`. It may follow the currency's
+    /// code and whitespace, e.g. `b"USD 1234.56"`, and surrounding ASCII whitespace is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatchError`] if the amount follows other currency's code,
+    /// and [`MoneyError::ParseStrError`] if the bytes are not such amount, or the amount doesn't fit [`Decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyParser, macros::dec, iso::USD};
+    ///
+    /// let record: &[u8] = b"AAPL,USD 1234.56,-0.5";
+    /// let fields: Vec<&[u8]> = record.split(|b| *b == b',').collect();
+    ///
+    /// assert_eq!(Money::<USD>::from_ascii(fields[1]).unwrap().amount(), dec!(1234.56));
+    /// assert_eq!(Money::<USD>::from_ascii(fields[2]).unwrap().amount(), dec!(-0.50));
+    /// assert!(Money::<USD>::from_ascii(fields[0]).is_err());
+    /// assert!(Money::<USD>::from_ascii(b"1,234.56").is_err());
+    /// ```
+    fn from_ascii(bytes: &[u8]) -> Result<Self, MoneyError> {
+        crate::parse::parse_ascii(bytes, C::CODE).map(Self::from_decimal)
+    }
+
+    /// Parse money from a string formatted with `format_str`, with amount's digits in `digits` or ASCII.
+    ///
+    /// Same as [`Self::from_str_format`], except digits of `digits` are read as ASCII digits first.
+    /// It parses strings produced by [`MoneyFormatter::format_with_digits`] with the same `format_str` and `digits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatchError`] if the code or symbol in the string does not match
+    /// the expected currency. Returns [`MoneyError::ParseStrError`] for any other malformed input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, DigitSet, Money, MoneyParser, macros::dec, iso::SAR};
+    ///
+    /// let m = Money::<SAR>::from_str_format_with_digits("SAR ١,٢٣٤.٥٠", "c na", DigitSet::ArabicIndic).unwrap();
+    /// assert_eq!(m.amount(), dec!(1234.5));
+    ///
+    /// let m = Money::<SAR>::from_str_format_with_digits("SAR 1,234.50", "c na", DigitSet::ArabicIndic).unwrap();
+    /// assert_eq!(m.amount(), dec!(1234.5));
+    /// ```
+    fn from_str_format_with_digits(
+        money_str: &str,
+        format_str: &str,
+        digits: crate::DigitSet,
+    ) -> Result<Self, MoneyError> {
+        crate::parse::check_input_len(money_str.as_bytes())?;
+        Self::from_str_format(&digits.delocalize(money_str), format_str)
+    }
 }
 
 /// Trait for customizing money formatting.
@@ -1504,12 +2513,14 @@ pub trait MoneyFormatter<C: Currency>: BaseMoney<C> {
     /// - 's': currency symbol (e.g., "$")
     /// - 'm': minor symbol (e.g., "cents")
     /// - 'n': negative sign (-), only displayed when amount is negative
+    /// - 'p': plus sign (+), only displayed when amount is positive, e.g. for credits
+    ///   (a format symbol since 0.14.0, a literal "p" in older format strings must now be escaped as `\p`)
     ///
     /// # Escaping Format Symbols
     ///
     /// To display format symbols as literal characters, prefix them with a backslash (\).
     /// This allows you to:
-    /// 1. Insert literal format symbol characters (a, c, s, m, n) into the output
+    /// 1. Insert literal format symbol characters (a, c, s, m, n, p) into the output
     /// 2. Mix escaped symbols with actual format symbols in the same string
     ///
     /// Escape sequences:
@@ -1518,6 +2529,7 @@ pub trait MoneyFormatter<C: Currency>: BaseMoney<C> {
     /// - `\s` outputs literal "s"
     /// - `\m` outputs literal "m"
     /// - `\n` outputs literal "n"
+    /// - `\p` outputs literal "p"
     /// - `\\` (double backslash in source) outputs literal "\"
     /// - `\x` (where x is not a format symbol or backslash) outputs literal "\x"
     ///
@@ -1580,7 +2592,11 @@ pub trait MoneyFormatter<C: Currency>: BaseMoney<C> {
     /// assert_eq!(negative.format("nsa"), "-$50.00");
     ///
     /// // not specifying the `n` for negative sign will omit the negative sign.
-    /// assert_eq!(negative.format("sa"), "$50.00")
+    /// assert_eq!(negative.format("sa"), "$50.00");
+    ///
+    /// // `p` shows plus sign for positive money, e.g. credits in transaction lists.
+    /// assert_eq!(money.format("pnsa"), "+$100.50");
+    /// assert_eq!(negative.format("pnsa"), "-$50.00");
     ///
     ///
     /// ```
@@ -1597,12 +2613,14 @@ pub trait MoneyFormatter<C: Currency>: BaseMoney<C> {
     /// - 's': currency symbol (e.g., "$")
     /// - 'm': minor symbol (e.g., "cents")
     /// - 'n': negative sign (-), only displayed when amount is negative
+    /// - 'p': plus sign (+), only displayed when amount is positive, e.g. for credits
+    ///   (a format symbol since 0.14.0, a literal "p" in older format strings must now be escaped as `\p`)
     ///
     /// # Escaping Format Symbols
     ///
     /// To display format symbols as literal characters, prefix them with a backslash (\).
     /// This allows you to:
-    /// 1. Insert literal format symbol characters (a, c, s, m, n) into the output
+    /// 1. Insert literal format symbol characters (a, c, s, m, n, p) into the output
     /// 2. Mix escaped symbols with actual format symbols in the same string
     ///
     /// Escape sequences:
@@ -1611,6 +2629,7 @@ pub trait MoneyFormatter<C: Currency>: BaseMoney<C> {
     /// - `\s` outputs literal "s"
     /// - `\m` outputs literal "m"
     /// - `\n` outputs literal "n"
+    /// - `\p` outputs literal "p"
     /// - `\\` (double backslash in source) outputs literal "\"
     /// - `\x` (where x is not a format symbol or backslash) outputs literal "\x"
     ///
@@ -1668,6 +2687,324 @@ pub trait MoneyFormatter<C: Currency>: BaseMoney<C> {
         format_with_separator(self, format_str, thousand_separator, decimal_separator)
     }
 
+    /// Format money according to the provided format string, with separators and digit groups of `grouping`.
+    ///
+    /// Same as [`Self::format_with_separator`], except integer digits are grouped by `grouping` instead of thousands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Grouping, Money, MoneyFormatter, macros::dec, iso::{INR, JPY}};
+    ///
+    /// let money = Money::<INR>::from_decimal(dec!(-1234567.891));
+    /// assert_eq!(money.format_with_grouping("nsa", ",", ".", Grouping::INDIAN), "-₹12,34,567.89");
+    ///
+    /// let money = Money::<JPY>::from_decimal(dec!(123456789));
+    /// assert_eq!(money.format_with_grouping("sa", ",", ".", Grouping::MYRIADS), "¥1,2345,6789");
+    /// ```
+    fn format_with_grouping(
+        &self,
+        format_str: &str,
+        thousand_separator: &str,
+        decimal_separator: &str,
+        grouping: crate::Grouping,
+    ) -> String {
+        crate::fmt::format_with_grouping(
+            self,
+            format_str,
+            thousand_separator,
+            decimal_separator,
+            grouping,
+        )
+    }
+
+    /// Writes money formatted with `format_str` into `w`, like [`Self::format`] without allocating a `String`.
+    ///
+    /// Useful in hot paths writing many amounts, e.g. into a report buffer or directly into a `Formatter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error of `w`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::USD};
+    ///
+    /// let money = Money::<USD>::from_decimal(dec!(-1234.5));
+    /// let mut report = String::new();
+    /// write!(report, "total: ").unwrap();
+    /// money.write_format("na c", &mut report).unwrap();
+    /// assert_eq!(report, "total: -1,234.50 USD");
+    /// ```
+    fn write_format(&self, format_str: &str, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        crate::fmt::write_with_grouping(
+            w,
+            self,
+            format_str,
+            C::THOUSAND_SEPARATOR,
+            C::DECIMAL_SEPARATOR,
+            crate::Grouping::THOUSANDS,
+        )
+    }
+
+    /// Writes money in code format into `w`, like [`BaseMoney::format_code`] without allocating a `String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error of `w`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::EUR};
+    ///
+    /// let money = Money::<EUR>::from_decimal(dec!(1234.5));
+    /// let mut line = String::new();
+    /// money.write_code(&mut line).unwrap();
+    /// assert_eq!(line, money.format_code());
+    /// assert_eq!(line, "EUR 1.234,50");
+    /// ```
+    fn write_code(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        self.write_format(CODE_FORMAT, w)
+    }
+
+    /// Writes money in symbol format into `w`, like [`BaseMoney::format_symbol`] without allocating a `String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error of `w`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::USD};
+    ///
+    /// let money = Money::<USD>::from_decimal(dec!(-1234.5));
+    /// let mut line = String::new();
+    /// money.write_symbol(&mut line).unwrap();
+    /// assert_eq!(line, "-$1,234.50");
+    /// ```
+    fn write_symbol(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        self.write_format(SYMBOL_FORMAT, w)
+    }
+
+    /// Formats money in code format with amount masked by the default [`MaskPolicy`](crate::fmt::MaskPolicy),
+    /// e.g. `"USD ••••.56"`, for logging without leaking figures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::{JPY, USD}};
+    ///
+    /// let money = Money::<USD>::from_decimal(dec!(1234.56));
+    /// assert_eq!(money.format_masked(), "USD ••••.56");
+    ///
+    /// let money = Money::<JPY>::from_decimal(dec!(-1234));
+    /// assert_eq!(money.format_masked(), "JPY ••••");
+    /// ```
+    fn format_masked(&self) -> String {
+        crate::fmt::format_masked(self, crate::fmt::MaskPolicy::default())
+    }
+
+    /// Formats money in code format with amount masked by `policy`, e.g. `"USD ***"` with
+    /// [`MaskPolicy::FULL`](crate::fmt::MaskPolicy::FULL).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RawMoney, MoneyFormatter, fmt::MaskPolicy, macros::dec, iso::USD};
+    ///
+    /// let money = RawMoney::<USD>::from_decimal(dec!(1234.5678));
+    /// assert_eq!(money.format_masked_with(MaskPolicy::MINOR), "USD ••••.57");
+    /// assert_eq!(money.format_masked_with(MaskPolicy::FULL), "USD ***");
+    /// ```
+    fn format_masked_with(&self, policy: crate::fmt::MaskPolicy) -> String {
+        crate::fmt::format_masked(self, policy)
+    }
+
+    /// Format money according to the provided format string, with amount's digits in `digits`.
+    ///
+    /// Same as [`Self::format`], except digits of the amount are rendered in `digits`, e.g. Arabic-Indic for
+    /// Arabic-market invoices. Parse them back with [`MoneyParser::from_str_format_with_digits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, DigitSet, Money, MoneyFormatter, macros::dec, iso::{INR, SAR}};
+    ///
+    /// let money = Money::<SAR>::from_decimal(dec!(1234.5));
+    /// assert_eq!(money.format_with_digits("na s", DigitSet::ArabicIndic), "١,٢٣٤.٥٠ ر.س");
+    ///
+    /// let money = Money::<INR>::from_decimal(dec!(-99.99));
+    /// assert_eq!(money.format_with_digits("c na", DigitSet::Devanagari), "INR -९९.९९");
+    /// ```
+    fn format_with_digits(&self, format_str: &str, digits: crate::DigitSet) -> String {
+        crate::fmt::format_with_digits(self, format_str, digits)
+    }
+
+    /// Format money according to the provided format string, with `minor_symbol` for `m` instead of the currency's
+    /// minor unit symbol.
+    ///
+    /// Same as [`Self::format`] otherwise, e.g. to show `"paise"` or plain `"minor"` rather than the currency's symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::{INR, USD}};
+    ///
+    /// let money = Money::<INR>::from_decimal(dec!(-1234.5));
+    /// assert_eq!(money.format_with_minor_symbol("c na m", "paise"), "INR -123,450 paise");
+    ///
+    /// let money = Money::<USD>::from_decimal(dec!(0.5));
+    /// assert_eq!(money.format_with_minor_symbol("na m", "minor"), "50 minor");
+    /// ```
+    fn format_with_minor_symbol(&self, format_str: &str, minor_symbol: &str) -> String {
+        crate::fmt::format_with_minor_symbol(self, format_str, minor_symbol)
+    }
+
+    /// Formats money with currency code in the smallest unit, with `minor_symbol` instead of the currency's.
+    ///
+    /// Same as [`BaseMoney::format_code_minor`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::JPY};
+    ///
+    /// let money = Money::<JPY>::from_decimal(dec!(1234));
+    /// assert_eq!(money.format_code_minor_with("yen"), "JPY 1,234 yen");
+    /// ```
+    fn format_code_minor_with(&self, minor_symbol: &str) -> String {
+        self.format_with_minor_symbol(CODE_FORMAT_MINOR, minor_symbol)
+    }
+
+    /// Formats money with currency symbol in the smallest unit, with `minor_symbol` instead of the currency's.
+    ///
+    /// Same as [`BaseMoney::format_symbol_minor`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::USD};
+    ///
+    /// let money = Money::<USD>::from_decimal(dec!(-12.34));
+    /// assert_eq!(money.format_symbol_minor_with("cents"), "-$1,234 cents");
+    /// ```
+    fn format_symbol_minor_with(&self, minor_symbol: &str) -> String {
+        self.format_with_minor_symbol(SYMBOL_FORMAT_MINOR, minor_symbol)
+    }
+
+    /// Returns money in English words, e.g. for cheque printing and legal documents.
+    ///
+    /// The amount is rounded into the currency's minor unit with bankers rounding. See [`crate::words::English`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::{GBP, JPY, USD}};
+    ///
+    /// let money = Money::<USD>::from_decimal(dec!(1234.56));
+    /// assert_eq!(money.to_words(), "one thousand two hundred thirty-four dollars and fifty-six cents");
+    ///
+    /// let money = Money::<GBP>::from_decimal(dec!(-1.01));
+    /// assert_eq!(money.to_words(), "minus one pound and one penny");
+    ///
+    /// let money = Money::<JPY>::from_decimal(dec!(1000000));
+    /// assert_eq!(money.to_words(), "one million yen");
+    /// ```
+    fn to_words(&self) -> String {
+        self.to_words_in(&crate::words::English)
+    }
+
+    /// Returns money in words of `language`.
+    ///
+    /// The amount is rounded into the currency's minor unit with bankers rounding.
+    /// See [`crate::words`] for implementing languages.
+    fn to_words_in(&self, language: &impl crate::words::Language) -> String {
+        language.amount_in_words(&crate::words::Parts::new(self))
+    }
+
+    /// Format money in symbol format fitting within `max_chars` characters, e.g. for smartwatches or terminal columns.
+    ///
+    /// The display is shortened progressively until it fits:
+    /// 1. Symbol format with currency's separators, e.g. `"-$1,234,567.89"`
+    /// 2. Without thousands grouping, e.g. `"-$1234567.89"`
+    /// 3. Without decimals, rounded with bankers rounding, e.g. `"-$1234568"`
+    /// 4. Compact notation (K, M, B, T) with 2, 1, then 0 decimals, e.g. `"-$1.23M"`, `"-$1.2M"`, `"-$1M"`
+    ///
+    /// The result is never cut off, so it remains unambiguous. Returns `None` if even the shortest display doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::{EUR, USD}};
+    ///
+    /// let money = Money::<USD>::from_decimal(dec!(-1234567.89));
+    /// assert_eq!(money.format_fit(20).unwrap(), "-$1,234,567.89");
+    /// assert_eq!(money.format_fit(12).unwrap(), "-$1234567.89");
+    /// assert_eq!(money.format_fit(9).unwrap(), "-$1234568");
+    /// assert_eq!(money.format_fit(7).unwrap(), "-$1.23M");
+    /// assert_eq!(money.format_fit(6).unwrap(), "-$1.2M");
+    /// assert_eq!(money.format_fit(4).unwrap(), "-$1M");
+    /// assert_eq!(money.format_fit(3), None);
+    ///
+    /// // currency's decimal separator is used
+    /// let money = Money::<EUR>::from_decimal(dec!(1500000));
+    /// assert_eq!(money.format_fit(5).unwrap(), "€1,5M");
+    /// ```
+    fn format_fit(&self, max_chars: usize) -> Option<String> {
+        crate::fmt::format_fit(self, max_chars)
+    }
+
+    /// Format money in compact notation with suffixes K, M, B and T, e.g. `"$1.2K"`, `"-$3.4M"` or `"€7,1B"`.
+    ///
+    /// Amounts are shown with at most 1 decimal, rounded with bankers rounding, and trailing zeros dropped.
+    /// Amounts below 1,000 are formatted in symbol format, e.g. `"$999.99"`. See [`Self::format_compact_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::{EUR, USD}};
+    ///
+    /// assert_eq!(Money::<USD>::from_decimal(dec!(1234.56)).format_compact(), "$1.2K");
+    /// assert_eq!(Money::<USD>::from_decimal(dec!(-3_400_000)).format_compact(), "-$3.4M");
+    /// assert_eq!(Money::<EUR>::from_decimal(dec!(7_080_000_000)).format_compact(), "€7,1B");
+    /// assert_eq!(Money::<USD>::from_decimal(dec!(2_000_000)).format_compact(), "$2M");
+    /// assert_eq!(Money::<USD>::from_decimal(dec!(999.99)).format_compact(), "$999.99");
+    /// ```
+    fn format_compact(&self) -> String {
+        crate::fmt::format_compact(self, 1, Decimal::ONE_THOUSAND)
+    }
+
+    /// Format money in compact notation with at most `precision` decimals, for amounts of at least `threshold`.
+    ///
+    /// Amounts whose absolute value is below `threshold`, or below 1,000, are formatted in symbol format.
+    /// Decimals are rounded with bankers rounding, and trailing zeros dropped. Amounts rounding into 1,000 of a suffix
+    /// take the next one, e.g. `"$1M"` rather than `"$1000K"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::USD};
+    ///
+    /// let money = Money::<USD>::from_decimal(dec!(1_234_567.89));
+    /// assert_eq!(money.format_compact_with(2, dec!(1000)), "$1.23M");
+    /// assert_eq!(money.format_compact_with(0, dec!(1000)), "$1M");
+    ///
+    /// // only amounts from a million are compacted
+    /// assert_eq!(money.format_compact_with(1, dec!(1_000_000)), "$1.2M");
+    /// let money = Money::<USD>::from_decimal(dec!(54_321));
+    /// assert_eq!(money.format_compact_with(1, dec!(1_000_000)), "$54,321.00");
+    ///
+    /// let money = Money::<USD>::from_decimal(dec!(999_999));
+    /// assert_eq!(money.format_compact_with(1, dec!(1000)), "$1M");
+    /// ```
+    fn format_compact_with(&self, precision: u32, threshold: Decimal) -> String {
+        crate::fmt::format_compact(self, precision, threshold)
+    }
+
     /// Format money's amount using locale standard with `format_str` format.
     ///
     /// `locale_str` supports ISO 639 lowercase language code, ISO 639 with ISO 3166-1 alpha‑2 uppercase region code,
@@ -1686,12 +3023,14 @@ pub trait MoneyFormatter<C: Currency>: BaseMoney<C> {
     /// - 's': currency symbol (e.g., "$")
     /// - 'm': minor symbol (e.g., "cents")
     /// - 'n': negative sign (-), only displayed when amount is negative
+    /// - 'p': plus sign (+), only displayed when amount is positive, e.g. for credits
+    ///   (a format symbol since 0.14.0, a literal "p" in older format strings must now be escaped as `\p`)
     ///
     /// # Escaping Format Symbols
     ///
     /// To display format symbols as literal characters, prefix them with a backslash (\).
     /// This allows you to:
-    /// 1. Insert literal format symbol characters (a, c, s, m, n) into the output
+    /// 1. Insert literal format symbol characters (a, c, s, m, n, p) into the output
     /// 2. Mix escaped symbols with actual format symbols in the same string
     ///
     /// Escape sequences:
@@ -1700,6 +3039,7 @@ pub trait MoneyFormatter<C: Currency>: BaseMoney<C> {
     /// - `\s` outputs literal "s"
     /// - `\m` outputs literal "m"
     /// - `\n` outputs literal "n"
+    /// - `\p` outputs literal "p"
     /// - `\\` (double backslash in source) outputs literal "\"
     /// - `\x` (where x is not a format symbol or backslash) outputs literal "\x"
     ///
@@ -1759,4 +3099,32 @@ pub trait MoneyFormatter<C: Currency>: BaseMoney<C> {
     ) -> Result<String, MoneyError> {
         crate::fmt::format_locale_amount(self, locale_str, format_str)
     }
+
+    /// Formats money in `locale`'s conventions, e.g. `"1.234,56 $"` for USD in German.
+    ///
+    /// Digits, grouping and decimal separator come from the locale, ignoring currency's own separators.
+    /// The currency symbol is placed before or after the amount by this crate's table of common locales,
+    /// separated by no-break space(U+00A0) if spaced, see [`crate::icu`] for its limits.
+    /// Negative sign is the locale's, put in front except where the table places it after the symbol, e.g. `nl`.
+    /// Amount is padded with zeros up to currency's minor unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, MoneyFormatter, icu::Locale};
+    ///
+    /// let money = money!(USD, 1234.56);
+    /// assert_eq!(money.format_locale(&"en-US".parse::<Locale>().unwrap()), "$1,234.56");
+    /// assert_eq!(money.format_locale(&"de-DE".parse::<Locale>().unwrap()), "1.234,56\u{a0}$");
+    /// assert_eq!(money.format_locale(&"nl".parse::<Locale>().unwrap()), "$\u{a0}1.234,56");
+    ///
+    /// let money = money!(EUR, -1234.5);
+    /// assert_eq!(money.format_locale(&"en".parse::<Locale>().unwrap()), "-€1,234.50");
+    /// assert_eq!(money.format_locale(&"de".parse::<Locale>().unwrap()), "-1.234,50\u{a0}€");
+    /// assert_eq!(money.format_locale(&"nl".parse::<Locale>().unwrap()), "€\u{a0}-1.234,50");
+    /// ```
+    #[cfg(feature = "icu")]
+    fn format_locale(&self, locale: &crate::icu::Locale) -> String {
+        crate::icu::format_locale(self, locale)
+    }
 }
@@ -0,0 +1,110 @@
+//! basket contains valuation of currency baskets, e.g. IMF's SDR.
+//!
+//! A basket is a list of fixed amounts of currencies. Its value in base currency is the sum of those amounts
+//! converted with [`ExchangeRates`].
+
+use crate::{Currency, Decimal, ExchangeRates, Money, MoneyError, base::BaseMoney};
+
+/// ISO 4217 currency of a basket component.
+///
+/// It's only constructed from a [`Currency`] type, so the currency code is always known at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{basket::IsoCurrency, iso::EUR};
+///
+/// assert_eq!(IsoCurrency::of::<EUR>().code(), "EUR");
+/// assert_eq!(IsoCurrency::from(EUR), IsoCurrency::of::<EUR>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsoCurrency {
+    code: &'static str,
+}
+
+impl IsoCurrency {
+    /// Returns currency of type `C`.
+    #[inline]
+    pub fn of<C: Currency>() -> Self {
+        Self { code: C::CODE }
+    }
+
+    /// Returns the ISO 4217 currency code, e.g. `"USD"`.
+    #[inline]
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+}
+
+impl<C: Currency> From<C> for IsoCurrency {
+    fn from(_: C) -> Self {
+        Self::of::<C>()
+    }
+}
+
+/// Computes value of basket `weights` in `Base` currency using `rates`.
+///
+/// `Base` is the base currency of `rates`.
+/// Each weight is a pair of currency and amount of that currency in the basket.
+/// Components are converted and summed with full precision, then rounded once into `Base`'s minor unit.
+///
+/// # Errors
+///
+/// Returns [`MoneyError::ExchangeError`] if rate of a component's currency is not in `rates` or is zero,
+/// and [`MoneyError::OverflowError`] if the computation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{ExchangeRates, BaseMoney, basket, dec, iso::{EUR, GBP, JPY, USD, CHF}};
+///
+/// // Units of each currency per 1 USD.
+/// let rates = ExchangeRates::<USD>::from([
+///     ("EUR", dec!(0.8)),
+///     ("JPY", dec!(150)),
+///     ("GBP", dec!(0.75)),
+/// ]);
+///
+/// let weights = [
+///     (USD.into(), dec!(0.5)),
+///     (EUR.into(), dec!(0.4)),
+///     (JPY.into(), dec!(15)),
+///     (GBP.into(), dec!(0.06)),
+/// ];
+///
+/// // 0.5 + 0.4 / 0.8 + 15 / 150 + 0.06 / 0.75 = 0.5 + 0.5 + 0.1 + 0.08
+/// let value = basket::value(&weights, &rates).unwrap();
+/// assert_eq!(value.amount(), dec!(1.18));
+///
+/// assert!(basket::value(&[(CHF.into(), dec!(1))], &rates).is_err());
+/// ```
+pub fn value<Base: Currency>(
+    weights: &[(IsoCurrency, Decimal)],
+    rates: &ExchangeRates<'_, Base>,
+) -> Result<Money<Base>, MoneyError> {
+    let mut total = Decimal::ZERO;
+    for (currency, amount) in weights {
+        let code = currency.code();
+        let rate = rates.get(code).ok_or_else(|| {
+            MoneyError::ExchangeError(
+                format!(
+                    "rate of {} not found in {} exchange rates",
+                    code,
+                    Base::CODE
+                )
+                .into(),
+            )
+        })?;
+        if rate.is_zero() {
+            return Err(MoneyError::ExchangeError(
+                format!("rate of {} in {} exchange rates is zero", code, Base::CODE).into(),
+            ));
+        }
+        let base_amount = amount.checked_div(rate).ok_or(MoneyError::OverflowError)?;
+        total = total
+            .checked_add(base_amount)
+            .ok_or(MoneyError::OverflowError)?;
+    }
+
+    Ok(Money::from_decimal(total))
+}
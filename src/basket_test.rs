@@ -0,0 +1,117 @@
+use crate::{
+    BaseMoney, Decimal, ExchangeRates, MoneyError,
+    basket::{self, IsoCurrency},
+    iso::{CHF, CNY, EUR, GBP, IDR, JPY, USD},
+    macros::dec,
+};
+
+fn rates() -> ExchangeRates<'static, USD> {
+    ExchangeRates::<USD>::from([
+        ("EUR", dec!(0.8)),
+        ("JPY", dec!(150)),
+        ("GBP", dec!(0.75)),
+        ("CNY", dec!(7.2)),
+    ])
+}
+
+#[test]
+fn test_iso_currency() {
+    assert_eq!(IsoCurrency::of::<USD>().code(), "USD");
+    assert_eq!(IsoCurrency::from(JPY).code(), "JPY");
+    assert_eq!(IsoCurrency::from(EUR), IsoCurrency::of::<EUR>());
+    assert_ne!(IsoCurrency::from(EUR), IsoCurrency::from(USD));
+}
+
+#[test]
+fn test_value() {
+    let weights = [
+        (USD.into(), dec!(0.57813)),
+        (EUR.into(), dec!(0.37379)),
+        (CNY.into(), dec!(1.0993)),
+        (JPY.into(), dec!(13.452)),
+        (GBP.into(), dec!(0.08087)),
+    ];
+    // 0.57813 + 0.4672375 + 0.152680555... + 0.08968 + 0.107826666... = 1.395554722...
+    let value = basket::value(&weights, &rates()).unwrap();
+    assert_eq!(value.amount(), dec!(1.40));
+    assert_eq!(value.code(), "USD");
+}
+
+#[test]
+fn test_value_base_only() {
+    let value = basket::value(&[(USD.into(), dec!(123.456))], &rates()).unwrap();
+    assert_eq!(value.amount(), dec!(123.46));
+}
+
+#[test]
+fn test_value_empty() {
+    let value = basket::value(&[], &rates()).unwrap();
+    assert_eq!(value.amount(), Decimal::ZERO);
+}
+
+#[test]
+fn test_value_rounds_once() {
+    // Each component alone is 0.004 USD and would round to zero, summed it's 0.012.
+    let weights = [
+        (EUR.into(), dec!(0.0032)),
+        (EUR.into(), dec!(0.0032)),
+        (EUR.into(), dec!(0.0032)),
+    ];
+    let value = basket::value(&weights, &rates()).unwrap();
+    assert_eq!(value.amount(), dec!(0.01));
+}
+
+#[test]
+fn test_value_negative_weight() {
+    let weights = [(USD.into(), dec!(10)), (EUR.into(), dec!(-4))];
+    let value = basket::value(&weights, &rates()).unwrap();
+    assert_eq!(value.amount(), dec!(5));
+}
+
+#[test]
+fn test_value_other_base() {
+    let rates = ExchangeRates::<EUR>::from([("USD", dec!(1.25)), ("IDR", dec!(20000))]);
+    let weights = [
+        (USD.into(), dec!(1.25)),
+        (IDR.into(), dec!(20000)),
+        (EUR.into(), dec!(1)),
+    ];
+    let value = basket::value(&weights, &rates).unwrap();
+    assert_eq!(value.amount(), dec!(3));
+    assert_eq!(value.code(), "EUR");
+
+    let rates = ExchangeRates::<JPY>::from([("USD", dec!(0.0067))]);
+    let value = basket::value(&[(USD.into(), dec!(1))], &rates).unwrap();
+    assert_eq!(value.amount(), dec!(149));
+
+    let rates = ExchangeRates::<IDR>::new();
+    let value = basket::value(&[(IDR.into(), dec!(1000.5))], &rates).unwrap();
+    assert_eq!(value.amount(), dec!(1000.50));
+}
+
+#[test]
+fn test_value_missing_rate() {
+    let weights = [(USD.into(), dec!(1)), (CHF.into(), dec!(1))];
+    let ret = basket::value(&weights, &rates());
+    assert!(matches!(ret, Err(MoneyError::ExchangeError(_))));
+}
+
+#[test]
+fn test_value_zero_rate() {
+    let rates = ExchangeRates::<USD>::from([("EUR", dec!(0.8)), ("CHF", dec!(0))]);
+    let weights = [(EUR.into(), dec!(1)), (CHF.into(), dec!(1))];
+    let ret = basket::value(&weights, &rates);
+    match ret {
+        Err(MoneyError::ExchangeError(msg)) => {
+            assert_eq!(msg.to_string(), "rate of CHF in USD exchange rates is zero")
+        }
+        other => panic!("expected exchange error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_value_overflow() {
+    let weights = [(USD.into(), Decimal::MAX), (USD.into(), Decimal::MAX)];
+    let ret = basket::value(&weights, &rates());
+    assert!(matches!(ret, Err(MoneyError::OverflowError)));
+}
@@ -0,0 +1,166 @@
+//! conversion contains conversion of money collections between currencies preserving totals.
+//!
+//! Converting and rounding each item separately can make the items no longer sum to the converted total,
+//! e.g. when re-denominating a multi-currency invoice. [`convert_all`] reconciles the converted items
+//! so they sum to the converted total, the total being converted once from the sum of the items.
+
+use std::cmp::Reverse;
+
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::{BaseMoney, Currency, Decimal, Money, MoneyError, exchange::Rate};
+
+/// Policy of [`convert_all`] for distributing the difference between the converted total and
+/// the sum of individually rounded items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReconcilePolicy {
+    /// Difference is given one minor unit at a time to items with the largest rounding remainders,
+    /// ties are broken by input order.
+    #[default]
+    LargestRemainder,
+
+    /// Whole difference is given to the item with the largest absolute amount, the first one on ties.
+    LargestItem,
+
+    /// Whole difference is given to the last item.
+    LastItem,
+}
+
+/// Converts `items` from `From` into `To` with `rate`, reconciling them to sum to the converted total.
+///
+/// Each item is converted and rounded into `To`'s minor unit, and the total is converted from the sum of `items`.
+/// The difference between them is distributed following `policy`.
+///
+/// If `From` == `To`, `rate` is ignored like in [`Exchange::convert`](crate::Exchange::convert).
+///
+/// # Errors
+///
+/// Returns [`MoneyError::ExchangeError`] if `rate` is not found or overflowed,
+/// and [`MoneyError::OverflowError`] if the conversion overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, money, macros::dec, iso::EUR};
+/// use moneylib::conversion::{ReconcilePolicy, convert_all};
+///
+/// let items = [money!(USD, 0.05), money!(USD, 0.05), money!(USD, 0.05)];
+///
+/// // Each item is 0.035 EUR, rounded into 0.04 EUR, while the total 0.15 USD is 0.105 EUR, rounded into 0.10 EUR.
+/// let converted = convert_all::<_, EUR>(&items, dec!(0.7), ReconcilePolicy::LargestRemainder).unwrap();
+/// let amounts: Vec<_> = converted.iter().map(|m| m.amount()).collect();
+/// assert_eq!(amounts, [dec!(0.03), dec!(0.03), dec!(0.04)]);
+///
+/// let converted = convert_all::<_, EUR>(&items, dec!(0.7), ReconcilePolicy::LastItem).unwrap();
+/// let amounts: Vec<_> = converted.iter().map(|m| m.amount()).collect();
+/// assert_eq!(amounts, [dec!(0.04), dec!(0.04), dec!(0.02)]);
+/// ```
+pub fn convert_all<From: Currency, To: Currency>(
+    items: &[Money<From>],
+    rate: impl Rate<From, To>,
+    policy: ReconcilePolicy,
+) -> Result<Vec<Money<To>>, MoneyError> {
+    let rate = if From::CODE == To::CODE {
+        Decimal::ONE
+    } else {
+        rate.get_rate().ok_or_else(|| {
+            MoneyError::ExchangeError(
+                format!(
+                    "overflowed or rate from {} to {} not found",
+                    From::CODE,
+                    To::CODE
+                )
+                .into(),
+            )
+        })?
+    };
+
+    let mut total = Decimal::ZERO;
+    let mut raws = Vec::with_capacity(items.len());
+    for item in items {
+        total = total
+            .checked_add(item.amount())
+            .ok_or(MoneyError::OverflowError)?;
+        raws.push(
+            item.amount()
+                .checked_mul(rate)
+                .ok_or(MoneyError::OverflowError)?,
+        );
+    }
+    let total =
+        Money::<To>::from_decimal(total.checked_mul(rate).ok_or(MoneyError::OverflowError)?);
+
+    let mut converted: Vec<Money<To>> = raws.iter().map(|raw| Money::from_decimal(*raw)).collect();
+    let mut sum = Decimal::ZERO;
+    for money in &converted {
+        sum = sum
+            .checked_add(money.amount())
+            .ok_or(MoneyError::OverflowError)?;
+    }
+    let diff = total
+        .amount()
+        .checked_sub(sum)
+        .ok_or(MoneyError::OverflowError)?;
+    if diff.is_zero() {
+        return Ok(converted);
+    }
+
+    match policy {
+        ReconcilePolicy::LargestRemainder => {
+            let unit = Decimal::new(1, To::MINOR_UNIT.into());
+            let step = if diff.is_sign_positive() { unit } else { -unit };
+            let steps = diff
+                .checked_div(step)
+                .and_then(|steps| steps.to_usize())
+                .ok_or(MoneyError::OverflowError)?;
+
+            // Remainders lost by rounding, in the direction of the difference.
+            let mut order: Vec<usize> = (0..converted.len()).collect();
+            let remainders: Vec<Decimal> = raws
+                .iter()
+                .zip(&converted)
+                .map(|(raw, money)| {
+                    let remainder = *raw - money.amount();
+                    if step.is_sign_positive() {
+                        remainder
+                    } else {
+                        -remainder
+                    }
+                })
+                .collect();
+            order.sort_by_key(|&i| Reverse(remainders[i]));
+
+            for i in order.into_iter().cycle().take(steps) {
+                converted[i] = Money::from_decimal(
+                    converted[i]
+                        .amount()
+                        .checked_add(step)
+                        .ok_or(MoneyError::OverflowError)?,
+                );
+            }
+        }
+        ReconcilePolicy::LargestItem | ReconcilePolicy::LastItem => {
+            let index = if policy == ReconcilePolicy::LargestItem {
+                // first of the largest
+                converted
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .max_by_key(|(_, money)| money.amount().abs())
+                    .map(|(i, _)| i)
+            } else {
+                converted.len().checked_sub(1)
+            };
+            if let Some(i) = index {
+                converted[i] = Money::from_decimal(
+                    converted[i]
+                        .amount()
+                        .checked_add(diff)
+                        .ok_or(MoneyError::OverflowError)?,
+                );
+            }
+        }
+    }
+
+    Ok(converted)
+}
@@ -0,0 +1,161 @@
+use crate::{
+    BaseMoney, Decimal, ExchangeRates, Money, MoneyError,
+    conversion::{ReconcilePolicy, convert_all},
+    iso::{EUR, JPY, USD},
+    macros::dec,
+    money,
+};
+
+fn amounts<C: crate::Currency>(items: &[Money<C>]) -> Vec<Decimal> {
+    items.iter().map(|m| m.amount()).collect()
+}
+
+fn sum<C: crate::Currency>(items: &[Money<C>]) -> Decimal {
+    items.iter().map(|m| m.amount()).sum()
+}
+
+#[test]
+fn test_convert_all_largest_remainder() {
+    let items = [money!(USD, 0.05), money!(USD, 0.05), money!(USD, 0.05)];
+    let converted =
+        convert_all::<_, EUR>(&items, dec!(0.7), ReconcilePolicy::LargestRemainder).unwrap();
+    assert_eq!(amounts(&converted), [dec!(0.03), dec!(0.03), dec!(0.04)]);
+    assert_eq!(sum(&converted), dec!(0.10));
+
+    // 0.5, 1.1 EUR, total 1.6 EUR, no difference.
+    let items = [money!(USD, 1), money!(USD, 2.2)];
+    let converted =
+        convert_all::<_, EUR>(&items, dec!(0.5), ReconcilePolicy::LargestRemainder).unwrap();
+    assert_eq!(amounts(&converted), [dec!(0.5), dec!(1.1)]);
+}
+
+#[test]
+fn test_convert_all_largest_remainder_picks_largest() {
+    // 1.113, 1.113, 1.124 -> 1.11, 1.11, 1.12 = 3.34, total 3.35.
+    let items = [money!(JPY, 1113), money!(JPY, 1113), money!(JPY, 1124)];
+    let converted =
+        convert_all::<_, USD>(&items, dec!(0.001), ReconcilePolicy::LargestRemainder).unwrap();
+    assert_eq!(amounts(&converted), [dec!(1.11), dec!(1.11), dec!(1.13)]);
+    assert_eq!(sum(&converted), dec!(3.35));
+
+    // 0.0134 x 3 = 0.0402 -> 0.04, each 0.0134 -> 0.01, difference 0.01 to the first on ties.
+    let items = [money!(JPY, 67), money!(JPY, 67), money!(JPY, 67)];
+    let converted =
+        convert_all::<_, USD>(&items, dec!(0.0002), ReconcilePolicy::LargestRemainder).unwrap();
+    assert_eq!(amounts(&converted), [dec!(0.02), dec!(0.01), dec!(0.01)]);
+
+    // Largest remainder is the third item.
+    let items = [money!(JPY, 61), money!(JPY, 61), money!(JPY, 74)];
+    let converted =
+        convert_all::<_, USD>(&items, dec!(0.0002), ReconcilePolicy::LargestRemainder).unwrap();
+    // 0.0122, 0.0122, 0.0148 -> 0.01, 0.01, 0.01, total 0.0392 -> 0.04.
+    assert_eq!(amounts(&converted), [dec!(0.01), dec!(0.01), dec!(0.02)]);
+}
+
+#[test]
+fn test_convert_all_multiple_steps() {
+    // Each 0.004 rounds to 0.00, total 0.04 distributed one cent at a time.
+    let items = vec![money!(JPY, 1); 10];
+    let converted =
+        convert_all::<_, USD>(&items, dec!(0.004), ReconcilePolicy::LargestRemainder).unwrap();
+    assert_eq!(sum(&converted), dec!(0.04));
+    assert_eq!(
+        amounts(&converted),
+        [
+            dec!(0.01),
+            dec!(0.01),
+            dec!(0.01),
+            dec!(0.01),
+            dec!(0),
+            dec!(0),
+            dec!(0),
+            dec!(0),
+            dec!(0),
+            dec!(0)
+        ]
+    );
+}
+
+#[test]
+fn test_convert_all_largest_item() {
+    let items = [money!(USD, 0.05), money!(USD, 0.07), money!(USD, 0.07)];
+    // 0.035, 0.049, 0.049 -> 0.04, 0.05, 0.05 = 0.14, total 0.133 -> 0.13.
+    let converted = convert_all::<_, EUR>(&items, dec!(0.7), ReconcilePolicy::LargestItem).unwrap();
+    assert_eq!(amounts(&converted), [dec!(0.04), dec!(0.04), dec!(0.05)]);
+    assert_eq!(sum(&converted), dec!(0.13));
+}
+
+#[test]
+fn test_convert_all_last_item() {
+    let items = [money!(USD, 0.05), money!(USD, 0.05), money!(USD, 0.05)];
+    let converted = convert_all::<_, EUR>(&items, dec!(0.7), ReconcilePolicy::LastItem).unwrap();
+    assert_eq!(amounts(&converted), [dec!(0.04), dec!(0.04), dec!(0.02)]);
+}
+
+#[test]
+fn test_convert_all_negative() {
+    let items = [money!(USD, -0.05), money!(USD, -0.05), money!(USD, -0.05)];
+    let converted =
+        convert_all::<_, EUR>(&items, dec!(0.7), ReconcilePolicy::LargestRemainder).unwrap();
+    assert_eq!(amounts(&converted), [dec!(-0.03), dec!(-0.03), dec!(-0.04)]);
+    assert_eq!(sum(&converted), dec!(-0.10));
+
+    let items = [money!(USD, 10), money!(USD, -0.05), money!(USD, -0.05)];
+    let converted = convert_all::<_, EUR>(&items, dec!(0.7), ReconcilePolicy::LargestItem).unwrap();
+    // 7, -0.035, -0.035 -> 7, -0.04, -0.04 = 6.92, total 6.93.
+    assert_eq!(amounts(&converted), [dec!(7.01), dec!(-0.04), dec!(-0.04)]);
+}
+
+#[test]
+fn test_convert_all_to_zero_decimal() {
+    let items = [money!(USD, 0.5), money!(USD, 0.5), money!(USD, 0.5)];
+    // 75, 75, 75, total 225.
+    let converted =
+        convert_all::<_, JPY>(&items, dec!(150), ReconcilePolicy::LargestRemainder).unwrap();
+    assert_eq!(amounts(&converted), [dec!(75), dec!(75), dec!(75)]);
+
+    // 0.5 x 3, 1.5 -> 2 on total, each 0.5 -> 0.
+    let items = [money!(USD, 0.01), money!(USD, 0.01), money!(USD, 0.01)];
+    let converted =
+        convert_all::<_, JPY>(&items, dec!(50), ReconcilePolicy::LargestRemainder).unwrap();
+    assert_eq!(amounts(&converted), [dec!(1), dec!(1), dec!(0)]);
+}
+
+#[test]
+fn test_convert_all_same_currency() {
+    let items = [money!(USD, 1.23), money!(USD, 4.56)];
+    let converted =
+        convert_all::<_, USD>(&items, dec!(2), ReconcilePolicy::LargestRemainder).unwrap();
+    assert_eq!(converted, items);
+}
+
+#[test]
+fn test_convert_all_empty() {
+    let converted =
+        convert_all::<USD, EUR>(&[], dec!(0.7), ReconcilePolicy::LargestRemainder).unwrap();
+    assert!(converted.is_empty());
+}
+
+#[test]
+fn test_convert_all_with_exchange_rates() {
+    let rates = ExchangeRates::<USD>::from([("EUR", dec!(0.7))]);
+    let items = [money!(USD, 0.05), money!(USD, 0.05), money!(USD, 0.05)];
+    let converted = convert_all::<_, EUR>(&items, &rates, ReconcilePolicy::default()).unwrap();
+    assert_eq!(amounts(&converted), [dec!(0.03), dec!(0.03), dec!(0.04)]);
+
+    let ret = convert_all::<_, JPY>(&items, &rates, ReconcilePolicy::default());
+    match ret {
+        Err(MoneyError::ExchangeError(msg)) => assert_eq!(
+            msg.to_string(),
+            "overflowed or rate from USD to JPY not found"
+        ),
+        other => panic!("expected exchange error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_convert_all_overflow() {
+    let items = [Money::<USD>::from_decimal(Decimal::MAX), money!(USD, 1)];
+    let ret = convert_all::<_, EUR>(&items, dec!(2), ReconcilePolicy::default());
+    assert!(matches!(ret, Err(MoneyError::OverflowError)));
+}
@@ -0,0 +1,122 @@
+//! crypto contains cryptocurrency marker types implementing [`Currency`](crate::Currency), so exchanges can use the same
+//! [`Money`](crate::Money) type across fiat and crypto.
+//!
+//! Minor units are each token's smallest unit, e.g. satoshi for BTC and wei for ETH, and numeric codes are `0` as
+//! they have no ISO 4217 code. Amounts use `,` thousand separator and `.` decimal separator.
+//!
+//! `Decimal` holds 28 to 29 significant digits, so the more decimal places a currency has, the smaller its largest
+//! amount with full precision, see [`Money::max_representable`](crate::Money::max_representable):
+//! about 7.9 × 10²⁰ BTC, but only about 79 billion ETH. Beyond it, amounts and results of checked operations still
+//! succeed with fewer decimal places, silently dropping the smallest units, and
+//! [`from_minor`](crate::BaseMoney::from_minor) doesn't help as the minor amount has the same limit.
+//!
+//! The short form of the money macros only resolves ISO currencies, use the long form, e.g. `money!(crypto::BTC, 1)`.
+//! To use them in [`DynMoney`](crate::obj_money::DynMoney), register with
+//! [`Context::register_currency`](crate::obj_money::Context::register_currency).
+//!
+//! # Examples
+//!
+//! ```
+//! use moneylib::{BaseMoney, Money, crypto::{self, BTC, ETH}, macros::dec, money};
+//!
+//! let btc = money!(crypto::BTC, 0.123456789);
+//! assert_eq!(btc.amount(), dec!(0.12345679));
+//! assert_eq!(btc.minor_amount().unwrap(), 12_345_679);
+//! assert_eq!(btc.format_symbol(), "₿0.12345679");
+//!
+//! let eth = Money::<ETH>::from_minor(1_500_000_000_000_000_000).unwrap();
+//! assert_eq!(eth.amount(), dec!(1.5));
+//! assert_eq!(Money::<BTC>::max_representable().amount().trunc(), dec!(792_281_625_142_643_375_935));
+//! ```
+
+crate::define_currency! {
+    /// Bitcoin, in 8 decimal places of satoshi.
+    pub struct BTC {
+        code: "BTC",
+        symbol: "₿",
+        name: "Bitcoin",
+        numeric: 0,
+        minor_unit: 8,
+        thousand_separator: ",",
+        decimal_separator: ".",
+        minor_unit_symbol: "sat",
+        minor_unit_name: "satoshi",
+    }
+}
+
+crate::define_currency! {
+    /// Ether, in 18 decimal places of wei.
+    ///
+    /// Amounts with full precision are limited to about 79 billion ETH by `Decimal`. Above about 7.9 × 10¹⁰ ETH,
+    /// checked operations such as `checked_add` return `Some` with fewer than 18 decimal places, losing wei without
+    /// an error.
+    pub struct ETH {
+        code: "ETH",
+        symbol: "Ξ",
+        name: "Ether",
+        numeric: 0,
+        minor_unit: 18,
+        thousand_separator: ",",
+        decimal_separator: ".",
+        minor_unit_symbol: "wei",
+        minor_unit_name: "wei",
+    }
+}
+
+crate::define_currency! {
+    /// Litecoin, in 8 decimal places of litoshi.
+    pub struct LTC {
+        code: "LTC",
+        symbol: "Ł",
+        name: "Litecoin",
+        numeric: 0,
+        minor_unit: 8,
+        thousand_separator: ",",
+        decimal_separator: ".",
+        minor_unit_symbol: "litoshi",
+        minor_unit_name: "litoshi",
+    }
+}
+
+crate::define_currency! {
+    /// Solana, in 9 decimal places of lamport.
+    pub struct SOL {
+        code: "SOL",
+        symbol: "◎",
+        name: "Solana",
+        numeric: 0,
+        minor_unit: 9,
+        thousand_separator: ",",
+        decimal_separator: ".",
+        minor_unit_symbol: "lamport",
+        minor_unit_name: "lamport",
+    }
+}
+
+crate::define_currency! {
+    /// Tether USD stablecoin, in 6 decimal places.
+    ///
+    /// Its symbol is its code, as `₮` is also the symbol of the Mongolian tögrög.
+    pub struct USDT {
+        code: "USDT",
+        symbol: "USDT",
+        name: "Tether USD",
+        numeric: 0,
+        minor_unit: 6,
+        thousand_separator: ",",
+        decimal_separator: ".",
+    }
+}
+
+crate::define_currency! {
+    /// USD Coin stablecoin, in 6 decimal places.
+    pub struct USDC {
+        code: "USDC",
+        symbol: "USDC",
+        name: "USD Coin",
+        numeric: 0,
+        minor_unit: 6,
+        thousand_separator: ",",
+        decimal_separator: ".",
+    }
+}
@@ -0,0 +1,68 @@
+use crate::crypto::{BTC, ETH, LTC, SOL, USDC, USDT};
+use crate::macros::dec;
+use crate::{BaseMoney, Currency, Decimal, Money, MoneyError, MoneyParser};
+
+#[test]
+fn test_crypto_currencies() {
+    assert_eq!((BTC::CODE, BTC::SYMBOL, BTC::MINOR_UNIT), ("BTC", "₿", 8));
+    assert_eq!((ETH::CODE, ETH::SYMBOL, ETH::MINOR_UNIT), ("ETH", "Ξ", 18));
+    assert_eq!((LTC::CODE, LTC::SYMBOL, LTC::MINOR_UNIT), ("LTC", "Ł", 8));
+    assert_eq!((SOL::CODE, SOL::SYMBOL, SOL::MINOR_UNIT), ("SOL", "◎", 9));
+    assert_eq!(
+        (USDT::CODE, USDT::SYMBOL, USDT::MINOR_UNIT),
+        ("USDT", "USDT", 6)
+    );
+    assert_eq!(
+        (USDC::CODE, USDC::SYMBOL, USDC::MINOR_UNIT),
+        ("USDC", "USDC", 6)
+    );
+
+    assert_eq!(BTC::MINOR_UNIT_NAME, "satoshi");
+    assert_eq!(ETH::MINOR_UNIT_NAME, "wei");
+    assert_eq!(BTC::NUMERIC, 0);
+    assert_eq!(BTC::THOUSAND_SEPARATOR, ",");
+    assert_eq!(BTC::DECIMAL_SEPARATOR, ".");
+}
+
+#[test]
+fn test_crypto_money() {
+    let btc = crate::money!(crate::crypto::BTC, 1_234.123456789);
+    assert_eq!(btc.amount(), dec!(1_234.12345679));
+    assert_eq!(btc.to_string(), "BTC 1,234.12345679");
+    assert_eq!(btc.format_symbol(), "₿1,234.12345679");
+    assert_eq!(btc.minor_amount().unwrap(), 123_412_345_679);
+
+    let parsed = Money::<BTC>::from_str_symbol("₿1,234.12345679").unwrap();
+    assert_eq!(parsed, btc);
+
+    let usdt = Money::<USDT>::new(dec!(99.1234567)).unwrap();
+    assert_eq!(usdt.amount(), dec!(99.123457));
+    assert_eq!(usdt.format_code(), "USDT 99.123457");
+}
+
+#[test]
+fn test_crypto_eth_decimal_limits() {
+    let one_wei = Money::<ETH>::from_minor(1).unwrap();
+    assert_eq!(one_wei.amount(), dec!(0.000000000000000001));
+
+    let eth = Money::<ETH>::new(dec!(120_000_000.123456789012345678)).unwrap();
+    assert_eq!(eth.amount(), dec!(120_000_000.123456789012345678));
+
+    // about 79 billion ETH with full precision
+    let max = Money::<ETH>::max_representable();
+    assert_eq!(max.amount().trunc(), dec!(79_228_162_514));
+    assert_eq!(max.amount().scale(), 18);
+
+    // beyond that, checked operations still succeed but lose wei, as documented on ETH.
+    let trillion = Money::<ETH>::new(dec!(1_000_000_000_000))
+        .unwrap()
+        .checked_add(dec!(0.123456789012345678))
+        .unwrap();
+    assert_eq!(trillion.amount(), dec!(1_000_000_000_000.1234567890123457));
+    assert_eq!(trillion.amount().scale(), 16);
+    assert!(matches!(
+        Money::<ETH>::from_minor(i128::MAX),
+        Err(MoneyError::OverflowError)
+    ));
+    assert!(Decimal::MAX > max.amount());
+}
@@ -0,0 +1,728 @@
+//! currency contains extensions over [`Currency`] metadata.
+//!
+//! It has blanket implementation for all types implementing [`Currency`].
+
+use rust_decimal::MathematicalOps;
+use rust_decimal::prelude::FromPrimitive;
+
+use crate::fmt::{CODE_FORMAT, SYMBOL_FORMAT};
+use crate::{Currency, Decimal, MoneyError, macros::dec};
+
+/// Describes the canonical layout used by a currency when formatting and parsing amounts.
+///
+/// Obtained from [`CurrencyFormat::format_spec`]. UI layers can use it to configure input
+/// masks and validation directly from the crate's metadata.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{CurrencyFormat, Grouping, iso::EUR};
+///
+/// let spec = EUR::format_spec();
+/// assert_eq!(spec.code, "EUR");
+/// assert_eq!(spec.symbol, "€");
+/// assert_eq!(spec.minor_unit, 2);
+/// assert_eq!(spec.thousand_separator, ".");
+/// assert_eq!(spec.decimal_separator, ",");
+/// assert_eq!(spec.grouping_size, 3);
+/// assert_eq!(spec.secondary_grouping_size, None);
+/// assert_eq!(spec.grouping(), Grouping::THOUSANDS);
+/// assert_eq!(spec.code_format, "c na");
+/// assert_eq!(spec.symbol_format, "nsa");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatSpec {
+    /// ISO 4217 currency code, e.g. `"USD"`.
+    pub code: &'static str,
+
+    /// Currency symbol, e.g. `"$"`.
+    pub symbol: &'static str,
+
+    /// Number of digits after the decimal separator.
+    pub minor_unit: u16,
+
+    /// Symbol of the minor unit, e.g. `"¢"`.
+    pub minor_unit_symbol: &'static str,
+
+    /// Separator between groups of integer digits.
+    pub thousand_separator: &'static str,
+
+    /// Separator between integer and fractional digits.
+    pub decimal_separator: &'static str,
+
+    /// Number of integer digits in each group between thousand separators.
+    pub grouping_size: usize,
+
+    /// Number of integer digits in each group before the last one if it differs from
+    /// [`grouping_size`](Self::grouping_size), e.g. `Some(2)` in Indian numbering `12,34,567`.
+    pub secondary_grouping_size: Option<usize>,
+
+    /// Format string used by [`BaseMoney::format_code`](crate::BaseMoney::format_code).
+    pub code_format: &'static str,
+
+    /// Format string used by [`BaseMoney::format_symbol`](crate::BaseMoney::format_symbol).
+    pub symbol_format: &'static str,
+}
+
+impl FormatSpec {
+    /// Returns digit groups of this layout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a grouping size is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{CurrencyFormat, FormatSpec, Grouping, iso::INR};
+    ///
+    /// let spec = FormatSpec {
+    ///     secondary_grouping_size: Some(2),
+    ///     ..INR::format_spec()
+    /// };
+    /// assert_eq!(spec.grouping(), Grouping::INDIAN);
+    /// assert_eq!(spec.input_mask(), "#,##,##0.00");
+    /// ```
+    pub fn grouping(&self) -> Grouping {
+        Grouping::new(
+            self.grouping_size,
+            self.secondary_grouping_size.unwrap_or(self.grouping_size),
+        )
+    }
+
+    /// Returns input mask of this layout, e.g. `#,##0.00`.
+    ///
+    /// `#` is an optional digit, `0` is a required digit, followed by the separators of the layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{CurrencyFormat, iso::{EUR, JPY, USD}};
+    ///
+    /// assert_eq!(USD::format_spec().input_mask(), "#,##0.00");
+    /// assert_eq!(EUR::format_spec().input_mask(), "#.##0,00");
+    /// assert_eq!(JPY::format_spec().input_mask(), "#,##0");
+    /// ```
+    pub fn input_mask(&self) -> String {
+        crate::fmt::input_mask(
+            self.thousand_separator,
+            self.decimal_separator,
+            self.grouping_size,
+            self.secondary_grouping_size.unwrap_or(self.grouping_size),
+            self.minor_unit,
+        )
+    }
+}
+
+/// Sizes of integer digit groups between thousand separators, used by formatting and parsing.
+///
+/// The primary group is the one nearest the decimal separator, and every group before it has the secondary size,
+/// e.g. `1,23,45,678` with primary size 3 and secondary size 2.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, Grouping, Money, MoneyFormatter, MoneyParser, macros::dec, iso::{CNY, INR}};
+///
+/// let money = Money::<INR>::from_decimal(dec!(12345678.9));
+/// assert_eq!(money.format_with_grouping("c na", ",", ".", Grouping::INDIAN), "INR 1,23,45,678.90");
+///
+/// let money = Money::<CNY>::from_decimal(dec!(123456789));
+/// assert_eq!(money.format_with_grouping("c na", ",", ".", Grouping::MYRIADS), "CNY 1,2345,6789.00");
+///
+/// let parsed = Money::<INR>::from_str_code_with_grouping("INR 1,23,45,678.90", ",", ".", Grouping::INDIAN).unwrap();
+/// assert_eq!(parsed.amount(), dec!(12345678.90));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Grouping {
+    primary: usize,
+    secondary: usize,
+}
+
+impl Grouping {
+    /// Groups of 3 digits, e.g. `1,234,567`.
+    pub const THOUSANDS: Self = Self::uniform(3);
+
+    /// Groups of 4 digits, e.g. `123,4567` in Chinese and Japanese.
+    pub const MYRIADS: Self = Self::uniform(4);
+
+    /// Group of 3 digits, then groups of 2 digits, e.g. `12,34,567` in Indian numbering.
+    pub const INDIAN: Self = Self::new(3, 2);
+
+    /// Creates grouping with `primary` digits in the group nearest the decimal separator,
+    /// and `secondary` digits in each group before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `primary` or `secondary` is zero.
+    pub const fn new(primary: usize, secondary: usize) -> Self {
+        assert!(
+            primary > 0 && secondary > 0,
+            "grouping sizes must be greater than zero"
+        );
+        Self { primary, secondary }
+    }
+
+    /// Creates grouping with `size` digits in every group.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub const fn uniform(size: usize) -> Self {
+        Self::new(size, size)
+    }
+
+    /// Returns number of digits in the group nearest the decimal separator.
+    pub const fn primary(&self) -> usize {
+        self.primary
+    }
+
+    /// Returns number of digits in each group before the primary group.
+    pub const fn secondary(&self) -> usize {
+        self.secondary
+    }
+
+    /// Returns grouping of `locale_str`, e.g. [`Grouping::INDIAN`] for `en-IN`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ParseLocale`] if the locale is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::Grouping;
+    ///
+    /// assert_eq!(Grouping::locale("en-US").unwrap(), Grouping::THOUSANDS);
+    /// assert_eq!(Grouping::locale("en-IN").unwrap(), Grouping::INDIAN);
+    /// ```
+    #[cfg(feature = "locale")]
+    pub fn locale(locale_str: &str) -> Result<Self, MoneyError> {
+        crate::fmt::locale_grouping(locale_str)
+    }
+
+    /// Returns `true` if a separator goes before the last `digits` integer digits.
+    pub(crate) fn is_boundary(&self, digits: usize) -> bool {
+        digits == self.primary
+            || (digits > self.primary && (digits - self.primary).is_multiple_of(self.secondary))
+    }
+}
+
+impl Default for Grouping {
+    fn default() -> Self {
+        Self::THOUSANDS
+    }
+}
+
+/// Number convention of user input, for parsing amounts regardless of the currency's own separators.
+///
+/// Input typed by users follows their locale rather than the currency, e.g. a French user paying in USD types
+/// `1 234,56`. Thousand separators are optional, but digits between them must be grouped by the convention's
+/// [`grouping`](Self::grouping).
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, Money, MoneyParser, ParseLocale, macros::dec, iso::{CHF, USD}};
+///
+/// let m = Money::<USD>::parse_locale("1 234,56", ParseLocale::French).unwrap();
+/// assert_eq!(m.amount(), dec!(1234.56));
+///
+/// let m = Money::<CHF>::parse_locale("CHF 1'234.56", ParseLocale::Swiss).unwrap();
+/// assert_eq!(m.amount(), dec!(1234.56));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseLocale {
+    /// `1,234.56`, e.g. English, Chinese and Japanese.
+    English,
+    /// `1.234,56`, e.g. German, Spanish, Italian and Indonesian.
+    German,
+    /// `1 234,56`, e.g. French, Russian, Polish and Swedish. Any of space, no-break space(U+00A0)
+    /// and narrow no-break space(U+202F) separates thousands.
+    French,
+    /// `1'234.56`, e.g. Swiss German. Either apostrophe(U+0027) or right single quotation mark(U+2019)
+    /// separates thousands.
+    Swiss,
+    /// `1,23,456.78`, Indian numbering.
+    Indian,
+}
+
+impl ParseLocale {
+    /// Returns separator between integer and fractional digits, e.g. `","` for [`ParseLocale::French`].
+    pub const fn decimal_separator(self) -> &'static str {
+        match self {
+            Self::English | Self::Swiss | Self::Indian => ".",
+            Self::German | Self::French => ",",
+        }
+    }
+
+    /// Returns sizes of integer digit groups between thousand separators.
+    pub const fn grouping(self) -> Grouping {
+        match self {
+            Self::Indian => Grouping::INDIAN,
+            _ => Grouping::THOUSANDS,
+        }
+    }
+
+    /// Returns characters separating thousands, the first one canonical.
+    pub(crate) const fn thousand_separators(self) -> &'static [char] {
+        match self {
+            Self::English | Self::Indian => &[','],
+            Self::German => &['.'],
+            Self::French => &[' ', '\u{a0}', '\u{202f}'],
+            Self::Swiss => &['\'', '\u{2019}'],
+        }
+    }
+}
+
+/// Options of [`MoneyParser::parse_with`](crate::MoneyParser::parse_with), composing accepted forms, separators and
+/// strictness into a single parse entry point.
+///
+/// By default the parser accepts code format `"USD 1,234.56"`, symbol format `"$1,234.56"` and plain amounts
+/// `"1,234.56"`, with the currency's own separators, [`Grouping::THOUSANDS`], `-` or accounting parentheses for
+/// negatives, and rounds amounts with more decimal places than the currency's minor unit. Scientific notation, e.g.
+/// `"1.2e3"`, is rejected like by every other parser, unless [`allow_exponent`](Self::allow_exponent).
+///
+/// Options are set with chained methods, each replacing the value set before.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, Money, MoneyError, MoneyParser, ParseOptions, macros::dec, iso::{EUR, USD}};
+///
+/// let options = ParseOptions::new()
+///     .require_code(false)
+///     .allow_symbol(true)
+///     .thousand_separator(" ")
+///     .decimal_separator(",");
+///
+/// assert_eq!(Money::<EUR>::parse_with("€1 234,56", &options).unwrap().amount(), dec!(1234.56));
+/// assert_eq!(Money::<EUR>::parse_with("EUR -1 234,56", &options).unwrap().amount(), dec!(-1234.56));
+/// assert_eq!(Money::<EUR>::parse_with("(1 234,56)", &options).unwrap().amount(), dec!(-1234.56));
+///
+/// let strict = ParseOptions::new().require_code(true).strict(true);
+/// assert!(Money::<USD>::parse_with("USD 1,234.56", &strict).is_ok());
+/// assert!(matches!(Money::<USD>::parse_with("$1,234.56", &strict), Err(MoneyError::ParseStrError(_))));
+/// assert!(matches!(Money::<USD>::parse_with("USD 1.005", &strict), Err(MoneyError::MinorUnitError(_))));
+///
+/// let scientific = ParseOptions::new().allow_exponent(true);
+/// assert_eq!(Money::<USD>::parse_with("USD 1.2e3", &scientific).unwrap().amount(), dec!(1200));
+/// assert!(Money::<USD>::parse_with("USD 1.2e3", &ParseOptions::new()).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub(crate) require_code: bool,
+    pub(crate) allow_symbol: bool,
+    pub(crate) allow_parentheses: bool,
+    pub(crate) allow_exponent: bool,
+    pub(crate) thousand_separator: Option<String>,
+    pub(crate) decimal_separator: Option<String>,
+    pub(crate) grouping: Grouping,
+    pub(crate) strict: bool,
+}
+
+impl ParseOptions {
+    /// Creates options accepting code, symbol and plain amounts with the currency's separators, see [`ParseOptions`].
+    pub fn new() -> Self {
+        Self {
+            require_code: false,
+            allow_symbol: true,
+            allow_parentheses: true,
+            allow_exponent: false,
+            thousand_separator: None,
+            decimal_separator: None,
+            grouping: Grouping::THOUSANDS,
+            strict: false,
+        }
+    }
+
+    /// Rejects strings without currency code, e.g. `"$1,234.56"` or `"1,234.56"`, if `require_code`.
+    pub fn require_code(mut self, require_code: bool) -> Self {
+        self.require_code = require_code;
+        self
+    }
+
+    /// Accepts currency symbol before the amount, e.g. `"$1,234.56"`, if `allow_symbol`.
+    pub fn allow_symbol(mut self, allow_symbol: bool) -> Self {
+        self.allow_symbol = allow_symbol;
+        self
+    }
+
+    /// Accepts negative amounts in parentheses, e.g. `"USD (1,234.56)"`, if `allow_parentheses`.
+    pub fn allow_parentheses(mut self, allow_parentheses: bool) -> Self {
+        self.allow_parentheses = allow_parentheses;
+        self
+    }
+
+    /// Accepts amounts in scientific notation, e.g. `"USD 1.2e3"` or `"$-5E-2"`, if `allow_exponent`.
+    ///
+    /// The exponent is expanded exactly by moving the decimal point, and amounts not fitting [`Decimal`] are rejected.
+    /// Without it, scientific notation is rejected with an error saying so.
+    pub fn allow_exponent(mut self, allow_exponent: bool) -> Self {
+        self.allow_exponent = allow_exponent;
+        self
+    }
+
+    /// Parses amounts with `thousand_separator` instead of the currency's.
+    pub fn thousand_separator(mut self, thousand_separator: &str) -> Self {
+        self.thousand_separator = Some(thousand_separator.to_string());
+        self
+    }
+
+    /// Parses amounts with `decimal_separator` instead of the currency's.
+    pub fn decimal_separator(mut self, decimal_separator: &str) -> Self {
+        self.decimal_separator = Some(decimal_separator.to_string());
+        self
+    }
+
+    /// Parses amounts with digit groups of `grouping`, e.g. [`Grouping::INDIAN`] for `1,23,456.78`.
+    pub fn grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Rejects amounts with more decimal places than the currency's minor unit if `strict`, instead of rounding them.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Unicode digits used to render and parse amounts, for locales expecting other digits than ASCII.
+///
+/// Only digits of the amount are rendered in the set, codes, symbols, signs and separators are kept as they are.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, DigitSet, Money, MoneyFormatter, MoneyParser, macros::dec, iso::{SAR, USD}};
+///
+/// let money = Money::<SAR>::from_decimal(dec!(-1234.5));
+/// assert_eq!(money.format_with_digits("c na", DigitSet::ArabicIndic), "SAR -١,٢٣٤.٥٠");
+///
+/// let parsed = Money::<SAR>::from_str_format_with_digits("SAR -١,٢٣٤.٥٠", "c na", DigitSet::ArabicIndic).unwrap();
+/// assert_eq!(parsed, money);
+///
+/// assert_eq!(DigitSet::Devanagari.localize("USD 1,234.50"), "USD १,२३४.५०");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum DigitSet {
+    /// ASCII digits `0123456789`.
+    #[default]
+    Latin,
+    /// Arabic-Indic digits `٠١٢٣٤٥٦٧٨٩`, e.g. of Arabic in Egypt and Saudi Arabia.
+    ArabicIndic,
+    /// Extended Arabic-Indic digits `۰۱۲۳۴۵۶۷۸۹`, e.g. of Persian and Urdu.
+    ExtendedArabicIndic,
+    /// Devanagari digits `०१२३४५६७८९`, e.g. of Hindi, Marathi and Nepali.
+    Devanagari,
+    /// Bengali digits `০১২৩৪৫৬৭৮৯`.
+    Bengali,
+    /// Thai digits `๐๑๒๓๔๕๖๗๘๙`.
+    Thai,
+}
+
+impl DigitSet {
+    /// Returns the zero digit of this set, the rest follow it in Unicode, e.g. `'٠'` for [`DigitSet::ArabicIndic`].
+    pub const fn zero(self) -> char {
+        match self {
+            Self::Latin => '0',
+            Self::ArabicIndic => '\u{660}',
+            Self::ExtendedArabicIndic => '\u{6f0}',
+            Self::Devanagari => '\u{966}',
+            Self::Bengali => '\u{9e6}',
+            Self::Thai => '\u{e50}',
+        }
+    }
+
+    /// Returns `ch` as digit of this set if it's an ASCII digit, otherwise `ch` as it is.
+    pub fn localize_char(self, ch: char) -> char {
+        ch.to_digit(10)
+            .and_then(|digit| char::from_u32(u32::from(self.zero()) + digit))
+            .unwrap_or(ch)
+    }
+
+    /// Returns `ch` as ASCII digit if it's a digit of this set, otherwise `ch` as it is.
+    pub fn delocalize_char(self, ch: char) -> char {
+        u32::from(ch)
+            .checked_sub(u32::from(self.zero()))
+            .filter(|digit| *digit < 10)
+            .and_then(|digit| char::from_digit(digit, 10))
+            .unwrap_or(ch)
+    }
+
+    /// Returns `s` with ASCII digits replaced by digits of this set.
+    pub fn localize(self, s: &str) -> String {
+        s.chars().map(|ch| self.localize_char(ch)).collect()
+    }
+
+    /// Returns `s` with digits of this set replaced by ASCII digits.
+    pub fn delocalize(self, s: &str) -> String {
+        s.chars().map(|ch| self.delocalize_char(ch)).collect()
+    }
+}
+
+/// Trait exposing the formatting and parsing metadata of a currency as associated functions.
+///
+/// It has blanket implementation for all types implementing [`Currency`].
+pub trait CurrencyFormat: Currency {
+    /// Returns the decimal separator used by the currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{CurrencyFormat, iso::{USD, EUR}};
+    ///
+    /// assert_eq!(USD::decimal_separator(), ".");
+    /// assert_eq!(EUR::decimal_separator(), ",");
+    /// ```
+    #[inline]
+    fn decimal_separator() -> &'static str {
+        Self::DECIMAL_SEPARATOR
+    }
+
+    /// Returns the thousands separator used by the currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{CurrencyFormat, iso::{USD, EUR}};
+    ///
+    /// assert_eq!(USD::thousand_separator(), ",");
+    /// assert_eq!(EUR::thousand_separator(), ".");
+    /// ```
+    #[inline]
+    fn thousand_separator() -> &'static str {
+        Self::THOUSAND_SEPARATOR
+    }
+
+    /// Returns true if the currency has no minor unit, e.g. JPY and KRW.
+    ///
+    /// Minor amount of such currency is the amount itself, not in hundredths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{CurrencyFormat, iso::{BHD, JPY, KRW, USD}};
+    ///
+    /// assert!(JPY::is_zero_decimal());
+    /// assert!(KRW::is_zero_decimal());
+    /// assert!(!USD::is_zero_decimal());
+    /// assert!(!BHD::is_zero_decimal());
+    /// ```
+    #[inline]
+    fn is_zero_decimal() -> bool {
+        Self::MINOR_UNIT == 0
+    }
+
+    /// Returns the canonical layout of the currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{CurrencyFormat, iso::JPY};
+    ///
+    /// let spec = JPY::format_spec();
+    /// assert_eq!(spec.code, "JPY");
+    /// assert_eq!(spec.minor_unit, 0);
+    /// assert_eq!(spec.thousand_separator, ",");
+    /// ```
+    fn format_spec() -> FormatSpec {
+        FormatSpec {
+            code: Self::CODE,
+            symbol: Self::SYMBOL,
+            minor_unit: Self::MINOR_UNIT,
+            minor_unit_symbol: Self::MINOR_UNIT_SYMBOL,
+            thousand_separator: Self::THOUSAND_SEPARATOR,
+            decimal_separator: Self::DECIMAL_SEPARATOR,
+            grouping_size: Grouping::THOUSANDS.primary(),
+            secondary_grouping_size: None,
+            code_format: CODE_FORMAT,
+            symbol_format: SYMBOL_FORMAT,
+        }
+    }
+
+    /// Returns input mask of the currency, e.g. `#,##0.00`.
+    ///
+    /// Form inputs accepting this mask are parseable by [`MoneyParser`](crate::MoneyParser) with the currency's separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{CurrencyFormat, iso::{BHD, EUR, USD}};
+    ///
+    /// assert_eq!(USD::input_mask(), "#,##0.00");
+    /// assert_eq!(EUR::input_mask(), "#.##0,00");
+    /// assert_eq!(BHD::input_mask(), "#,##0.000");
+    /// ```
+    fn input_mask() -> String {
+        Self::format_spec().input_mask()
+    }
+
+    /// Returns input mask of the currency with separators and grouping of `locale_str`.
+    ///
+    /// Number of fraction digits follows the currency's minor unit.
+    /// Digits in mask are always `#` and `0` regardless of the locale's numbering system.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale_str` - Locale code, e.g. en-US, en-GB, fr-FR, id-ID, ar-SA, ar-AE
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{CurrencyFormat, iso::{INR, JPY, USD}};
+    ///
+    /// assert_eq!(USD::input_mask_locale("en-US").unwrap(), "#,##0.00");
+    /// assert_eq!(USD::input_mask_locale("de-DE").unwrap(), "#.##0,00");
+    /// assert_eq!(JPY::input_mask_locale("de-DE").unwrap(), "#.##0");
+    /// assert_eq!(INR::input_mask_locale("en-IN").unwrap(), "#,##,##0.00");
+    ///
+    /// assert!(USD::input_mask_locale("!!!invalid").is_err());
+    /// ```
+    #[cfg(feature = "locale")]
+    fn input_mask_locale(locale_str: &str) -> Result<String, MoneyError> {
+        crate::fmt::input_mask_locale(locale_str, Self::MINOR_UNIT)
+    }
+
+    /// Returns step cash amounts of the currency are rounded to, or `None` if cash is paid in its minor unit.
+    ///
+    /// Steps follow the smallest coin in circulation where point-of-sale cash totals are rounded, e.g. `0.05` for
+    /// CHF and CAD, and `1` for SEK. Used by [`BaseMoney::round_cash_default`](crate::BaseMoney::round_cash_default).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{CurrencyFormat, macros::dec, iso::{CHF, DKK, SEK, USD}};
+    ///
+    /// assert_eq!(CHF::cash_rounding(), Some(dec!(0.05)));
+    /// assert_eq!(DKK::cash_rounding(), Some(dec!(0.50)));
+    /// assert_eq!(SEK::cash_rounding(), Some(dec!(1)));
+    /// assert_eq!(USD::cash_rounding(), None);
+    /// ```
+    fn cash_rounding() -> Option<Decimal> {
+        CASH_ROUNDINGS
+            .iter()
+            .find(|(code, _)| *code == Self::CODE)
+            .map(|(_, step)| *step)
+    }
+}
+
+// Cash rounding steps of currencies whose smallest coins were withdrawn or never minted.
+const CASH_ROUNDINGS: &[(&str, Decimal)] = &[
+    ("AUD", dec!(0.05)),
+    ("CAD", dec!(0.05)),
+    ("CHF", dec!(0.05)),
+    ("CZK", dec!(1)),
+    ("DKK", dec!(0.50)),
+    ("HUF", dec!(5)),
+    ("NOK", dec!(1)),
+    ("NZD", dec!(0.10)),
+    ("SEK", dec!(1)),
+];
+
+impl<C: Currency> CurrencyFormat for C {}
+
+// Checks that `amount` read from storage or parsed strictly fits minor unit of `C`, ignoring trailing zeros,
+// rather than silently rounding amounts likely stored in other currency.
+pub(crate) fn check_minor_unit<C: Currency>(amount: Decimal) -> Result<(), MoneyError> {
+    if amount.normalize().scale() > u32::from(C::MINOR_UNIT) {
+        return Err(MoneyError::MinorUnitError(
+            format!(
+                "amount {} has more decimal places than {}'s minor unit of {}",
+                amount,
+                C::CODE,
+                C::MINOR_UNIT
+            )
+            .into(),
+        ));
+    }
+    Ok(())
+}
+
+// Scales checked for misplaced minor unit, e.g. JPY amount multiplied by 100 as if cents.
+const MINOR_SCALES: u16 = 4;
+
+/// Checks that `minor_amount` is `amount` in minor unit of currency `C`.
+///
+/// Use it where minor amounts cross system boundaries, e.g. payment providers taking integer amounts,
+/// to catch treating amounts of zero-decimal currencies like JPY as cents(x100), and similar scaling bugs.
+///
+/// # Errors
+///
+/// Returns [`MoneyError::MinorUnitError`] if `amount` has more decimals than `C`'s minor unit,
+/// or if `minor_amount` doesn't match, naming the wrong scale if any.
+/// Returns [`MoneyError::OverflowError`] if `amount` can't be represented in minor unit.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{MoneyError, check_minor_semantics, macros::dec, iso::{JPY, USD}};
+///
+/// assert!(check_minor_semantics::<USD>(dec!(10.50), 1050).is_ok());
+/// assert!(check_minor_semantics::<JPY>(dec!(1000), 1000).is_ok());
+///
+/// // JPY has no minor unit, 1000 JPY isn't 100000 sen.
+/// let err = check_minor_semantics::<JPY>(dec!(1000), 100_000).unwrap_err();
+/// assert!(matches!(err, MoneyError::MinorUnitError(_)));
+/// assert!(err.to_string().contains("scaled by 100 instead of 1"));
+/// ```
+pub fn check_minor_semantics<C: Currency>(
+    amount: Decimal,
+    minor_amount: i128,
+) -> Result<(), MoneyError> {
+    let scaled = |scale: u16| -> Option<Decimal> {
+        Decimal::TEN
+            .checked_powu(scale.into())
+            .and_then(|factor| amount.checked_mul(factor))
+    };
+
+    let expected = scaled(C::MINOR_UNIT).ok_or(MoneyError::OverflowError)?;
+    if !expected.fract().is_zero() {
+        return Err(MoneyError::MinorUnitError(
+            format!(
+                "{} {} has more decimals than minor unit of {} digits",
+                amount,
+                C::CODE,
+                C::MINOR_UNIT
+            )
+            .into(),
+        ));
+    }
+
+    let minor = Decimal::from_i128(minor_amount).ok_or(MoneyError::OverflowError)?;
+    if expected == minor {
+        return Ok(());
+    }
+
+    let wrong_scale = (0..=MINOR_SCALES)
+        .filter(|scale| *scale != C::MINOR_UNIT)
+        .find(|scale| scaled(*scale) == Some(minor));
+    let err = match wrong_scale {
+        Some(scale) => format!(
+            "minor amount {} of {} {} is scaled by {} instead of {}",
+            minor_amount,
+            amount,
+            C::CODE,
+            Decimal::TEN.powu(scale.into()),
+            Decimal::TEN.powu(C::MINOR_UNIT.into()),
+        ),
+        None => format!(
+            "minor amount {} doesn't match {} {}, expected {}",
+            minor_amount,
+            amount,
+            C::CODE,
+            expected.normalize()
+        ),
+    };
+
+    Err(MoneyError::MinorUnitError(err.into()))
+}
@@ -0,0 +1,323 @@
+use crate::iso::{BHD, CHF, EUR, JPY, USD};
+use crate::{
+    BaseMoney, CurrencyFormat, DigitSet, FormatSpec, Grouping, Money, MoneyError, MoneyFormatter,
+    check_minor_semantics, macros::dec,
+};
+
+#[test]
+fn test_separators_associated_functions() {
+    assert_eq!(USD::thousand_separator(), ",");
+    assert_eq!(USD::decimal_separator(), ".");
+
+    assert_eq!(EUR::thousand_separator(), ".");
+    assert_eq!(EUR::decimal_separator(), ",");
+
+    assert_eq!(CHF::thousand_separator(), "'");
+    assert_eq!(CHF::decimal_separator(), ".");
+}
+
+#[test]
+fn test_separators_match_money_accessors() {
+    let money = Money::<EUR>::from_decimal(dec!(1234.56));
+    assert_eq!(EUR::thousand_separator(), money.thousand_separator());
+    assert_eq!(EUR::decimal_separator(), money.decimal_separator());
+}
+
+#[test]
+fn test_format_spec_usd() {
+    let spec = USD::format_spec();
+    assert_eq!(
+        spec,
+        FormatSpec {
+            code: "USD",
+            symbol: "$",
+            minor_unit: 2,
+            minor_unit_symbol: "¢",
+            thousand_separator: ",",
+            decimal_separator: ".",
+            grouping_size: 3,
+            secondary_grouping_size: None,
+            code_format: "c na",
+            symbol_format: "nsa",
+        }
+    );
+}
+
+#[test]
+fn test_format_spec_minor_units() {
+    assert_eq!(JPY::format_spec().minor_unit, 0);
+    assert_eq!(BHD::format_spec().minor_unit, 3);
+}
+
+#[test]
+fn test_format_spec_formats_agree_with_money() {
+    let spec = EUR::format_spec();
+    let money = Money::<EUR>::from_decimal(dec!(-1234567.89));
+    assert_eq!(
+        money.format_with_separator(
+            spec.code_format,
+            spec.thousand_separator,
+            spec.decimal_separator
+        ),
+        money.format_code()
+    );
+    assert_eq!(
+        money.format_with_separator(
+            spec.symbol_format,
+            spec.thousand_separator,
+            spec.decimal_separator
+        ),
+        money.format_symbol()
+    );
+}
+
+#[test]
+fn test_input_mask() {
+    assert_eq!(USD::input_mask(), "#,##0.00");
+    assert_eq!(EUR::input_mask(), "#.##0,00");
+    assert_eq!(CHF::input_mask(), "#'##0.00");
+    assert_eq!(JPY::input_mask(), "#,##0");
+    assert_eq!(BHD::input_mask(), "#,##0.000");
+    assert_eq!(USD::input_mask(), USD::format_spec().input_mask());
+
+    let spec = FormatSpec {
+        grouping_size: 4,
+        thousand_separator: " ",
+        ..USD::format_spec()
+    };
+    assert_eq!(spec.input_mask(), "# ###0.00");
+
+    let spec = FormatSpec {
+        secondary_grouping_size: Some(2),
+        ..USD::format_spec()
+    };
+    assert_eq!(spec.input_mask(), "#,##,##0.00");
+}
+
+#[test]
+fn test_grouping() {
+    assert_eq!(Grouping::default(), Grouping::THOUSANDS);
+    assert_eq!(Grouping::uniform(4), Grouping::MYRIADS);
+    assert_eq!(Grouping::INDIAN.primary(), 3);
+    assert_eq!(Grouping::INDIAN.secondary(), 2);
+    assert_eq!(USD::format_spec().grouping(), Grouping::THOUSANDS);
+
+    let spec = FormatSpec {
+        grouping_size: 4,
+        ..USD::format_spec()
+    };
+    assert_eq!(spec.grouping(), Grouping::MYRIADS);
+}
+
+#[test]
+#[should_panic(expected = "grouping sizes must be greater than zero")]
+fn test_grouping_zero_size() {
+    let _ = Grouping::new(3, 0);
+}
+
+#[cfg(feature = "locale")]
+#[test]
+fn test_grouping_locale() {
+    assert_eq!(Grouping::locale("en-US").unwrap(), Grouping::THOUSANDS);
+    assert_eq!(Grouping::locale("de-DE").unwrap(), Grouping::THOUSANDS);
+    assert_eq!(Grouping::locale("hi-IN").unwrap(), Grouping::INDIAN);
+    assert!(Grouping::locale("!!!invalid").is_err());
+}
+
+#[cfg(feature = "locale")]
+#[test]
+fn test_input_mask_locale() {
+    assert_eq!(USD::input_mask_locale("en-US").unwrap(), "#,##0.00");
+    assert_eq!(EUR::input_mask_locale("de-DE").unwrap(), "#.##0,00");
+    assert_eq!(EUR::input_mask_locale("fr-FR").unwrap(), "#\u{202f}##0,00");
+    assert_eq!(JPY::input_mask_locale("ja-JP").unwrap(), "#,##0");
+    assert_eq!(BHD::input_mask_locale("en-US").unwrap(), "#,##0.000");
+    assert_eq!(USD::input_mask_locale("hi-IN").unwrap(), "#,##,##0.00");
+    assert_eq!(USD::input_mask_locale("ar-SA").unwrap(), "#٬##0٫00");
+    assert!(USD::input_mask_locale("!!!invalid").is_err());
+}
+
+#[test]
+fn test_is_zero_decimal() {
+    assert!(JPY::is_zero_decimal());
+    assert!(!USD::is_zero_decimal());
+    assert!(!BHD::is_zero_decimal());
+}
+
+#[test]
+fn test_check_minor_semantics_ok() {
+    assert!(check_minor_semantics::<USD>(dec!(1234.56), 123456).is_ok());
+    assert!(check_minor_semantics::<USD>(dec!(-0.01), -1).is_ok());
+    assert!(check_minor_semantics::<USD>(dec!(10), 1000).is_ok());
+    assert!(check_minor_semantics::<JPY>(dec!(1000), 1000).is_ok());
+    assert!(check_minor_semantics::<BHD>(dec!(1.234), 1234).is_ok());
+    assert!(check_minor_semantics::<USD>(dec!(0), 0).is_ok());
+}
+
+#[test]
+fn test_check_minor_semantics_zero_decimal_as_cents() {
+    let err = check_minor_semantics::<JPY>(dec!(1000), 100_000).unwrap_err();
+    assert!(matches!(err, MoneyError::MinorUnitError(_)));
+    assert_eq!(
+        err.to_string(),
+        "[MONEYLIB] minor unit error: minor amount 100000 of 1000 JPY is scaled by 100 instead of 1"
+    );
+}
+
+#[test]
+fn test_check_minor_semantics_wrong_scale() {
+    // BHD has 3 decimals
+    let err = check_minor_semantics::<BHD>(dec!(1.5), 150).unwrap_err();
+    assert!(err.to_string().contains("scaled by 100 instead of 1000"));
+
+    // major amount sent as minor
+    let err = check_minor_semantics::<USD>(dec!(25), 25).unwrap_err();
+    assert!(err.to_string().contains("scaled by 1 instead of 100"));
+}
+
+#[test]
+fn test_check_minor_semantics_mismatch() {
+    let err = check_minor_semantics::<USD>(dec!(10.50), 1049).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "[MONEYLIB] minor unit error: minor amount 1049 doesn't match 10.50 USD, expected 1050"
+    );
+
+    // fractional minor amount
+    let err = check_minor_semantics::<JPY>(dec!(10.5), 10).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "[MONEYLIB] minor unit error: 10.5 JPY has more decimals than minor unit of 0 digits"
+    );
+
+    assert!(matches!(
+        check_minor_semantics::<USD>(crate::Decimal::MAX, 1),
+        Err(MoneyError::OverflowError)
+    ));
+}
+
+#[test]
+fn test_digit_set() {
+    assert_eq!(DigitSet::default(), DigitSet::Latin);
+    assert_eq!(DigitSet::Latin.localize("USD 1,234.50"), "USD 1,234.50");
+    assert_eq!(DigitSet::ArabicIndic.localize("0123456789"), "٠١٢٣٤٥٦٧٨٩");
+    assert_eq!(
+        DigitSet::ExtendedArabicIndic.localize("0123456789"),
+        "۰۱۲۳۴۵۶۷۸۹"
+    );
+    assert_eq!(DigitSet::Devanagari.localize("0123456789"), "०१२३४५६७८९");
+    assert_eq!(DigitSet::Bengali.localize("0123456789"), "০১২৩৪৫৬৭৮৯");
+    assert_eq!(DigitSet::Thai.localize("-1,234.5 THB"), "-๑,๒๓๔.๕ THB");
+
+    for digits in [
+        DigitSet::Latin,
+        DigitSet::ArabicIndic,
+        DigitSet::ExtendedArabicIndic,
+        DigitSet::Devanagari,
+        DigitSet::Bengali,
+        DigitSet::Thai,
+    ] {
+        let s = "EUR -1.234.567,89 (x9)";
+        assert_eq!(digits.delocalize(&digits.localize(s)), s);
+        assert_eq!(
+            digits.localize_char('7'),
+            digits.localize("7").chars().next().unwrap()
+        );
+    }
+
+    // digits of other sets are kept as they are
+    assert_eq!(DigitSet::ArabicIndic.delocalize("۱٢"), "۱2");
+    assert_eq!(DigitSet::ArabicIndic.delocalize_char('/'), '/');
+    assert_eq!(DigitSet::Thai.delocalize_char('\u{e5a}'), '\u{e5a}');
+}
+
+crate::define_currency! {
+    /// Loyalty points for `define_currency!` tests.
+    pub(crate) struct PTS {
+        code: "PTS",
+        symbol: "pts",
+        name: "Loyalty points",
+        numeric: 0,
+        minor_unit: 0,
+        thousand_separator: ",",
+        decimal_separator: ".",
+    }
+}
+
+crate::define_currency! {
+    struct XTS {
+        code: "XTS",
+        symbol: "T$",
+        name: "Test currency",
+        numeric: 963,
+        minor_unit: 3,
+        thousand_separator: ".",
+        decimal_separator: ",",
+        minor_unit_symbol: "m",
+        minor_unit_name: "mill",
+        origin: "Testland",
+        locale: "de-DE"
+    }
+}
+
+#[test]
+fn test_define_currency_constants() {
+    use crate::Currency;
+
+    assert_eq!(PTS::CODE, "PTS");
+    assert_eq!(PTS::SYMBOL, "pts");
+    assert_eq!(PTS::NAME, "Loyalty points");
+    assert_eq!(PTS::NUMERIC, 0);
+    assert_eq!(PTS::MINOR_UNIT, 0);
+    assert_eq!(PTS::MINOR_UNIT_SYMBOL, "minor");
+    assert_eq!(PTS::MINOR_UNIT_NAME, "minor");
+    assert_eq!(PTS::ORIGIN, "");
+    assert_eq!(PTS::LOCALE, "en-US");
+
+    assert_eq!(XTS::NUMERIC, 963);
+    assert_eq!(XTS::MINOR_UNIT_SYMBOL, "m");
+    assert_eq!(XTS::MINOR_UNIT_NAME, "mill");
+    assert_eq!(XTS::ORIGIN, "Testland");
+    assert_eq!(XTS::LOCALE, "de-DE");
+    assert_eq!(XTS::thousand_separator(), ".");
+    assert_eq!(XTS::decimal_separator(), ",");
+
+    assert_eq!(PTS, PTS::default());
+}
+
+#[test]
+fn test_define_currency_money() {
+    use crate::MoneyParser;
+
+    let points = Money::<PTS>::new(dec!(1_250.6)).unwrap();
+    assert_eq!(points, crate::money!(self::PTS, 1_251));
+    assert_eq!(points.code(), "PTS");
+    assert_eq!(points.to_string(), "PTS 1,251");
+    assert_eq!(Money::<PTS>::from_str_code("PTS 1,251").unwrap(), points);
+
+    let test = Money::<XTS>::from_str_code("XTS 1.234,5678").unwrap();
+    assert_eq!(test.amount(), dec!(1_234.568));
+    assert_eq!(test.to_string(), "XTS 1.234,568");
+    assert_eq!(test.minor_amount().unwrap(), 1_234_568);
+
+    // copy, as the currency is `Copy`
+    let copied = test;
+    assert_eq!(copied, test);
+}
+
+#[cfg(feature = "raw_money")]
+#[test]
+fn test_define_currency_raw_money() {
+    let raw = crate::raw!(self::XTS, 1.23456);
+    assert_eq!(raw.amount(), dec!(1.23456));
+    assert_eq!(raw.finish().amount(), dec!(1.235));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_define_currency_serde() {
+    let points = Money::<PTS>::new(1_250).unwrap();
+    let json = serde_json::to_string(&points).unwrap();
+    assert_eq!(json, "1250");
+    assert_eq!(serde_json::from_str::<Money<PTS>>(&json).unwrap(), points);
+}
@@ -0,0 +1,131 @@
+//! decimal contains the scale math bridging [`Decimal`] amounts and scaled integers, the same used by the crate.
+//!
+//! Minor amounts are amounts scaled by `10^minor_unit`, e.g. `1234.56` USD is `123456` cents. Systems storing amounts
+//! as integers with their own scale, e.g. ledgers in millicents or payment networks in 4 decimal places, can use these
+//! functions to convert consistently with [`BaseMoney::minor_amount`](crate::BaseMoney::minor_amount) and
+//! [`BaseMoney::from_minor`](crate::BaseMoney::from_minor).
+//!
+//! All functions are checked: they return `None` instead of panicking, losing digits silently, or clamping the scale.
+
+use rust_decimal::MathematicalOps;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+
+use crate::{Decimal, RoundingStrategy};
+
+/// Returns `10^exp`, or `None` if it doesn't fit [`Decimal`], i.e. `exp` is more than 28.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{decimal, macros::dec};
+///
+/// assert_eq!(decimal::pow10(2), Some(dec!(100)));
+/// assert_eq!(decimal::pow10(0), Some(dec!(1)));
+/// assert_eq!(decimal::pow10(29), None);
+/// ```
+pub fn pow10(exp: u32) -> Option<Decimal> {
+    Decimal::TEN.checked_powu(exp.into())
+}
+
+/// Returns `amount` scaled by `10^scale` into integer, e.g. `1234.567` with scale 2 is `123457`.
+///
+/// `amount` is rounded into `scale` decimal places with bankers rounding first, like minor amounts of the crate.
+///
+/// Returns `None` if the scaled amount doesn't fit [`Decimal`].
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{decimal, macros::dec};
+///
+/// assert_eq!(decimal::to_scaled_i128(dec!(1234.56), 2), Some(123456));
+/// assert_eq!(decimal::to_scaled_i128(dec!(-1234.565), 2), Some(-123456));
+/// assert_eq!(decimal::to_scaled_i128(dec!(1.5), 5), Some(150000));
+/// assert_eq!(decimal::to_scaled_i128(dec!(1.5), 0), Some(2));
+/// assert_eq!(decimal::to_scaled_i128(dec!(1), 29), None);
+/// ```
+pub fn to_scaled_i128(amount: Decimal, scale: u32) -> Option<i128> {
+    amount.round_dp(scale).checked_mul(pow10(scale)?)?.to_i128()
+}
+
+/// Returns integer `value` scaled down by `10^scale` into decimal, e.g. `123456` with scale 2 is `1234.56`.
+///
+/// Returns `None` if `value` doesn't fit [`Decimal`] or `scale` is more than 28.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{decimal, macros::dec};
+///
+/// assert_eq!(decimal::from_scaled_i128(123456, 2), Some(dec!(1234.56)));
+/// assert_eq!(decimal::from_scaled_i128(-5, 3), Some(dec!(-0.005)));
+/// assert_eq!(decimal::from_scaled_i128(i128::MAX, 2), None);
+/// assert_eq!(decimal::from_scaled_i128(1, 29), None);
+/// ```
+pub fn from_scaled_i128(value: i128, scale: u32) -> Option<Decimal> {
+    Decimal::from_i128(value)?.checked_div(pow10(scale)?)
+}
+
+/// Returns `amount` with exactly `scale` decimal places if no nonzero digit is dropped, e.g. `1.50` into `1.5000`.
+///
+/// Returns `None` if `amount` has nonzero digits beyond `scale`, or can't hold `scale` decimal places.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{decimal, macros::dec};
+///
+/// let rescaled = decimal::checked_rescale(dec!(1.50), 4).unwrap();
+/// assert_eq!(rescaled.to_string(), "1.5000");
+/// assert_eq!(decimal::checked_rescale(dec!(1.500), 1).unwrap().to_string(), "1.5");
+///
+/// assert_eq!(decimal::checked_rescale(dec!(1.55), 1), None);
+/// assert_eq!(decimal::checked_rescale(dec!(1), 29), None);
+/// ```
+pub fn checked_rescale(amount: Decimal, scale: u32) -> Option<Decimal> {
+    if amount.normalize().scale() > scale {
+        return None;
+    }
+    exact_scale(amount, scale)
+}
+
+/// Returns `amount` rounded into `scale` decimal places with `strategy`, with exactly `scale` decimal places.
+///
+/// Returns `None` if `amount` can't hold `scale` decimal places.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{RoundingStrategy, decimal, macros::dec};
+///
+/// let rounded = decimal::rescale_with(dec!(1.005), 2, RoundingStrategy::HalfUp).unwrap();
+/// assert_eq!(rounded.to_string(), "1.01");
+///
+/// let rounded = decimal::rescale_with(dec!(1.2), 3, RoundingStrategy::Floor).unwrap();
+/// assert_eq!(rounded.to_string(), "1.200");
+///
+/// assert_eq!(decimal::rescale_with(dec!(1), 29, RoundingStrategy::BankersRounding), None);
+/// ```
+pub fn rescale_with(amount: Decimal, scale: u32, strategy: RoundingStrategy) -> Option<Decimal> {
+    exact_scale(strategy.round_dp(amount, scale), scale)
+}
+
+// Sets scale of `amount` without rounding, `None` if `Decimal::rescale` would reduce it.
+fn exact_scale(mut amount: Decimal, scale: u32) -> Option<Decimal> {
+    if scale > Decimal::MAX_SCALE {
+        return None;
+    }
+    amount.rescale(scale);
+    (amount.scale() == scale).then_some(amount)
+}
+
+// Returns `minor_unit` as scale capped to `Decimal::MAX_SCALE`, usable in const context where `u32::from` isn't.
+pub(crate) const fn minor_scale(minor_unit: u16) -> u32 {
+    let mut scale = 0;
+    let mut remaining = minor_unit;
+    while remaining > 0 && scale < Decimal::MAX_SCALE {
+        scale += 1;
+        remaining -= 1;
+    }
+    scale
+}
@@ -0,0 +1,107 @@
+use crate::decimal::{checked_rescale, from_scaled_i128, pow10, rescale_with, to_scaled_i128};
+use crate::iso::{BHD, JPY, USD};
+use crate::macros::dec;
+use crate::{BaseMoney, Decimal, Money, RoundingStrategy};
+
+#[test]
+fn test_pow10() {
+    assert_eq!(pow10(0), Some(dec!(1)));
+    assert_eq!(pow10(3), Some(dec!(1000)));
+    assert_eq!(pow10(28), Some(dec!(10000000000000000000000000000)));
+    assert_eq!(pow10(29), None);
+}
+
+#[test]
+fn test_to_scaled_i128() {
+    assert_eq!(to_scaled_i128(dec!(1234.56), 2), Some(123456));
+    assert_eq!(to_scaled_i128(dec!(-0.01), 2), Some(-1));
+    assert_eq!(to_scaled_i128(dec!(0), 4), Some(0));
+    assert_eq!(to_scaled_i128(dec!(12.5), 0), Some(12));
+    assert_eq!(to_scaled_i128(dec!(13.5), 0), Some(14));
+    assert_eq!(to_scaled_i128(dec!(1.23456789), 4), Some(12346));
+    assert_eq!(
+        to_scaled_i128(Decimal::MAX, 0),
+        Some(Decimal::MAX.mantissa())
+    );
+    assert_eq!(to_scaled_i128(Decimal::MAX, 2), None);
+    assert_eq!(to_scaled_i128(dec!(1), 29), None);
+}
+
+#[test]
+fn test_from_scaled_i128() {
+    assert_eq!(from_scaled_i128(123456, 2), Some(dec!(1234.56)));
+    assert_eq!(from_scaled_i128(-1, 2), Some(dec!(-0.01)));
+    assert_eq!(from_scaled_i128(7, 0), Some(dec!(7)));
+    assert_eq!(
+        from_scaled_i128(1, 28),
+        Some(dec!(0.0000000000000000000000000001))
+    );
+    assert_eq!(
+        from_scaled_i128(Decimal::MAX.mantissa(), 0),
+        Some(Decimal::MAX)
+    );
+    assert_eq!(from_scaled_i128(Decimal::MAX.mantissa() + 1, 0), None);
+    assert_eq!(from_scaled_i128(1, 29), None);
+}
+
+#[test]
+fn test_scaled_i128_roundtrip_matches_minor_amount() {
+    for amount in [dec!(0), dec!(1234.56), dec!(-0.01), dec!(99999999.99)] {
+        let money = Money::<USD>::from_decimal(amount);
+        let minor = to_scaled_i128(money.amount(), 2).unwrap();
+        assert_eq!(money.minor_amount(), Some(minor));
+        assert_eq!(from_scaled_i128(minor, 2), Some(money.amount()));
+        assert_eq!(Money::<USD>::from_minor(minor).unwrap(), money);
+    }
+
+    let money = Money::<BHD>::from_decimal(dec!(1.234));
+    assert_eq!(money.minor_amount(), to_scaled_i128(money.amount(), 3));
+    let money = Money::<JPY>::from_decimal(dec!(1234));
+    assert_eq!(money.minor_amount(), to_scaled_i128(money.amount(), 0));
+}
+
+#[test]
+fn test_checked_rescale() {
+    let rescaled = checked_rescale(dec!(1.5), 4).unwrap();
+    assert_eq!(rescaled, dec!(1.5));
+    assert_eq!(rescaled.scale(), 4);
+
+    let rescaled = checked_rescale(dec!(1.5000), 1).unwrap();
+    assert_eq!(rescaled.scale(), 1);
+    assert_eq!(checked_rescale(dec!(-7), 0).unwrap().scale(), 0);
+
+    assert_eq!(checked_rescale(dec!(1.25), 1), None);
+    assert_eq!(checked_rescale(dec!(1), 29), None);
+    // no room for more decimal places
+    assert_eq!(checked_rescale(Decimal::MAX, 1), None);
+}
+
+#[test]
+fn test_rescale_with() {
+    let cases = [
+        (RoundingStrategy::BankersRounding, dec!(1.22)),
+        (RoundingStrategy::HalfUp, dec!(1.23)),
+        (RoundingStrategy::HalfDown, dec!(1.22)),
+        (RoundingStrategy::Ceil, dec!(1.23)),
+        (RoundingStrategy::Floor, dec!(1.22)),
+    ];
+    for (strategy, expected) in cases {
+        let rounded = rescale_with(dec!(1.225), 2, strategy).unwrap();
+        assert_eq!(rounded, expected);
+        assert_eq!(rounded.scale(), 2);
+    }
+
+    let rounded = rescale_with(dec!(3), 2, RoundingStrategy::Floor).unwrap();
+    assert_eq!(rounded.to_string(), "3.00");
+    assert_eq!(rescale_with(dec!(1), 29, RoundingStrategy::Ceil), None);
+    assert_eq!(rescale_with(Decimal::MAX, 1, RoundingStrategy::Ceil), None);
+}
+
+#[test]
+fn test_minor_scale() {
+    assert_eq!(crate::decimal::minor_scale(0), 0);
+    assert_eq!(crate::decimal::minor_scale(2), 2);
+    assert_eq!(crate::decimal::minor_scale(28), 28);
+    assert_eq!(crate::decimal::minor_scale(29), 28);
+    assert_eq!(crate::decimal::minor_scale(u16::MAX), 28);
+}
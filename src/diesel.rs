@@ -0,0 +1,112 @@
+//! diesel contains `ToSql`/`FromSql` implementations mapping money into Postgres `NUMERIC` columns.
+//!
+//! With them, `Money<C>` and `RawMoney<C>` can be used directly as fields of `Queryable`/`Insertable` structs
+//! of `Numeric` columns, and as `Nullable<Numeric>` with `Option`. Only the amount is stored,
+//! the currency comes from the type.
+//!
+//! Reading `Money<C>` fails on amounts with more decimal places than `C`'s minor unit, see
+//! [storage invariants](crate#storage). Reading `RawMoney<C>` keeps the amount as is.
+//!
+//! [`sum_money`] sums a column in queries, giving zero instead of `NULL` on no rows:
+//!
+//! ```ignore
+//! use diesel::prelude::*;
+//! use moneylib::{Money, iso::USD, diesel::sum_money};
+//!
+//! diesel::table! {
+//!     invoices (id) {
+//!         id -> Int4,
+//!         total -> Numeric,
+//!     }
+//! }
+//!
+//! #[derive(Queryable, Insertable)]
+//! #[diesel(table_name = invoices)]
+//! struct Invoice {
+//!     id: i32,
+//!     total: Money<USD>,
+//! }
+//!
+//! let sum: Money<USD> = invoices::table
+//!     .select(sum_money(invoices::total))
+//!     .get_result(conn)?;
+//! ```
+
+use ::diesel::deserialize::{self, FromSql, FromSqlRow};
+use ::diesel::expression::{AsExpression, Expression};
+use ::diesel::helper_types::sum;
+use ::diesel::pg::{Pg, PgValue};
+use ::diesel::serialize::{self, Output, ToSql};
+use ::diesel::sql_types::{Nullable, Numeric};
+
+use crate::{BaseMoney, Currency, Decimal, Money};
+
+// Proxies deriving `AsExpression` and `Queryable` for the money types.
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Numeric)]
+#[allow(dead_code)]
+struct MoneyProxy<C: Currency>(Money<C>);
+
+#[cfg(feature = "raw_money")]
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Numeric)]
+#[allow(dead_code)]
+struct RawMoneyProxy<C: Currency>(crate::RawMoney<C>);
+
+mod functions {
+    use ::diesel::sql_types::{Nullable, Numeric};
+
+    ::diesel::define_sql_function! {
+        #[sql_name = "COALESCE"]
+        fn coalesce_numeric(x: Nullable<Numeric>, y: Numeric) -> Numeric;
+    }
+}
+
+/// Return type of [`sum_money`].
+pub type SumMoney<E> = functions::coalesce_numeric<sum<E>, Decimal>;
+
+/// Sums numeric expression `expr` in a query, e.g. money column, as `COALESCE(SUM(expr), 0)`.
+///
+/// Unlike `diesel::dsl::sum`, it's not nullable, so it's read directly into `Money<C>`, being zero on no rows.
+pub fn sum_money<E>(expr: E) -> SumMoney<E>
+where
+    E: Expression<SqlType = Numeric>,
+    sum<E>: Expression<SqlType = Nullable<Numeric>>,
+{
+    functions::coalesce_numeric(::diesel::dsl::sum(expr), Decimal::ZERO)
+}
+
+impl<C: Currency> ToSql<Numeric, Pg> for Money<C> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        // amount is a copy, written through output reborrowed for its lifetime.
+        let amount = self.amount();
+        <Decimal as ToSql<Numeric, Pg>>::to_sql(&amount, &mut out.reborrow())
+    }
+}
+
+impl<C: Currency> FromSql<Numeric, Pg> for Money<C> {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let amount = <Decimal as FromSql<Numeric, Pg>>::from_sql(bytes)?;
+        crate::currency::check_minor_unit::<C>(amount)?;
+        Ok(Self::from_decimal(amount))
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> ToSql<Numeric, Pg> for crate::RawMoney<C> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let amount = self.amount();
+        <Decimal as ToSql<Numeric, Pg>>::to_sql(&amount, &mut out.reborrow())
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> FromSql<Numeric, Pg> for crate::RawMoney<C> {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(Self::from_decimal(
+            <Decimal as FromSql<Numeric, Pg>>::from_sql(bytes)?,
+        ))
+    }
+}
@@ -0,0 +1,94 @@
+use ::diesel::debug_query;
+use ::diesel::deserialize::Queryable;
+use ::diesel::pg::Pg;
+use ::diesel::prelude::*;
+use ::diesel::sql_types::{Int4, Nullable, Numeric};
+
+use crate::diesel::sum_money;
+use crate::iso::{JPY, USD};
+use crate::{Money, money};
+
+::diesel::table! {
+    invoices (id) {
+        id -> Int4,
+        total -> Numeric,
+        fee -> Nullable<Numeric>,
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = invoices)]
+struct NewInvoice {
+    id: i32,
+    total: Money<USD>,
+    fee: Option<Money<USD>>,
+}
+
+#[derive(Queryable)]
+#[allow(dead_code)]
+struct Invoice {
+    id: i32,
+    total: Money<USD>,
+    fee: Option<Money<USD>>,
+}
+
+fn assert_queryable<T: Queryable<ST, Pg>, ST>() {}
+
+#[test]
+fn test_queryable() {
+    assert_queryable::<Money<USD>, Numeric>();
+    assert_queryable::<Option<Money<JPY>>, Nullable<Numeric>>();
+    assert_queryable::<Invoice, (Int4, Numeric, Nullable<Numeric>)>();
+}
+
+#[test]
+fn test_insert() {
+    let invoice = NewInvoice {
+        id: 1,
+        total: money!(USD, 1234.5),
+        fee: None,
+    };
+    let query = ::diesel::insert_into(invoices::table).values(&invoice);
+    #[cfg(not(feature = "redact_debug"))]
+    assert_eq!(
+        debug_query::<Pg, _>(&query).to_string(),
+        r#"INSERT INTO "invoices" ("id", "total", "fee") VALUES ($1, $2, DEFAULT) -- binds: [1, Money(USD, 1234.5)]"#
+    );
+    #[cfg(feature = "redact_debug")]
+    assert!(
+        debug_query::<Pg, _>(&query)
+            .to_string()
+            .ends_with("-- binds: [1, Money(USD, ••••.50)]")
+    );
+}
+
+#[test]
+fn test_filter() {
+    let query = invoices::table
+        .select(invoices::id)
+        .filter(invoices::total.ge(money!(USD, 10)))
+        .filter(invoices::fee.eq(Some(money!(USD, 0.5))));
+    #[cfg(not(feature = "redact_debug"))]
+    assert_eq!(
+        debug_query::<Pg, _>(&query).to_string(),
+        r#"SELECT "invoices"."id" FROM "invoices" WHERE (("invoices"."total" >= $1) AND ("invoices"."fee" = $2)) -- binds: [Money(USD, 10), Some(Money(USD, 0.5))]"#
+    );
+    #[cfg(feature = "redact_debug")]
+    assert!(
+        debug_query::<Pg, _>(&query)
+            .to_string()
+            .ends_with("-- binds: [Money(USD, ••••.00), Some(Money(USD, ••••.50))]")
+    );
+}
+
+#[test]
+fn test_sum_money() {
+    let query = invoices::table.select(sum_money(invoices::total));
+    assert_eq!(
+        debug_query::<Pg, _>(&query).to_string(),
+        r#"SELECT COALESCE(sum("invoices"."total"), $1) FROM "invoices" -- binds: [0]"#
+    );
+
+    fn assert_numeric<E: Expression<SqlType = Numeric>>(_: E) {}
+    assert_numeric(sum_money(invoices::total));
+}
@@ -2,7 +2,7 @@ use std::{error::Error, fmt::Display};
 
 pub type ErrVal = Box<dyn Error + Send + Sync + 'static>;
 
-const ERROR_PREFIX: &str = "[MONEYLIB]";
+pub(crate) const ERROR_PREFIX: &str = "[MONEYLIB]";
 
 /// Error type for moneylib.
 #[non_exhaustive]
@@ -10,10 +10,20 @@ const ERROR_PREFIX: &str = "[MONEYLIB]";
 pub enum MoneyError {
     ParseStrError(ErrVal),
     OverflowError,
+    DivisionByZeroError,
 
     /// CurrencyMismatchError(got, expected)
     CurrencyMismatchError(String, String),
 
+    /// Minor amount doesn't match the currency's minor unit, e.g. JPY amount sent as cents.
+    MinorUnitError(ErrVal),
+
+    /// Input is longer than parsers accept, 256 bytes or 64 digits, rejected before parsing, e.g. of untrusted clients.
+    InputTooLong(ErrVal),
+
+    /// No rate solves the cash flows, e.g. internal rate of return without sign change or not converging.
+    FinanceError(ErrVal),
+
     #[cfg(feature = "locale")]
     ParseLocale(ErrVal),
 
@@ -22,6 +32,10 @@ pub enum MoneyError {
 
     #[cfg(feature = "obj_money")]
     ObjMoneyError(ErrVal),
+
+    /// AmbiguousSymbolError(symbol, candidate codes), symbol shared by currencies with no preference resolving it.
+    #[cfg(feature = "obj_money")]
+    AmbiguousSymbolError(String, Vec<String>),
 }
 
 impl Display for MoneyError {
@@ -31,6 +45,8 @@ impl Display for MoneyError {
 
             MoneyError::OverflowError => write!(f, "{ERROR_PREFIX} got overflowed"),
 
+            MoneyError::DivisionByZeroError => write!(f, "{ERROR_PREFIX} division by zero"),
+
             MoneyError::CurrencyMismatchError(got, expected) => {
                 write!(
                     f,
@@ -38,6 +54,14 @@ impl Display for MoneyError {
                 )
             }
 
+            MoneyError::MinorUnitError(err) => {
+                write!(f, "{ERROR_PREFIX} minor unit error: {}", err)
+            }
+
+            MoneyError::InputTooLong(err) => write!(f, "{ERROR_PREFIX} input too long: {}", err),
+
+            MoneyError::FinanceError(err) => write!(f, "{ERROR_PREFIX} finance error: {}", err),
+
             #[cfg(feature = "locale")]
             MoneyError::ParseLocale(err) => {
                 write!(f, "{ERROR_PREFIX} error parsing locale: {}", err)
@@ -48,6 +72,13 @@ impl Display for MoneyError {
 
             #[cfg(feature = "obj_money")]
             MoneyError::ObjMoneyError(err) => write!(f, "{ERROR_PREFIX} obj_money error: {}", err),
+
+            #[cfg(feature = "obj_money")]
+            MoneyError::AmbiguousSymbolError(symbol, candidates) => write!(
+                f,
+                "{ERROR_PREFIX} ambiguous symbol {symbol}, candidates: {}",
+                candidates.join(", ")
+            ),
         }
     }
 }
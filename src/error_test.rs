@@ -13,6 +13,12 @@ fn test_overflow_error_display() {
     assert_eq!(err.to_string(), "[MONEYLIB] got overflowed");
 }
 
+#[test]
+fn test_division_by_zero_error_display() {
+    let err = MoneyError::DivisionByZeroError;
+    assert_eq!(err.to_string(), "[MONEYLIB] division by zero");
+}
+
 #[test]
 fn test_currency_mismatch_error_display() {
     let err = MoneyError::CurrencyMismatchError("EUR".to_string(), "USD".to_string());
@@ -22,6 +28,15 @@ fn test_currency_mismatch_error_display() {
     );
 }
 
+#[test]
+fn test_minor_unit_error_display() {
+    let err = MoneyError::MinorUnitError("scaled by 100".to_string().into());
+    assert_eq!(
+        err.to_string(),
+        "[MONEYLIB] minor unit error: scaled by 100"
+    );
+}
+
 #[cfg(feature = "locale")]
 #[test]
 fn test_parse_locale_error_display() {
@@ -45,3 +60,31 @@ fn test_obj_money_error_display() {
     let err = MoneyError::ObjMoneyError(err_msg.into());
     assert!(err.to_string().contains("obj_money error"));
 }
+
+#[cfg(feature = "obj_money")]
+#[test]
+fn test_ambiguous_symbol_error_display() {
+    let err = MoneyError::AmbiguousSymbolError("$".into(), vec!["CAD".into(), "USD".into()]);
+    assert_eq!(
+        err.to_string(),
+        "[MONEYLIB] ambiguous symbol $, candidates: CAD, USD"
+    );
+}
+
+#[test]
+fn test_input_too_long_error_display() {
+    let err = MoneyError::InputTooLong("300 bytes, more than maximum of 256 bytes".into());
+    assert_eq!(
+        err.to_string(),
+        "[MONEYLIB] input too long: 300 bytes, more than maximum of 256 bytes"
+    );
+}
+
+#[test]
+fn test_finance_error_display() {
+    let err = MoneyError::FinanceError("cash flows have no sign change".into());
+    assert_eq!(
+        err.to_string(),
+        "[MONEYLIB] finance error: cash flows have no sign change"
+    );
+}
@@ -0,0 +1,446 @@
+//! finance contains time value of money calculations over cash flows of [`Money`].
+//!
+//! All computations are done in [`Decimal`] with full precision, like [`RawMoney`](crate::RawMoney), and only the
+//! result is rounded into currency's minor unit, with bankers rounding like [`Money::from_decimal`].
+//!
+//! `rate` is the interest rate per period in percent, 5% -> rate = 5. Cash flows are at the end of each period,
+//! the first one at period 0 is not discounted.
+//!
+//! [`fv`], [`pv`] and [`pmt`] follow spreadsheet(Excel's `FV`, `PV` and `PMT`) semantics: money paid out is negative
+//! and money received is positive, and payments are at the end or beginning of each period by [`PaymentTiming`].
+//! Their result is rounded into currency's minor unit with the given [`RoundingStrategy`].
+
+use rust_decimal::MathematicalOps;
+
+use crate::{BaseMoney, Currency, Decimal, Money, MoneyError, RoundingStrategy, macros::dec};
+
+/// Default starting guess of [`irr`] in percent, 10%.
+pub const IRR_GUESS: Decimal = dec!(10);
+
+/// Default tolerance of [`irr`] in percent, iteration stops once the rate moves less than this.
+pub const IRR_TOLERANCE: Decimal = dec!(0.000_000_01);
+
+/// Default maximum number of iterations of [`irr`].
+pub const IRR_MAX_ITERATIONS: u32 = 100;
+
+/// When payments are made within each period, Excel's `type` argument.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PaymentTiming {
+    /// At the end of each period, `type` 0.
+    #[default]
+    End,
+
+    /// At the beginning of each period, `type` 1.
+    Beginning,
+}
+
+// Maximum number of times the discount factor is doubled searching for a sign change of NPV.
+const MAX_BRACKET_STEPS: u32 = 64;
+
+/// Returns net present value of `cashflows` discounted at `rate` percent per period.
+///
+/// The first cash flow is at period 0, e.g. the initial investment as negative amount, and each following one
+/// a period later.
+///
+/// # Errors
+///
+/// - [`MoneyError::DivisionByZeroError`] if `rate` is -100.
+/// - [`MoneyError::OverflowError`] if the computation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{finance, money, macros::dec};
+///
+/// let cashflows = [money!(USD, -1_000), money!(USD, 300), money!(USD, 400), money!(USD, 500)];
+/// assert_eq!(finance::npv(dec!(10), &cashflows).unwrap(), money!(USD, -21.04));
+/// assert_eq!(finance::npv(dec!(0), &cashflows).unwrap(), money!(USD, 200));
+///
+/// assert!(finance::npv(dec!(-100), &cashflows).is_err());
+/// ```
+pub fn npv<C: Currency>(rate: Decimal, cashflows: &[Money<C>]) -> Result<Money<C>, MoneyError> {
+    let growth = growth(rate)?;
+    if growth.is_zero() {
+        return Err(MoneyError::DivisionByZeroError);
+    }
+
+    // Horner's scheme from the last cash flow, discounting one period at a time without powers.
+    let value = cashflows
+        .iter()
+        .rev()
+        .try_fold(Decimal::ZERO, |acc, cashflow| {
+            acc.checked_div(growth)
+                .and_then(|discounted| discounted.checked_add(cashflow.amount()))
+                .ok_or(MoneyError::OverflowError)
+        })?;
+
+    Ok(Money::from_decimal(value))
+}
+
+/// Returns internal rate of return of `cashflows` in percent per period, the rate at which their [`npv`] is zero.
+///
+/// Same as [`irr_with`] with [`IRR_GUESS`], [`IRR_TOLERANCE`] and [`IRR_MAX_ITERATIONS`].
+///
+/// # Errors
+///
+/// See [`irr_with`].
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{finance, money, macros::dec};
+///
+/// let cashflows = [
+///     money!(USD, -70_000),
+///     money!(USD, 12_000),
+///     money!(USD, 15_000),
+///     money!(USD, 18_000),
+///     money!(USD, 21_000),
+///     money!(USD, 26_000),
+/// ];
+/// assert_eq!(finance::irr(&cashflows).unwrap().round_dp(2), dec!(8.66));
+/// assert_eq!(finance::irr(&cashflows[..5]).unwrap().round_dp(2), dec!(-2.12));
+///
+/// // no sign change, no rate makes it zero.
+/// assert!(finance::irr(&[money!(USD, 100), money!(USD, 100)]).is_err());
+/// ```
+pub fn irr<C: Currency>(cashflows: &[Money<C>]) -> Result<Decimal, MoneyError> {
+    irr_with(cashflows, IRR_GUESS, IRR_TOLERANCE, IRR_MAX_ITERATIONS)
+}
+
+/// Returns internal rate of return of `cashflows` in percent per period, starting from `guess` percent.
+///
+/// The root is first bracketed searching away from `guess`, then refined with Newton steps falling back to bisection
+/// whenever a step leaves the bracket, so it always converges once bracketed. Iteration stops once the rate moves less
+/// than `tolerance` percent. With several sign changes more than one rate may solve the cash flows, the one nearest to
+/// `guess` from above is returned.
+///
+/// The rate is not rounded, round it with e.g. [`Decimal::round_dp`].
+///
+/// # Errors
+///
+/// - [`MoneyError::FinanceError`] if `cashflows` don't have both positive and negative amounts, `guess` is not greater
+///   than -100, no sign change is found, or it doesn't converge within `max_iterations`.
+/// - [`MoneyError::OverflowError`] if the computation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{finance, money, macros::dec};
+///
+/// let cashflows = [money!(USD, -1_000), money!(USD, 600), money!(USD, 600)];
+/// let rate = finance::irr_with(&cashflows, dec!(5), dec!(0.0001), 50).unwrap();
+/// assert_eq!(rate.round_dp(2), dec!(13.07));
+/// assert_eq!(finance::npv(rate, &cashflows).unwrap(), money!(USD, 0));
+///
+/// assert!(finance::irr_with(&cashflows, dec!(5), dec!(0.0001), 0).is_err());
+/// ```
+pub fn irr_with<C: Currency>(
+    cashflows: &[Money<C>],
+    guess: Decimal,
+    tolerance: Decimal,
+    max_iterations: u32,
+) -> Result<Decimal, MoneyError> {
+    // Leading zero cash flows only shift the others by whole periods, the same rate solves the rest.
+    let amounts: Vec<Decimal> = cashflows
+        .iter()
+        .map(BaseMoney::amount)
+        .skip_while(|amount| amount.is_zero())
+        .collect();
+    let has_positive = amounts.iter().any(|amount| *amount > Decimal::ZERO);
+    let has_negative = amounts.iter().any(|amount| *amount < Decimal::ZERO);
+    if !has_positive || !has_negative {
+        return Err(MoneyError::FinanceError(
+            "cash flows must have both positive and negative amounts".into(),
+        ));
+    }
+
+    let growth = growth(guess)?;
+    if growth <= Decimal::ZERO {
+        return Err(MoneyError::FinanceError(
+            format!("guess must be greater than -100, got {guess}").into(),
+        ));
+    }
+
+    // Solved over discount factor `1 / (1 + rate / 100)` turning NPV into a polynomial whose value at 0 is the first
+    // cash flow, `lower` keeps its sign and `upper` the opposite.
+    let first_negative = amounts[0].is_sign_negative();
+    let mut lower = Decimal::ZERO;
+    let mut upper = Decimal::ONE
+        .checked_div(growth)
+        .ok_or(MoneyError::OverflowError)?;
+    let mut bracket_steps = 0;
+    loop {
+        let (value, _) = polynomial(&amounts, upper)?;
+        if value.is_zero() {
+            return rate(upper);
+        }
+        if value.is_sign_negative() != first_negative {
+            break;
+        }
+        bracket_steps += 1;
+        if bracket_steps > MAX_BRACKET_STEPS {
+            return Err(MoneyError::FinanceError(
+                "no internal rate of return found".into(),
+            ));
+        }
+        lower = upper;
+        upper = upper
+            .checked_mul(dec!(2))
+            .ok_or(MoneyError::OverflowError)?;
+    }
+
+    let mut factor = upper;
+    let mut current = rate(factor)?;
+    for _ in 0..max_iterations {
+        let (value, slope) = polynomial(&amounts, factor)?;
+        if value.is_zero() {
+            return Ok(current);
+        }
+        if value.is_sign_negative() == first_negative {
+            lower = factor;
+        } else {
+            upper = factor;
+        }
+
+        let next = match value
+            .checked_div(slope)
+            .and_then(|step| factor.checked_sub(step))
+        {
+            Some(next) if next > lower && next < upper => next,
+            _ => lower
+                .checked_add(upper)
+                .and_then(|sum| sum.checked_div(dec!(2)))
+                .ok_or(MoneyError::OverflowError)?,
+        };
+        let next_rate = rate(next)?;
+        let moved = next_rate
+            .checked_sub(current)
+            .ok_or(MoneyError::OverflowError)?;
+        if moved.abs() <= tolerance {
+            return Ok(next_rate);
+        }
+        factor = next;
+        current = next_rate;
+    }
+
+    Err(MoneyError::FinanceError(
+        format!("internal rate of return did not converge within {max_iterations} iterations")
+            .into(),
+    ))
+}
+
+/// Returns future value after `periods` of `payment` per period on top of `present` value, Excel's `FV`.
+///
+/// # Errors
+///
+/// [`MoneyError::OverflowError`] if the computation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{RoundingStrategy, finance::{self, PaymentTiming}, money, macros::dec};
+///
+/// // FV(0.5%, 10, -200, -500, 1)
+/// let fv = finance::fv(
+///     dec!(0.5),
+///     10,
+///     money!(USD, -200),
+///     money!(USD, -500),
+///     PaymentTiming::Beginning,
+///     RoundingStrategy::HalfUp,
+/// )
+/// .unwrap();
+/// assert_eq!(fv, money!(USD, 2_581.40));
+/// ```
+pub fn fv<C: Currency>(
+    rate: Decimal,
+    periods: u32,
+    payment: Money<C>,
+    present: Money<C>,
+    timing: PaymentTiming,
+    strategy: RoundingStrategy,
+) -> Result<Money<C>, MoneyError> {
+    let (compound, annuity) = factors(rate, periods, timing)?;
+
+    // -(pv * (1 + rate)^n + pmt * annuity)
+    let value = present
+        .amount()
+        .checked_mul(compound)
+        .zip(payment.amount().checked_mul(annuity))
+        .and_then(|(present, payments)| present.checked_add(payments))
+        .ok_or(MoneyError::OverflowError)?;
+
+    Ok(rounded(-value, strategy))
+}
+
+/// Returns present value of `payment` per period for `periods` plus `future` value at the end, Excel's `PV`.
+///
+/// # Errors
+///
+/// - [`MoneyError::DivisionByZeroError`] if `rate` is -100.
+/// - [`MoneyError::OverflowError`] if the computation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{RoundingStrategy, finance::{self, PaymentTiming}, money, macros::dec};
+///
+/// // PV(8%/12, 12*20, 500): paying 500 monthly for 20 years is worth 59,777.15 today.
+/// let pv = finance::pv(
+///     dec!(8) / dec!(12),
+///     240,
+///     money!(USD, 500),
+///     money!(USD, 0),
+///     PaymentTiming::End,
+///     RoundingStrategy::HalfUp,
+/// )
+/// .unwrap();
+/// assert_eq!(pv, money!(USD, -59_777.15));
+/// ```
+pub fn pv<C: Currency>(
+    rate: Decimal,
+    periods: u32,
+    payment: Money<C>,
+    future: Money<C>,
+    timing: PaymentTiming,
+    strategy: RoundingStrategy,
+) -> Result<Money<C>, MoneyError> {
+    let (compound, annuity) = factors(rate, periods, timing)?;
+    if compound.is_zero() {
+        return Err(MoneyError::DivisionByZeroError);
+    }
+
+    // -(fv + pmt * annuity) / (1 + rate)^n
+    let value = payment
+        .amount()
+        .checked_mul(annuity)
+        .and_then(|payments| future.amount().checked_add(payments))
+        .and_then(|value| value.checked_div(compound))
+        .ok_or(MoneyError::OverflowError)?;
+
+    Ok(rounded(-value, strategy))
+}
+
+/// Returns payment per period paying off `present` value down to `future` value in `periods`, Excel's `PMT`.
+///
+/// # Errors
+///
+/// - [`MoneyError::DivisionByZeroError`] if `periods` is zero.
+/// - [`MoneyError::OverflowError`] if the computation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{RoundingStrategy, finance::{self, PaymentTiming}, money, macros::dec};
+///
+/// // PMT(8%/12, 10, 10000): monthly payment of a 10,000 loan over 10 months.
+/// let pmt = finance::pmt(
+///     dec!(8) / dec!(12),
+///     10,
+///     money!(USD, 10_000),
+///     money!(USD, 0),
+///     PaymentTiming::End,
+///     RoundingStrategy::HalfUp,
+/// )
+/// .unwrap();
+/// assert_eq!(pmt, money!(USD, -1_037.03));
+///
+/// // Rounding away from zero so the loan is surely paid off.
+/// let pmt = finance::pmt(
+///     dec!(8) / dec!(12),
+///     10,
+///     money!(USD, 10_000),
+///     money!(USD, 0),
+///     PaymentTiming::End,
+///     RoundingStrategy::Ceil,
+/// )
+/// .unwrap();
+/// assert_eq!(pmt, money!(USD, -1_037.04));
+/// ```
+pub fn pmt<C: Currency>(
+    rate: Decimal,
+    periods: u32,
+    present: Money<C>,
+    future: Money<C>,
+    timing: PaymentTiming,
+    strategy: RoundingStrategy,
+) -> Result<Money<C>, MoneyError> {
+    let (compound, annuity) = factors(rate, periods, timing)?;
+    if annuity.is_zero() {
+        return Err(MoneyError::DivisionByZeroError);
+    }
+
+    // -(fv + pv * (1 + rate)^n) / annuity
+    let value = present
+        .amount()
+        .checked_mul(compound)
+        .and_then(|present| future.amount().checked_add(present))
+        .and_then(|value| value.checked_div(annuity))
+        .ok_or(MoneyError::OverflowError)?;
+
+    Ok(rounded(-value, strategy))
+}
+
+// Returns `(1 + rate)^periods` and annuity factor of payments, the future value of paying 1 every period.
+fn factors(
+    rate: Decimal,
+    periods: u32,
+    timing: PaymentTiming,
+) -> Result<(Decimal, Decimal), MoneyError> {
+    let growth = growth(rate)?;
+    let fraction = growth
+        .checked_sub(Decimal::ONE)
+        .ok_or(MoneyError::OverflowError)?;
+    let compound = growth
+        .checked_powu(periods.into())
+        .ok_or(MoneyError::OverflowError)?;
+
+    // n when there's no interest, ((1 + rate)^n - 1) / rate otherwise, one period more for payments in advance.
+    if fraction.is_zero() {
+        return Ok((compound, Decimal::from(periods)));
+    }
+    let annuity = compound
+        .checked_sub(Decimal::ONE)
+        .and_then(|interest| interest.checked_div(fraction))
+        .and_then(|annuity| match timing {
+            PaymentTiming::End => Some(annuity),
+            PaymentTiming::Beginning => annuity.checked_mul(growth),
+        })
+        .ok_or(MoneyError::OverflowError)?;
+
+    Ok((compound, annuity))
+}
+
+fn rounded<C: Currency>(value: Decimal, strategy: RoundingStrategy) -> Money<C> {
+    Money::from_decimal(strategy.round_dp(value, C::MINOR_UNIT.into()))
+}
+
+// Returns value and derivative at `factor` of polynomial with `amounts` as coefficients, lowest degree first.
+fn polynomial(amounts: &[Decimal], factor: Decimal) -> Result<(Decimal, Decimal), MoneyError> {
+    amounts
+        .iter()
+        .rev()
+        .try_fold((Decimal::ZERO, Decimal::ZERO), |(value, slope), amount| {
+            let slope = slope.checked_mul(factor)?.checked_add(value)?;
+            let value = value.checked_mul(factor)?.checked_add(*amount)?;
+            Some((value, slope))
+        })
+        .ok_or(MoneyError::OverflowError)
+}
+
+// Returns rate in percent of discount `factor`, `(1 / factor - 1) * 100`.
+fn rate(factor: Decimal) -> Result<Decimal, MoneyError> {
+    Decimal::ONE
+        .checked_div(factor)
+        .and_then(|growth| growth.checked_sub(Decimal::ONE))
+        .and_then(|rate| rate.checked_mul(dec!(100)))
+        .ok_or(MoneyError::OverflowError)
+}
+
+// Returns `1 + rate / 100`.
+fn growth(rate: Decimal) -> Result<Decimal, MoneyError> {
+    rate.checked_div(dec!(100))
+        .and_then(|rate| Decimal::ONE.checked_add(rate))
+        .ok_or(MoneyError::OverflowError)
+}
@@ -0,0 +1,456 @@
+use proptest::prelude::*;
+
+use crate::finance::{self, PaymentTiming};
+use crate::iso::USD;
+use crate::macros::{dec, money};
+use crate::{BaseMoney, Decimal, Money, MoneyError, RoundingStrategy};
+
+#[test]
+fn test_npv() {
+    let cashflows = [
+        money!(USD, -10_000),
+        money!(USD, 3_000),
+        money!(USD, 4_200),
+        money!(USD, 6_800),
+    ];
+    // -10000 + 3000/1.1 + 4200/1.21 + 6800/1.331
+    assert_eq!(
+        finance::npv(dec!(10), &cashflows).unwrap(),
+        money!(USD, 1_307.29)
+    );
+    assert_eq!(
+        finance::npv(dec!(0), &cashflows).unwrap(),
+        money!(USD, 4_000)
+    );
+
+    // negative rates grow later cash flows
+    assert_eq!(
+        finance::npv(dec!(-50), &[money!(USD, 0), money!(USD, 1)]).unwrap(),
+        money!(USD, 2)
+    );
+}
+
+#[test]
+fn test_npv_rounds_only_the_result() {
+    // discounted cash flows are 0.0045... and 0.0020..., rounding them first would give zero.
+    let cashflows = [money!(USD, 0), money!(USD, 0.01), money!(USD, 0.01)];
+    assert_eq!(
+        finance::npv(dec!(120), &cashflows).unwrap(),
+        money!(USD, 0.01)
+    );
+
+    let npv = finance::npv(dec!(10), &[money!(JPY, 0), money!(JPY, 1), money!(JPY, 1)]).unwrap();
+    // 1/1.1 + 1/1.21 = 1.735...
+    assert_eq!(npv, money!(JPY, 2));
+}
+
+#[test]
+fn test_npv_edge_cases() {
+    assert_eq!(
+        finance::npv::<USD>(dec!(10), &[]).unwrap(),
+        Money::<USD>::default()
+    );
+    assert_eq!(
+        finance::npv(dec!(10), &[money!(USD, -5)]).unwrap(),
+        money!(USD, -5)
+    );
+    assert!(matches!(
+        finance::npv(dec!(-100), &[money!(USD, 1), money!(USD, 1)]),
+        Err(MoneyError::DivisionByZeroError)
+    ));
+    assert!(matches!(
+        finance::npv(
+            dec!(-99.99999),
+            &[
+                Money::<USD>::from_decimal(Decimal::ZERO),
+                Money::<USD>::from_decimal(Decimal::MAX)
+            ]
+        ),
+        Err(MoneyError::OverflowError)
+    ));
+}
+
+#[test]
+fn test_npv_amount_scale() {
+    let npv = finance::npv(dec!(5), &[money!(USD, 100), money!(USD, 105)]).unwrap();
+    assert_eq!(npv.amount(), dec!(200));
+}
+
+#[test]
+fn test_irr() {
+    let cashflows = [
+        money!(USD, -10_000),
+        money!(USD, 3_000),
+        money!(USD, 4_200),
+        money!(USD, 6_800),
+    ];
+    let rate = finance::irr(&cashflows).unwrap();
+    assert_eq!(rate.round_dp(6), dec!(16.340560));
+    assert_eq!(finance::npv(rate, &cashflows).unwrap(), money!(USD, 0));
+
+    assert_eq!(
+        finance::irr(&[money!(USD, -100), money!(USD, 110)])
+            .unwrap()
+            .round_dp(8),
+        dec!(10)
+    );
+
+    // negative rate, paid back less than invested
+    assert_eq!(
+        finance::irr(&[money!(USD, -100), money!(USD, 50), money!(USD, 40)])
+            .unwrap()
+            .round_dp(2),
+        dec!(-6.99)
+    );
+
+    // borrowing first, repaying later
+    assert_eq!(
+        finance::irr(&[money!(USD, 100), money!(USD, -110)])
+            .unwrap()
+            .round_dp(8),
+        dec!(10)
+    );
+}
+
+#[test]
+fn test_irr_leading_zero_cashflows() {
+    let rate = finance::irr(&[
+        money!(USD, 0),
+        money!(USD, 0),
+        money!(USD, -100),
+        money!(USD, 0),
+        money!(USD, 121),
+    ])
+    .unwrap();
+    assert_eq!(rate.round_dp(8), dec!(10));
+}
+
+#[test]
+fn test_irr_with_guess_far_from_rate() {
+    let cashflows = [money!(USD, -1_000), money!(USD, 600), money!(USD, 600)];
+    for guess in [
+        dec!(-99),
+        dec!(-50),
+        dec!(0),
+        dec!(13),
+        dec!(500),
+        dec!(1_000_000),
+    ] {
+        let rate = finance::irr_with(&cashflows, guess, finance::IRR_TOLERANCE, 100).unwrap();
+        assert_eq!(rate.round_dp(4), dec!(13.0662), "guess {guess}");
+    }
+}
+
+#[test]
+fn test_irr_errors() {
+    assert!(matches!(
+        finance::irr::<USD>(&[]),
+        Err(MoneyError::FinanceError(_))
+    ));
+    assert!(matches!(
+        finance::irr(&[money!(USD, 100), money!(USD, 100)]),
+        Err(MoneyError::FinanceError(_))
+    ));
+    assert!(matches!(
+        finance::irr(&[money!(USD, -100), money!(USD, 0)]),
+        Err(MoneyError::FinanceError(_))
+    ));
+
+    let cashflows = [money!(USD, -100), money!(USD, 110)];
+    match finance::irr_with(&cashflows, dec!(-100), finance::IRR_TOLERANCE, 10) {
+        Err(MoneyError::FinanceError(msg)) => {
+            assert_eq!(msg.to_string(), "guess must be greater than -100, got -100")
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+    match finance::irr_with(&cashflows, dec!(50), Decimal::ZERO, 1) {
+        Err(MoneyError::FinanceError(msg)) => assert_eq!(
+            msg.to_string(),
+            "internal rate of return did not converge within 1 iterations"
+        ),
+        other => panic!("unexpected {other:?}"),
+    }
+}
+
+// Examples of Excel's documentation of FV, PV and PMT.
+#[test]
+fn test_excel_fixtures() {
+    let monthly = |annual: Decimal| annual / dec!(12);
+    let end = PaymentTiming::End;
+    let beginning = PaymentTiming::Beginning;
+    let half_up = RoundingStrategy::HalfUp;
+
+    // FV(0.06/12, 10, -200, -500, 1)
+    assert_eq!(
+        finance::fv(
+            monthly(dec!(6)),
+            10,
+            money!(USD, -200),
+            money!(USD, -500),
+            beginning,
+            half_up
+        )
+        .unwrap(),
+        money!(USD, 2_581.40)
+    );
+    // FV(0.12/12, 12, -1000)
+    assert_eq!(
+        finance::fv(
+            monthly(dec!(12)),
+            12,
+            money!(USD, -1_000),
+            money!(USD, 0),
+            end,
+            half_up
+        )
+        .unwrap(),
+        money!(USD, 12_682.50)
+    );
+    // FV(0.11/12, 35, -2000, , 1)
+    assert_eq!(
+        finance::fv(
+            monthly(dec!(11)),
+            35,
+            money!(USD, -2_000),
+            money!(USD, 0),
+            beginning,
+            half_up
+        )
+        .unwrap(),
+        money!(USD, 82_846.25)
+    );
+    // PV(0.08/12, 12*20, 500, , 0)
+    assert_eq!(
+        finance::pv(
+            monthly(dec!(8)),
+            240,
+            money!(USD, 500),
+            money!(USD, 0),
+            end,
+            half_up
+        )
+        .unwrap(),
+        money!(USD, -59_777.15)
+    );
+    // PMT(0.08/12, 10, 10000)
+    assert_eq!(
+        finance::pmt(
+            monthly(dec!(8)),
+            10,
+            money!(USD, 10_000),
+            money!(USD, 0),
+            end,
+            half_up
+        )
+        .unwrap(),
+        money!(USD, -1_037.03)
+    );
+    // PMT(0.08/12, 10, 10000, 0, 1)
+    assert_eq!(
+        finance::pmt(
+            monthly(dec!(8)),
+            10,
+            money!(USD, 10_000),
+            money!(USD, 0),
+            beginning,
+            half_up
+        )
+        .unwrap(),
+        money!(USD, -1_030.16)
+    );
+    // PMT(0.06/12, 18*12, 0, 50000)
+    assert_eq!(
+        finance::pmt(
+            monthly(dec!(6)),
+            216,
+            money!(USD, 0),
+            money!(USD, 50_000),
+            end,
+            half_up
+        )
+        .unwrap(),
+        money!(USD, -129.08)
+    );
+}
+
+#[test]
+fn test_fv_pv_pmt_zero_rate() {
+    let end = PaymentTiming::End;
+    let bankers = RoundingStrategy::BankersRounding;
+    assert_eq!(
+        finance::fv(
+            dec!(0),
+            12,
+            money!(USD, -100),
+            money!(USD, -1_000),
+            end,
+            bankers
+        )
+        .unwrap(),
+        money!(USD, 2_200)
+    );
+    assert_eq!(
+        finance::pv(
+            dec!(0),
+            12,
+            money!(USD, -100),
+            money!(USD, -1_000),
+            end,
+            bankers
+        )
+        .unwrap(),
+        money!(USD, 2_200)
+    );
+    assert_eq!(
+        finance::pmt(
+            dec!(0),
+            12,
+            money!(USD, 1_000),
+            money!(USD, 200),
+            end,
+            bankers
+        )
+        .unwrap(),
+        money!(USD, -100)
+    );
+    // timing doesn't matter without interest
+    assert_eq!(
+        finance::pmt(
+            dec!(0),
+            12,
+            money!(USD, 1_000),
+            money!(USD, 200),
+            PaymentTiming::Beginning,
+            bankers
+        )
+        .unwrap(),
+        money!(USD, -100)
+    );
+}
+
+#[test]
+fn test_fv_pv_pmt_rounding() {
+    let end = PaymentTiming::End;
+    // -1037.0320...
+    let pmt = |strategy| {
+        finance::pmt(
+            dec!(8) / dec!(12),
+            10,
+            money!(USD, 10_000),
+            money!(USD, 0),
+            end,
+            strategy,
+        )
+        .unwrap()
+    };
+    assert_eq!(pmt(RoundingStrategy::HalfUp), money!(USD, -1_037.03));
+    assert_eq!(pmt(RoundingStrategy::Ceil), money!(USD, -1_037.04));
+    assert_eq!(pmt(RoundingStrategy::Floor), money!(USD, -1_037.03));
+    assert_eq!(pmt(RoundingStrategy::Down), money!(USD, -1_037.04));
+
+    let jpy = finance::pmt(
+        dec!(1),
+        3,
+        money!(JPY, 10_000),
+        money!(JPY, 0),
+        end,
+        RoundingStrategy::Ceil,
+    )
+    .unwrap();
+    // -3400.2211...
+    assert_eq!(jpy, money!(JPY, -3_401));
+}
+
+#[test]
+fn test_fv_pv_pmt_errors() {
+    let end = PaymentTiming::End;
+    let bankers = RoundingStrategy::BankersRounding;
+    assert!(matches!(
+        finance::pmt(dec!(5), 0, money!(USD, 1_000), money!(USD, 0), end, bankers),
+        Err(MoneyError::DivisionByZeroError)
+    ));
+    assert!(matches!(
+        finance::pv(
+            dec!(-100),
+            2,
+            money!(USD, 100),
+            money!(USD, 0),
+            end,
+            bankers
+        ),
+        Err(MoneyError::DivisionByZeroError)
+    ));
+    assert!(matches!(
+        finance::fv(
+            dec!(1_000_000),
+            100,
+            money!(USD, 100),
+            money!(USD, 0),
+            end,
+            bankers
+        ),
+        Err(MoneyError::OverflowError)
+    ));
+}
+
+proptest! {
+    // Paying `pmt` on `pv` for `periods` leaves nothing, up to the rounding of `pmt` accumulated over the periods.
+    #[test]
+    fn prop_pmt_pays_off_pv(
+        rate in 0u32..1_000,
+        periods in 1u32..=360,
+        present in 1i64..100_000_000,
+        beginning in any::<bool>(),
+    ) {
+        let rate = Decimal::new(rate.into(), 2);
+        let present = Money::<USD>::from_minor(present.into()).unwrap();
+        let timing = if beginning { PaymentTiming::Beginning } else { PaymentTiming::End };
+        let bankers = RoundingStrategy::BankersRounding;
+
+        let payment = finance::pmt(rate, periods, present, Money::default(), timing, bankers).unwrap();
+        prop_assert!(payment.is_negative());
+        let remaining = finance::fv(rate, periods, payment, present, timing, bankers).unwrap();
+
+        // half a cent per payment, compounded to the end
+        let annuity = finance::fv(rate, periods, money!(USD, -0.01), Money::default(), timing, RoundingStrategy::Ceil).unwrap();
+        prop_assert!(remaining.amount().abs() <= annuity.amount(), "{} > {}", remaining, annuity);
+    }
+
+    // Discounting the future value of `pv` gives `pv` back, up to a cent of rounding.
+    #[test]
+    fn prop_pv_of_fv(
+        rate in 0u32..5_000,
+        periods in 0u32..=120,
+        present in -100_000_000i64..100_000_000,
+    ) {
+        let rate = Decimal::new(rate.into(), 2);
+        let present = Money::<USD>::from_minor(present.into()).unwrap();
+        let end = PaymentTiming::End;
+        let bankers = RoundingStrategy::BankersRounding;
+
+        let future = finance::fv(rate, periods, Money::default(), present, end, bankers).unwrap();
+        let back = finance::pv(rate, periods, Money::default(), future, end, bankers).unwrap();
+        prop_assert!((back.amount() - present.amount()).abs() <= dec!(0.01), "{} != {}", back, present);
+    }
+
+    // Without interest the functions are plain sums of the cash flows.
+    #[test]
+    fn prop_zero_rate_is_linear(
+        periods in 1u32..=360,
+        payment in -1_000_000i64..1_000_000,
+        present in -100_000_000i64..100_000_000,
+    ) {
+        let payment = Money::<USD>::from_minor(payment.into()).unwrap();
+        let present = Money::<USD>::from_minor(present.into()).unwrap();
+        let end = PaymentTiming::End;
+        let bankers = RoundingStrategy::BankersRounding;
+
+        let total = present.amount() + payment.amount() * Decimal::from(periods);
+        let future = finance::fv(dec!(0), periods, payment, present, end, bankers).unwrap();
+        prop_assert_eq!(future.amount(), -total);
+        prop_assert_eq!(
+            finance::pmt(dec!(0), periods, present, future, end, bankers).unwrap(),
+            payment
+        );
+    }
+}
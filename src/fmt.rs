@@ -1,15 +1,19 @@
+use std::fmt::Write as _;
+
 use crate::Currency;
 
 use crate::MoneyError;
+use crate::currency::{DigitSet, Grouping};
 use crate::{BaseMoney, Decimal};
 
-const ESCAPE_SYMBOL: char = '\\';
+pub(crate) const ESCAPE_SYMBOL: char = '\\';
 
-const AMOUNT_FORMAT_SYMBOL: char = 'a';
-const CODE_FORMAT_SYMBOL: char = 'c';
-const SYMBOL_FORMAT_SYMBOL: char = 's';
-const MINOR_FORMAT_SYMBOL: char = 'm';
-const NEGATIVE_FORMAT_SYMBOL: char = 'n';
+pub(crate) const AMOUNT_FORMAT_SYMBOL: char = 'a';
+pub(crate) const CODE_FORMAT_SYMBOL: char = 'c';
+pub(crate) const SYMBOL_FORMAT_SYMBOL: char = 's';
+pub(crate) const MINOR_FORMAT_SYMBOL: char = 'm';
+pub(crate) const NEGATIVE_FORMAT_SYMBOL: char = 'n';
+pub(crate) const POSITIVE_FORMAT_SYMBOL: char = 'p';
 
 pub(crate) static FORMAT_SYMBOLS: &[char] = &[
     'a', // amount
@@ -17,6 +21,7 @@ pub(crate) static FORMAT_SYMBOLS: &[char] = &[
     's', // currency symbol
     'm', // minor symbol
     'n', // negative sign
+    'p', // plus sign
 ];
 
 pub(crate) const CODE_FORMAT: &str = "c na"; // E.g. USD 1,000.23 or USD -1,000.23
@@ -33,12 +38,14 @@ pub(crate) const SYMBOL_FORMAT_MINOR: &str = "nsa m"; // E.g. $100,023 cents or
 /// - 's': currency symbol (e.g., "$")
 /// - 'm': minor symbol (e.g., "cents")
 /// - 'n': negative sign (-), only displayed when amount is negative
+/// - 'p': plus sign (+), only displayed when amount is positive, e.g. for credits
+///   (a format symbol since 0.14.0, a literal "p" in older format strings must now be escaped as `\p`)
 ///
 /// # Escaping Format Symbols
 ///
 /// To display format symbols as literal characters, prefix them with a backslash (\).
 /// This allows you to:
-/// 1. Insert literal format symbol characters (a, c, s, m, n) into the output
+/// 1. Insert literal format symbol characters (a, c, s, m, n, p) into the output
 /// 2. Mix escaped symbols with actual format symbols in the same string
 ///
 /// Escape sequences:
@@ -47,6 +54,7 @@ pub(crate) const SYMBOL_FORMAT_MINOR: &str = "nsa m"; // E.g. $100,023 cents or
 /// - `\s` outputs literal "s"
 /// - `\m` outputs literal "m"
 /// - `\n` outputs literal "n"
+/// - `\p` outputs literal "p"
 /// - `\\` (double backslash in source) outputs literal "\"
 /// - `\x` (where x is not a format symbol or backslash) outputs literal "\x"
 ///
@@ -77,21 +85,76 @@ pub(crate) fn format<C: Currency>(money: &impl BaseMoney<C>, format_str: &str) -
 }
 
 /// Formats an i128 with thousands separators (absolute value)
+#[cfg(any(test, feature = "obj_money"))]
 pub(crate) fn format_128_abs(num: i128, thousand_separator: &str) -> String {
-    let abs_num = num.abs();
-    let num_str = abs_num.to_string();
-
     let mut result = String::new();
-    let len = num_str.len();
+    let _ = write_128_abs(&mut result, num, thousand_separator, Grouping::THOUSANDS);
+    result
+}
+
+// Longest Decimal and i128 rendered, e.g. 39 digits of `u128::MAX`.
+const DIGITS_CAPACITY: usize = 48;
+
+// Stack buffer of rendered digits, so writing amounts doesn't allocate.
+struct Digits {
+    buf: [u8; DIGITS_CAPACITY],
+    len: usize,
+}
+
+impl Digits {
+    fn render(value: impl std::fmt::Display) -> Result<Self, std::fmt::Error> {
+        let mut digits = Self {
+            buf: [0; DIGITS_CAPACITY],
+            len: 0,
+        };
+        write!(digits, "{}", value)?;
+        Ok(digits)
+    }
+
+    fn as_str(&self) -> &str {
+        // only ascii digits and `.` are written.
+        std::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
 
-    for (i, ch) in num_str.chars().enumerate() {
-        if i > 0 && (len - i).is_multiple_of(3) {
-            result.push_str(thousand_separator);
+impl std::fmt::Write for Digits {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(std::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+// Writes ascii `digits` with `thousand_separator` between groups of `grouping`.
+fn write_grouped(
+    w: &mut impl std::fmt::Write,
+    digits: &str,
+    thousand_separator: &str,
+    grouping: Grouping,
+) -> std::fmt::Result {
+    let len = digits.len();
+    for i in 0..len {
+        if i > 0 && grouping.is_boundary(len - i) {
+            w.write_str(thousand_separator)?;
         }
-        result.push(ch);
+        w.write_str(&digits[i..=i])?;
     }
+    Ok(())
+}
 
-    result
+/// Writes an i128 with thousands separators between groups of `grouping` (absolute value)
+pub(crate) fn write_128_abs(
+    w: &mut impl std::fmt::Write,
+    num: i128,
+    thousand_separator: &str,
+    grouping: Grouping,
+) -> std::fmt::Result {
+    let digits = Digits::render(num.unsigned_abs())?;
+    write_grouped(w, digits.as_str(), thousand_separator, grouping)
 }
 
 /// Formats a Decimal with thousands separators (absolute value)
@@ -101,76 +164,337 @@ pub(crate) fn format_decimal_abs(
     decimal_separator: &str,
     minor_unit: u16,
 ) -> String {
-    let abs_decimal = decimal.abs();
-    let decimal_str = abs_decimal.to_string();
+    format_decimal_abs_grouped(
+        decimal,
+        thousand_separator,
+        decimal_separator,
+        minor_unit,
+        Grouping::THOUSANDS,
+    )
+}
+
+/// Formats a Decimal with thousands separators between groups of `grouping` (absolute value)
+pub(crate) fn format_decimal_abs_grouped(
+    decimal: Decimal,
+    thousand_separator: &str,
+    decimal_separator: &str,
+    minor_unit: u16,
+    grouping: Grouping,
+) -> String {
+    let mut result = String::new();
+    let _ = write_decimal_abs(
+        &mut result,
+        decimal,
+        thousand_separator,
+        decimal_separator,
+        minor_unit,
+        grouping,
+    );
+    result
+}
+
+/// Writes a Decimal with thousands separators between groups of `grouping` (absolute value),
+/// with fractional part padded with zeros into `minor_unit` digits.
+pub(crate) fn write_decimal_abs(
+    w: &mut impl std::fmt::Write,
+    decimal: Decimal,
+    thousand_separator: &str,
+    decimal_separator: &str,
+    minor_unit: u16,
+    grouping: Grouping,
+) -> std::fmt::Result {
+    let digits = Digits::render(decimal.abs())?;
 
     // Split into integer and fractional parts
-    let parts: Vec<&str> = decimal_str.split('.').collect();
-    let integer_part = parts[0];
-    let fractional_part = parts.get(1);
+    let (integer_part, fractional_part) = match digits.as_str().split_once('.') {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (digits.as_str(), ""),
+    };
 
-    // Format integer part with thousands separators
-    let mut result = String::new();
-    let len = integer_part.len();
+    // Write integer part with thousands separators
+    write_grouped(w, integer_part, thousand_separator, grouping)?;
 
-    for (i, ch) in integer_part.chars().enumerate() {
-        if i > 0 && (len - i).is_multiple_of(3) {
-            result.push_str(thousand_separator);
+    // Write fractional part, appending zeros up to minor unit
+    if !fractional_part.is_empty() || minor_unit > 0 {
+        w.write_str(decimal_separator)?;
+        w.write_str(fractional_part)?;
+        for _ in fractional_part.len()..usize::from(minor_unit) {
+            w.write_char('0')?;
         }
-        result.push(ch);
     }
 
-    // Add fractional part if it exists, or append zeros if None
-    if let Some(frac) = fractional_part {
-        result.push_str(decimal_separator);
-        if frac.len() >= minor_unit.into() {
-            result.push_str(frac);
-        } else {
-            result.push_str(frac);
-            let frac_len = frac.len();
-            let minor_unit_len: usize = minor_unit.into();
-            let remaining_frac_len = minor_unit_len - frac_len;
-            result.push_str(&"0".repeat(remaining_frac_len));
-        }
-    } else if minor_unit > 0 {
-        // If no fractional part and minor_unit > 0, append decimal separator with zeros
-        result.push_str(decimal_separator);
-        result.push_str(&"0".repeat(minor_unit.into()));
-    }
+    Ok(())
+}
+
+pub(crate) fn format_with_separator<C: Currency>(
+    money: &impl BaseMoney<C>,
+    format_str: &str,
+    thousand_separator: &str,
+    decimal_separator: &str,
+) -> String {
+    format_with_grouping(
+        money,
+        format_str,
+        thousand_separator,
+        decimal_separator,
+        Grouping::THOUSANDS,
+    )
+}
 
+pub(crate) fn format_with_grouping<C: Currency>(
+    money: &impl BaseMoney<C>,
+    format_str: &str,
+    thousand_separator: &str,
+    decimal_separator: &str,
+    grouping: Grouping,
+) -> String {
+    let mut result = String::new();
+    let _ = write_with_grouping(
+        &mut result,
+        money,
+        format_str,
+        thousand_separator,
+        decimal_separator,
+        grouping,
+    );
     result
 }
 
-pub(crate) fn format_with_separator<C: Currency>(
+/// Writes money formatted with `format_str` into `w`, without allocating.
+pub(crate) fn write_with_grouping<C: Currency, W: std::fmt::Write>(
+    w: &mut W,
     money: &impl BaseMoney<C>,
     format_str: &str,
     thousand_separator: &str,
     decimal_separator: &str,
+    grouping: Grouping,
+) -> std::fmt::Result {
+    write_money(
+        w,
+        money,
+        PatternTokens::new(format_str),
+        AmountLayout {
+            is_minor: contains_active_format_symbol(format_str, MINOR_FORMAT_SYMBOL),
+            separators: (thousand_separator, decimal_separator),
+            grouping,
+            digits: DigitSet::Latin,
+        },
+        C::MINOR_UNIT_SYMBOL,
+    )
+}
+
+/// Formats money with `format_str` and currency's separators, with `minor_symbol` for `m` instead of the currency's.
+pub(crate) fn format_with_minor_symbol<C: Currency>(
+    money: &impl BaseMoney<C>,
+    format_str: &str,
+    minor_symbol: &str,
 ) -> String {
-    let is_negative = money.is_negative();
+    let mut result = String::new();
+    let _ = write_money(
+        &mut result,
+        money,
+        PatternTokens::new(format_str),
+        AmountLayout::of::<C>(format_str, DigitSet::Latin),
+        minor_symbol,
+    );
+    result
+}
 
-    // Use absolute value for display if negative
-    let display_amount = if contains_active_format_symbol(format_str, MINOR_FORMAT_SYMBOL) {
-        if let Some(minor_amount) = money.minor_amount() {
-            format_128_abs(minor_amount, thousand_separator)
-        } else {
-            "OVERFLOWED".into()
-        }
-    } else {
-        format_decimal_abs(
+/// Formats money with `format_str` and currency's separators, with amount's digits in `digits`.
+pub(crate) fn format_with_digits<C: Currency>(
+    money: &impl BaseMoney<C>,
+    format_str: &str,
+    digits: DigitSet,
+) -> String {
+    let mut result = String::new();
+    let _ = write_money(
+        &mut result,
+        money,
+        PatternTokens::new(format_str),
+        AmountLayout::of::<C>(format_str, digits),
+        C::MINOR_UNIT_SYMBOL,
+    );
+    result
+}
+
+// Writes absolute amount of money into `w`, in minor unit if `is_minor`.
+fn write_amount_abs<C: Currency>(
+    w: &mut impl std::fmt::Write,
+    money: &impl BaseMoney<C>,
+    is_minor: bool,
+    (thousand_separator, decimal_separator): (&str, &str),
+    grouping: Grouping,
+) -> std::fmt::Result {
+    if !is_minor {
+        write_decimal_abs(
+            w,
             money.amount(),
             thousand_separator,
             decimal_separator,
             C::MINOR_UNIT,
+            grouping,
         )
+    } else if let Some(minor_amount) = money.minor_amount() {
+        write_128_abs(w, minor_amount, thousand_separator, grouping)
+    } else {
+        w.write_str("OVERFLOWED")
+    }
+}
+
+// Writer replacing ASCII digits written into `w` with digits of `digits`.
+struct LocalizedDigits<'w, W> {
+    w: &'w mut W,
+    digits: DigitSet,
+}
+
+impl<W: std::fmt::Write> std::fmt::Write for LocalizedDigits<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        s.chars().try_for_each(|ch| self.write_char(ch))
+    }
+
+    fn write_char(&mut self, ch: char) -> std::fmt::Result {
+        self.w.write_char(self.digits.localize_char(ch))
+    }
+}
+
+// Layout of the amount written by `write_money`.
+#[derive(Clone, Copy)]
+struct AmountLayout<'s> {
+    // amount in minor unit, for `m` symbol.
+    is_minor: bool,
+    // thousand and decimal separators.
+    separators: (&'s str, &'s str),
+    grouping: Grouping,
+    digits: DigitSet,
+}
+
+impl AmountLayout<'static> {
+    // Layout of `format_str` with currency's separators and thousands grouping.
+    fn of<C: Currency>(format_str: &str, digits: DigitSet) -> Self {
+        Self {
+            is_minor: contains_active_format_symbol(format_str, MINOR_FORMAT_SYMBOL),
+            separators: (C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR),
+            grouping: Grouping::THOUSANDS,
+            digits,
+        }
+    }
+}
+
+// Writes money as `tokens` into `w`, with amount in `layout`, and `minor_symbol` for `m`.
+fn write_money<'a, C: Currency, W: std::fmt::Write>(
+    w: &mut W,
+    money: &impl BaseMoney<C>,
+    tokens: impl Iterator<Item = Token<'a>>,
+    layout: AmountLayout<'_>,
+    minor_symbol: &str,
+) -> std::fmt::Result {
+    let is_negative = money.is_negative();
+    let is_positive = money.is_positive();
+    let AmountLayout {
+        is_minor,
+        separators,
+        grouping,
+        digits,
+    } = layout;
+
+    let write_amount = |w: &mut W| {
+        if digits == DigitSet::Latin {
+            write_amount_abs(w, money, is_minor, separators, grouping)
+        } else {
+            write_amount_abs(
+                &mut LocalizedDigits { w, digits },
+                money,
+                is_minor,
+                separators,
+                grouping,
+            )
+        }
+    };
+
+    write_tokens::<C, _>(
+        w,
+        tokens,
+        write_amount,
+        is_negative,
+        is_positive,
+        minor_symbol,
+    )
+}
+
+/// Returns `amount` rounded into `scale` decimal places with bankers rounding, padded with zeros into exactly `scale`
+/// decimal places if it can hold them. `scale` is clamped into [`Decimal::MAX_SCALE`].
+pub(crate) fn round_to_scale(amount: Decimal, scale: u32) -> Decimal {
+    let scale = scale.min(Decimal::MAX_SCALE);
+    let mut amount = amount.round_dp(scale);
+    // pads zeros, amount is already rounded.
+    amount.rescale(scale);
+    amount
+}
+
+/// Formats `amount` in code format with its own decimal places, padded with zeros into at least `min_scale`
+/// decimal places, e.g. `USD -1,234.5` with `min_scale` 0 or `USD -1,234.50` with 2.
+pub(crate) fn format_code_scaled(
+    code: &str,
+    amount: Decimal,
+    separators: (&str, &str),
+    min_scale: u32,
+) -> String {
+    let sign = if amount.is_sign_negative() && !amount.is_zero() {
+        "-"
+    } else {
+        ""
     };
+    let (thousand_separator, decimal_separator) = separators;
+    // clamped into `Decimal::MAX_SCALE`, which fits.
+    let min_scale = u16::try_from(min_scale.min(Decimal::MAX_SCALE)).unwrap_or_default();
+    format!(
+        "{} {}{}",
+        code,
+        sign,
+        format_decimal_abs(amount, thousand_separator, decimal_separator, min_scale)
+    )
+}
 
-    format_with_amount::<C>(&display_amount, is_negative, format_str)
+/// Writes money displayed as `display`, or in code format rounded into `f`'s precision if any, e.g. `{:.3}`,
+/// padded into `f`'s width with its fill and alignment. Alignment defaults to right, like numbers.
+pub(crate) fn write_display(
+    f: &mut std::fmt::Formatter<'_>,
+    code: &str,
+    amount: Decimal,
+    separators: (&str, &str),
+    display: impl FnOnce() -> String,
+) -> std::fmt::Result {
+    let formatted = match f.precision() {
+        Some(precision) => {
+            let scale = u32::try_from(precision).unwrap_or(Decimal::MAX_SCALE);
+            format_code_scaled(code, round_to_scale(amount, scale), separators, 0)
+        }
+        None => display(),
+    };
+
+    let Some(width) = f.width() else {
+        return f.write_str(&formatted);
+    };
+    let padding = width.saturating_sub(formatted.chars().count());
+    let (before, after) = match f.align() {
+        Some(std::fmt::Alignment::Left) => (0, padding),
+        Some(std::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(std::fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        std::fmt::Write::write_char(f, fill)?;
+    }
+    f.write_str(&formatted)?;
+    for _ in 0..after {
+        std::fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
 }
 
 /// Returns true if `symbol` appears as an active (non-escaped, non-literal-block) format symbol
 /// in `format_str`.
-fn contains_active_format_symbol(format_str: &str, symbol: char) -> bool {
+pub(crate) fn contains_active_format_symbol(format_str: &str, symbol: char) -> bool {
     let mut chars = format_str.chars().peekable();
     while let Some(ch) = chars.next() {
         if ch == ESCAPE_SYMBOL {
@@ -199,51 +523,580 @@ fn contains_active_format_symbol(format_str: &str, symbol: char) -> bool {
 pub(crate) fn format_with_amount<C: Currency>(
     display_amount: &str,
     is_negative: bool,
+    is_positive: bool,
     format_str: &str,
 ) -> String {
-    let mut chars = format_str.chars().peekable();
-
     let mut result = String::new();
-    while let Some(ch) = chars.next() {
-        if ch == ESCAPE_SYMBOL {
-            if let Some(&next_ch) = chars.peek() {
-                if next_ch == '{' {
-                    chars.next(); // consume '{'
-                    // collect everything until closing '}', output literally
-                    for inner_ch in chars.by_ref() {
-                        if inner_ch == '}' {
-                            break;
-                        }
-                        result.push(inner_ch);
-                    }
-                    continue;
-                } else if FORMAT_SYMBOLS.contains(&next_ch) || next_ch == ESCAPE_SYMBOL {
-                    chars.next();
-                    result.push(next_ch);
+    let _ = write_with_amount::<C, _>(
+        &mut result,
+        |w: &mut String| w.write_str(display_amount),
+        is_negative,
+        is_positive,
+        format_str,
+    );
+    result
+}
+
+/// Writes money formatted with `format_str` into `w`, with the absolute amount written by `write_amount`.
+pub(crate) fn write_with_amount<C: Currency, W: std::fmt::Write>(
+    w: &mut W,
+    write_amount: impl FnMut(&mut W) -> std::fmt::Result,
+    is_negative: bool,
+    is_positive: bool,
+    format_str: &str,
+) -> std::fmt::Result {
+    write_tokens::<C, _>(
+        w,
+        PatternTokens::new(format_str),
+        write_amount,
+        is_negative,
+        is_positive,
+        C::MINOR_UNIT_SYMBOL,
+    )
+}
+
+// Writes `tokens` of a format pattern into `w`, with the absolute amount written by `write_amount`.
+fn write_tokens<'a, C: Currency, W: std::fmt::Write>(
+    w: &mut W,
+    tokens: impl Iterator<Item = Token<'a>>,
+    mut write_amount: impl FnMut(&mut W) -> std::fmt::Result,
+    is_negative: bool,
+    is_positive: bool,
+    minor_symbol: &str,
+) -> std::fmt::Result {
+    for token in tokens {
+        match token {
+            Token::Char(ch) => w.write_char(ch)?,
+            Token::Literal(literal) => w.write_str(literal)?,
+            Token::Amount => write_amount(w)?,
+            Token::Code => w.write_str(C::CODE)?,
+            Token::Symbol => w.write_str(C::SYMBOL)?,
+            Token::MinorSymbol => w.write_str(minor_symbol)?,
+            Token::Negative => {
+                if is_negative {
+                    w.write_char('-')?;
+                }
+            }
+            Token::Positive => {
+                if is_positive {
+                    w.write_char('+')?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Token of a format pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Char(char),
+    Literal(&'a str),
+    Amount,
+    Code,
+    Symbol,
+    MinorSymbol,
+    Negative,
+    Positive,
+}
+
+// Tokens of a format pattern, with escapes and literal blocks resolved into literal chars.
+struct PatternTokens<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    in_literal_block: bool,
+}
+
+impl<'a> PatternTokens<'a> {
+    fn new(format_str: &'a str) -> Self {
+        Self {
+            chars: format_str.chars().peekable(),
+            in_literal_block: false,
+        }
+    }
+}
+
+impl<'a> Iterator for PatternTokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ch = self.chars.next()?;
+            if self.in_literal_block {
+                // everything until closing '}' is literal
+                if ch == '}' {
+                    self.in_literal_block = false;
                     continue;
-                } else {
-                    result.push(ch);
                 }
-            } else {
-                result.push(ch);
+                return Some(Token::Char(ch));
             }
-        } else {
-            match ch {
-                AMOUNT_FORMAT_SYMBOL => result.push_str(display_amount),
-                CODE_FORMAT_SYMBOL => result.push_str(C::CODE),
-                SYMBOL_FORMAT_SYMBOL => result.push_str(C::SYMBOL),
-                MINOR_FORMAT_SYMBOL => result.push_str(C::MINOR_UNIT_SYMBOL),
-                NEGATIVE_FORMAT_SYMBOL => {
-                    if is_negative {
-                        result.push('-');
+
+            if ch == ESCAPE_SYMBOL {
+                return match self.chars.peek() {
+                    Some('{') => {
+                        self.chars.next(); // consume '{'
+                        self.in_literal_block = true;
+                        continue;
                     }
-                }
-                ' ' => result.push(' '),
-                _ => result.push(ch),
+                    Some(&next_ch)
+                        if FORMAT_SYMBOLS.contains(&next_ch) || next_ch == ESCAPE_SYMBOL =>
+                    {
+                        self.chars.next();
+                        Some(Token::Char(next_ch))
+                    }
+                    _ => Some(Token::Char(ch)),
+                };
             }
+
+            return Some(match ch {
+                AMOUNT_FORMAT_SYMBOL => Token::Amount,
+                CODE_FORMAT_SYMBOL => Token::Code,
+                SYMBOL_FORMAT_SYMBOL => Token::Symbol,
+                MINOR_FORMAT_SYMBOL => Token::MinorSymbol,
+                NEGATIVE_FORMAT_SYMBOL => Token::Negative,
+                POSITIVE_FORMAT_SYMBOL => Token::Positive,
+                _ => Token::Char(ch),
+            });
         }
     }
+}
 
+// Compiled token of a format pattern, owning its literal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Amount,
+    Code,
+    Symbol,
+    MinorSymbol,
+    Negative,
+    Positive,
+}
+
+impl Segment {
+    fn token(&self) -> Token<'_> {
+        match self {
+            Segment::Literal(literal) => Token::Literal(literal),
+            Segment::Amount => Token::Amount,
+            Segment::Code => Token::Code,
+            Segment::Symbol => Token::Symbol,
+            Segment::MinorSymbol => Token::MinorSymbol,
+            Segment::Negative => Token::Negative,
+            Segment::Positive => Token::Positive,
+        }
+    }
+}
+
+/// Format pattern compiled once, to format many values without re-scanning the pattern.
+///
+/// The pattern is the same format string used by [`MoneyFormatter::format`](crate::MoneyFormatter::format),
+/// e.g. `"nsa m"`, with the same escapes and literal blocks. Formatting with a compiled pattern gives the same
+/// result as formatting with its string, while the pattern is scanned only in [`compile`](Self::compile).
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, Money, MoneyFormatter, fmt::MoneyFormat, macros::dec, iso::{EUR, USD}};
+///
+/// let pattern = MoneyFormat::compile("\\{Total:} c na");
+///
+/// let money = Money::<USD>::from_decimal(dec!(-1234.5));
+/// assert_eq!(pattern.format(&money), "Total: USD -1,234.50");
+/// assert_eq!(pattern.format(&money), money.format("\\{Total:} c na"));
+///
+/// // separators follow each value's currency
+/// let money = Money::<EUR>::from_decimal(dec!(1234.5));
+/// assert_eq!(pattern.format(&money), "Total: EUR 1.234,50");
+/// assert_eq!(pattern.format_with_separator(&money, " ", "."), "Total: EUR 1 234.50");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoneyFormat {
+    pattern: String,
+    segments: Vec<Segment>,
+    is_minor: bool,
+    minor_symbol: Option<String>,
+    digits: DigitSet,
+}
+
+impl MoneyFormat {
+    /// Compiles format pattern `format_str`, e.g. `"c na"`.
+    ///
+    /// Like format strings, every pattern is valid: characters other than format symbols are literal.
+    pub fn compile(format_str: &str) -> Self {
+        let mut segments: Vec<Segment> = vec![];
+        for token in PatternTokens::new(format_str) {
+            let segment = match token {
+                Token::Char(ch) => {
+                    // consecutive literal chars are merged into one literal.
+                    if let Some(Segment::Literal(literal)) = segments.last_mut() {
+                        literal.push(ch);
+                        continue;
+                    }
+                    Segment::Literal(ch.to_string())
+                }
+                Token::Literal(literal) => Segment::Literal(literal.to_string()),
+                Token::Amount => Segment::Amount,
+                Token::Code => Segment::Code,
+                Token::Symbol => Segment::Symbol,
+                Token::MinorSymbol => Segment::MinorSymbol,
+                Token::Negative => Segment::Negative,
+                Token::Positive => Segment::Positive,
+            };
+            segments.push(segment);
+        }
+        let is_minor = segments.contains(&Segment::MinorSymbol);
+
+        Self {
+            pattern: format_str.to_string(),
+            segments,
+            is_minor,
+            minor_symbol: None,
+            digits: DigitSet::Latin,
+        }
+    }
+
+    /// Uses `minor_symbol` for `m` instead of each currency's minor unit symbol, e.g. `"paise"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, fmt::MoneyFormat, macros::dec, iso::INR};
+    ///
+    /// let pattern = MoneyFormat::compile("c na m").with_minor_symbol("paise");
+    /// let money = Money::<INR>::from_decimal(dec!(12.34));
+    /// assert_eq!(pattern.format(&money), "INR 1,234 paise");
+    /// ```
+    pub fn with_minor_symbol(mut self, minor_symbol: &str) -> Self {
+        self.minor_symbol = Some(minor_symbol.to_string());
+        self
+    }
+
+    /// Renders amounts in `digits` instead of ASCII digits, e.g. [`DigitSet::ArabicIndic`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, DigitSet, Money, fmt::MoneyFormat, macros::dec, iso::SAR};
+    ///
+    /// let pattern = MoneyFormat::compile("na c").with_digits(DigitSet::ArabicIndic);
+    /// let money = Money::<SAR>::from_decimal(dec!(1234.5));
+    /// assert_eq!(pattern.format_with_separator(&money, "\u{66c}", "\u{66b}"), "١٬٢٣٤٫٥٠ SAR");
+    /// ```
+    pub fn with_digits(mut self, digits: DigitSet) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Returns the pattern this was compiled from.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Formats `money` with its currency's separators, same as [`MoneyFormatter::format`](crate::MoneyFormatter::format).
+    pub fn format<C: Currency>(&self, money: &impl BaseMoney<C>) -> String {
+        self.format_with_separator(money, C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR)
+    }
+
+    /// Formats `money` with separators, same as
+    /// [`MoneyFormatter::format_with_separator`](crate::MoneyFormatter::format_with_separator).
+    pub fn format_with_separator<C: Currency>(
+        &self,
+        money: &impl BaseMoney<C>,
+        thousand_separator: &str,
+        decimal_separator: &str,
+    ) -> String {
+        let mut result = String::new();
+        let _ =
+            self.write_with_separator(money, thousand_separator, decimal_separator, &mut result);
+        result
+    }
+
+    /// Writes `money` formatted with its currency's separators into `w`, without allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns error of `w`.
+    pub fn write<C: Currency>(
+        &self,
+        money: &impl BaseMoney<C>,
+        w: &mut impl std::fmt::Write,
+    ) -> std::fmt::Result {
+        self.write_with_separator(money, C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR, w)
+    }
+
+    /// Writes `money` formatted with separators into `w`, without allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns error of `w`.
+    pub fn write_with_separator<C: Currency>(
+        &self,
+        money: &impl BaseMoney<C>,
+        thousand_separator: &str,
+        decimal_separator: &str,
+        w: &mut impl std::fmt::Write,
+    ) -> std::fmt::Result {
+        write_money(
+            w,
+            money,
+            self.segments.iter().map(Segment::token),
+            AmountLayout {
+                is_minor: self.is_minor,
+                separators: (thousand_separator, decimal_separator),
+                grouping: Grouping::THOUSANDS,
+                digits: self.digits,
+            },
+            self.minor_symbol.as_deref().unwrap_or(C::MINOR_UNIT_SYMBOL),
+        )
+    }
+}
+
+/// Policy of masking amounts, for logging money without leaking figures.
+///
+/// The amount is replaced by a fixed number of mask characters, hiding its sign and magnitude, optionally followed by
+/// its digits of the minor unit, rounded with bankers rounding, to tell amounts apart while debugging.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, Money, MoneyFormatter, fmt::MaskPolicy, macros::dec, iso::{EUR, USD}};
+///
+/// let money = Money::<USD>::from_decimal(dec!(-1234.56));
+/// assert_eq!(money.format_masked_with(MaskPolicy::MINOR), "USD ••••.56");
+/// assert_eq!(money.format_masked_with(MaskPolicy::FULL), "USD ***");
+///
+/// let money = Money::<EUR>::from_decimal(dec!(1234.56));
+/// assert_eq!(money.format_masked_with(MaskPolicy::new('x', 2, true)), "EUR xx,56");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaskPolicy {
+    mask: char,
+    len: usize,
+    show_minor: bool,
+}
+
+impl MaskPolicy {
+    /// Masks the whole amount, e.g. `"USD ***"`.
+    pub const FULL: Self = Self::new('*', 3, false);
+
+    /// Masks the amount except its digits of the minor unit, e.g. `"USD ••••.56"`. This is the default.
+    pub const MINOR: Self = Self::new('•', 4, true);
+
+    /// Creates policy masking amounts with `len` of `mask` characters, followed by digits of the minor unit
+    /// if `show_minor`.
+    pub const fn new(mask: char, len: usize, show_minor: bool) -> Self {
+        Self {
+            mask,
+            len,
+            show_minor,
+        }
+    }
+}
+
+impl Default for MaskPolicy {
+    fn default() -> Self {
+        Self::MINOR
+    }
+}
+
+/// Masks `amount` with `policy`, with digits of the minor unit after `decimal_separator` if shown, e.g. `••••.56`.
+pub(crate) fn mask_amount(
+    amount: Decimal,
+    minor_unit: u16,
+    decimal_separator: &str,
+    policy: MaskPolicy,
+) -> String {
+    let mut masked: String = std::iter::repeat_n(policy.mask, policy.len).collect();
+    if policy.show_minor {
+        let rounded = round_to_scale(amount.abs(), minor_unit.into()).to_string();
+        if let Some((_, minor)) = rounded.split_once('.') {
+            masked.push_str(decimal_separator);
+            masked.push_str(minor);
+        }
+    }
+    masked
+}
+
+/// Formats money in code format with amount masked by `policy`, e.g. `"USD ••••.56"`.
+pub(crate) fn format_masked<C: Currency>(money: &impl BaseMoney<C>, policy: MaskPolicy) -> String {
+    format!(
+        "{} {}",
+        C::CODE,
+        mask_amount(money.amount(), C::MINOR_UNIT, C::DECIMAL_SEPARATOR, policy)
+    )
+}
+
+/// Amount shown by `Debug` of money.
+#[cfg(not(feature = "redact_debug"))]
+pub(crate) fn debug_amount(amount: Decimal, _minor_unit: u16) -> Decimal {
+    amount
+}
+
+/// Amount shown by `Debug` of money, masked with the default [`MaskPolicy`], e.g. `••••.56`.
+#[cfg(feature = "redact_debug")]
+pub(crate) fn debug_amount(amount: Decimal, minor_unit: u16) -> String {
+    mask_amount(amount, minor_unit, ".", MaskPolicy::default())
+}
+
+const COMPACT_SUFFIXES: &[(&str, u32)] = &[("K", 3), ("M", 6), ("B", 9), ("T", 12)]; // E.g. $1.2M
+
+/// Formats money in symbol format within `max_chars` characters.
+///
+/// Candidates are tried in order until one fits:
+/// 1. Symbol format, e.g. "-$1,234,567.89"
+/// 2. Without grouping, e.g. "-$1234567.89"
+/// 3. Without decimals, rounded with bankers rounding, e.g. "-$1234568"
+/// 4. Compact notation with 2, 1, then 0 decimals, e.g. "-$1.23M", "-$1.2M", "-$1M"
+///
+/// Returns `None` if none of them fits.
+pub(crate) fn format_fit<C: Currency>(
+    money: &impl BaseMoney<C>,
+    max_chars: usize,
+) -> Option<String> {
+    let fits = |s: &String| s.chars().count() <= max_chars;
+
+    let grouped = format_with_separator(
+        money,
+        SYMBOL_FORMAT,
+        C::THOUSAND_SEPARATOR,
+        C::DECIMAL_SEPARATOR,
+    );
+    if fits(&grouped) {
+        return Some(grouped);
+    }
+
+    let ungrouped = format_with_separator(money, SYMBOL_FORMAT, "", C::DECIMAL_SEPARATOR);
+    if fits(&ungrouped) {
+        return Some(ungrouped);
+    }
+
+    let integer = money.amount().round_dp(0);
+    let is_negative = integer.is_sign_negative() && !integer.is_zero();
+    let whole = format_with_amount::<C>(
+        &format_decimal_abs(integer, "", C::DECIMAL_SEPARATOR, 0),
+        is_negative,
+        false,
+        SYMBOL_FORMAT,
+    );
+    if fits(&whole) {
+        return Some(whole);
+    }
+
+    for dp in (0..=2).rev() {
+        let compact = format_compact_dp(money, dp)?;
+        if fits(&compact) {
+            return Some(compact);
+        }
+    }
+
+    None
+}
+
+/// Formats money in symbol format, in compact notation with at most `dp` decimals if its absolute amount
+/// is at least `threshold`, e.g. "-$1.2M".
+pub(crate) fn format_compact<C: Currency>(
+    money: &impl BaseMoney<C>,
+    dp: u32,
+    threshold: Decimal,
+) -> String {
+    if money.amount().abs() >= threshold
+        && let Some(compact) = format_compact_dp(money, dp)
+    {
+        return compact;
+    }
+
+    format_with_separator(
+        money,
+        SYMBOL_FORMAT,
+        C::THOUSAND_SEPARATOR,
+        C::DECIMAL_SEPARATOR,
+    )
+}
+
+/// Formats money in compact notation with at most `dp` decimals rounded with bankers rounding,
+/// e.g. "-$1.23M", `None` if its absolute amount is below the smallest suffix.
+fn format_compact_dp<C: Currency>(money: &impl BaseMoney<C>, dp: u32) -> Option<String> {
+    let abs = money.amount().abs();
+    let is_negative = money.is_negative();
+    // Largest suffix not exceeding the amount.
+    let mut suffix = COMPACT_SUFFIXES
+        .iter()
+        .rposition(|(_, exponent)| abs >= Decimal::from(10i64.pow(*exponent)))?;
+    let compact_amount = |suffix: usize| {
+        let unit = Decimal::from(10i64.pow(COMPACT_SUFFIXES[suffix].1));
+        abs.checked_div(unit)
+            .map(|scaled| scaled.round_dp(dp).normalize())
+    };
+    let mut scaled = compact_amount(suffix)?;
+    // E.g. 999,999 is rounded into 1000K, use 1M instead.
+    if scaled >= Decimal::ONE_THOUSAND && suffix + 1 < COMPACT_SUFFIXES.len() {
+        suffix += 1;
+        scaled = compact_amount(suffix)?;
+    }
+    let compact = format!(
+        "{}{}",
+        format_decimal_abs(scaled, "", C::DECIMAL_SEPARATOR, 0),
+        COMPACT_SUFFIXES[suffix].0
+    );
+    Some(format_with_amount::<C>(
+        &compact,
+        is_negative,
+        false,
+        SYMBOL_FORMAT,
+    ))
+}
+
+#[cfg(feature = "raw_money")]
+const MAX_DECIMAL_DIGITS: u32 = 29; // E.g. 79,228,162,514,264,337,593,543,950,335
+
+/// Formats amount in scientific notation with `sig_digits` significant digits and currency code,
+/// e.g. "1.234e12 USD". Digits are rounded with bankers rounding, and are between 1 and 29, the maximum digits of `Decimal`.
+#[cfg(feature = "raw_money")]
+pub(crate) fn format_scientific<C: Currency>(amount: Decimal, sig_digits: u32) -> String {
+    let sig_digits: usize = sig_digits
+        .clamp(1, MAX_DECIMAL_DIGITS)
+        .try_into()
+        .unwrap_or(1);
+    let mantissa = amount.mantissa().unsigned_abs();
+    let digits = mantissa.to_string();
+    let len = digits.len();
+
+    let mut exponent = i64::try_from(len).unwrap_or(i64::MAX) - 1 - i64::from(amount.scale());
+    let significand = if mantissa == 0 {
+        exponent = 0;
+        "0".repeat(sig_digits)
+    } else if len > sig_digits {
+        // mantissa of `Decimal` has at most 29 digits.
+        let drop = u32::try_from(len - sig_digits).unwrap_or(MAX_DECIMAL_DIGITS);
+        let divisor = 10u128.pow(drop);
+        let mut head = mantissa / divisor;
+        let rem = mantissa % divisor;
+        let half = divisor / 2;
+        if rem > half || (rem == half && head % 2 == 1) {
+            head += 1;
+        }
+        let mut head = head.to_string();
+        // carried into new digit, e.g. 9.99 rounded into 10.0
+        if head.len() > sig_digits {
+            head.pop();
+            exponent += 1;
+        }
+        head
+    } else {
+        digits + &"0".repeat(sig_digits - len)
+    };
+
+    let mut result = String::new();
+    if amount.is_sign_negative() && mantissa != 0 {
+        result.push('-');
+    }
+    let (first, rest) = significand.split_at(1);
+    result.push_str(first);
+    if !rest.is_empty() {
+        result.push('.');
+        result.push_str(rest);
+    }
+    result.push_str(&format!("e{} {}", exponent, C::CODE));
     result
 }
 
@@ -271,6 +1124,7 @@ pub(crate) fn format_locale_amount<C: Currency>(
         .map_err(|_| MoneyError::ParseLocale("failed initiating decimal formatter".into()))?;
 
     let is_negative = money.is_negative();
+    let is_positive = money.is_positive();
     let curr_minor_unit = C::MINOR_UNIT.into();
     let abs_amount = if money.scale() < curr_minor_unit {
         let remaining_scale: usize = (curr_minor_unit - money.scale())
@@ -297,7 +1151,434 @@ pub(crate) fn format_locale_amount<C: Currency>(
 
     let formatted_decimal = formatter.format(&decimal).to_string();
 
-    let ret = format_with_amount::<C>(&formatted_decimal, is_negative, format_str);
+    let ret = format_with_amount::<C>(&formatted_decimal, is_negative, is_positive, format_str);
+
+    Ok(ret)
+}
+
+const MASK_OPTIONAL_DIGIT: char = '#';
+const MASK_REQUIRED_DIGIT: char = '0';
+
+/// Builds input mask from separators and digit groups, e.g. `#,##0.00`.
+///
+/// `groups` are sizes of integer digit groups from the most significant, only the last two are used.
+fn build_input_mask(
+    thousand_separator: &str,
+    decimal_separator: &str,
+    groups: &[usize],
+    minor_unit: usize,
+) -> String {
+    let mut ret = String::new();
+    match groups {
+        [.., secondary, primary] => {
+            ret.push(MASK_OPTIONAL_DIGIT);
+            ret.push_str(thousand_separator);
+            if groups.len() > 2 && secondary != primary {
+                ret.push_str(&MASK_OPTIONAL_DIGIT.to_string().repeat(*secondary));
+                ret.push_str(thousand_separator);
+            }
+            ret.push_str(
+                &MASK_OPTIONAL_DIGIT
+                    .to_string()
+                    .repeat(primary.saturating_sub(1)),
+            );
+        }
+        _ => ret.push(MASK_OPTIONAL_DIGIT),
+    }
+    ret.push(MASK_REQUIRED_DIGIT);
+
+    if minor_unit > 0 {
+        ret.push_str(decimal_separator);
+        ret.push_str(&MASK_REQUIRED_DIGIT.to_string().repeat(minor_unit));
+    }
+
+    ret
+}
+
+pub(crate) fn input_mask(
+    thousand_separator: &str,
+    decimal_separator: &str,
+    grouping_size: usize,
+    secondary_grouping_size: usize,
+    minor_unit: u16,
+) -> String {
+    // secondary groups only show in the mask after the first group.
+    let groups = if secondary_grouping_size == grouping_size {
+        vec![grouping_size, grouping_size]
+    } else {
+        vec![1, secondary_grouping_size, grouping_size]
+    };
+    build_input_mask(
+        thousand_separator,
+        decimal_separator,
+        &groups,
+        minor_unit.into(),
+    )
+}
+
+#[cfg(feature = "locale")]
+pub(crate) fn input_mask_locale(locale_str: &str, minor_unit: u16) -> Result<String, MoneyError> {
+    let minor_unit: usize = minor_unit.into();
+    let (groups, thousand_separator, decimal_separator) =
+        locale_number_format(locale_str, minor_unit)?;
+
+    Ok(build_input_mask(
+        &thousand_separator,
+        &decimal_separator,
+        &groups,
+        minor_unit,
+    ))
+}
+
+/// Returns thousand and decimal separators of locale `locale_str`, e.g. `(".", ",")` for `de-DE`.
+#[cfg(all(feature = "locale", feature = "obj_money"))]
+pub(crate) fn locale_separators(locale_str: &str) -> Result<(String, String), MoneyError> {
+    let (_, thousand_separator, decimal_separator) = locale_number_format(locale_str, 2)?;
+    Ok((thousand_separator, decimal_separator))
+}
+
+/// Returns grouping of locale `locale_str`, e.g. 3 then 2 digits for `en-IN`.
+///
+/// Locales not grouping the sample number fall back into [`Grouping::THOUSANDS`].
+#[cfg(feature = "locale")]
+pub(crate) fn locale_grouping(locale_str: &str) -> Result<Grouping, MoneyError> {
+    let (groups, ..) = locale_number_format(locale_str, 0)?;
+    Ok(match groups[..] {
+        [_, .., secondary, primary] if secondary > 0 && primary > 0 => {
+            Grouping::new(primary, secondary)
+        }
+        [_, primary] if primary > 0 => Grouping::uniform(primary),
+        _ => Grouping::THOUSANDS,
+    })
+}
+
+/// Formats a sample number with `minor_unit` decimal places in locale `locale_str`, and returns
+/// sizes of its integer digit groups, its grouping separator and its decimal separator.
+#[cfg(feature = "locale")]
+fn locale_number_format(
+    locale_str: &str,
+    minor_unit: usize,
+) -> Result<(Vec<usize>, String, String), MoneyError> {
+    use icu_decimal::{DecimalFormatter, input::Decimal as LocaleDecimal};
+    use icu_locale::Locale;
+
+    // Sample with enough integer digits to reveal primary and secondary grouping.
+    const SAMPLE_INTEGER: &str = "1234567";
+
+    let loc: Locale = locale_str.parse().map_err(|_| {
+        MoneyError::ParseLocale(
+            format!(
+                "failed parsing locale {} , invalid or not found",
+                locale_str
+            )
+            .into(),
+        )
+    })?;
+    let formatter = DecimalFormatter::try_new(loc.into(), Default::default())
+        .map_err(|_| MoneyError::ParseLocale("failed initiating decimal formatter".into()))?;
+
+    let sample = if minor_unit > 0 {
+        format!("{}.{}", SAMPLE_INTEGER, "0".repeat(minor_unit))
+    } else {
+        SAMPLE_INTEGER.to_string()
+    };
+    let decimal = LocaleDecimal::try_from_str(&sample).map_err(|_| {
+        MoneyError::ParseLocale(format!("failed parsing {} into locale decimal", &sample).into())
+    })?;
+    let formatted = formatter.format(&decimal).to_string();
+
+    // Split formatted sample into integer digit groups, grouping separator, and decimal separator.
+    let mut groups: Vec<usize> = vec![];
+    let mut thousand_separator = String::new();
+    let mut decimal_separator = String::new();
+    let mut separator = String::new();
+    let mut integer_digits = 0;
+    for ch in formatted.chars() {
+        if integer_digits == SAMPLE_INTEGER.len() {
+            if ch.is_numeric() {
+                break;
+            }
+            decimal_separator.push(ch);
+            continue;
+        }
+        if ch.is_numeric() {
+            if !separator.is_empty() {
+                thousand_separator = std::mem::take(&mut separator);
+                groups.push(0);
+            }
+            match groups.last_mut() {
+                Some(size) => *size += 1,
+                None => groups.push(1),
+            }
+            integer_digits += 1;
+        } else if !groups.is_empty() {
+            separator.push(ch);
+        }
+    }
+
+    Ok((groups, thousand_separator, decimal_separator))
+}
+
+// Money in a column aligned at decimal separator.
+pub(crate) struct ColumnCell<'a> {
+    code: &'a str,
+    // sign and grouped integer digits.
+    integer: String,
+    // decimal separator and fraction digits, empty if none.
+    fraction: String,
+}
+
+impl<'a> ColumnCell<'a> {
+    pub(crate) fn new(
+        code: &'a str,
+        amount: Decimal,
+        (thousand_separator, decimal_separator): (&str, &str),
+        minor_unit: u16,
+    ) -> Self {
+        let mut integer = String::new();
+        if amount.is_sign_negative() && !amount.is_zero() {
+            integer.push('-');
+        }
+        let _ = write_decimal_abs(
+            &mut integer,
+            amount.trunc(),
+            thousand_separator,
+            decimal_separator,
+            0,
+            Grouping::THOUSANDS,
+        );
+
+        let mut fraction = String::new();
+        let _ = write_decimal_abs(
+            &mut fraction,
+            amount.fract(),
+            "",
+            decimal_separator,
+            minor_unit,
+            Grouping::THOUSANDS,
+        );
+        // drops the zero integer digit of the fraction.
+        let fraction = fraction.split_off(1);
+
+        Self {
+            code,
+            integer,
+            fraction,
+        }
+    }
+}
+
+/// Formats `cells` in code format padded with spaces into the same width, with decimal separators aligned.
+pub(crate) fn align_cells(cells: &[ColumnCell<'_>]) -> Vec<String> {
+    let code_width = cells.iter().map(|cell| char_width(cell.code)).max();
+    let integer_width = cells.iter().map(|cell| char_width(&cell.integer)).max();
+    let fraction_width = cells.iter().map(|cell| char_width(&cell.fraction)).max();
+    let (code_width, integer_width, fraction_width) = (
+        code_width.unwrap_or_default(),
+        integer_width.unwrap_or_default(),
+        fraction_width.unwrap_or_default(),
+    );
+
+    cells
+        .iter()
+        .map(|cell| {
+            format!(
+                "{:<code_width$} {:>integer_width$}{:<fraction_width$}",
+                cell.code, cell.integer, cell.fraction
+            )
+        })
+        .collect()
+}
+
+/// Formats `moneys` in code format padded with spaces into the same width, so their decimal separators align
+/// vertically, e.g. for money columns of CLI and PDF reports.
+///
+/// Amounts have at least the currency's minor unit digits, and every digit of amounts with more, e.g. of
+/// [`RawMoney`](crate::RawMoney). Shorter fractions are padded with spaces. Width is counted in characters,
+/// so the column aligns in monospace fonts. For mixed currencies, see `ObjIterOps::align_column` with `obj_money` feature.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, RawMoney, macros::dec, money, fmt::align_column, iso::USD};
+///
+/// let column = align_column(&[money!(USD, 1234.5), money!(USD, -20), money!(USD, 0.07)]);
+/// assert_eq!(column, ["USD 1,234.50", "USD   -20.00", "USD     0.07"]);
+///
+/// let raws = [dec!(1.5), dec!(-1234.125)].map(RawMoney::<USD>::from_decimal);
+/// assert_eq!(align_column(&raws), ["USD      1.50 ", "USD -1,234.125"]);
+/// ```
+pub fn align_column<C: Currency>(moneys: &[impl BaseMoney<C>]) -> Vec<String> {
+    let cells: Vec<ColumnCell<'_>> = moneys
+        .iter()
+        .map(|money| {
+            ColumnCell::new(
+                C::CODE,
+                money.amount(),
+                (C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR),
+                C::MINOR_UNIT,
+            )
+        })
+        .collect();
+    align_cells(&cells)
+}
+
+/// Labeled money, a row of [`table`] and [`markdown_table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoneyRecord<'a, C: Currency> {
+    /// Label of the row, e.g. `"Rent"`.
+    pub label: &'a str,
+
+    /// Money of the row.
+    pub money: crate::Money<C>,
+}
+
+impl<'a, C: Currency> MoneyRecord<'a, C> {
+    /// Creates a record of `money` labeled `label`.
+    pub fn new(label: &'a str, money: crate::Money<C>) -> Self {
+        Self { label, money }
+    }
+}
+
+const TABLE_LABEL_HEADER: &str = "Label";
+const TABLE_AMOUNT_HEADER: &str = "Amount";
+const TABLE_TOTAL_LABEL: &str = "Total";
+
+// Labels with formatted amounts, and formatted total.
+type TableRows<'a> = (Vec<(&'a str, String)>, String);
+
+fn table_rows<'a, C: Currency>(
+    records: &[MoneyRecord<'a, C>],
+) -> Result<TableRows<'a>, MoneyError> {
+    let mut total = Decimal::ZERO;
+    let mut rows = Vec::with_capacity(records.len());
+    for record in records {
+        total = total
+            .checked_add(record.money.amount())
+            .ok_or(MoneyError::OverflowError)?;
+        rows.push((record.label, format(&record.money, CODE_FORMAT)));
+    }
+    Ok((
+        rows,
+        format(&crate::Money::<C>::from_decimal(total), CODE_FORMAT),
+    ))
+}
+
+fn char_width(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// Formats `records` into aligned plain-text table with a total row, e.g. for CLI tools and email reports.
+///
+/// Amounts are formatted like [`BaseMoney::format_code`] and right-aligned,
+/// so their decimal separators line up. Width is counted in characters.
+///
+/// # Errors
+///
+/// Returns [`MoneyError::OverflowError`] if the total overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{money, fmt::{MoneyRecord, table}};
+///
+/// let records = [
+///     MoneyRecord::new("Rent", money!(USD, 1200)),
+///     MoneyRecord::new("Groceries", money!(USD, 345.67)),
+///     MoneyRecord::new("Refund", money!(USD, -20)),
+/// ];
+/// assert_eq!(
+///     table(&records).unwrap(),
+///     "\
+/// Label            Amount
+/// ---------  ------------
+/// Rent       USD 1,200.00
+/// Groceries    USD 345.67
+/// Refund       USD -20.00
+/// ---------  ------------
+/// Total      USD 1,525.67
+/// "
+/// );
+/// ```
+pub fn table<C: Currency>(records: &[MoneyRecord<'_, C>]) -> Result<String, MoneyError> {
+    let (rows, total) = table_rows(records)?;
+    let label_width = rows
+        .iter()
+        .map(|(label, _)| char_width(label))
+        .chain([
+            char_width(TABLE_LABEL_HEADER),
+            char_width(TABLE_TOTAL_LABEL),
+        ])
+        .max()
+        .unwrap_or_default();
+    let amount_width = rows
+        .iter()
+        .map(|(_, amount)| char_width(amount))
+        .chain([char_width(TABLE_AMOUNT_HEADER), char_width(&total)])
+        .max()
+        .unwrap_or_default();
+
+    let rule = format!(
+        "{}  {}\n",
+        "-".repeat(label_width),
+        "-".repeat(amount_width)
+    );
+    let mut ret = format!(
+        "{:<label_width$}  {:>amount_width$}\n",
+        TABLE_LABEL_HEADER, TABLE_AMOUNT_HEADER
+    );
+    ret.push_str(&rule);
+    for (label, amount) in &rows {
+        ret.push_str(&format!("{label:<label_width$}  {amount:>amount_width$}\n"));
+    }
+    ret.push_str(&rule);
+    ret.push_str(&format!(
+        "{:<label_width$}  {:>amount_width$}\n",
+        TABLE_TOTAL_LABEL, total
+    ));
+
+    Ok(ret)
+}
+
+/// Formats `records` into markdown table with a bold total row, amounts right-aligned.
+///
+/// `|` in labels is escaped.
+///
+/// # Errors
+///
+/// Returns [`MoneyError::OverflowError`] if the total overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{money, fmt::{MoneyRecord, markdown_table}};
+///
+/// let records = [
+///     MoneyRecord::new("Rent", money!(EUR, 1200)),
+///     MoneyRecord::new("Food | Drinks", money!(EUR, 45.5)),
+/// ];
+/// assert_eq!(
+///     markdown_table(&records).unwrap(),
+///     "\
+/// | Label | Amount |
+/// |:--|--:|
+/// | Rent | EUR 1.200,00 |
+/// | Food \\| Drinks | EUR 45,50 |
+/// | **Total** | **EUR 1.245,50** |
+/// "
+/// );
+/// ```
+pub fn markdown_table<C: Currency>(records: &[MoneyRecord<'_, C>]) -> Result<String, MoneyError> {
+    let (rows, total) = table_rows(records)?;
+
+    let mut ret = format!(
+        "| {} | {} |\n|:--|--:|\n",
+        TABLE_LABEL_HEADER, TABLE_AMOUNT_HEADER
+    );
+    for (label, amount) in &rows {
+        ret.push_str(&format!("| {} | {} |\n", label.replace('|', "\\|"), amount));
+    }
+    ret.push_str(&format!("| **{}** | **{}** |\n", TABLE_TOTAL_LABEL, total));
 
     Ok(ret)
 }
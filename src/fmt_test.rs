@@ -3,8 +3,8 @@ use crate::iso::{EUR, GBP, JPY, USD};
 
 use crate::Money;
 use crate::fmt::{format, format_128_abs, format_decimal_abs};
-use crate::macros::dec;
-use crate::{BaseMoney, Decimal};
+use crate::macros::{dec, money};
+use crate::{BaseMoney, Decimal, MoneyFormatter};
 use std::str::FromStr;
 
 #[test]
@@ -352,6 +352,34 @@ fn test_format_numeric_characters() {
     assert_eq!(format(&money, "Pri\\ce: a USD"), "Price: 100.50 USD");
 }
 
+#[test]
+fn test_format_plus_sign() {
+    let credit = Money::<USD>::new(dec!(25)).unwrap();
+    let debit = Money::<USD>::new(dec!(-25)).unwrap();
+    let zero = Money::<USD>::new(dec!(0)).unwrap();
+
+    assert_eq!(format(&credit, "pnsa"), "+$25.00");
+    assert_eq!(format(&debit, "pnsa"), "-$25.00");
+    assert_eq!(format(&zero, "pnsa"), "$0.00");
+    assert_eq!(format(&credit, "c pna"), "USD +25.00");
+    assert_eq!(format(&debit, "c pna"), "USD -25.00");
+    assert_eq!(format(&credit, "psa m"), "+$2,500 ¢");
+
+    // `p` alone omits the negative sign.
+    assert_eq!(format(&debit, "psa"), "$25.00");
+
+    // Escaped and in literal block.
+    assert_eq!(format(&credit, "\\p pa"), "p +25.00");
+    assert_eq!(format(&credit, "\\{pa} pa"), "pa +25.00");
+    assert_eq!(format(&credit, "\\profit: a"), "profit: 25.00");
+
+    let credit = Money::<EUR>::new(dec!(1234.5)).unwrap();
+    assert_eq!(
+        format_with_separator(&credit, "pnsa", ".", ","),
+        "+€1.234,50"
+    );
+}
+
 #[test]
 fn test_format_case_sensitivity() {
     let money = Money::<USD>::new(dec!(100.50)).unwrap();
@@ -362,6 +390,7 @@ fn test_format_case_sensitivity() {
     assert_eq!(format(&money, "S"), "S");
     assert_eq!(format(&money, "M"), "M");
     assert_eq!(format(&money, "N"), "N");
+    assert_eq!(format(&money, "P"), "P");
 }
 
 #[test]
@@ -557,3 +586,587 @@ fn test_format_literal_block_with_backslash_inside() {
         "path\\to\\file 100.50"
     );
 }
+
+#[test]
+fn test_format_fit() {
+    let money = Money::<USD>::from_decimal(dec!(1234567.89));
+    assert_eq!(money.format_fit(13).unwrap(), "$1,234,567.89");
+    assert_eq!(money.format_fit(12).unwrap(), "$1234567.89");
+    assert_eq!(money.format_fit(11).unwrap(), "$1234567.89");
+    assert_eq!(money.format_fit(10).unwrap(), "$1234568");
+    assert_eq!(money.format_fit(8).unwrap(), "$1234568");
+    assert_eq!(money.format_fit(7).unwrap(), "$1.23M");
+    assert_eq!(money.format_fit(5).unwrap(), "$1.2M");
+    assert_eq!(money.format_fit(3).unwrap(), "$1M");
+    assert_eq!(money.format_fit(2), None);
+    assert_eq!(money.format_fit(0), None);
+}
+
+#[test]
+fn test_format_fit_small_amounts() {
+    let money = Money::<USD>::from_decimal(dec!(999.99));
+    assert_eq!(money.format_fit(7).unwrap(), "$999.99");
+    assert_eq!(money.format_fit(6).unwrap(), "$1000");
+    // below 1,000 there's no compact notation
+    assert_eq!(money.format_fit(4), None);
+
+    let money = Money::<USD>::from_decimal(dec!(-0.40));
+    assert_eq!(money.format_fit(6).unwrap(), "-$0.40");
+    assert_eq!(money.format_fit(5).unwrap(), "$0");
+
+    let money = Money::<USD>::default();
+    assert_eq!(money.format_fit(2).unwrap(), "$0");
+}
+
+#[test]
+fn test_format_fit_compact() {
+    // rounds into next suffix
+    let money = Money::<USD>::from_decimal(dec!(999999));
+    assert_eq!(money.format_fit(7).unwrap(), "$999999");
+    assert_eq!(money.format_fit(6).unwrap(), "$1M");
+
+    let money = Money::<USD>::from_decimal(dec!(-12345678901234));
+    assert_eq!(money.format_fit(8).unwrap(), "-$12.35T");
+    assert_eq!(money.format_fit(5).unwrap(), "-$12T");
+
+    // beyond trillions stays in T
+    let money = Money::<JPY>::from_decimal(dec!(5000000000000000));
+    assert_eq!(money.format_fit(6).unwrap(), "¥5000T");
+
+    // currency's decimal separator
+    let money = Money::<EUR>::from_decimal(dec!(1500000));
+    assert_eq!(money.format_fit(5).unwrap(), "€1,5M");
+}
+
+#[test]
+fn test_table() {
+    use crate::fmt::{MoneyRecord, table};
+
+    let records = [
+        MoneyRecord::new("Subscription", money!(USD, 12.5)),
+        MoneyRecord::new("Hosting", money!(USD, -1234567.89)),
+    ];
+    assert_eq!(
+        table(&records).unwrap(),
+        "\
+Label                    Amount
+------------  -----------------
+Subscription          USD 12.50
+Hosting       USD -1,234,567.89
+------------  -----------------
+Total         USD -1,234,555.39
+"
+    );
+
+    // width counted in characters.
+    let records = [MoneyRecord::new("Café", money!(JPY, 500))];
+    assert_eq!(
+        table(&records).unwrap(),
+        "\
+Label   Amount
+-----  -------
+Café   JPY 500
+-----  -------
+Total  JPY 500
+"
+    );
+}
+
+#[test]
+fn test_table_empty() {
+    use crate::fmt::{MoneyRecord, table};
+
+    let records: [MoneyRecord<'_, USD>; 0] = [];
+    assert_eq!(
+        table(&records).unwrap(),
+        "\
+Label    Amount
+-----  --------
+-----  --------
+Total  USD 0.00
+"
+    );
+}
+
+#[test]
+fn test_table_overflow() {
+    use crate::fmt::{MoneyRecord, markdown_table, table};
+
+    let records = [
+        MoneyRecord::new("a", Money::<JPY>::max_representable()),
+        MoneyRecord::new("b", Money::<JPY>::max_representable()),
+    ];
+    assert!(matches!(
+        table(&records),
+        Err(crate::MoneyError::OverflowError)
+    ));
+    assert!(matches!(
+        markdown_table(&records),
+        Err(crate::MoneyError::OverflowError)
+    ));
+}
+
+#[test]
+fn test_markdown_table() {
+    use crate::fmt::{MoneyRecord, markdown_table};
+
+    let records = [
+        MoneyRecord::new("a|b|c", money!(GBP, -3)),
+        MoneyRecord::new("", money!(GBP, 1000)),
+    ];
+    assert_eq!(
+        markdown_table(&records).unwrap(),
+        "\
+| Label | Amount |
+|:--|--:|
+| a\\|b\\|c | GBP -3.00 |
+|  | GBP 1,000.00 |
+| **Total** | **GBP 997.00** |
+"
+    );
+}
+
+#[test]
+fn test_display_width_fill_alignment() {
+    let money = money!(USD, -1234.5);
+    assert_eq!(format!("{:16}", money), "   USD -1,234.50");
+    assert_eq!(format!("{:>16}", money), "   USD -1,234.50");
+    assert_eq!(format!("{:<16}|", money), "USD -1,234.50   |");
+    assert_eq!(format!("{:^18}|", money), "  USD -1,234.50   |");
+    assert_eq!(format!("{:·>15}", money), "··USD -1,234.50");
+    // narrower width than the displayed money doesn't truncate.
+    assert_eq!(format!("{:5}", money), "USD -1,234.50");
+    assert_eq!(format!("{:>width$}", money, width = 14), " USD -1,234.50");
+
+    // width counted in characters.
+    assert_eq!(format!("{:12}", money!(EUR, 1234.5)), "EUR 1.234,50");
+    assert_eq!(format!("{:14}", money!(EUR, 1234.5)), "  EUR 1.234,50");
+}
+
+#[test]
+fn test_display_precision() {
+    let money = money!(USD, 1234.56);
+    assert_eq!(format!("{:.0}", money), "USD 1,235");
+    assert_eq!(format!("{:.1}", money), "USD 1,234.6");
+    assert_eq!(format!("{:.4}", money), "USD 1,234.5600");
+    assert_eq!(format!("{:>14.1}", money), "   USD 1,234.6");
+
+    // bankers rounding.
+    assert_eq!(format!("{:.0}", money!(USD, 2.5)), "USD 2");
+    assert_eq!(format!("{:.0}", money!(USD, 3.5)), "USD 4");
+
+    // rounded into zero drops the sign.
+    assert_eq!(format!("{:.1}", money!(USD, -0.04)), "USD 0.0");
+
+    assert_eq!(format!("{:.2}", money!(EUR, -1234567)), "EUR -1.234.567,00");
+    assert_eq!(format!("{:.2}", money!(JPY, 1234)), "JPY 1,234.00");
+    assert_eq!(
+        format!("{:.100}", money!(JPY, 1)),
+        format!("JPY 1.{}", "0".repeat(28))
+    );
+}
+
+#[cfg(feature = "raw_money")]
+#[test]
+fn test_display_raw_money_flags() {
+    let raw = crate::macros::raw!(USD, 1234.5678);
+    assert_eq!(format!("{}", raw), "USD 1,234.5678");
+    assert_eq!(format!("{:.2}", raw), "USD 1,234.57");
+    assert_eq!(format!("{:<15.1}|", raw), "USD 1,234.6    |");
+    assert_eq!(format!("{:>16}", raw), "  USD 1,234.5678");
+}
+
+#[test]
+fn test_format_with_grouping() {
+    use crate::Grouping;
+    use crate::iso::{CNY, INR};
+
+    let money = Money::<INR>::from_decimal(dec!(-12345678.9));
+    assert_eq!(
+        money.format_with_grouping("c na", ",", ".", Grouping::INDIAN),
+        "INR -1,23,45,678.90"
+    );
+    assert_eq!(
+        money.format_with_grouping("c na", ",", ".", Grouping::THOUSANDS),
+        money.format_with_separator("c na", ",", ".")
+    );
+    assert_eq!(
+        money.format_with_grouping("na m", ",", ".", Grouping::INDIAN),
+        "-1,23,45,67,890 paisa"
+    );
+
+    let money = Money::<CNY>::from_decimal(dec!(123456789.01));
+    assert_eq!(
+        money.format_with_grouping("sa", " ", ".", Grouping::MYRIADS),
+        "¥1 2345 6789.01"
+    );
+    assert_eq!(
+        money.format_with_grouping("sa", ",", ".", Grouping::new(4, 2)),
+        "¥1,23,45,6789.01"
+    );
+
+    // numbers not longer than the primary group are not grouped
+    let money = Money::<CNY>::from_decimal(dec!(1234));
+    assert_eq!(
+        money.format_with_grouping("sa", ",", ".", Grouping::MYRIADS),
+        "¥1234.00"
+    );
+}
+
+#[test]
+fn test_parse_with_grouping() {
+    use crate::iso::{CNY, INR};
+    use crate::{Grouping, MoneyError, MoneyParser};
+
+    let money = Money::<INR>::from_str_code_with_grouping(
+        "INR -1,23,45,678.90",
+        ",",
+        ".",
+        Grouping::INDIAN,
+    )
+    .unwrap();
+    assert_eq!(money.amount(), dec!(-12345678.90));
+
+    let money =
+        Money::<CNY>::from_str_symbol_with_grouping("¥1 2345 6789.01", " ", ".", Grouping::MYRIADS)
+            .unwrap();
+    assert_eq!(money.amount(), dec!(123456789.01));
+
+    // ungrouped amounts are accepted with any grouping
+    let money =
+        Money::<INR>::from_str_code_with_grouping("INR 12345678", ",", ".", Grouping::INDIAN)
+            .unwrap();
+    assert_eq!(money.amount(), dec!(12345678));
+
+    for invalid in [
+        "INR 12,345,678",
+        "INR 123,45,678",
+        "INR 1,23,4567",
+        "INR ,23,456",
+    ] {
+        assert!(
+            matches!(
+                Money::<INR>::from_str_code_with_grouping(invalid, ",", ".", Grouping::INDIAN),
+                Err(MoneyError::ParseStrError(_))
+            ),
+            "{}",
+            invalid
+        );
+    }
+
+    // formatted amounts parse back with the same grouping
+    for grouping in [Grouping::THOUSANDS, Grouping::MYRIADS, Grouping::INDIAN] {
+        let money = Money::<INR>::from_decimal(dec!(-9876543210.12));
+        let formatted = money.format_with_grouping("nsa", ",", ".", grouping);
+        assert_eq!(
+            Money::<INR>::from_str_symbol_with_grouping(&formatted, ",", ".", grouping).unwrap(),
+            money
+        );
+    }
+}
+
+// Fixed-capacity writer failing once full.
+struct Bounded<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> std::fmt::Write for Bounded<N> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(std::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_write_format() {
+    let cases = [
+        dec!(0),
+        dec!(-0.01),
+        dec!(1234567.891),
+        dec!(-1234567.8),
+        Decimal::MAX,
+        Decimal::MIN,
+    ];
+    for amount in cases {
+        let money = Money::<USD>::from_decimal(amount);
+        for format_str in ["c na", "nsa", "c na m", "\\{Total:} pa \\c", "nsa m"] {
+            let mut written = String::new();
+            money.write_format(format_str, &mut written).unwrap();
+            assert_eq!(written, money.format(format_str));
+        }
+
+        let mut written = String::new();
+        money.write_code(&mut written).unwrap();
+        assert_eq!(written, money.format_code());
+
+        let mut written = String::new();
+        money.write_symbol(&mut written).unwrap();
+        assert_eq!(written, money.format_symbol());
+    }
+
+    let money = Money::<EUR>::from_decimal(dec!(-1234.5));
+    let mut bounded = Bounded::<32> {
+        buf: [0; 32],
+        len: 0,
+    };
+    money.write_code(&mut bounded).unwrap();
+    assert_eq!(&bounded.buf[..bounded.len], "EUR -1.234,50".as_bytes());
+
+    // errors of the writer are returned
+    let mut bounded = Bounded::<8> {
+        buf: [0; 8],
+        len: 0,
+    };
+    assert!(money.write_code(&mut bounded).is_err());
+}
+
+#[test]
+fn test_format_longest_amounts() {
+    assert_eq!(
+        format_decimal_abs(dec!(-0.0000000000000000000000000001), ",", ".", 2),
+        "0.0000000000000000000000000001"
+    );
+    assert_eq!(
+        format_decimal_abs(Decimal::MIN, ",", ".", 2),
+        "79,228,162,514,264,337,593,543,950,335.00"
+    );
+    assert_eq!(
+        format_128_abs(i128::MIN, ","),
+        "170,141,183,460,469,231,731,687,303,715,884,105,728"
+    );
+}
+
+#[test]
+fn test_money_format_compile() {
+    use crate::fmt::MoneyFormat;
+
+    let patterns = [
+        "c na",
+        "nsa",
+        "nsa m",
+        "c pna m",
+        "\\{Total (c):} na \\c \\\\ \\x",
+        "\\{unclosed a",
+        "a\\",
+        "",
+    ];
+    let amounts = [dec!(0), dec!(-0.01), dec!(1234567.891), Decimal::MAX];
+    for pattern in patterns {
+        let compiled = MoneyFormat::compile(pattern);
+        assert_eq!(compiled.pattern(), pattern);
+        for amount in amounts {
+            let money = Money::<USD>::from_decimal(amount);
+            assert_eq!(compiled.format(&money), money.format(pattern));
+            assert_eq!(
+                compiled.format_with_separator(&money, " ", ","),
+                money.format_with_separator(pattern, " ", ",")
+            );
+
+            let mut written = String::new();
+            compiled.write(&money, &mut written).unwrap();
+            assert_eq!(written, money.format(pattern));
+        }
+    }
+
+    let compiled = MoneyFormat::compile("sa");
+    assert_eq!(compiled.format(&money!(EUR, 1234.5)), "€1.234,50");
+    assert_eq!(compiled.format(&money!(JPY, -1234)), "¥1,234");
+    assert_eq!(compiled, MoneyFormat::compile("sa"));
+}
+
+#[test]
+fn test_format_compact() {
+    let cases = [
+        (dec!(0), "$0.00"),
+        (dec!(999.99), "$999.99"),
+        (dec!(-999.99), "-$999.99"),
+        (dec!(1000), "$1K"),
+        (dec!(1234.56), "$1.2K"),
+        (dec!(-1250), "-$1.2K"),
+        (dec!(1350), "$1.4K"),
+        (dec!(999_949), "$999.9K"),
+        (dec!(999_950), "$1M"),
+        (dec!(3_400_000), "$3.4M"),
+        (dec!(7_100_000_000), "$7.1B"),
+        (dec!(2_500_000_000_000), "$2.5T"),
+        (dec!(1_500_000_000_000_000), "$1500T"),
+    ];
+    for (amount, expected) in cases {
+        assert_eq!(
+            Money::<USD>::from_decimal(amount).format_compact(),
+            expected,
+            "{}",
+            amount
+        );
+    }
+
+    assert_eq!(
+        Money::<EUR>::from_decimal(dec!(-7_080_000_000)).format_compact(),
+        "-€7,1B"
+    );
+    assert_eq!(money!(JPY, 12_345).format_compact(), "¥12.3K");
+}
+
+#[test]
+fn test_format_compact_with() {
+    let money = Money::<USD>::from_decimal(dec!(-1_234_567.89));
+    assert_eq!(money.format_compact_with(3, dec!(1000)), "-$1.235M");
+    assert_eq!(money.format_compact_with(0, dec!(1000)), "-$1M");
+    assert_eq!(money.format_compact_with(1, dec!(1_234_567.89)), "-$1.2M");
+    assert_eq!(
+        money.format_compact_with(1, dec!(1_234_567.90)),
+        "-$1,234,567.89"
+    );
+
+    // thresholds below the smallest suffix don't compact smaller amounts
+    let money = Money::<USD>::from_decimal(dec!(999.5));
+    assert_eq!(money.format_compact_with(1, dec!(0)), "$999.50");
+    assert_eq!(money.format_compact_with(1, dec!(0)), money.format_symbol());
+}
+
+#[test]
+fn test_format_with_minor_symbol() {
+    use crate::fmt::MoneyFormat;
+
+    let money = Money::<EUR>::from_decimal(dec!(-1234.56));
+    assert_eq!(money.format_code_minor_with("cent"), "EUR -123.456 cent");
+    assert_eq!(money.format_symbol_minor_with("ct"), "-€123.456 ct");
+    assert_eq!(
+        money.format_with_minor_symbol("c na m", "cent"),
+        money.format_code_minor_with("cent")
+    );
+    // escaped `m` stays literal
+    assert_eq!(
+        money.format_with_minor_symbol("na \\m m", "cent"),
+        "-123.456 m cent"
+    );
+    // the currency's symbol when not overridden
+    assert_eq!(
+        money.format_with_minor_symbol("c na m", "c"),
+        money.format_code_minor()
+    );
+
+    let pattern = MoneyFormat::compile("nsa m").with_minor_symbol("units");
+    assert_eq!(pattern.format(&money), "-€123.456 units");
+    assert_eq!(pattern.format(&money!(JPY, 5)), "¥5 units");
+    assert_ne!(pattern, MoneyFormat::compile("nsa m"));
+    assert_eq!(
+        MoneyFormat::compile("nsa m").format(&money),
+        money.format_symbol_minor()
+    );
+}
+
+#[test]
+fn test_format_with_digits() {
+    use crate::iso::SAR;
+    use crate::{DigitSet, MoneyParser, fmt::MoneyFormat};
+
+    let money = Money::<SAR>::from_decimal(dec!(-1234567.5));
+    let formatted = money.format_with_digits("c na", DigitSet::ArabicIndic);
+    assert_eq!(formatted, "SAR -١,٢٣٤,٥٦٧.٥٠");
+    assert_eq!(
+        Money::<SAR>::from_str_format_with_digits(&formatted, "c na", DigitSet::ArabicIndic)
+            .unwrap(),
+        money
+    );
+
+    // only the amount is localized
+    assert_eq!(
+        money.format_with_digits("#1: na m", DigitSet::ExtendedArabicIndic),
+        "#1: -۱۲۳,۴۵۶,۷۵۰ halala"
+    );
+    assert_eq!(
+        money.format_with_digits("c na", DigitSet::Latin),
+        money.format("c na")
+    );
+    assert_eq!(
+        Money::<SAR>::from_decimal(Decimal::MAX).format_with_digits("na m", DigitSet::Thai),
+        "OVERFLOWED halala"
+    );
+
+    let eur = Money::<EUR>::from_decimal(dec!(1234.5));
+    assert_eq!(eur.format_with_digits("sa", DigitSet::Bengali), "€১.২৩৪,৫০");
+    assert_eq!(
+        Money::<EUR>::from_str_format_with_digits("€১.২৩৪,৫০", "sa", DigitSet::Bengali).unwrap(),
+        eur
+    );
+    // digits of other sets are not parsed
+    assert!(Money::<EUR>::from_str_format_with_digits("€১.২৩৪,৫০", "sa", DigitSet::Thai).is_err());
+
+    let pattern = MoneyFormat::compile("na c").with_digits(DigitSet::Devanagari);
+    assert_eq!(pattern.format(&eur), "१.२३४,५० EUR");
+    let mut w = String::new();
+    pattern.write(&money, &mut w).unwrap();
+    assert_eq!(w, "-१,२३४,५६७.५० SAR");
+    assert_ne!(pattern, MoneyFormat::compile("na c"));
+}
+
+#[test]
+fn test_format_masked() {
+    use crate::fmt::MaskPolicy;
+    use crate::iso::BHD;
+
+    let money = Money::<USD>::from_decimal(dec!(-1234.56));
+    assert_eq!(MaskPolicy::default(), MaskPolicy::MINOR);
+    assert_eq!(money.format_masked(), "USD ••••.56");
+    assert_eq!(money.format_masked_with(MaskPolicy::FULL), "USD ***");
+    // magnitude is hidden
+    assert_eq!(
+        Money::<USD>::from_decimal(dec!(98765432.56)).format_masked(),
+        money.format_masked()
+    );
+
+    assert_eq!(money!(USD, 7).format_masked(), "USD ••••.00");
+    assert_eq!(money!(JPY, 1234).format_masked(), "JPY ••••");
+    assert_eq!(money!(EUR, 0.5).format_masked(), "EUR ••••,50");
+    assert_eq!(
+        Money::<BHD>::from_decimal(dec!(1.5)).format_masked_with(MaskPolicy::new('#', 1, true)),
+        "BHD #.500"
+    );
+    assert_eq!(
+        money.format_masked_with(MaskPolicy::new('#', 0, false)),
+        "USD "
+    );
+}
+
+#[cfg(feature = "redact_debug")]
+#[test]
+fn test_debug_redacted() {
+    assert_eq!(
+        format!("{:?}", Money::<USD>::from_decimal(dec!(-1234.56))),
+        "Money(USD, ••••.56)"
+    );
+    assert_eq!(format!("{:?}", money!(JPY, 1234)), "Money(JPY, ••••)");
+    assert_eq!(format!("{:?}", money!(EUR, 1234.5)), "Money(EUR, ••••.50)");
+}
+
+#[test]
+fn test_align_column() {
+    use crate::fmt::align_column;
+
+    let column = align_column(&[money!(EUR, 1234567.5), money!(EUR, -0.5), money!(EUR, 12)]);
+    assert_eq!(
+        column,
+        ["EUR 1.234.567,50", "EUR        -0,50", "EUR        12,00"]
+    );
+
+    assert_eq!(
+        align_column(&[money!(JPY, 500), money!(JPY, -1234567)]),
+        ["JPY        500", "JPY -1,234,567"]
+    );
+    assert_eq!(
+        align_column(&[Money::<USD>::from_decimal(dec!(-0.001))]),
+        ["USD 0.00"]
+    );
+    assert!(align_column::<USD>(&[] as &[Money<USD>]).is_empty());
+
+    // every row has the same width
+    let column = align_column(&[money!(USD, 1), money!(USD, 99999.99), money!(USD, -5.1)]);
+    assert!(column.iter().all(|row| row.chars().count() == 13));
+}
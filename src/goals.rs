@@ -0,0 +1,174 @@
+//! goals contains savings goal projection with monthly compounding.
+//!
+//! All computations are done in [`Decimal`], balance is credited and rounded into currency's minor unit every period,
+//! the same way a savings account does.
+//!
+//! `rate` is the annual interest rate in percent, 6% -> rate = 6, compounded monthly.
+//! Contributions are added at the end of each period, after the interest.
+
+use rust_decimal::MathematicalOps;
+
+use crate::{BaseMoney, Currency, Decimal, Money, RoundingStrategy, macros::dec};
+
+/// Maximum number of monthly periods projected, 100 years.
+pub const MAX_PERIODS: u32 = 1200;
+
+const MONTHS_IN_YEAR: Decimal = dec!(12);
+
+/// Result of [`project`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Projection<C: Currency> {
+    /// Number of monthly periods to reach the target.
+    pub periods: u32,
+
+    /// Balance at the end of the last period, greater than or equal to the target.
+    pub balance: Money<C>,
+}
+
+/// Projects number of monthly periods needed to grow `current` into `target`.
+///
+/// Returns `None` if the target cannot be reached within [`MAX_PERIODS`], if `monthly_contribution` or `rate` is negative,
+/// or the computation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{money, dec, goals};
+///
+/// // Without interest: 1,000 + 12 x 500 = 7,000
+/// let projection = goals::project(money!(USD, 1_000), money!(USD, 500), dec!(0), money!(USD, 7_000)).unwrap();
+/// assert_eq!(projection.periods, 12);
+/// assert_eq!(projection.balance, money!(USD, 7_000));
+///
+/// // With 6% annual interest, the balance grows further.
+/// let projection = goals::project(money!(USD, 1_000), money!(USD, 500), dec!(6), money!(USD, 7_000)).unwrap();
+/// assert_eq!(projection.periods, 12);
+/// assert_eq!(projection.balance, money!(USD, 7_229.45));
+///
+/// // Already reached.
+/// let projection = goals::project(money!(USD, 1_000), money!(USD, 0), dec!(0), money!(USD, 500)).unwrap();
+/// assert_eq!(projection.periods, 0);
+///
+/// // Never reached.
+/// assert!(goals::project(money!(USD, 0), money!(USD, 0), dec!(5), money!(USD, 1)).is_none());
+/// ```
+pub fn project<C: Currency>(
+    current: Money<C>,
+    monthly_contribution: Money<C>,
+    rate: Decimal,
+    target: Money<C>,
+) -> Option<Projection<C>> {
+    if monthly_contribution.is_negative() || rate.is_sign_negative() {
+        return None;
+    }
+
+    let monthly_rate = monthly_rate(rate)?;
+    let mut balance = current.amount();
+    for periods in 0..=MAX_PERIODS {
+        if balance >= target.amount() {
+            return Some(Projection {
+                periods,
+                balance: Money::from_decimal(balance),
+            });
+        }
+        balance = next_balance::<C>(balance, monthly_contribution.amount(), monthly_rate)?;
+    }
+
+    None
+}
+
+/// Returns monthly contribution required to grow `current` into `target` in `periods` months.
+///
+/// The contribution is the smallest amount in currency's minor unit with which [`project`] reaches
+/// the target in `periods` months.
+/// Returns zero if `current` alone reaches the target.
+///
+/// Returns `None` if `periods` is zero or more than [`MAX_PERIODS`], `rate` is negative, or the computation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{money, dec, goals};
+///
+/// let contribution = goals::required_contribution(money!(USD, 1_000), dec!(0), money!(USD, 7_000), 12).unwrap();
+/// assert_eq!(contribution, money!(USD, 500));
+///
+/// let contribution = goals::required_contribution(money!(USD, 1_000), dec!(6), money!(USD, 7_000), 12).unwrap();
+/// assert_eq!(contribution, money!(USD, 481.40));
+///
+/// let projection = goals::project(money!(USD, 1_000), contribution, dec!(6), money!(USD, 7_000)).unwrap();
+/// assert_eq!(projection.periods, 12);
+/// ```
+pub fn required_contribution<C: Currency>(
+    current: Money<C>,
+    rate: Decimal,
+    target: Money<C>,
+    periods: u32,
+) -> Option<Money<C>> {
+    if periods == 0 || periods > MAX_PERIODS || rate.is_sign_negative() {
+        return None;
+    }
+
+    let monthly_rate = monthly_rate(rate)?;
+    let n = Decimal::from(periods);
+
+    // Future value of current balance without contributions.
+    let growth = Decimal::ONE
+        .checked_add(monthly_rate)?
+        .checked_powu(periods.into())?;
+    let shortfall = target
+        .amount()
+        .checked_sub(current.amount().checked_mul(growth)?)?;
+    if shortfall <= Decimal::ZERO {
+        return Some(Money::default());
+    }
+
+    // Future value of annuity factor, n when there's no interest.
+    let annuity = if monthly_rate.is_zero() {
+        n
+    } else {
+        growth
+            .checked_sub(Decimal::ONE)?
+            .checked_div(monthly_rate)?
+    };
+    let contribution = shortfall
+        .checked_div(annuity)?
+        .round_dp_with_strategy(C::MINOR_UNIT.into(), RoundingStrategy::Ceil.into());
+
+    // Balance is rounded every period, so adjust the contribution by minor unit to the smallest one reaching the target.
+    let ulp = Decimal::new(1, C::MINOR_UNIT.into());
+    let reaches = |contribution: Decimal| -> Option<bool> {
+        let mut balance = current.amount();
+        for _ in 0..periods {
+            balance = next_balance::<C>(balance, contribution, monthly_rate)?;
+        }
+        Some(balance >= target.amount())
+    };
+    let mut contribution = contribution;
+    if reaches(contribution)? {
+        while contribution > Decimal::ZERO && reaches(contribution.checked_sub(ulp)?)? {
+            contribution = contribution.checked_sub(ulp)?;
+        }
+    } else {
+        while !reaches(contribution)? {
+            contribution = contribution.checked_add(ulp)?;
+        }
+    }
+
+    Some(Money::from_decimal(contribution))
+}
+
+fn monthly_rate(rate: Decimal) -> Option<Decimal> {
+    rate.checked_div(dec!(100))?.checked_div(MONTHS_IN_YEAR)
+}
+
+fn next_balance<C: Currency>(
+    balance: Decimal,
+    contribution: Decimal,
+    monthly_rate: Decimal,
+) -> Option<Decimal> {
+    let interest = balance
+        .checked_mul(monthly_rate)?
+        .round_dp(C::MINOR_UNIT.into());
+    balance.checked_add(interest)?.checked_add(contribution)
+}
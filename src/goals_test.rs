@@ -0,0 +1,85 @@
+use crate::goals::{self, MAX_PERIODS, Projection};
+use crate::macros::{dec, money};
+
+#[test]
+fn test_project_without_interest() {
+    let p = goals::project(
+        money!(USD, 0),
+        money!(USD, 100),
+        dec!(0),
+        money!(USD, 1_050),
+    )
+    .unwrap();
+    assert_eq!(
+        p,
+        Projection {
+            periods: 11,
+            balance: money!(USD, 1_100)
+        }
+    );
+}
+
+#[test]
+fn test_project_with_interest() {
+    // 12% annual = 1% monthly, balance 100 -> 101 -> 102.01 -> 103.03 (1.0201 rounded to 1.02)
+    let p = goals::project(money!(USD, 100), money!(USD, 0), dec!(12), money!(USD, 103)).unwrap();
+    assert_eq!(p.periods, 3);
+    assert_eq!(p.balance, money!(USD, 103.03));
+
+    let p = goals::project(
+        money!(JPY, 10_000),
+        money!(JPY, 1_000),
+        dec!(12),
+        money!(JPY, 12_100),
+    )
+    .unwrap();
+    // 10,000 -> 11,100 -> 12,211
+    assert_eq!(p.periods, 2);
+    assert_eq!(p.balance, money!(JPY, 12_211));
+}
+
+#[test]
+fn test_project_invalid() {
+    assert!(goals::project(money!(USD, 0), money!(USD, -1), dec!(0), money!(USD, 1)).is_none());
+    assert!(goals::project(money!(USD, 0), money!(USD, 1), dec!(-1), money!(USD, 1)).is_none());
+    // unreachable within MAX_PERIODS
+    let target = money!(USD, 1_201);
+    assert!(goals::project(money!(USD, 0), money!(USD, 1), dec!(0), target).is_none());
+    let p = goals::project(money!(USD, 0), money!(USD, 1), dec!(0), money!(USD, 1_200)).unwrap();
+    assert_eq!(p.periods, MAX_PERIODS);
+}
+
+#[test]
+fn test_required_contribution() {
+    let c = goals::required_contribution(money!(USD, 0), dec!(0), money!(USD, 1_000), 3).unwrap();
+    assert_eq!(c, money!(USD, 333.34));
+
+    let c =
+        goals::required_contribution(money!(USD, 5_000), dec!(0), money!(USD, 1_000), 3).unwrap();
+    assert_eq!(c, money!(USD, 0));
+
+    for rate in [dec!(0), dec!(1.5), dec!(5), dec!(24)] {
+        for periods in [1, 7, 36, 120] {
+            let c =
+                goals::required_contribution(money!(USD, 250), rate, money!(USD, 20_000), periods)
+                    .unwrap();
+            let p = goals::project(money!(USD, 250), c, rate, money!(USD, 20_000)).unwrap();
+            assert_eq!(p.periods, periods);
+
+            // one minor unit less doesn't reach the target in time
+            let less = c - money!(USD, 0.01);
+            let p = goals::project(money!(USD, 250), less, rate, money!(USD, 20_000)).unwrap();
+            assert!(p.periods > periods);
+        }
+    }
+}
+
+#[test]
+fn test_required_contribution_invalid() {
+    assert!(goals::required_contribution(money!(USD, 0), dec!(0), money!(USD, 1), 0).is_none());
+    assert!(
+        goals::required_contribution(money!(USD, 0), dec!(0), money!(USD, 1), MAX_PERIODS + 1)
+            .is_none()
+    );
+    assert!(goals::required_contribution(money!(USD, 0), dec!(-2), money!(USD, 1), 1).is_none());
+}
@@ -0,0 +1,131 @@
+//! icu contains locale-aware money formatting.
+//!
+//! Unlike [`MoneyFormatter::format_locale_amount`](crate::MoneyFormatter::format_locale_amount) taking the format string
+//! from the caller, [`MoneyFormatter::format_locale`](crate::MoneyFormatter::format_locale) takes everything from the locale:
+//! digits, grouping and decimal separator come from ICU's data, and symbol placement from this crate's own table of
+//! common locales, e.g. `¤#,##0.00` for `en`, `#,##0.00 ¤` for `de` and `¤ #,##0.00` for `nl`.
+//!
+//! The table only approximates CLDR currency patterns: it covers symbol side, spacing and sign position of the listed
+//! locales, has no plural rules or currency-specific spacing, and other locales use `¤#,##0.00`.
+
+use icu_decimal::{DecimalFormatter, input::Decimal as LocaleDecimal};
+
+use crate::{BaseMoney, Currency, Decimal};
+
+pub use icu_locale::Locale;
+
+// No-break space, separating symbol and amount.
+const NBSP: &str = "\u{a0}";
+
+/// Placement of currency symbol and negative sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placement {
+    /// `¤#,##0.00`, the default.
+    Prefix,
+    /// `¤ #,##0.00`
+    PrefixSpace,
+    /// `¤ #,##0.00;¤ -#,##0.00`
+    PrefixSpaceInnerSign,
+    /// `#,##0.00 ¤`
+    SuffixSpace,
+}
+
+// Locales whose pattern differs from their language's, checked first.
+const REGION_PLACEMENTS: &[(&str, &str, Placement)] = &[
+    ("de", "AT", Placement::PrefixSpace),
+    ("de", "CH", Placement::PrefixSpace),
+    ("de", "LI", Placement::PrefixSpace),
+    ("es", "419", Placement::Prefix),
+    ("es", "MX", Placement::Prefix),
+    ("es", "US", Placement::Prefix),
+    ("pt", "PT", Placement::SuffixSpace),
+    ("fr", "CH", Placement::SuffixSpace),
+    ("it", "CH", Placement::PrefixSpace),
+];
+
+// Languages whose pattern differs from the default.
+const LANGUAGE_PLACEMENTS: &[(&str, Placement)] = &[
+    ("ar", Placement::SuffixSpace),
+    ("bg", Placement::SuffixSpace),
+    ("ca", Placement::SuffixSpace),
+    ("cs", Placement::SuffixSpace),
+    ("da", Placement::SuffixSpace),
+    ("de", Placement::SuffixSpace),
+    ("el", Placement::SuffixSpace),
+    ("es", Placement::SuffixSpace),
+    ("et", Placement::SuffixSpace),
+    ("fi", Placement::SuffixSpace),
+    ("fr", Placement::SuffixSpace),
+    ("he", Placement::SuffixSpace),
+    ("hr", Placement::SuffixSpace),
+    ("hu", Placement::SuffixSpace),
+    ("it", Placement::SuffixSpace),
+    ("lt", Placement::SuffixSpace),
+    ("lv", Placement::SuffixSpace),
+    ("nb", Placement::SuffixSpace),
+    ("nl", Placement::PrefixSpaceInnerSign),
+    ("no", Placement::SuffixSpace),
+    ("pl", Placement::SuffixSpace),
+    ("pt", Placement::PrefixSpace),
+    ("ro", Placement::SuffixSpace),
+    ("ru", Placement::SuffixSpace),
+    ("sk", Placement::SuffixSpace),
+    ("sl", Placement::SuffixSpace),
+    ("sv", Placement::SuffixSpace),
+    ("uk", Placement::SuffixSpace),
+    ("vi", Placement::SuffixSpace),
+];
+
+fn placement(locale: &Locale) -> Placement {
+    let language = locale.id.language.as_str();
+    if let Some(region) = locale.id.region
+        && let Some((.., placement)) = REGION_PLACEMENTS
+            .iter()
+            .find(|(lang, reg, _)| *lang == language && *reg == region.as_str())
+    {
+        return *placement;
+    }
+
+    LANGUAGE_PLACEMENTS
+        .iter()
+        .find(|(lang, _)| *lang == language)
+        .map_or(Placement::Prefix, |(_, placement)| *placement)
+}
+
+// Formats `amount` with locale's digits and separators, `None` if ICU fails.
+fn format_decimal(formatter: &DecimalFormatter, amount: Decimal) -> Option<String> {
+    let decimal = LocaleDecimal::try_from_str(&amount.to_string()).ok()?;
+    Some(formatter.format(&decimal).to_string())
+}
+
+pub(crate) fn format_locale<C: Currency>(money: &impl BaseMoney<C>, locale: &Locale) -> String {
+    let mut abs = money.amount().abs();
+    if abs.scale() < u32::from(C::MINOR_UNIT) {
+        abs.rescale(C::MINOR_UNIT.into());
+    }
+
+    let Some((sign, number)) = DecimalFormatter::try_new(locale.into(), Default::default())
+        .ok()
+        .and_then(|formatter| {
+            let number = format_decimal(&formatter, abs)?;
+            let sign = if money.is_negative() {
+                // locale's minus sign, e.g. with bidi marks, is what precedes the absolute number.
+                let signed = format_decimal(&formatter, -abs)?;
+                signed.strip_suffix(number.as_str())?.to_string()
+            } else {
+                String::new()
+            };
+            Some((sign, number))
+        })
+    else {
+        // compiled data falls back to root locale, so this is not expected.
+        return money.format_code();
+    };
+
+    match placement(locale) {
+        Placement::Prefix => format!("{}{}{}", sign, C::SYMBOL, number),
+        Placement::PrefixSpace => format!("{}{}{}{}", sign, C::SYMBOL, NBSP, number),
+        Placement::PrefixSpaceInnerSign => format!("{}{}{}{}", C::SYMBOL, NBSP, sign, number),
+        Placement::SuffixSpace => format!("{}{}{}{}", sign, number, NBSP, C::SYMBOL),
+    }
+}
@@ -0,0 +1,71 @@
+use crate::icu::Locale;
+use crate::iso::INR;
+use crate::macros::dec;
+use crate::{BaseMoney, Money, MoneyFormatter, money};
+
+fn locale(s: &str) -> Locale {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_format_locale_symbol_placement() {
+    let money = money!(USD, 1234.56);
+    assert_eq!(money.format_locale(&locale("en-US")), "$1,234.56");
+    assert_eq!(money.format_locale(&locale("de-DE")), "1.234,56\u{a0}$");
+    assert_eq!(
+        money.format_locale(&locale("de-AT")),
+        "$\u{a0}1\u{a0}234,56"
+    );
+    assert_eq!(money.format_locale(&locale("pt-BR")), "$\u{a0}1.234,56");
+    // pt-PT groups only from 5 integer digits.
+    assert_eq!(money.format_locale(&locale("pt-PT")), "1234,56\u{a0}$");
+    assert_eq!(money.format_locale(&locale("ja-JP")), "$1,234.56");
+
+    // Latin American Spanish puts symbol in front, unlike Spain's.
+    assert_eq!(money.format_locale(&locale("es-ES")), "1234,56\u{a0}$");
+    assert_eq!(money.format_locale(&locale("es-MX")), "$1,234.56");
+    assert_eq!(money.format_locale(&locale("es-US")), "$1,234.56");
+    assert_eq!(money.format_locale(&locale("es-419")), "$1,234.56");
+}
+
+#[test]
+fn test_format_locale_negative_and_padding() {
+    assert_eq!(
+        money!(EUR, -1234.5).format_locale(&locale("fr-FR")),
+        "-1\u{202f}234,50\u{a0}€"
+    );
+    assert_eq!(money!(JPY, -1500).format_locale(&locale("ja")), "-¥1,500");
+    assert_eq!(
+        money!(EUR, -1234.5).format_locale(&locale("nl-NL")),
+        "€\u{a0}-1.234,50"
+    );
+    assert_eq!(
+        money!(EUR, 1234.5).format_locale(&locale("nl-NL")),
+        "€\u{a0}1.234,50"
+    );
+    assert_eq!(money!(BHD, 1).format_locale(&locale("en")), "BD1.000");
+    assert_eq!(money!(USD, 0).format_locale(&locale("en")), "$0.00");
+}
+
+#[test]
+fn test_format_locale_digits_and_grouping() {
+    let money = Money::<INR>::new(dec!(1234012.52)).unwrap();
+    assert_eq!(money.format_locale(&locale("en-IN")), "₹12,34,012.52");
+    assert_eq!(
+        money.format_locale(&locale("hi-IN-u-nu-deva")),
+        "₹१२,३४,०१२.५२"
+    );
+
+    // unknown language falls back to the default pattern.
+    assert_eq!(
+        money!(USD, 1234.56).format_locale(&locale("xx")),
+        "$1,234.56"
+    );
+}
+
+#[cfg(feature = "raw_money")]
+#[test]
+fn test_format_locale_raw_money() {
+    let money = crate::raw!(USD, 1234.5678);
+    assert_eq!(money.format_locale(&locale("de")), "1.234,5678\u{a0}$");
+}
@@ -13,18 +13,21 @@ pub mod prelude {
     pub use crate::BaseMoney;
     pub use crate::BaseOps;
     pub use crate::Currency;
+    pub use crate::CurrencyFormat;
     pub use crate::IterOps;
     pub use crate::MoneyFormatter;
     pub use crate::MoneyOps;
     pub use crate::MoneyParser;
     pub use crate::PercentOps;
     pub use crate::RoundingStrategy;
+    pub use crate::RunningOps;
     pub use crate::base::{Amount, DecimalNumber};
     pub use crate::{Decimal, Money, MoneyError};
+    pub use crate::{Guarded, OverflowPolicy};
 
     pub use crate::iso;
 
-    pub use crate::macros::{dec, money};
+    pub use crate::macros::{const_money, dec, define_currency, money};
 
     #[cfg(feature = "raw_money")]
     pub use crate::RawMoney;
@@ -75,6 +78,12 @@ pub use error::MoneyError;
 
 pub use currencylib::Currency;
 
+mod currency;
+pub use currency::{
+    CurrencyFormat, DigitSet, FormatSpec, Grouping, ParseLocale, ParseOptions,
+    check_minor_semantics,
+};
+
 /// Contains all ISO 4217 currencies.
 pub mod iso {
     pub use currencylib::*;
@@ -86,38 +95,116 @@ pub use money::Money;
 #[cfg(feature = "raw_money")]
 mod raw_money;
 #[cfg(feature = "raw_money")]
-pub use raw_money::RawMoney;
+pub use raw_money::{RawMoney, TrackedMoney};
 
 mod iter_ops;
 mod ops;
 mod percent_ops;
 pub use percent_ops::PercentOps;
+mod overflow;
+pub use overflow::{Guarded, OverflowPolicy};
+mod running_ops;
+pub use running_ops::{RunningBalance, RunningOps};
 mod split_alloc_ops;
 
+pub mod adjustment;
+
+pub mod balance;
+
+pub mod finance;
+
+pub mod goals;
+
+pub mod stats;
+
+pub mod waterfall;
+
 #[cfg(feature = "exchange")]
 mod exchange;
 #[cfg(feature = "exchange")]
 pub use exchange::{Exchange, ExchangeRates};
 
+#[cfg(feature = "exchange")]
+pub mod basket;
+
+#[cfg(feature = "exchange")]
+pub mod conversion;
+
 #[cfg(feature = "serde")]
 /// Serde implementations
 pub mod serde;
 
-mod fmt;
+/// Formatting of money collections.
+pub mod fmt;
+
+pub mod decimal;
+
+pub mod words;
 
 mod parse;
 
 #[cfg(feature = "obj_money")]
 pub mod obj_money;
 
+#[cfg(feature = "validator")]
+pub mod validator;
+
+#[cfg(feature = "chrono")]
+pub mod settlement;
+
+#[cfg(feature = "schemars")]
+pub mod schemars;
+
+#[cfg(feature = "utoipa")]
+mod utoipa;
+
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+
+#[cfg(feature = "diesel")]
+pub mod diesel;
+
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+
+#[cfg(feature = "postgres")]
+pub mod postgres;
+
+#[cfg(feature = "sea-orm")]
+pub mod sea_orm;
+
+#[cfg(feature = "shadow")]
+pub mod shadow;
+
+#[cfg(feature = "icu")]
+pub mod icu;
+
+#[cfg(feature = "serde_contract")]
+pub mod serde_contract;
+
+#[cfg(feature = "crypto")]
+pub mod crypto;
+
 // ----------------- test modules -----------------
 
+#[cfg(test)]
+mod currency_test;
+
 #[cfg(test)]
 mod fmt_test;
 
+#[cfg(test)]
+mod decimal_test;
+
+#[cfg(test)]
+mod words_test;
+
 #[cfg(test)]
 mod money_test;
 
+#[cfg(test)]
+mod parse_test;
+
 #[cfg(test)]
 mod error_test;
 
@@ -127,11 +214,80 @@ mod ops_test;
 #[cfg(test)]
 mod iter_ops_test;
 
+#[cfg(test)]
+mod overflow_test;
+
 #[cfg(test)]
 mod percent_ops_test;
 
+#[cfg(test)]
+mod running_ops_test;
+
 #[cfg(test)]
 mod split_alloc_ops_test;
 
+#[cfg(test)]
+mod adjustment_test;
+
+#[cfg(test)]
+mod balance_test;
+
+#[cfg(test)]
+mod finance_test;
+
+#[cfg(test)]
+mod goals_test;
+
+#[cfg(test)]
+mod stats_test;
+
+#[cfg(test)]
+mod waterfall_test;
+
 #[cfg(all(test, feature = "exchange"))]
 mod exchange_test;
+
+#[cfg(all(test, feature = "exchange"))]
+mod basket_test;
+
+#[cfg(all(test, feature = "exchange"))]
+mod conversion_test;
+
+#[cfg(all(test, feature = "validator"))]
+mod validator_test;
+
+#[cfg(all(test, feature = "chrono"))]
+mod settlement_test;
+
+#[cfg(all(test, feature = "schemars"))]
+mod schemars_test;
+
+#[cfg(all(test, feature = "utoipa"))]
+mod utoipa_test;
+
+#[cfg(all(test, feature = "sqlx"))]
+mod sqlx_test;
+
+#[cfg(all(test, feature = "diesel"))]
+mod diesel_test;
+
+#[cfg(all(test, feature = "rusqlite"))]
+mod rusqlite_test;
+
+#[cfg(all(test, feature = "postgres"))]
+mod postgres_test;
+
+#[cfg(all(test, feature = "sea-orm"))]
+mod sea_orm_test;
+
+#[cfg(all(test, feature = "shadow"))]
+mod shadow_test;
+
+#[cfg(all(test, feature = "icu"))]
+mod icu_test;
+
+#[cfg(all(test, feature = "serde_contract"))]
+mod serde_contract_test;
+
+#[cfg(all(test, feature = "crypto"))]
+mod crypto_test;
@@ -43,6 +43,161 @@ macro_rules! money {
     };
 }
 
+/// Creates a [`Money`](crate::Money) instance in const context using a currency type and a decimal amount.
+///
+/// Same forms as [`money!`](crate::money), but usable in `const` and `static` items, e.g. fee tables and limits,
+/// through [`Money::try_from_mantissa`](crate::Money::try_from_mantissa). It's evaluated at compile time even outside of
+/// const context. Nothing is rounded, an amount with more decimal places than the currency's minor unit fails to
+/// compile.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{Money, money, macros::const_money, iso::USD};
+///
+/// const MIN_BALANCE: Money<USD> = const_money!(USD, 10);
+/// static FEES: [Money<USD>; 3] = [const_money!(USD, 0.30), const_money!(USD, 1.50), const_money!(USD, 2.50)];
+///
+/// assert_eq!(MIN_BALANCE, money!(USD, 10));
+/// assert_eq!(FEES[2], money!(USD, 2.50));
+/// assert_eq!(const_money!(USD, -1_000.500), money!(USD, -1_000.50));
+/// ```
+///
+/// ```compile_fail
+/// use moneylib::{Money, macros::const_money, iso::USD};
+///
+/// const FEE: Money<USD> = const_money!(USD, 0.305);
+/// ```
+#[macro_export]
+macro_rules! const_money {
+    // Short form: bare ISO currency identifier, auto-resolved from crate::iso
+    ($currency:ident, $($amount:tt)+) => {
+        {
+            const MONEY: $crate::Money<$crate::iso::$currency> = {
+                const AMOUNT: $crate::Decimal = $crate::dec!($($amount)+);
+                match $crate::Money::<$crate::iso::$currency>::try_from_mantissa(AMOUNT.mantissa(), AMOUNT.scale()) {
+                    Some(money) => money,
+                    None => panic!("amount doesn't fit currency's minor unit or Decimal"),
+                }
+            };
+            MONEY
+        }
+    };
+    // Long form: explicit path for custom currency types (must be in scope)
+    ($currency:path, $($amount:tt)+) => {
+        {
+            const MONEY: $crate::Money<$currency> = {
+                const AMOUNT: $crate::Decimal = $crate::dec!($($amount)+);
+                match $crate::Money::<$currency>::try_from_mantissa(AMOUNT.mantissa(), AMOUNT.scale()) {
+                    Some(money) => money,
+                    None => panic!("amount doesn't fit currency's minor unit or Decimal"),
+                }
+            };
+            MONEY
+        }
+    };
+}
+
+/// Declares a custom currency marker type implementing [`Currency`](crate::Currency), e.g. for loyalty points and
+/// test currencies not covered by ISO 4217.
+///
+/// The type plugs into [`Money`](crate::Money), [`RawMoney`](crate::RawMoney), the long form of the money macros,
+/// formatting, parsing and serde the same way as currencies in [`crate::iso`].
+/// It derives `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `Hash`, `PartialOrd`, `Ord` and `Default`.
+///
+/// Fields must be given in the order below. The trailing `minor_unit_symbol`, `minor_unit_name`, `origin` and `locale`
+/// are optional, defaulting to `"minor"`, `"minor"`, `""` and `"en-US"`.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, Currency, Money, MoneyParser, macros::{dec, define_currency}};
+///
+/// define_currency! {
+///     /// Loyalty points earned on purchases.
+///     pub struct PTS {
+///         code: "PTS",
+///         symbol: "pts",
+///         name: "Loyalty points",
+///         numeric: 0,
+///         minor_unit: 0,
+///         thousand_separator: ",",
+///         decimal_separator: ".",
+///     }
+/// }
+///
+/// define_currency! {
+///     struct XTS {
+///         code: "XTS",
+///         symbol: "T$",
+///         name: "Test currency",
+///         numeric: 963,
+///         minor_unit: 3,
+///         thousand_separator: ".",
+///         decimal_separator: ",",
+///         minor_unit_symbol: "m",
+///         minor_unit_name: "mill",
+///         origin: "Testland",
+///         locale: "de-DE",
+///     }
+/// }
+///
+/// let points = Money::<PTS>::new(dec!(1_250.6)).unwrap();
+/// assert_eq!(points.amount(), dec!(1_251));
+/// assert_eq!(points.to_string(), "PTS 1,251");
+/// assert_eq!(PTS::MINOR_UNIT_NAME, "minor");
+///
+/// let test = Money::<XTS>::from_str_code("XTS 1.234,5678").unwrap();
+/// assert_eq!(test.amount(), dec!(1_234.568));
+/// assert_eq!(test.minor_unit(), 3);
+/// ```
+#[macro_export]
+macro_rules! define_currency {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            code: $code:expr,
+            symbol: $symbol:expr,
+            name: $currency_name:expr,
+            numeric: $numeric:expr,
+            minor_unit: $minor_unit:expr,
+            thousand_separator: $thousand_separator:expr,
+            decimal_separator: $decimal_separator:expr
+            $(, minor_unit_symbol: $minor_unit_symbol:expr)?
+            $(, minor_unit_name: $minor_unit_name:expr)?
+            $(, origin: $origin:expr)?
+            $(, locale: $locale:expr)?
+            $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+        $vis struct $name;
+
+        impl $crate::Currency for $name {
+            const CODE: &'static str = $code;
+            const SYMBOL: &'static str = $symbol;
+            const NAME: &'static str = $currency_name;
+            const NUMERIC: u16 = $numeric;
+            const MINOR_UNIT: u16 = $minor_unit;
+            const MINOR_UNIT_SYMBOL: &'static str =
+                $crate::define_currency!(@or $($minor_unit_symbol)?; "minor");
+            const MINOR_UNIT_NAME: &'static str =
+                $crate::define_currency!(@or $($minor_unit_name)?; "minor");
+            const THOUSAND_SEPARATOR: &'static str = $thousand_separator;
+            const DECIMAL_SEPARATOR: &'static str = $decimal_separator;
+            const ORIGIN: &'static str = $crate::define_currency!(@or $($origin)?; "");
+            const LOCALE: &'static str = $crate::define_currency!(@or $($locale)?; "en-US");
+        }
+    };
+    (@or $value:expr; $default:expr) => {
+        $value
+    };
+    (@or ; $default:expr) => {
+        $default
+    };
+}
+
 /// Creates a [`RawMoney`](crate::RawMoney) instance using a currency type and a decimal amount.
 ///
 /// **Short form (ISO currencies):** pass a bare ISO 4217 currency code — it is resolved from
@@ -175,6 +330,10 @@ macro_rules! dec {
 
 pub use crate::money;
 
+pub use crate::const_money;
+
+pub use crate::define_currency;
+
 #[cfg(feature = "raw_money")]
 pub use crate::raw;
 
@@ -1,18 +1,15 @@
-use rust_decimal::prelude::ToPrimitive;
 use std::{
     fmt::{Debug, Display},
-    iter::Sum,
+    hash::{Hash, Hasher},
     marker::PhantomData,
     str::FromStr,
 };
 
 use crate::{
     BaseMoney, BaseOps, Decimal, MoneyError, MoneyOps,
-    base::{Amount, MoneyParser},
-    macros::dec,
+    base::{Amount, DecimalNumber, MoneyParser},
 };
 use crate::{Currency, MoneyFormatter};
-use rust_decimal::MathematicalOps;
 
 /// Represents a monetary value with a specific currency and amount.
 ///
@@ -48,25 +45,460 @@ use rust_decimal::MathematicalOps;
 /// - [`BaseMoney`] trait for core money operations and accessors
 /// - [`BaseOps`] trait for arithmetic and comparison operations
 /// - [`MoneyFormatter`] trait for custom formatting and rounding
-#[derive(Copy, PartialEq, Eq)]
+#[derive(Copy)]
 pub struct Money<C: Currency> {
     amount: Decimal,
     _currency: PhantomData<C>,
 }
 
+impl<C> Money<C>
+where
+    C: Currency,
+{
+    /// Zero money, the canonical zero at currency's scale, e.g. `0.00` for USD and `0` for JPY, same as [`Default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, money, iso::{JPY, USD}};
+    ///
+    /// const NO_FEE: Money<USD> = Money::ZERO;
+    /// assert_eq!(NO_FEE, money!(USD, 0));
+    /// assert_eq!(NO_FEE.to_string(), "USD 0.00");
+    /// assert_eq!(Money::<JPY>::ZERO.scale(), 0);
+    /// assert!(money!(USD, 0.01) > Money::ZERO);
+    /// ```
+    pub const ZERO: Self = Self {
+        amount: Decimal::from_parts(0, 0, 0, false, crate::decimal::minor_scale(C::MINOR_UNIT)),
+        _currency: PhantomData,
+    };
+
+    /// Largest money, with amount of [`Decimal::MAX`].
+    ///
+    /// Unlike [`max_representable`](Self::max_representable) it doesn't keep decimal places of the minor unit,
+    /// it's the bound where checked operations overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, money, iso::USD};
+    ///
+    /// assert_eq!(Money::<USD>::MAX.amount(), moneylib::Decimal::MAX);
+    /// assert!(Money::<USD>::MAX.checked_add(money!(USD, 1)).is_none());
+    /// ```
+    pub const MAX: Self = Self {
+        amount: Decimal::MAX,
+        _currency: PhantomData,
+    };
+
+    /// Smallest(most negative) money, with amount of [`Decimal::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, money, iso::USD};
+    ///
+    /// assert_eq!(Money::<USD>::MIN.amount(), moneylib::Decimal::MIN);
+    /// assert_eq!(-Money::<USD>::MIN, Money::<USD>::MAX);
+    /// ```
+    pub const MIN: Self = Self {
+        amount: Decimal::MIN,
+        _currency: PhantomData,
+    };
+
+    /// Creates money of `mantissa` scaled down by `10^scale` in const context, e.g. `1250` with scale 2 is `12.50`.
+    ///
+    /// Lets fee tables and limits live in `const` and `static` items, see also [`const_money!`](crate::const_money).
+    /// Nothing is rounded, as rounding isn't available in const context, so the amount must fit the currency's minor
+    /// unit, ignoring trailing zeros.
+    ///
+    /// Returns `None` if the amount has nonzero digits beyond the currency's minor unit, or `mantissa` doesn't fit
+    /// 96 bits of [`Decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, money, iso::{JPY, USD}};
+    ///
+    /// const FEE: Option<Money<USD>> = Money::try_from_mantissa(1250, 2);
+    ///
+    /// assert_eq!(FEE, Some(money!(USD, 12.50)));
+    /// assert_eq!(Money::<JPY>::try_from_mantissa(500_000, 0), Some(money!(JPY, 500_000)));
+    /// assert_eq!(Money::<USD>::try_from_mantissa(-15_000, 3), Some(money!(USD, -15)));
+    ///
+    /// // 1.005 doesn't fit USD's cents.
+    /// assert_eq!(Money::<USD>::try_from_mantissa(1005, 3), None);
+    /// assert_eq!(Money::<USD>::try_from_mantissa(i128::MAX, 0), None);
+    /// ```
+    pub const fn try_from_mantissa(mantissa: i128, scale: u32) -> Option<Self> {
+        let minor_scale = crate::decimal::minor_scale(C::MINOR_UNIT);
+        let (mut mantissa, mut scale) = (mantissa, scale);
+        // Trailing zeros beyond minor unit don't need rounding.
+        while scale > minor_scale && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        if scale > minor_scale {
+            return None;
+        }
+        // 96-bit little-endian parts of Decimal, `as` and `try_from` aren't available in const context.
+        let bytes = mantissa.unsigned_abs().to_le_bytes();
+        if bytes[12] != 0 || bytes[13] != 0 || bytes[14] != 0 || bytes[15] != 0 {
+            return None;
+        }
+        let lo = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let mid = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        let hi = u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
+        Some(Self {
+            amount: Decimal::from_parts(lo, mid, hi, mantissa < 0, scale),
+            _currency: PhantomData,
+        })
+    }
+
+    /// Adds `rhs` to this money, returning `None` on overflow.
+    ///
+    /// Same as [`BaseOps::checked_add`] without requiring the trait in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, macros::dec};
+    ///
+    /// let m = money!(USD, 100);
+    /// assert_eq!(m.checked_add(dec!(50.25)), Some(money!(USD, 150.25)));
+    /// assert_eq!(m.checked_add(m), Some(money!(USD, 200)));
+    /// assert_eq!(m.checked_add(moneylib::Decimal::MAX), None);
+    /// ```
+    #[inline]
+    pub fn checked_add<RHS>(self, rhs: RHS) -> Option<Self>
+    where
+        RHS: Amount<C>,
+    {
+        BaseOps::checked_add(&self, rhs)
+    }
+
+    /// Subtracts `rhs` from this money, returning `None` on overflow.
+    ///
+    /// Same as [`BaseOps::checked_sub`] without requiring the trait in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, macros::dec};
+    ///
+    /// let m = money!(USD, 100);
+    /// assert_eq!(m.checked_sub(dec!(150)), Some(money!(USD, -50)));
+    /// assert_eq!(m.checked_sub(moneylib::Decimal::MIN), None);
+    /// ```
+    #[inline]
+    pub fn checked_sub<RHS>(self, rhs: RHS) -> Option<Self>
+    where
+        RHS: Amount<C>,
+    {
+        BaseOps::checked_sub(&self, rhs)
+    }
+
+    /// Multiplies this money by `rhs`, returning `None` on overflow.
+    ///
+    /// Same as [`BaseOps::checked_mul`] without requiring the trait in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, macros::dec};
+    ///
+    /// let m = money!(USD, 10);
+    /// assert_eq!(m.checked_mul(3), Some(money!(USD, 30)));
+    /// assert_eq!(m.checked_mul(moneylib::Decimal::MAX), None);
+    /// ```
+    #[inline]
+    pub fn checked_mul<RHS>(self, rhs: RHS) -> Option<Self>
+    where
+        RHS: DecimalNumber,
+    {
+        BaseOps::checked_mul(&self, rhs)
+    }
+
+    /// Divides this money by `rhs`, returning `None` on overflow or division by zero.
+    ///
+    /// Same as [`BaseOps::checked_div`] without requiring the trait in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, macros::dec};
+    ///
+    /// let m = money!(USD, 100);
+    /// assert_eq!(m.checked_div(4), Some(money!(USD, 25)));
+    /// assert_eq!(m.checked_div(0), None);
+    /// ```
+    #[inline]
+    pub fn checked_div<RHS>(self, rhs: RHS) -> Option<Self>
+    where
+        RHS: DecimalNumber,
+    {
+        BaseOps::checked_div(&self, rhs)
+    }
+
+    /// Negates this money.
+    ///
+    /// Negation of [`Decimal`] never overflows, so this always returns `Some`.
+    /// Provided for symmetry with other checked operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, macros::dec};
+    ///
+    /// assert_eq!(money!(USD, 100).checked_neg(), Some(money!(USD, -100)));
+    /// assert_eq!(money!(USD, -100).checked_neg(), Some(money!(USD, 100)));
+    /// ```
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::from_decimal(-self.amount))
+    }
+
+    /// Returns absolute value of this money.
+    ///
+    /// Absolute value of [`Decimal`] never overflows, so this always returns `Some`.
+    /// Provided for symmetry with other checked operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, macros::dec};
+    ///
+    /// assert_eq!(money!(USD, -100).checked_abs(), Some(money!(USD, 100)));
+    /// ```
+    #[inline]
+    pub fn checked_abs(self) -> Option<Self> {
+        Some(Self::from_decimal(self.amount.abs()))
+    }
+
+    /// Returns the largest money representable with full precision of `C`'s minor unit.
+    ///
+    /// It's [`Decimal::MAX`]'s 96-bit mantissa at scale of the minor unit, e.g. `792281625142643375935439503.35` for USD,
+    /// so 29 significant digits. Use it to pre-check input and size database columns, e.g. `NUMERIC(29, 2)` for USD.
+    /// Amounts above it can still be held, but lose decimal places.
+    /// Minor units above [`Decimal::MAX_SCALE`] are capped to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{Money, BaseMoney, macros::dec, iso::{JPY, USD}};
+    ///
+    /// assert_eq!(Money::<USD>::max_representable().amount(), dec!(792281625142643375935439503.35));
+    /// assert_eq!(Money::<JPY>::max_representable().amount(), moneylib::Decimal::MAX);
+    /// assert!(Money::<USD>::max_representable().checked_add(dec!(0.01)).is_some());
+    /// ```
+    pub fn max_representable() -> Self {
+        let mut max = Decimal::MAX;
+        // never fails as scale is capped.
+        let _ = max.set_scale(u32::from(C::MINOR_UNIT).min(Decimal::MAX_SCALE));
+        Self::from_decimal(max)
+    }
+
+    /// Returns the smallest(most negative) money representable with full precision of `C`'s minor unit,
+    /// the negation of [`max_representable`](Self::max_representable).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{Money, BaseMoney, macros::dec, iso::USD};
+    ///
+    /// assert_eq!(Money::<USD>::min_representable().amount(), dec!(-792281625142643375935439503.35));
+    /// ```
+    pub fn min_representable() -> Self {
+        Self::from_decimal(-Self::max_representable().amount)
+    }
+
+    /// Creates money from `f64` only if it's exactly an amount in currency's minor unit, instead of silently rounding.
+    ///
+    /// The float is read as its shortest decimal representation reading back into the same float, so `10.1` is exactly
+    /// `10.10`, while `10.100000000000001` has more decimal places than USD's minor unit. [`new`](BaseMoney::new) would
+    /// round both into `10.10`. Same as `Money::try_from(value)`.
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::MinorUnitError`] if `value` has more decimal places than currency's minor unit.
+    /// - [`MoneyError::OverflowError`] if `value` is not finite or cannot be represented as `Decimal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{Money, MoneyError, money, iso::{JPY, USD}};
+    ///
+    /// assert_eq!(Money::<USD>::try_from_f64_exact(10.1).unwrap(), money!(USD, 10.10));
+    /// assert!(matches!(
+    ///     Money::<USD>::try_from_f64_exact(10.100000000000001),
+    ///     Err(MoneyError::MinorUnitError(_))
+    /// ));
+    /// assert!(Money::<JPY>::try_from_f64_exact(100.5).is_err());
+    /// assert!(Money::<USD>::try_from_f64_exact(f64::NAN).is_err());
+    /// ```
+    pub fn try_from_f64_exact(value: f64) -> Result<Self, MoneyError> {
+        if !value.is_finite() {
+            return Err(MoneyError::OverflowError);
+        }
+        let amount = match Decimal::from_str_exact(&value.to_string()) {
+            Ok(amount) => amount,
+            // more decimal places than `Decimal` can hold, so more than any minor unit.
+            Err(rust_decimal::Error::Underflow) => {
+                return Err(MoneyError::MinorUnitError(
+                    format!(
+                        "amount {} has more decimal places than {}'s minor unit of {}",
+                        value,
+                        C::CODE,
+                        C::MINOR_UNIT
+                    )
+                    .into(),
+                ));
+            }
+            Err(_) => return Err(MoneyError::OverflowError),
+        };
+        crate::currency::check_minor_unit::<C>(amount)?;
+        Ok(Self::from_decimal(amount))
+    }
+
+    /// Creates money from `f64` rounded into currency's minor unit, returning the rounding delta along with it.
+    ///
+    /// The delta is the rounded amount minus the float's shortest decimal representation(up to 28 decimal places), like
+    /// [`round_returning_delta`](BaseMoney::round_returning_delta), so zero delta means the float was exact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::OverflowError`] if `value` is not finite or cannot be represented as `Decimal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{Money, money, macros::dec, iso::USD};
+    ///
+    /// assert_eq!(Money::<USD>::from_f64_lossy(10.1).unwrap(), (money!(USD, 10.10), dec!(0)));
+    /// assert_eq!(
+    ///     Money::<USD>::from_f64_lossy(10.100000000000001).unwrap(),
+    ///     (money!(USD, 10.10), dec!(-0.000000000000001))
+    /// );
+    /// assert_eq!(Money::<USD>::from_f64_lossy(0.125).unwrap(), (money!(USD, 0.12), dec!(-0.005)));
+    /// ```
+    pub fn from_f64_lossy(value: f64) -> Result<(Self, Decimal), MoneyError> {
+        if !value.is_finite() {
+            return Err(MoneyError::OverflowError);
+        }
+        let amount =
+            Decimal::from_str(&value.to_string()).map_err(|_| MoneyError::OverflowError)?;
+        let money = Self::from_decimal(amount);
+        let delta = money
+            .amount
+            .checked_sub(amount)
+            .ok_or(MoneyError::OverflowError)?;
+        Ok((money, delta))
+    }
+
+    /// Parses `input` in any accepted representation and formats it into the canonical [`Display`] string,
+    /// e.g. `"USD 1,234.56"`.
+    ///
+    /// Accepted representations are code format and symbol format with currency's separators,
+    /// e.g. `"USD 1,234.56"` and `"$1,234.56"`, and plain number, e.g. `"1234.56"`.
+    /// The amount is rounded into currency's minor unit. Normalizing the output again returns it unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ParseStrError`] if `input` is in none of the accepted representations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{Money, iso::{EUR, USD}};
+    ///
+    /// assert_eq!(Money::<USD>::normalize_str("$1,234.5").unwrap(), "USD 1,234.50");
+    /// assert_eq!(Money::<USD>::normalize_str(" 1234.567 ").unwrap(), "USD 1,234.57");
+    /// assert_eq!(Money::<USD>::normalize_str("USD 1,234.50").unwrap(), "USD 1,234.50");
+    /// assert_eq!(Money::<EUR>::normalize_str("-€1.234,5").unwrap(), "EUR -1.234,50");
+    /// assert!(Money::<USD>::normalize_str("EUR 1,234.50").is_err());
+    /// ```
+    pub fn normalize_str(input: &str) -> Result<String, MoneyError> {
+        let input = input.trim();
+        Self::from_str_code(input)
+            .or_else(|_| Self::from_str_symbol(input))
+            .or_else(|_| Self::from_str(input))
+            .map(|money| money.to_string())
+            .map_err(|_| {
+                MoneyError::ParseStrError(
+                    format!("failed normalizing {:?} as {} money", input, C::CODE).into(),
+                )
+            })
+    }
+}
+
 impl<C: Currency> Default for Money<C> {
     /// Returns money with zero amount.
+    ///
+    /// Default is the canonical zero at currency's scale, e.g. `0.00` for USD and `0` for JPY,
+    /// so `#[derive(Default)]` and `#[serde(default)]` work on structs containing money.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, iso::{JPY, USD}};
+    ///
+    /// #[derive(Default)]
+    /// struct Account {
+    ///     balance: Money<USD>,
+    ///     points: Money<JPY>,
+    /// }
+    ///
+    /// let account = Account::default();
+    /// assert!(account.balance.is_zero());
+    /// assert_eq!(account.balance.to_string(), "USD 0.00");
+    /// assert_eq!(account.balance.scale(), 2);
+    /// assert_eq!(account.points.scale(), 0);
+    /// ```
     fn default() -> Self {
-        Self {
-            amount: Decimal::default(),
-            _currency: PhantomData,
-        }
+        Self::ZERO
     }
 }
 
-impl<C: Currency> Ord for Money<C>
+impl<C> PartialEq for Money<C>
+where
+    C: Currency,
+{
+    /// Compares amounts by value regardless of scale, e.g. `1.5 == 1.50`.
+    fn eq(&self, other: &Self) -> bool {
+        self.amount == other.amount
+    }
+}
+
+impl<C> Eq for Money<C> where C: Currency {}
+
+/// Total ordering of money by amount.
+///
+/// Currency type doesn't need to implement `PartialEq`/`Ord`, so `sort()`, `BTreeMap`, `min`, and `max`
+/// work for any currency.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, Money, iso::USD, macros::dec};
+/// use std::collections::BTreeMap;
+///
+/// let mut moneys = vec![
+///     Money::<USD>::from_decimal(dec!(3)),
+///     Money::<USD>::from_decimal(dec!(-1)),
+///     Money::<USD>::from_decimal(dec!(2)),
+/// ];
+/// moneys.sort();
+/// assert_eq!(moneys[0].amount(), dec!(-1));
+/// assert_eq!(moneys[2].amount(), dec!(3));
+///
+/// let mut buckets = BTreeMap::new();
+/// buckets.insert(Money::<USD>::from_decimal(dec!(100)), "high");
+/// buckets.insert(Money::<USD>::from_decimal(dec!(10)), "low");
+/// assert_eq!(buckets.first_key_value().unwrap().1, &"low");
+/// ```
+impl<C> Ord for Money<C>
 where
-    C: Currency + PartialEq + Eq,
+    C: Currency,
 {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.amount.cmp(&other.amount)
@@ -75,13 +507,36 @@ where
 
 impl<C> PartialOrd for Money<C>
 where
-    C: Currency + PartialEq + Eq,
+    C: Currency,
 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
+/// Hashes the amount normalized, consistent with `PartialEq`.
+///
+/// Amounts equal in value but different in scale hash equally, e.g. `1.5` and `1.50`.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, Money, iso::USD, macros::dec};
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(Money::<USD>::from_decimal(dec!(1.5)));
+/// assert!(set.contains(&Money::<USD>::from_decimal(dec!(1.50))));
+/// ```
+impl<C> Hash for Money<C>
+where
+    C: Currency,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.amount.normalize().hash(state);
+    }
+}
+
 impl<C> Amount<C> for Money<C>
 where
     C: Currency,
@@ -98,7 +553,10 @@ where
 {
     type Err = MoneyError;
 
-    /// Parse money from string number.
+    /// Parse money from string number, or negative number in parentheses of accounting exports, e.g. `"(12.5)"`.
+    ///
+    /// Strings starting with the currency's symbol after the sign are parsed in symbol format with the currency's
+    /// separators like [`MoneyParser::from_str_symbol`], e.g. `"$1,234.56"`, `"-$1,234.56"` and `"$-1,234.56"`.
     ///
     /// # Examples
     ///
@@ -109,13 +567,27 @@ where
     /// let money = Money::<USD>::from_str("12334.4439").unwrap();
     /// assert_eq!(money, money!(USD, 12334.44));
     /// assert_eq!(money.amount(), dec!(12334.44));
+    ///
+    /// assert_eq!(Money::<USD>::from_str("(12.5)").unwrap(), money!(USD, -12.5));
+    ///
+    /// assert_eq!(Money::<USD>::from_str("$1,234.56").unwrap(), money!(USD, 1234.56));
+    /// assert_eq!(Money::<USD>::from_str("$-1,234.56").unwrap(), money!(USD, -1234.56));
+    /// assert!(Money::<USD>::from_str("€1,234.56").is_err());
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        let dec_num = Decimal::from_str(s).map_err(|err| {
-            MoneyError::ParseStrError(format!("failed parsing money from string: {}", err).into())
-        })?;
-        Ok(Self::from_decimal(dec_num))
+        crate::parse::parse_number_or_symbol::<C>(s).map(Self::from_decimal)
+    }
+}
+
+/// Exact conversion from `f64`, see [`Money::try_from_f64_exact`].
+impl<C> TryFrom<f64> for Money<C>
+where
+    C: Currency,
+{
+    type Error = MoneyError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_from_f64_exact(value)
     }
 }
 
@@ -148,12 +620,31 @@ impl<C: Currency> Clone for Money<C> {
 /// let money = Money::<USD>::new(dec!(-1234.56)).unwrap();
 /// assert_eq!(format!("{}", money), "USD -1,234.56");
 /// ```
+///
+/// Width, fill and alignment pad the displayed money, right-aligned by default like numbers.
+/// Precision displays the amount rounded into that many decimal places with bankers rounding.
+///
+/// ```
+/// use moneylib::money;
+///
+/// let money = money!(USD, 1234.56);
+/// assert_eq!(format!("{:15}", money), "   USD 1,234.56");
+/// assert_eq!(format!("{:*<15}", money), "USD 1,234.56***");
+/// assert_eq!(format!("{:^16.0}", money), "   USD 1,235    ");
+/// assert_eq!(format!("{:.3}", money), "USD 1,234.560");
+/// ```
 impl<C> Display for Money<C>
 where
     C: Currency,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.display())
+        crate::fmt::write_display(
+            f,
+            C::CODE,
+            self.amount,
+            (C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR),
+            || self.display(),
+        )
     }
 }
 
@@ -162,11 +653,17 @@ where
     C: Currency,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Money({}, {})", C::CODE, self.amount)
+        write!(
+            f,
+            "Money({}, {})",
+            C::CODE,
+            crate::fmt::debug_amount(self.amount, C::MINOR_UNIT)
+        )
     }
 }
 
-impl<C: Currency> Sum for Money<C> {
+#[cfg(feature = "panic_ops")]
+impl<C: Currency> ::std::iter::Sum for Money<C> {
     /// Sum all moneys
     ///
     /// WARN: PANIC!!! if overflowed.
@@ -175,7 +672,8 @@ impl<C: Currency> Sum for Money<C> {
     }
 }
 
-impl<'a, C: Currency> Sum<&'a Money<C>> for Money<C> {
+#[cfg(feature = "panic_ops")]
+impl<'a, C: Currency> ::std::iter::Sum<&'a Money<C>> for Money<C> {
     /// Sum all moneys(borrowed)
     ///
     /// WARN: PANIC!!! if overflowed.
@@ -190,8 +688,11 @@ where
 {
     #[inline(always)]
     fn from_decimal(amount: Decimal) -> Self {
+        let rounded = amount.round_dp(C::MINOR_UNIT.into());
+        #[cfg(feature = "shadow")]
+        crate::shadow::observe::<C>(amount, rounded);
         Self {
-            amount: amount.round_dp(C::MINOR_UNIT.into()),
+            amount: rounded,
             _currency: PhantomData,
         }
     }
@@ -203,9 +704,7 @@ where
 
     #[inline(always)]
     fn minor_amount(&self) -> Option<i128> {
-        self.amount()
-            .checked_mul(dec!(10).checked_powu(self.minor_unit().into())?)?
-            .to_i128()
+        crate::decimal::to_scaled_i128(self.amount(), self.minor_unit().into())
     }
 }
 
@@ -2,8 +2,10 @@ use crate::iso::{AUD, BDT, BHD, CHF, EUR, GBP, IDR, INR, JPY, SAR, SGD, USD};
 
 use crate::macros::dec;
 use crate::{
-    BaseMoney, BaseOps, Money, MoneyError, MoneyFormatter, MoneyParser, RoundingStrategy, money,
+    BaseMoney, BaseOps, Decimal, Money, MoneyError, MoneyFormatter, MoneyParser, RoundingStrategy,
+    money,
 };
+use proptest::prelude::*;
 use std::str::FromStr;
 
 // ==================== Money::new() Tests ====================
@@ -36,6 +38,12 @@ fn test_new_with_large_amount() {
 fn test_money_default() {
     let money = Money::<IDR>::default();
     assert!(money.is_zero());
+
+    assert_eq!(Money::<USD>::default().scale(), 2);
+    assert_eq!(Money::<USD>::default().to_string(), "USD 0.00");
+    assert_eq!(Money::<JPY>::default().scale(), 0);
+    assert_eq!(Money::<BHD>::default().amount().to_string(), "0.000");
+    assert_eq!(Money::<USD>::default(), money!(USD, 0));
 }
 
 #[test]
@@ -1695,6 +1703,35 @@ fn test_custom_money_round_with_ceil() {
     assert_eq!(rounded.amount(), dec!(123.44));
 }
 
+#[test]
+fn test_money_round_returning_delta() {
+    // Money is already rounded into its minor unit.
+    let (rounded, delta) = Money::<USD>::new(dec!(123.456))
+        .unwrap()
+        .round_returning_delta();
+    assert_eq!(rounded.amount(), dec!(123.46));
+    assert_eq!(delta, dec!(0));
+
+    let (rounded, delta) =
+        money!(USD, 123.45).round_with_returning_delta(0, RoundingStrategy::HalfUp);
+    assert_eq!(rounded.amount(), dec!(123));
+    assert_eq!(delta, dec!(-0.45));
+
+    // rounded into more decimal places than the minor unit still holds the minor unit.
+    let (rounded, delta) = money!(JPY, 100).round_with_returning_delta(2, RoundingStrategy::Ceil);
+    assert_eq!(rounded.amount(), dec!(100));
+    assert_eq!(delta, dec!(0));
+
+    let total: crate::Decimal = [money!(USD, 0.5), money!(USD, 1.5), money!(USD, -2.25)]
+        .into_iter()
+        .map(|m| {
+            m.round_with_returning_delta(0, RoundingStrategy::BankersRounding)
+                .1
+        })
+        .sum();
+    assert_eq!(total, dec!(0.25));
+}
+
 #[test]
 fn test_custom_money_round_with_floor() {
     let money = Money::<USD>::new(dec!(123.449)).unwrap();
@@ -1743,6 +1780,23 @@ fn test_remainder() {
     assert_eq!(ret.amount(), dec!(1));
 }
 
+#[test]
+fn test_remainder_integer() {
+    let money = money!(USD, 97.50);
+    assert_eq!(money % 25_i32, money!(USD, 22.50));
+    assert_eq!(money % 25_i64, money!(USD, 22.50));
+    assert_eq!(money % 25_i128, money!(USD, 22.50));
+    assert_eq!(money!(USD, -97.50) % 25, money!(USD, -22.50));
+    assert_eq!(money!(USD, 100) % -3, money!(USD, 1));
+    assert_eq!(money!(USD, 100) % 4, money!(USD, 0));
+}
+
+#[test]
+#[should_panic(expected = "remainder operation failed")]
+fn test_remainder_integer_zero_panic() {
+    let _ = money!(USD, 100) % 0;
+}
+
 // ==================== Operator Tests (Money + Decimal) ====================
 
 #[test]
@@ -3137,6 +3191,62 @@ fn test_from_minor_error() {
     assert!(toobig.is_err());
 }
 
+// ==================== from_implied_decimal_str() Tests ====================
+
+#[test]
+fn test_from_implied_decimal_str() {
+    let money = Money::<USD>::from_implied_decimal_str("123456").unwrap();
+    assert_eq!(money.amount(), dec!(1234.56));
+    assert_eq!(money, Money::<USD>::from_minor(123456).unwrap());
+
+    let money = Money::<BHD>::from_implied_decimal_str(" 1000500 ").unwrap();
+    assert_eq!(money.amount(), dec!(1000.500));
+
+    let money = Money::<JPY>::from_implied_decimal_str("0100").unwrap();
+    assert_eq!(money.amount(), dec!(100));
+}
+
+#[test]
+fn test_from_implied_decimal_str_short_and_negative() {
+    assert_eq!(
+        Money::<USD>::from_implied_decimal_str("5")
+            .unwrap()
+            .amount(),
+        dec!(0.05)
+    );
+    assert_eq!(
+        Money::<USD>::from_implied_decimal_str("-12345")
+            .unwrap()
+            .amount(),
+        dec!(-123.45)
+    );
+    assert!(
+        Money::<USD>::from_implied_decimal_str("000")
+            .unwrap()
+            .is_zero()
+    );
+}
+
+#[test]
+fn test_from_implied_decimal_str_invalid() {
+    for input in [
+        "", " ", "-", "+100", "12.34", "1,234", "12 34", "1_000", "--1", "１２",
+    ] {
+        assert!(
+            matches!(
+                Money::<USD>::from_implied_decimal_str(input),
+                Err(MoneyError::ParseStrError(_))
+            ),
+            "{input:?} should be rejected"
+        );
+    }
+
+    assert!(matches!(
+        Money::<USD>::from_implied_decimal_str("9999999999999999999999999999999"),
+        Err(MoneyError::OverflowError)
+    ));
+}
+
 // ==================== format_locale_amount() Tests ====================
 
 #[cfg(feature = "locale")]
@@ -3517,6 +3627,13 @@ fn test_money_remainder() {
     let money = money!(USD, 100);
     let rem = money.checked_rem(i128::MAX);
     assert!(rem.is_none());
+
+    assert_eq!(
+        money!(USD, 97.50).checked_rem(dec!(25)).unwrap(),
+        money!(USD, 22.50)
+    );
+    assert!(money.checked_rem(0).is_none());
+    assert!(money.checked_rem(dec!(0)).is_none());
 }
 
 // ==================== from_code_locale_separator Tests ====================
@@ -3842,3 +3959,1596 @@ fn test_parse_multiple_decimal_separators_via_code() {
     let result = Money::<USD>::from_str_code_with("USD 1.2.3", ",", ".");
     assert!(matches!(result, Err(MoneyError::ParseStrError(_))));
 }
+
+// ==================== Inherent checked arithmetic Tests ====================
+
+#[test]
+fn test_inherent_checked_arithmetic() {
+    let m = money!(USD, 100.50);
+    assert_eq!(m.checked_add(dec!(0.005)).unwrap().amount(), dec!(100.50));
+    assert_eq!(
+        m.checked_add(money!(USD, 1)).unwrap().amount(),
+        dec!(101.50)
+    );
+    assert_eq!(m.checked_sub(200).unwrap().amount(), dec!(-99.50));
+    assert_eq!(m.checked_mul(dec!(0.333)).unwrap().amount(), dec!(33.47));
+    assert_eq!(m.checked_div(3).unwrap().amount(), dec!(33.50));
+    assert_eq!(m.checked_neg().unwrap().amount(), dec!(-100.50));
+    assert_eq!(m.checked_neg().unwrap().checked_abs(), Some(m));
+
+    let max = Money::<USD>::from_decimal(crate::Decimal::MAX);
+    assert!(max.checked_add(1).is_none());
+    assert!(max.checked_neg().unwrap().checked_sub(1).is_none());
+    assert!(max.checked_mul(2).is_none());
+    assert!(m.checked_div(0).is_none());
+    assert_eq!(max.checked_neg().unwrap().checked_abs(), Some(max));
+}
+
+#[test]
+fn test_inherent_checked_arithmetic_matches_base_ops() {
+    let m = money!(JPY, 1_001);
+    assert_eq!(m.checked_add(1), BaseOps::checked_add(&m, 1));
+    assert_eq!(m.checked_sub(1), BaseOps::checked_sub(&m, 1));
+    assert_eq!(
+        m.checked_mul(dec!(1.5)),
+        BaseOps::checked_mul(&m, dec!(1.5))
+    );
+    assert_eq!(m.checked_div(2), BaseOps::checked_div(&m, 2));
+    assert_eq!(m.checked_abs(), Some(BaseOps::abs(&m)));
+    assert_eq!(m.checked_neg(), Some(-m));
+}
+
+// ==================== max_representable()/min_representable() Tests ====================
+
+#[test]
+fn test_max_min_representable() {
+    let max = Money::<USD>::max_representable();
+    assert_eq!(max.amount(), dec!(792281625142643375935439503.35));
+    assert_eq!(max.amount().scale(), 2);
+    assert_eq!(Money::<USD>::min_representable(), -max);
+
+    assert_eq!(
+        Money::<BHD>::max_representable().amount(),
+        dec!(79228162514264337593543950.335)
+    );
+    assert_eq!(
+        Money::<JPY>::min_representable().amount(),
+        crate::Decimal::MIN
+    );
+
+    // full precision survives round-trip through minor amount.
+    let minor = max.minor_amount().unwrap();
+    assert_eq!(Money::<USD>::from_minor(minor).unwrap(), max);
+    assert_eq!(max.checked_sub(dec!(0.01)).unwrap().amount().scale(), 2);
+}
+
+#[test]
+fn test_max_representable_capped_scale() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Fine;
+    impl crate::Currency for Fine {
+        const CODE: &'static str = "FIN";
+        const SYMBOL: &'static str = "F";
+        const NAME: &'static str = "Fine";
+        const NUMERIC: u16 = 999;
+        const MINOR_UNIT: u16 = 40;
+        const MINOR_UNIT_SYMBOL: &'static str = "f";
+        const MINOR_UNIT_NAME: &'static str = "f";
+        const THOUSAND_SEPARATOR: &'static str = ",";
+        const DECIMAL_SEPARATOR: &'static str = ".";
+        const ORIGIN: &'static str = "Fine Land";
+        const LOCALE: &'static str = "en-US";
+    }
+
+    let max = Money::<Fine>::max_representable();
+    assert_eq!(max.amount().scale(), crate::Decimal::MAX_SCALE);
+    assert_eq!(max.amount(), dec!(7.9228162514264337593543950335));
+}
+
+// ==================== Saturating arithmetic Tests ====================
+
+#[test]
+fn test_saturating_arithmetic() {
+    let m = money!(USD, 100.50);
+    assert_eq!(
+        m.saturating_add(dec!(0.255)).unwrap().amount(),
+        dec!(100.76)
+    );
+    assert_eq!(
+        m.saturating_sub(money!(USD, 200)).unwrap().amount(),
+        dec!(-99.50)
+    );
+    assert_eq!(m.saturating_mul(dec!(0.5)).unwrap().amount(), dec!(50.25));
+
+    let max = Money::<USD>::from_decimal(crate::Decimal::MAX);
+    let min = Money::<USD>::from_decimal(crate::Decimal::MIN);
+    assert_eq!(max.saturating_add(m).unwrap(), max);
+    assert_eq!(max.saturating_sub(-1).unwrap(), max);
+    assert_eq!(min.saturating_sub(m).unwrap(), min);
+    assert_eq!(min.saturating_add(-1).unwrap(), min);
+    assert_eq!(max.saturating_mul(2).unwrap(), max);
+    assert_eq!(max.saturating_mul(-2).unwrap(), min);
+    assert_eq!(min.saturating_mul(-2).unwrap(), max);
+
+    assert!(m.saturating_add(f64::NAN).is_none());
+    assert!(m.saturating_sub(f64::INFINITY).is_none());
+    assert!(m.saturating_mul(f64::NAN).is_none());
+}
+
+// ==================== Hash Tests ====================
+
+#[test]
+fn test_hash_consistent_with_eq() {
+    use std::collections::{HashMap, HashSet};
+    use std::hash::{BuildHasher, RandomState};
+
+    let a = Money::<BHD>::from_decimal(dec!(1.5));
+    let b = Money::<BHD>::from_decimal(dec!(1.500));
+    assert_eq!(a, b);
+    assert_ne!(a.scale(), b.scale());
+
+    let state = RandomState::new();
+    assert_eq!(state.hash_one(a), state.hash_one(b));
+    assert_eq!(
+        state.hash_one(money!(USD, -0)),
+        state.hash_one(money!(USD, 0))
+    );
+
+    let set: HashSet<Money<USD>> = [money!(USD, 1), money!(USD, 1.00), money!(USD, 2)].into();
+    assert_eq!(set.len(), 2);
+
+    let mut counts: HashMap<Money<USD>, u32> = HashMap::new();
+    for m in [money!(USD, 5), money!(USD, 5.0), money!(USD, 7)] {
+        *counts.entry(m).or_default() += 1;
+    }
+    assert_eq!(counts[&money!(USD, 5.00)], 2);
+    assert_eq!(counts[&money!(USD, 7)], 1);
+}
+
+// ==================== Total ordering Tests ====================
+
+#[test]
+fn test_total_ordering() {
+    use std::collections::BTreeMap;
+
+    let mut moneys = vec![
+        money!(USD, 10.5),
+        money!(USD, -3),
+        money!(USD, 0),
+        money!(USD, 10.50),
+        money!(USD, 2),
+    ];
+    moneys.sort();
+    assert_eq!(
+        moneys,
+        vec![
+            money!(USD, -3),
+            money!(USD, 0),
+            money!(USD, 2),
+            money!(USD, 10.5),
+            money!(USD, 10.5)
+        ]
+    );
+    assert_eq!(moneys.iter().max(), Some(&money!(USD, 10.5)));
+    assert_eq!(
+        money!(USD, 1).clamp(money!(USD, 2), money!(USD, 3)),
+        money!(USD, 2)
+    );
+
+    let mut map = BTreeMap::new();
+    map.insert(money!(USD, 1.5), "a");
+    map.insert(money!(USD, 1.50), "b");
+    map.insert(money!(USD, -1), "c");
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.values().collect::<Vec<_>>(), vec![&"c", &"b"]);
+}
+
+#[test]
+fn test_total_ordering_custom_currency_without_eq() {
+    #[derive(Clone)]
+    struct NoEq;
+    impl crate::Currency for NoEq {
+        const CODE: &'static str = "NEQ";
+        const SYMBOL: &'static str = "N";
+        const NAME: &'static str = "No Eq";
+        const NUMERIC: u16 = 998;
+        const MINOR_UNIT: u16 = 2;
+        const MINOR_UNIT_SYMBOL: &'static str = "n";
+        const MINOR_UNIT_NAME: &'static str = "no";
+        const THOUSAND_SEPARATOR: &'static str = ",";
+        const DECIMAL_SEPARATOR: &'static str = ".";
+        const ORIGIN: &'static str = "Nowhere";
+        const LOCALE: &'static str = "en-US";
+    }
+
+    let mut moneys = vec![
+        Money::<NoEq>::from_decimal(dec!(2)),
+        Money::<NoEq>::from_decimal(dec!(1)),
+    ];
+    moneys.sort();
+    assert!(moneys[0] < moneys[1]);
+    assert!(moneys[0] == Money::<NoEq>::from_decimal(dec!(1.00)));
+}
+
+// ==================== from_str_format Tests ====================
+
+#[test]
+fn test_from_str_format_amount_code() {
+    let money = Money::<USD>::from_str_format("1,234.56 USD", "a c").unwrap();
+    assert_eq!(money.amount(), dec!(1234.56));
+
+    let money = Money::<EUR>::from_str_format("-1.234,56 EUR", "na c").unwrap();
+    assert_eq!(money.amount(), dec!(-1234.56));
+
+    let money = Money::<USD>::from_str_format("  1234.5 USD ", "a c").unwrap();
+    assert_eq!(money.amount(), dec!(1234.50));
+}
+
+#[test]
+fn test_from_str_format_with_separators() {
+    let money = Money::<USD>::from_str_format_with("USD 1.234,56", "c a", ".", ",").unwrap();
+    assert_eq!(money.amount(), dec!(1234.56));
+
+    let money = Money::<CHF>::from_str_format_with("1 000 000.99 CHF", "a c", " ", ".").unwrap();
+    assert_eq!(money.amount(), dec!(1000000.99));
+}
+
+#[test]
+fn test_from_str_format_signs() {
+    let money = Money::<USD>::from_str_format("+$10.00", "pnsa").unwrap();
+    assert_eq!(money.amount(), dec!(10));
+
+    let money = Money::<USD>::from_str_format("-$10.00", "pnsa").unwrap();
+    assert_eq!(money.amount(), dec!(-10));
+
+    let money = Money::<USD>::from_str_format("$-10.00", "sna").unwrap();
+    assert_eq!(money.amount(), dec!(-10));
+
+    // sign is not in format
+    assert!(Money::<USD>::from_str_format("-$10.00", "sa").is_err());
+}
+
+#[test]
+fn test_from_str_format_minor() {
+    let money = Money::<USD>::from_str_format("123,456 ¢", "a m").unwrap();
+    assert_eq!(money.amount(), dec!(1234.56));
+
+    let money = Money::<JPY>::from_str_format("JPY -1,234 m", r"c na \m").unwrap();
+    assert_eq!(money.amount(), dec!(-1234));
+
+    // minor amount has no decimal part
+    assert!(Money::<USD>::from_str_format("1.5 ¢", "a m").is_err());
+}
+
+#[test]
+fn test_from_str_format_literals() {
+    let money = Money::<USD>::from_str_format("Total: USD 100.00", r"\{Total:} c a").unwrap();
+    assert_eq!(money.amount(), dec!(100));
+
+    let money = Money::<USD>::from_str_format("[100.00] (USD)", "[a] (c)").unwrap();
+    assert_eq!(money.amount(), dec!(100));
+
+    let money = Money::<USD>::from_str_format(r"a 100.00", r"\a a").unwrap();
+    assert_eq!(money.amount(), dec!(100));
+}
+
+#[test]
+fn test_from_str_format_roundtrip() {
+    for format_str in [
+        "na c",
+        "c na",
+        "nsa",
+        "pnsa",
+        "sna m",
+        r"\{amount:} na \{in} c",
+    ] {
+        for amount in [dec!(0), dec!(1234567.89), dec!(-0.01), dec!(42)] {
+            let money = Money::<EUR>::from_decimal(amount);
+            let formatted = money.format(format_str);
+            assert_eq!(
+                Money::<EUR>::from_str_format(&formatted, format_str).unwrap(),
+                money,
+                "{} with format {}",
+                formatted,
+                format_str
+            );
+        }
+    }
+}
+
+#[test]
+fn test_from_str_format_errors() {
+    assert!(matches!(
+        Money::<USD>::from_str_format("1,234.56 EUR", "a c"),
+        Err(MoneyError::CurrencyMismatchError(_, _))
+    ));
+    assert!(matches!(
+        Money::<USD>::from_str_format("€1,234.56", "sa"),
+        Err(MoneyError::CurrencyMismatchError(_, _))
+    ));
+    assert!(matches!(
+        Money::<USD>::from_str_format("1,234.56 USD extra", "a c"),
+        Err(MoneyError::ParseStrError(_))
+    ));
+    assert!(matches!(
+        Money::<USD>::from_str_format("USD", "c"),
+        Err(MoneyError::ParseStrError(_))
+    ));
+    assert!(matches!(
+        Money::<USD>::from_str_format("USD 1,23.45", "c a"),
+        Err(MoneyError::ParseStrError(_))
+    ));
+    assert!(matches!(
+        Money::<USD>::from_str_format("USD", "c a"),
+        Err(MoneyError::ParseStrError(_))
+    ));
+    assert!(Money::<USD>::from_str_format("1.00 - 2.00", "a - a").is_err());
+    assert!(Money::<USD>::from_str_format("1.00 - 1.00", "a - a").is_ok());
+}
+
+#[test]
+fn test_normalize_str() {
+    assert_eq!(
+        Money::<USD>::normalize_str("USD 1,234.56").unwrap(),
+        "USD 1,234.56"
+    );
+    assert_eq!(
+        Money::<USD>::normalize_str("USD 1234.5").unwrap(),
+        "USD 1,234.50"
+    );
+    assert_eq!(
+        Money::<USD>::normalize_str("$1,234.5").unwrap(),
+        "USD 1,234.50"
+    );
+    assert_eq!(Money::<USD>::normalize_str("-$0.005").unwrap(), "USD 0.00");
+    assert_eq!(Money::<USD>::normalize_str("-$0.015").unwrap(), "USD -0.02");
+    assert_eq!(
+        Money::<USD>::normalize_str("\t1234567.891\n").unwrap(),
+        "USD 1,234,567.89"
+    );
+    assert_eq!(Money::<JPY>::normalize_str("¥1,234").unwrap(), "JPY 1,234");
+    assert_eq!(Money::<JPY>::normalize_str("1234.5").unwrap(), "JPY 1,234");
+    assert_eq!(
+        Money::<EUR>::normalize_str("EUR 1.234,56").unwrap(),
+        "EUR 1.234,56"
+    );
+    assert_eq!(Money::<CHF>::normalize_str("1234").unwrap(), "CHF 1'234.00");
+}
+
+#[test]
+fn test_normalize_str_idempotent() {
+    for input in [
+        "$1,234.5",
+        "-1234.567",
+        "USD 0",
+        "$0.1",
+        "USD -9,999,999.999",
+    ] {
+        let once = Money::<USD>::normalize_str(input).unwrap();
+        assert_eq!(Money::<USD>::normalize_str(&once).unwrap(), once);
+    }
+    for input in ["€1.234,5", "-1234.567", "EUR 0"] {
+        let once = Money::<EUR>::normalize_str(input).unwrap();
+        assert_eq!(Money::<EUR>::normalize_str(&once).unwrap(), once);
+    }
+}
+
+#[test]
+fn test_normalize_str_invalid() {
+    for input in [
+        "",
+        "abc",
+        "EUR 1.00",
+        "€1.00",
+        "USD",
+        "$",
+        "1,23,4.5",
+        "USD 1.234,56",
+    ] {
+        let ret = Money::<USD>::normalize_str(input);
+        assert!(
+            matches!(ret, Err(MoneyError::ParseStrError(_))),
+            "{:?}: {:?}",
+            input,
+            ret
+        );
+    }
+    assert_eq!(
+        Money::<USD>::normalize_str(" abc ")
+            .unwrap_err()
+            .to_string(),
+        "[MONEYLIB] parsing error: failed normalizing \"abc\" as USD money"
+    );
+}
+
+// ==================== parse_locale Tests ====================
+
+#[test]
+fn test_parse_locale() {
+    use crate::ParseLocale;
+
+    for (input, locale, expected) in [
+        ("1,234.56", ParseLocale::English, dec!(1234.56)),
+        ("1234.56", ParseLocale::English, dec!(1234.56)),
+        ("1.234.567,8", ParseLocale::German, dec!(1234567.8)),
+        ("1 234,56", ParseLocale::French, dec!(1234.56)),
+        ("1\u{a0}234,56", ParseLocale::French, dec!(1234.56)),
+        (
+            "1\u{202f}234\u{202f}567",
+            ParseLocale::French,
+            dec!(1234567),
+        ),
+        ("1'234.56", ParseLocale::Swiss, dec!(1234.56)),
+        ("1\u{2019}234.56", ParseLocale::Swiss, dec!(1234.56)),
+        ("1,23,456.78", ParseLocale::Indian, dec!(123456.78)),
+        ("-1 234,56", ParseLocale::French, dec!(-1234.56)),
+        ("USD 1 234,56", ParseLocale::French, dec!(1234.56)),
+        ("-USD 1 234,56", ParseLocale::French, dec!(-1234.56)),
+        ("USD -1 234,56", ParseLocale::French, dec!(-1234.56)),
+        ("1 234,56 USD", ParseLocale::French, dec!(1234.56)),
+        ("1 234,56 $", ParseLocale::French, dec!(1234.56)),
+        ("-$1.234,5", ParseLocale::German, dec!(-1234.5)),
+        ("  $0,99  ", ParseLocale::German, dec!(0.99)),
+        ("1,235", ParseLocale::German, dec!(1.24)),
+    ] {
+        let m = Money::<USD>::parse_locale(input, locale)
+            .unwrap_or_else(|err| panic!("{:?} {:?}: {}", input, locale, err));
+        assert_eq!(m.amount(), expected, "{:?} {:?}", input, locale);
+    }
+
+    for (input, locale) in [
+        ("", ParseLocale::English),
+        ("USD", ParseLocale::English),
+        ("1,234.56", ParseLocale::French),
+        ("1 234.56", ParseLocale::French),
+        ("12,34,567.89", ParseLocale::English),
+        ("123,456.78", ParseLocale::Indian),
+        ("1'234,56", ParseLocale::Swiss),
+        ("--1", ParseLocale::English),
+        ("1,234.5.6", ParseLocale::English),
+        ("1,234.", ParseLocale::English),
+    ] {
+        assert!(
+            matches!(
+                Money::<USD>::parse_locale(input, locale),
+                Err(MoneyError::ParseStrError(_))
+            ),
+            "{:?} {:?}",
+            input,
+            locale
+        );
+    }
+
+    for (input, locale, currency) in [
+        ("EUR 1.234,56", ParseLocale::German, "EUR"),
+        ("1.234,56 €", ParseLocale::German, "€"),
+        ("-€1,234.56", ParseLocale::English, "€"),
+        ("1’234.56 CHF", ParseLocale::Swiss, "CHF"),
+    ] {
+        assert!(
+            matches!(
+                Money::<USD>::parse_locale(input, locale),
+                Err(MoneyError::CurrencyMismatchError(got, expected)) if got == currency && expected == "USD"
+            ),
+            "{:?} {:?}",
+            input,
+            locale
+        );
+    }
+
+    let m = Money::<CHF>::parse_locale("CHF 1'234'567.05", ParseLocale::Swiss).unwrap();
+    assert_eq!(m.amount(), dec!(1234567.05));
+    assert_eq!(ParseLocale::French.decimal_separator(), ",");
+    assert_eq!(ParseLocale::Indian.grouping(), crate::Grouping::INDIAN);
+}
+
+// ==================== Parentheses negative Tests ====================
+
+#[test]
+fn test_parse_parentheses_negative() {
+    use crate::ParseLocale;
+
+    let expected = money!(USD, -1234.56);
+    assert_eq!(
+        Money::<USD>::from_str_code("USD (1,234.56)").unwrap(),
+        expected
+    );
+    assert_eq!(
+        Money::<USD>::from_str_code(" (USD 1,234.56) ").unwrap(),
+        expected
+    );
+    assert_eq!(
+        Money::<USD>::from_str_symbol("($1,234.56)").unwrap(),
+        expected
+    );
+    assert_eq!(
+        Money::<USD>::from_str_symbol("$(1,234.56)").unwrap(),
+        expected
+    );
+    assert_eq!(
+        Money::<EUR>::from_str_code_with("EUR (1.234,56)", ".", ",").unwrap(),
+        money!(EUR, -1234.56)
+    );
+    assert_eq!(Money::<USD>::from_str("(1234.56)").unwrap(), expected);
+    assert_eq!(
+        Money::<USD>::normalize_str("($1,234.56)").unwrap(),
+        "USD -1,234.56"
+    );
+    assert_eq!(
+        Money::<USD>::parse_locale("(1,234.56)", ParseLocale::English).unwrap(),
+        expected
+    );
+    assert_eq!(
+        Money::<USD>::parse_locale("(1 234,56) $", ParseLocale::French).unwrap(),
+        expected
+    );
+    assert_eq!(
+        Money::<USD>::from_str_code("USD (0)").unwrap(),
+        money!(USD, 0)
+    );
+
+    // negated more than once, or unbalanced
+    for input in [
+        "USD (-1,234.56)",
+        "(USD -1,234.56)",
+        "(USD (1,234.56))",
+        "USD (1,234.56",
+        "USD 1,234.56)",
+    ] {
+        assert!(
+            matches!(
+                Money::<USD>::from_str_code(input),
+                Err(MoneyError::ParseStrError(_))
+            ),
+            "{}",
+            input
+        );
+    }
+    for input in ["$(-1,234.56)", "-$(1,234.56)", "(-$1,234.56)", "$(1,234.56"] {
+        assert!(Money::<USD>::from_str_symbol(input).is_err(), "{}", input);
+    }
+    for input in ["(-1234.56)", "((1234.56))", "(1234.56", "()"] {
+        assert!(Money::<USD>::from_str(input).is_err(), "{}", input);
+    }
+    for input in [
+        "(-1,234.56)",
+        "-(1,234.56)",
+        "(USD -1,234.56)",
+        "-USD (1,234.56)",
+    ] {
+        assert!(
+            Money::<USD>::parse_locale(input, ParseLocale::English).is_err(),
+            "{}",
+            input
+        );
+    }
+}
+
+// ==================== FromStr symbol format Tests ====================
+
+#[test]
+fn test_from_str_symbol_prefixed() {
+    for (input, expected) in [
+        ("$1,234.56", dec!(1234.56)),
+        ("  $1234.56 ", dec!(1234.56)),
+        ("-$1,234.56", dec!(-1234.56)),
+        ("$-1,234.56", dec!(-1234.56)),
+        ("($1,234.56)", dec!(-1234.56)),
+        ("$0.005", dec!(0.00)),
+    ] {
+        assert_eq!(
+            Money::<USD>::from_str(input).unwrap().amount(),
+            expected,
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        Money::<EUR>::from_str("€-1.234,5").unwrap(),
+        money!(EUR, -1234.5)
+    );
+    // plain numbers keep parsing without separators
+    assert_eq!(
+        Money::<EUR>::from_str("-1234.5").unwrap(),
+        money!(EUR, -1234.5)
+    );
+
+    for input in [
+        "€1,234.56",
+        "$",
+        "-$-1",
+        "$1.234,56",
+        "$$1",
+        "1,234.56",
+        "$ 1",
+    ] {
+        assert!(Money::<USD>::from_str(input).is_err(), "{}", input);
+    }
+
+    assert_eq!(
+        Money::<USD>::from_str_symbol("$-1,234.56").unwrap(),
+        money!(USD, -1234.56)
+    );
+    assert!(Money::<USD>::from_str_symbol("-$-1,234.56").is_err());
+}
+
+// ==================== parse_with Tests ====================
+
+#[test]
+fn test_parse_with_default_options() {
+    let options = crate::ParseOptions::default();
+    for (input, expected) in [
+        ("USD 1,234.56", dec!(1234.56)),
+        ("USD -1,234.56", dec!(-1234.56)),
+        ("(USD 1,234.56)", dec!(-1234.56)),
+        ("USD (1,234.56)", dec!(-1234.56)),
+        ("$1,234.56", dec!(1234.56)),
+        ("-$1,234.56", dec!(-1234.56)),
+        ("($1,234.56)", dec!(-1234.56)),
+        ("1,234.56", dec!(1234.56)),
+        (" -1234.56 ", dec!(-1234.56)),
+        ("(0.5)", dec!(-0.5)),
+        ("1.005", dec!(1.00)),
+    ] {
+        assert_eq!(
+            Money::<USD>::parse_with(input, &options).unwrap().amount(),
+            expected,
+            "{}",
+            input
+        );
+    }
+
+    assert!(matches!(
+        Money::<USD>::parse_with("EUR 1", &options),
+        Err(MoneyError::CurrencyMismatchError(_, _))
+    ));
+    for input in [
+        "",
+        "-",
+        "()",
+        "USD",
+        "(USD -1)",
+        "-(1)",
+        "€1",
+        "1,23",
+        "1.2.3",
+        "$-$1",
+        "USD 1 USD",
+    ] {
+        assert!(
+            matches!(
+                Money::<USD>::parse_with(input, &options),
+                Err(MoneyError::ParseStrError(_))
+            ),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_with_options() {
+    let options = crate::ParseOptions::new()
+        .thousand_separator(" ")
+        .decimal_separator(",");
+    assert_eq!(
+        Money::<EUR>::parse_with("(EUR 1 234 567,8)", &options).unwrap(),
+        money!(EUR, -1234567.8)
+    );
+    assert_eq!(
+        Money::<EUR>::parse_with("€-1 234,5", &options).unwrap(),
+        money!(EUR, -1234.5)
+    );
+    assert!(Money::<EUR>::parse_with("EUR 1.234,5", &options).is_err());
+
+    let options = crate::ParseOptions::new().require_code(true);
+    assert!(Money::<USD>::parse_with("USD 1", &options).is_ok());
+    assert!(Money::<USD>::parse_with("$1", &options).is_err());
+    assert!(Money::<USD>::parse_with("1", &options).is_err());
+
+    let options = crate::ParseOptions::new().allow_symbol(false);
+    assert!(Money::<USD>::parse_with("1", &options).is_ok());
+    assert!(Money::<USD>::parse_with("-$1", &options).is_err());
+
+    let options = crate::ParseOptions::new().allow_parentheses(false);
+    assert!(Money::<USD>::parse_with("-1", &options).is_ok());
+    for input in ["(1)", "USD (1)", "(USD 1)", "($1)"] {
+        assert!(
+            Money::<USD>::parse_with(input, &options).is_err(),
+            "{}",
+            input
+        );
+    }
+
+    let options = crate::ParseOptions::new()
+        .grouping(crate::Grouping::INDIAN)
+        .strict(true);
+    assert_eq!(
+        Money::<INR>::parse_with("₹12,34,567.8", &options).unwrap(),
+        money!(INR, 1234567.8)
+    );
+    assert!(Money::<INR>::parse_with("₹1,234,567", &options).is_err());
+    assert!(Money::<INR>::parse_with("INR 1.250", &options).is_ok());
+    assert!(matches!(
+        Money::<INR>::parse_with("INR 1.255", &options),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+    assert!(matches!(
+        Money::<JPY>::parse_with("¥1.5", &crate::ParseOptions::new().strict(true)),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+}
+
+#[test]
+fn test_parse_with_exponent() {
+    let options = crate::ParseOptions::new().allow_exponent(true);
+    for (input, expected) in [
+        ("1.2e3", dec!(1200)),
+        ("1E-2", dec!(0.01)),
+        ("-1.5e+1", dec!(-15)),
+        ("USD 1,234.5e2", dec!(123450)),
+        ("(USD 1.2e3)", dec!(-1200)),
+        ("$1.2e0", dec!(1.2)),
+        ("($1.25e-1)", dec!(-0.12)),
+        ("1e0", dec!(1)),
+        ("12345e-4", dec!(1.23)),
+    ] {
+        assert_eq!(
+            Money::<USD>::parse_with(input, &options).unwrap().amount(),
+            expected,
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        Money::<EUR>::parse_with("EUR 1e3", &options)
+            .unwrap()
+            .amount(),
+        dec!(1000)
+    );
+
+    for input in ["1e", "e3", "1e3.5", "1e1e1", "1e99", "1e-29", "USD e3"] {
+        assert!(
+            matches!(
+                Money::<USD>::parse_with(input, &options),
+                Err(MoneyError::ParseStrError(_))
+            ),
+            "{}",
+            input
+        );
+    }
+    assert!(matches!(
+        Money::<USD>::parse_with("1.5e-3", &options.clone().strict(true)),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+
+    // rejected by default and by every other parser
+    let Err(MoneyError::ParseStrError(err)) =
+        Money::<USD>::parse_with("1.2e3", &crate::ParseOptions::new())
+    else {
+        panic!("scientific notation must be rejected by default");
+    };
+    assert!(err.to_string().contains("scientific notation"));
+    assert!(Money::<USD>::from_str("1.2e3").is_err());
+    assert!(Money::<USD>::from_str_code("USD 1.2e3").is_err());
+    assert!(Money::<USD>::from_str_symbol("$1.2e3").is_err());
+}
+
+// ==================== from_ascii Tests ====================
+
+#[test]
+fn test_from_ascii() {
+    for (input, expected) in [
+        (&b"1234.56"[..], dec!(1234.56)),
+        (b"-1234.56", dec!(-1234.56)),
+        (b"+7", dec!(7)),
+        (b"(1234.5)", dec!(-1234.5)),
+        (b" \t0.005\r\n", dec!(0.00)),
+        (b"USD 1234.56", dec!(1234.56)),
+        (b"USD\t-1", dec!(-1)),
+        (b"(USD 1)", dec!(-1)),
+        (b"USD (1)", dec!(-1)),
+        (b"000120", dec!(120)),
+    ] {
+        assert_eq!(
+            Money::<USD>::from_ascii(input).unwrap().amount(),
+            expected,
+            "{}",
+            String::from_utf8_lossy(input)
+        );
+    }
+    assert_eq!(
+        Money::<JPY>::from_ascii(b"79228162514264337593543950335")
+            .unwrap()
+            .amount(),
+        crate::Decimal::MAX
+    );
+
+    for input in [
+        &b""[..],
+        b"-",
+        b"()",
+        b".5",
+        b"1.",
+        b"1.2.3",
+        b"1,234.56",
+        b"1 234",
+        b"(-1)",
+        b"-(1)",
+        b"--1",
+        b"USD",
+        b"USD1",
+        b"$1",
+        b"1e3",
+        b"\xff1",
+        b"1234567890123456789012345678901234567890",
+        b"0.00000000000000000000000000001",
+        b"79228162514264337593543950336",
+    ] {
+        assert!(
+            matches!(
+                Money::<USD>::from_ascii(input),
+                Err(MoneyError::ParseStrError(_))
+            ),
+            "{}",
+            String::from_utf8_lossy(input)
+        );
+    }
+
+    assert!(matches!(
+        Money::<USD>::from_ascii(b"EUR 1"),
+        Err(MoneyError::CurrencyMismatchError(got, expected)) if got == "EUR" && expected == "USD"
+    ));
+    assert!(matches!(
+        Money::<USD>::from_ascii(b"(EUR 1)"),
+        Err(MoneyError::CurrencyMismatchError(got, expected)) if got == "EUR" && expected == "USD"
+    ));
+}
+
+// ==================== Input length guard Tests ====================
+
+#[test]
+fn test_parsers_reject_too_long_input() {
+    let too_many_digits = format!("USD {}", "1".repeat(65));
+    let too_long = format!("USD 1{}", " ".repeat(300));
+    let huge = "9".repeat(1 << 20);
+
+    for input in [too_many_digits.as_str(), too_long.as_str(), huge.as_str()] {
+        let results = [
+            Money::<USD>::from_str(input),
+            Money::<USD>::from_str_code(input),
+            Money::<USD>::from_str_symbol(input),
+            Money::<USD>::from_str_code_with_grouping(input, ",", ".", crate::Grouping::INDIAN),
+            Money::<USD>::from_str_format(input, "c na"),
+            Money::<USD>::from_str_format_with_digits(input, "c na", crate::DigitSet::ArabicIndic),
+            Money::<USD>::parse_locale(input, crate::ParseLocale::French),
+            Money::<USD>::parse_with(input, &crate::ParseOptions::new()),
+            Money::<USD>::from_ascii(input.as_bytes()),
+            Money::<USD>::from_implied_decimal_str(input),
+        ];
+        for result in results {
+            assert!(
+                matches!(result, Err(MoneyError::InputTooLong(_))),
+                "{:?}",
+                result.map_err(|err| err.to_string())
+            );
+        }
+    }
+
+    // limits themselves are accepted
+    let max_digits = format!("{}.{}", "0".repeat(34), "1".repeat(30));
+    assert!(Money::<USD>::from_str(&max_digits).is_ok());
+    let max_len = format!("{}1", " ".repeat(255));
+    assert_eq!(max_len.len(), 256);
+    assert!(Money::<USD>::from_str(&max_len).is_ok());
+}
+
+// ==================== Cash rounding Tests ====================
+
+#[test]
+fn test_round_cash() {
+    for (amount, strategy, expected) in [
+        (dec!(12.32), RoundingStrategy::HalfUp, dec!(12.30)),
+        (dec!(12.33), RoundingStrategy::HalfUp, dec!(12.35)),
+        (dec!(12.31), RoundingStrategy::Ceil, dec!(12.35)),
+        (dec!(12.34), RoundingStrategy::Floor, dec!(12.30)),
+        (dec!(-12.33), RoundingStrategy::HalfUp, dec!(-12.35)),
+        (dec!(-12.31), RoundingStrategy::Ceil, dec!(-12.35)),
+        (dec!(12.35), RoundingStrategy::Floor, dec!(12.35)),
+        (dec!(0), RoundingStrategy::Ceil, dec!(0)),
+    ] {
+        let money = Money::<CHF>::from_decimal(amount);
+        assert_eq!(
+            money.round_cash(dec!(0.05), strategy).unwrap().amount(),
+            expected,
+            "{} {:?}",
+            amount,
+            strategy
+        );
+    }
+
+    assert_eq!(
+        money!(USD, 1234.56)
+            .round_cash(dec!(100), RoundingStrategy::HalfUp)
+            .unwrap(),
+        money!(USD, 1200)
+    );
+    assert!(
+        money!(USD, 1)
+            .round_cash(dec!(0), RoundingStrategy::HalfUp)
+            .is_none()
+    );
+    assert!(
+        money!(USD, 1)
+            .round_cash(dec!(-0.05), RoundingStrategy::HalfUp)
+            .is_none()
+    );
+    assert!(
+        Money::<JPY>::from_decimal(crate::Decimal::MAX)
+            .round_cash(dec!(0.05), RoundingStrategy::HalfUp)
+            .is_none()
+    );
+
+    assert_eq!(
+        money!(CAD, 1.02)
+            .round_cash_default(RoundingStrategy::HalfUp)
+            .unwrap(),
+        money!(CAD, 1.00)
+    );
+    assert_eq!(
+        money!(DKK, 10.74)
+            .round_cash_default(RoundingStrategy::HalfUp)
+            .unwrap(),
+        money!(DKK, 10.50)
+    );
+    assert_eq!(
+        money!(HUF, 1242.5)
+            .round_cash_default(RoundingStrategy::HalfUp)
+            .unwrap(),
+        money!(HUF, 1245)
+    );
+    assert_eq!(
+        money!(SEK, 10.49)
+            .round_cash_default(RoundingStrategy::HalfUp)
+            .unwrap(),
+        money!(SEK, 10)
+    );
+    assert_eq!(
+        money!(USD, 10.49)
+            .round_cash_default(RoundingStrategy::HalfUp)
+            .unwrap(),
+        money!(USD, 10.49)
+    );
+    assert_eq!(
+        money!(JPY, 1049)
+            .round_cash_default(RoundingStrategy::HalfUp)
+            .unwrap(),
+        money!(JPY, 1049)
+    );
+}
+
+// ==================== Directional rounding Tests ====================
+
+#[test]
+fn test_round_with_directional_strategies() {
+    for (amount, up, down, ceil, floor) in [
+        (dec!(2.1), dec!(3), dec!(2), dec!(3), dec!(2)),
+        (dec!(2.9), dec!(3), dec!(2), dec!(3), dec!(2)),
+        (dec!(-2.1), dec!(-2), dec!(-3), dec!(-3), dec!(-2)),
+        (dec!(-2.9), dec!(-2), dec!(-3), dec!(-3), dec!(-2)),
+        (dec!(2), dec!(2), dec!(2), dec!(2), dec!(2)),
+        (dec!(0), dec!(0), dec!(0), dec!(0), dec!(0)),
+    ] {
+        let money = Money::<USD>::from_decimal(amount);
+        assert_eq!(money.round_with(0, RoundingStrategy::Up).amount(), up);
+        assert_eq!(money.round_with(0, RoundingStrategy::Down).amount(), down);
+        assert_eq!(money.round_with(0, RoundingStrategy::Ceil).amount(), ceil);
+        assert_eq!(money.round_with(0, RoundingStrategy::Floor).amount(), floor);
+    }
+
+    assert_eq!(
+        crate::decimal::rescale_with(dec!(-1.001), 2, RoundingStrategy::Up),
+        Some(dec!(-1.00))
+    );
+    assert_eq!(
+        crate::decimal::rescale_with(dec!(-1.001), 2, RoundingStrategy::Down),
+        Some(dec!(-1.01))
+    );
+}
+
+// ==================== round_to_nearest Tests ====================
+
+#[test]
+fn test_round_to_nearest() {
+    for (amount, step, strategy, expected) in [
+        (dec!(1.12), dec!(0.25), RoundingStrategy::HalfUp, dec!(1.00)),
+        (dec!(1.13), dec!(0.25), RoundingStrategy::HalfUp, dec!(1.25)),
+        (dec!(1.13), dec!(0.25), RoundingStrategy::Floor, dec!(1.00)),
+        (dec!(-1.13), dec!(0.25), RoundingStrategy::Down, dec!(-1.25)),
+        (dec!(-1.13), dec!(0.25), RoundingStrategy::Up, dec!(-1.00)),
+        (
+            dec!(1499.99),
+            dec!(1000),
+            RoundingStrategy::HalfUp,
+            dec!(1000),
+        ),
+        (
+            dec!(1500),
+            dec!(1000),
+            RoundingStrategy::BankersRounding,
+            dec!(2000),
+        ),
+        (
+            dec!(2500),
+            dec!(1000),
+            RoundingStrategy::BankersRounding,
+            dec!(2000),
+        ),
+        (dec!(0.07), dec!(0.10), RoundingStrategy::HalfUp, dec!(0.10)),
+        (dec!(0.33), dec!(0.3), RoundingStrategy::HalfUp, dec!(0.30)),
+    ] {
+        assert_eq!(
+            Money::<USD>::from_decimal(amount)
+                .round_to_nearest(step, strategy)
+                .unwrap()
+                .amount(),
+            expected,
+            "{} to {} with {:?}",
+            amount,
+            step,
+            strategy
+        );
+    }
+
+    assert!(
+        money!(USD, 1)
+            .round_to_nearest(dec!(0), RoundingStrategy::HalfUp)
+            .is_none()
+    );
+    assert!(
+        Money::<JPY>::from_decimal(crate::Decimal::MAX)
+            .round_to_nearest(dec!(0.25), RoundingStrategy::HalfUp)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_round_to_nearest_with_offset() {
+    for (amount, expected) in [
+        (dec!(12.40), dec!(11.99)),
+        (dec!(12.50), dec!(12.99)),
+        (dec!(12.99), dec!(12.99)),
+        (dec!(0.10), dec!(-0.01)),
+        (dec!(-3.40), dec!(-3.01)),
+    ] {
+        assert_eq!(
+            Money::<USD>::from_decimal(amount)
+                .round_to_nearest_with_offset(dec!(1), dec!(0.99), RoundingStrategy::HalfUp)
+                .unwrap()
+                .amount(),
+            expected,
+            "{}",
+            amount
+        );
+    }
+
+    // offsets beyond step shift by whole steps
+    assert_eq!(
+        money!(USD, 12.40)
+            .round_to_nearest_with_offset(dec!(1), dec!(5.99), RoundingStrategy::HalfUp)
+            .unwrap(),
+        money!(USD, 11.99)
+    );
+    assert!(
+        money!(USD, 12.40)
+            .round_to_nearest_with_offset(dec!(0), dec!(0.99), RoundingStrategy::HalfUp)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_normalize() {
+    let money = Money::<USD>::from_decimal(dec!(100.50));
+    assert_eq!(money.scale(), 2);
+    assert_eq!(money.mantissa(), 10_050);
+
+    let normalized = money.normalize();
+    assert_eq!(normalized, money);
+    assert_eq!(normalized.scale(), 1);
+    assert_eq!(normalized.mantissa(), 1_005);
+    assert_eq!(normalized.format_code(), "USD 100.50");
+
+    assert_eq!(
+        Money::<JPY>::from_decimal(dec!(-0)).normalize(),
+        Money::<JPY>::default()
+    );
+    assert_eq!(Money::<USD>::default().normalize().scale(), 0);
+}
+
+#[test]
+fn test_associated_constants() {
+    const ZERO: Money<USD> = Money::ZERO;
+    static LIMIT: Money<USD> = Money::MAX;
+
+    assert!(ZERO.is_zero());
+    assert_eq!(ZERO, Money::<USD>::default());
+    assert_eq!(ZERO.scale(), 2);
+    assert_eq!(ZERO.to_string(), "USD 0.00");
+    assert_eq!(Money::<JPY>::ZERO.scale(), 0);
+    assert_eq!(Money::<BHD>::ZERO.scale(), 3);
+    assert!(!Money::<USD>::ZERO.amount().is_sign_negative());
+
+    assert_eq!(LIMIT.amount(), crate::Decimal::MAX);
+    assert_eq!(Money::<USD>::MIN.amount(), crate::Decimal::MIN);
+    assert!(Money::<USD>::MAX > Money::<USD>::max_representable());
+    assert!(Money::<USD>::MIN < Money::<USD>::min_representable());
+    assert_eq!(Money::<USD>::MAX.checked_add(dec!(1)), None);
+    assert_eq!(Money::<USD>::MIN.checked_sub(dec!(1)), None);
+}
+
+#[test]
+fn test_try_from_mantissa() {
+    const FEE: Option<Money<USD>> = Money::try_from_mantissa(1250, 2);
+    const LIMIT: Option<Money<JPY>> = Money::try_from_mantissa(-1, 0);
+
+    assert_eq!(FEE.unwrap().amount(), dec!(12.50));
+    assert_eq!(LIMIT.unwrap().amount(), dec!(-1));
+
+    // trailing zeros beyond minor unit are dropped
+    let money = Money::<USD>::try_from_mantissa(-15_000, 3).unwrap();
+    assert_eq!(money.amount(), dec!(-15));
+    assert_eq!(money.scale(), 2);
+    assert_eq!(
+        Money::<BHD>::try_from_mantissa(1, 3).unwrap().amount(),
+        dec!(0.001)
+    );
+    assert_eq!(
+        Money::<USD>::try_from_mantissa(5, 0).unwrap().amount(),
+        dec!(5)
+    );
+    assert_eq!(
+        Money::<USD>::try_from_mantissa(79_228_162_514_264_337_593_543_950_335, 2),
+        Some(Money::<USD>::max_representable())
+    );
+    assert_eq!(
+        Money::<USD>::try_from_mantissa(-79_228_162_514_264_337_593_543_950_335, 0),
+        Some(Money::<USD>::MIN)
+    );
+    // scale above Decimal's maximum of 28 is fine with enough trailing zeros.
+    assert_eq!(
+        Money::<USD>::try_from_mantissa(10_i128.pow(30), 30),
+        Some(money!(USD, 1))
+    );
+}
+
+#[test]
+fn test_try_from_mantissa_invalid() {
+    // beyond minor unit
+    assert_eq!(Money::<USD>::try_from_mantissa(1005, 3), None);
+    assert_eq!(Money::<JPY>::try_from_mantissa(1, 1), None);
+    // out of range of Decimal
+    assert_eq!(Money::<USD>::try_from_mantissa(i128::MAX, 0), None);
+    assert_eq!(
+        Money::<USD>::try_from_mantissa(-79_228_162_514_264_337_593_543_950_336, 2),
+        None
+    );
+}
+
+#[test]
+fn test_const_money() {
+    const MIN_BALANCE: Money<USD> = crate::macros::const_money!(USD, 10);
+    static FEES: [Money<EUR>; 2] = [
+        crate::macros::const_money!(EUR, 0.30),
+        crate::macros::const_money!(crate::iso::EUR, -1_000.500),
+    ];
+
+    assert_eq!(MIN_BALANCE, money!(USD, 10));
+    assert_eq!(FEES[0], money!(EUR, 0.30));
+    assert_eq!(FEES[1], money!(EUR, -1_000.50));
+    assert_eq!(crate::macros::const_money!(JPY, 1e3), money!(JPY, 1_000));
+}
+
+#[test]
+fn test_from_major_minor() {
+    assert_eq!(
+        Money::<USD>::from_major_minor(12, 34).unwrap().amount(),
+        dec!(12.34)
+    );
+    assert_eq!(
+        Money::<USD>::from_major_minor(12, 5).unwrap().amount(),
+        dec!(12.05)
+    );
+    assert_eq!(
+        Money::<USD>::from_major_minor(0, 99).unwrap().amount(),
+        dec!(0.99)
+    );
+    assert_eq!(
+        Money::<USD>::from_major_minor(-12, 34).unwrap().amount(),
+        dec!(-12.34)
+    );
+    assert_eq!(
+        Money::<USD>::from_major_minor(-12, 0).unwrap().amount(),
+        dec!(-12)
+    );
+    assert_eq!(
+        Money::<BHD>::from_major_minor(1, 5).unwrap().amount(),
+        dec!(1.005)
+    );
+    assert_eq!(
+        Money::<JPY>::from_major_minor(1_000, 0).unwrap().amount(),
+        dec!(1000)
+    );
+
+    assert!(matches!(
+        Money::<USD>::from_major_minor(12, 100),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+    assert!(matches!(
+        Money::<BHD>::from_major_minor(12, 1_000),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+    match Money::<JPY>::from_major_minor(1_000, 5) {
+        Err(MoneyError::MinorUnitError(msg)) => assert_eq!(
+            msg.to_string(),
+            "minor 5 must be less than 1 for JPY's minor unit of 0"
+        ),
+        other => panic!("unexpected {other:?}"),
+    }
+    assert!(matches!(
+        Money::<USD>::from_major_minor(i128::MAX, 0),
+        Err(MoneyError::OverflowError)
+    ));
+    assert!(matches!(
+        Money::<USD>::from_major_minor(i128::MAX / 100, 99),
+        Err(MoneyError::OverflowError)
+    ));
+}
+
+#[test]
+fn test_into_parts_trunc_fract() {
+    let money = money!(USD, 12.34);
+    assert_eq!(money.into_parts(), Some((12, 34)));
+    assert_eq!(money.trunc(), money!(USD, 12));
+    assert_eq!(money.fract(), money!(USD, 0.34));
+    assert_eq!(money.trunc() + money.fract(), money);
+
+    let money = money!(USD, -12.05);
+    assert_eq!(money.into_parts(), Some((-12, 5)));
+    assert_eq!(money.trunc(), money!(USD, -12));
+    assert_eq!(money.fract(), money!(USD, -0.05));
+
+    assert_eq!(money!(USD, -0.34).into_parts(), Some((0, 34)));
+    assert_eq!(money!(USD, 0).into_parts(), Some((0, 0)));
+    assert_eq!(money!(JPY, -1_234).into_parts(), Some((-1_234, 0)));
+    assert_eq!(money!(JPY, -1_234).fract(), money!(JPY, 0));
+    assert_eq!(money!(BHD, 1.005).into_parts(), Some((1, 5)));
+    assert_eq!(
+        Money::<USD>::MAX.into_parts(),
+        Some((79_228_162_514_264_337_593_543_950_335, 0))
+    );
+    assert_eq!(
+        Money::<USD>::max_representable().into_parts(),
+        Some((792_281_625_142_643_375_935_439_503, 35))
+    );
+
+    for (major, minor) in [(12, 34), (-7, 1), (0, 99), (1_000_000, 0)] {
+        let money = Money::<USD>::from_major_minor(major, minor).unwrap();
+        assert_eq!(money.into_parts(), Some((major, minor)));
+    }
+}
+
+#[test]
+fn test_checked_abs_diff() {
+    let a = money!(USD, 100);
+    let b = money!(USD, 120.50);
+    assert_eq!(a.checked_abs_diff(b), Some(money!(USD, 20.50)));
+    assert_eq!(b.checked_abs_diff(a), Some(money!(USD, 20.50)));
+    assert_eq!(a.checked_abs_diff(a), Some(Money::<USD>::ZERO));
+    assert_eq!(
+        money!(USD, -10).checked_abs_diff(money!(USD, 10)),
+        Some(money!(USD, 20))
+    );
+    assert_eq!(
+        money!(USD, -10).checked_abs_diff(money!(USD, -30.01)),
+        Some(money!(USD, 20.01))
+    );
+    assert_eq!(
+        money!(JPY, 1).checked_abs_diff(money!(JPY, 1_000)),
+        Some(money!(JPY, 999))
+    );
+
+    assert_eq!(Money::<USD>::MAX.checked_abs_diff(Money::MIN), None);
+    assert_eq!(Money::<USD>::MIN.checked_abs_diff(Money::MAX), None);
+    assert_eq!(Money::<USD>::MIN.checked_abs_diff(money!(USD, 1)), None);
+    assert_eq!(
+        Money::<USD>::MAX.checked_abs_diff(Money::MAX),
+        Some(Money::<USD>::ZERO)
+    );
+}
+
+#[test]
+fn test_ratio_to() {
+    assert_eq!(
+        money!(USD, 25).ratio_to(money!(USD, 100)).unwrap(),
+        dec!(0.25)
+    );
+    // not rounded into cents
+    assert_eq!(
+        money!(USD, 1).ratio_to(money!(USD, 3)).unwrap(),
+        dec!(1) / dec!(3)
+    );
+    assert_eq!(
+        money!(USD, 0.01).ratio_to(money!(USD, 1_000)).unwrap(),
+        dec!(0.00001)
+    );
+    assert_eq!(
+        money!(USD, 150).ratio_to(money!(USD, -100)).unwrap(),
+        dec!(-1.5)
+    );
+    assert_eq!(money!(USD, 0).ratio_to(money!(USD, 5)).unwrap(), dec!(0));
+
+    // weights summing to one
+    let parts = [money!(USD, 20), money!(USD, 30), money!(USD, 50)];
+    let total = money!(USD, 100);
+    let weights: Vec<_> = parts.iter().map(|p| p.ratio_to(total).unwrap()).collect();
+    assert_eq!(weights, [dec!(0.2), dec!(0.3), dec!(0.5)]);
+
+    assert!(matches!(
+        money!(USD, 1).ratio_to(money!(USD, 0)),
+        Err(MoneyError::DivisionByZeroError)
+    ));
+    assert!(matches!(
+        Money::<USD>::MAX.ratio_to(money!(USD, 0.01)),
+        Err(MoneyError::OverflowError)
+    ));
+}
+
+#[test]
+fn test_mul_add() {
+    let price = money!(BHD, 0.125);
+    assert_eq!(price.mul_add(dec!(0.1), price).unwrap(), money!(BHD, 0.138));
+    assert_eq!(
+        price
+            .checked_mul(dec!(0.1))
+            .unwrap()
+            .checked_add(price)
+            .unwrap(),
+        money!(BHD, 0.137)
+    );
+
+    assert_eq!(
+        money!(USD, 0.13)
+            .mul_add(dec!(0.5), money!(USD, 0.13))
+            .unwrap(),
+        money!(USD, 0.20)
+    );
+    assert_eq!(
+        money!(USD, 100)
+            .mul_add(dec!(-1), money!(USD, 100))
+            .unwrap(),
+        money!(USD, 0)
+    );
+    assert_eq!(
+        money!(USD, 100).mul_add(dec!(0), money!(USD, -5)).unwrap(),
+        money!(USD, -5)
+    );
+
+    assert!(matches!(
+        Money::<USD>::MAX.mul_add(dec!(2), money!(USD, 0)),
+        Err(MoneyError::OverflowError)
+    ));
+    assert!(matches!(
+        Money::<USD>::MAX.mul_add(dec!(1), money!(USD, 1)),
+        Err(MoneyError::OverflowError)
+    ));
+}
+
+#[test]
+fn test_div_rem() {
+    assert_eq!(
+        money!(USD, 100).div_rem(3).unwrap(),
+        (money!(USD, 33.33), money!(USD, 0.01))
+    );
+    assert_eq!(
+        money!(USD, 100).div_rem(4).unwrap(),
+        (money!(USD, 25), money!(USD, 0))
+    );
+    assert_eq!(
+        money!(USD, 0.02).div_rem(3).unwrap(),
+        (money!(USD, 0), money!(USD, 0.02))
+    );
+    assert_eq!(
+        money!(USD, -100).div_rem(3).unwrap(),
+        (money!(USD, -33.33), money!(USD, -0.01))
+    );
+    assert_eq!(
+        money!(USD, 100).div_rem(-3).unwrap(),
+        (money!(USD, -33.33), money!(USD, 0.01))
+    );
+    assert_eq!(
+        money!(JPY, 1000).div_rem(7).unwrap(),
+        (money!(JPY, 142), money!(JPY, 6))
+    );
+    assert_eq!(
+        money!(BHD, 10).div_rem(3).unwrap(),
+        (money!(BHD, 3.333), money!(BHD, 0.001))
+    );
+
+    let money = Money::<USD>::MAX;
+    let (quotient, remainder) = money.div_rem(7).unwrap();
+    assert_eq!(
+        quotient.amount() * dec!(7) + remainder.amount(),
+        money.amount()
+    );
+    assert!(remainder.amount() < dec!(0.07));
+    let (quotient, remainder) = money.div_rem(i64::MIN).unwrap();
+    assert_eq!(
+        quotient.amount() * Decimal::from(i64::MIN) + remainder.amount(),
+        money.amount()
+    );
+
+    assert!(matches!(
+        money!(USD, 100).div_rem(0),
+        Err(MoneyError::DivisionByZeroError)
+    ));
+}
+
+proptest! {
+    #[test]
+    fn prop_div_rem_is_exact(minor in -1_000_000_000_i64..1_000_000_000, divisor in 1_i64..10_000) {
+        let money = Money::<USD>::from_minor(minor.into()).unwrap();
+        let (quotient, remainder) = money.div_rem(divisor).unwrap();
+        prop_assert_eq!(quotient.amount() * Decimal::from(divisor) + remainder.amount(), money.amount());
+        prop_assert!(remainder.amount().abs() < Decimal::from(divisor) * dec!(0.01));
+        prop_assert!(remainder.is_zero() || remainder.is_negative() == money.is_negative());
+    }
+}
+
+#[test]
+fn test_checked_clamp() {
+    let money = money!(USD, 1_250);
+
+    // money bounds
+    assert_eq!(
+        money.checked_clamp(money!(USD, 500), money!(USD, 1_000)),
+        Some(money!(USD, 1_000))
+    );
+    assert_eq!(
+        money.checked_clamp(money!(USD, 1_500), money!(USD, 2_000)),
+        Some(money!(USD, 1_500))
+    );
+    assert_eq!(
+        money.checked_clamp(money!(USD, 1_250), money!(USD, 1_250)),
+        Some(money)
+    );
+
+    // amount bounds
+    assert_eq!(money.checked_clamp(dec!(500), dec!(2_000)), Some(money));
+    assert_eq!(money.checked_clamp(500, 1_000), Some(money!(USD, 1_000)));
+    assert_eq!(
+        money.checked_clamp(500_i64, 1_000),
+        Some(money!(USD, 1_000))
+    );
+    assert_eq!(
+        money.checked_clamp(1_500.5_f64, 2_000.0),
+        Some(money!(USD, 1_500.50))
+    );
+
+    // bound rounded into minor unit like from_decimal
+    assert_eq!(
+        money.checked_clamp(dec!(1_300.125), dec!(2_000)),
+        Some(money!(USD, 1_300.12))
+    );
+
+    assert_eq!(money.checked_clamp(2_000, 500), None);
+    assert_eq!(money.checked_clamp(f64::NAN, 2_000.0), None);
+    assert_eq!(money.checked_clamp(0.0, f64::NAN), None);
+}
+
+#[test]
+fn test_try_from_f64_exact() {
+    assert_eq!(
+        Money::<USD>::try_from_f64_exact(10.1).unwrap(),
+        money!(USD, 10.10)
+    );
+    assert_eq!(
+        Money::<USD>::try_from_f64_exact(-0.01).unwrap(),
+        money!(USD, -0.01)
+    );
+    assert_eq!(
+        Money::<USD>::try_from_f64_exact(1e20).unwrap(),
+        money!(USD, 100_000_000_000_000_000_000)
+    );
+    assert_eq!(
+        Money::<JPY>::try_from_f64_exact(100.0).unwrap(),
+        money!(JPY, 100)
+    );
+    assert_eq!(
+        Money::<BHD>::try_from_f64_exact(0.125).unwrap(),
+        money!(BHD, 0.125)
+    );
+    assert_eq!(Money::<USD>::try_from(0.3).unwrap(), money!(USD, 0.30));
+
+    assert!(matches!(
+        Money::<USD>::try_from_f64_exact(10.100000000000001),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+    assert!(matches!(
+        Money::<USD>::try_from(0.125),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+    assert!(matches!(
+        Money::<JPY>::try_from_f64_exact(0.5),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+    assert!(matches!(
+        Money::<USD>::try_from_f64_exact(1e-30),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+    assert_eq!(
+        Money::<USD>::try_from_f64_exact(-0.0).unwrap(),
+        money!(USD, 0)
+    );
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, f64::MAX, 1e29] {
+        assert!(matches!(
+            Money::<USD>::try_from_f64_exact(value),
+            Err(MoneyError::OverflowError)
+        ));
+    }
+}
+
+#[test]
+fn test_from_f64_lossy() {
+    assert_eq!(
+        Money::<USD>::from_f64_lossy(10.1).unwrap(),
+        (money!(USD, 10.10), dec!(0))
+    );
+    assert_eq!(
+        Money::<USD>::from_f64_lossy(10.100000000000001).unwrap(),
+        (money!(USD, 10.10), dec!(-0.000000000000001))
+    );
+    assert_eq!(
+        Money::<USD>::from_f64_lossy(0.135).unwrap(),
+        (money!(USD, 0.14), dec!(0.005))
+    );
+    assert_eq!(
+        Money::<JPY>::from_f64_lossy(-2.5).unwrap(),
+        (money!(JPY, -2), dec!(0.5))
+    );
+    assert!(Money::<USD>::from_f64_lossy(f64::NAN).is_err());
+}
+
+#[test]
+fn test_map_amount() {
+    let money = money!(USD, 10);
+    assert_eq!(money.map_amount(|d| d / dec!(3)), money!(USD, 3.33));
+    assert_eq!(money.map_amount(|d| d * dec!(0.0125)), money!(USD, 0.12));
+    assert_eq!(money.map_amount(|d| -d), money!(USD, -10));
+    assert_eq!(
+        money!(JPY, 1_000).map_amount(|d| d * dec!(1.085)),
+        money!(JPY, 1_085)
+    );
+}
+
+#[test]
+fn test_try_map_amount() {
+    let money = money!(USD, 100);
+    assert_eq!(
+        money
+            .try_map_amount(|d| d.checked_mul(dec!(0.029))?.checked_add(dec!(0.30)))
+            .unwrap(),
+        money!(USD, 3.20)
+    );
+    assert_eq!(
+        money.try_map_amount(|d| d.checked_div(dec!(8))).unwrap(),
+        money!(USD, 12.50)
+    );
+    assert!(matches!(
+        money.try_map_amount(|d| d.checked_div(dec!(0))),
+        Err(MoneyError::OverflowError)
+    ));
+    assert!(matches!(
+        Money::<USD>::MAX.try_map_amount(|d| d.checked_add(dec!(1))),
+        Err(MoneyError::OverflowError)
+    ));
+}
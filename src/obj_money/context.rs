@@ -36,6 +36,10 @@ static CURRENCIES: LazyLock<RwLock<HashMap<&'static str, super::DynCurrency>>> =
         )
     });
 
+/// Global preference order of currency codes sharing a symbol, e.g. `"$"` -> `["USD", "CAD"]`.
+static SYMBOL_PREFERENCES: LazyLock<RwLock<HashMap<String, Vec<&'static str>>>> =
+    LazyLock::new(Default::default);
+
 /// Global runtime context for `obj_money`.
 ///
 /// `Context` manages two pieces of process-wide state:
@@ -44,7 +48,9 @@ static CURRENCIES: LazyLock<RwLock<HashMap<&'static str, super::DynCurrency>>> =
 ///    rounding so amounts are stored with full precision (mirrors [`RawMoney`](crate::RawMoney)).
 /// 2. **Currency registry** (`CURRENCIES` map) — a `HashMap` pre-seeded with every currency from
 ///    [`currencylib`](currencylib). Custom currencies can be added via
-///    [`register_currency`](Self::register_currency).
+///    [`register_currency`](Self::register_currency), or at runtime via [`register`](Self::register).
+/// 3. **Symbol preferences** — preference order of currencies sharing a symbol, e.g. `"$"`,
+///    used when parsing symbol-only strings. Set via [`prefer_symbol`](Self::prefer_symbol).
 ///
 /// All methods are `pub` and operate on the static singletons, so no instance is needed.
 pub struct Context;
@@ -111,24 +117,67 @@ impl Context {
     /// assert!(Context::register_currency::<USD>().is_err());
     /// ```
     pub fn register_currency<C: Currency>() -> Result<(), MoneyError> {
+        Self::register(super::DynCurrency::from_curr::<C>())
+    }
+
+    /// Registers a currency defined at runtime in the global registry, e.g. store credits defined by merchants
+    /// and loaded at startup, without a compile-time [`Currency`] type.
+    ///
+    /// Once registered, the currency is available to [`DynMoney`](super::DynMoney), code and symbol parsing,
+    /// and symbol lookup, like ISO currencies.
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::ObjMoneyError`] if the currency code is already registered or if the
+    ///   internal `RwLock` is poisoned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{macros::dec, obj_money::{Context, DynCurrency, DynMoney, ObjMoney}};
+    ///
+    /// let credit = DynCurrency::builder("SHOPCR", 2)
+    ///     .symbol("SC$")
+    ///     .name("Shop credit")
+    ///     .register()
+    ///     .unwrap();
+    ///
+    /// let money = DynMoney::new_with_code("SHOPCR", dec!(12.345)).unwrap();
+    /// assert_eq!(money.amount(), dec!(12.34));
+    /// assert_eq!(money.name(), "Shop credit");
+    /// assert_eq!(Context::get_currency_by_symbol("SC$").unwrap().code(), "SHOPCR");
+    ///
+    /// assert!(Context::register(credit).is_err());
+    /// ```
+    pub fn register(currency: super::DynCurrency) -> Result<(), MoneyError> {
+        Self::register_with(currency.code, || currency).map(|_| ())
+    }
+
+    /// Registers the currency made by `make` under `code`, calling `make` only if `code` isn't registered yet,
+    /// so metadata of [`DynCurrencyBuilder`](super::DynCurrencyBuilder) is only leaked for new currencies.
+    pub(super) fn register_with(
+        code: &str,
+        make: impl FnOnce() -> super::DynCurrency,
+    ) -> Result<super::DynCurrency, MoneyError> {
         let mut write = CURRENCIES.write().map_err(|_| {
             MoneyError::ObjMoneyError("failed getting lock to write into CURRENCIES".into())
         })?;
 
-        if write.contains_key(&C::CODE) {
+        if write.contains_key(code) {
             return Err(MoneyError::ObjMoneyError(
                 format!(
                     "Currency with code {} already exist: {:?}",
-                    C::CODE,
-                    get_data(C::CODE)
+                    code,
+                    get_data(code)
                 )
                 .into(),
             ));
         }
 
-        write.insert(C::CODE, super::DynCurrency::from_curr::<C>());
+        let currency = make();
+        write.insert(currency.code, currency);
 
-        Ok(())
+        Ok(currency)
     }
 
     /// Returns `true` if the given currency `code` is present in the registry.
@@ -239,4 +288,116 @@ impl Context {
 
         None
     }
+
+    /// Retrieves all [`DynCurrency`](super::dyn_money::DynCurrency) in the registry with `symbol`,
+    /// sorted by code.
+    ///
+    /// Returns empty if none, or if the `RwLock` is poisoned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::obj_money::Context;
+    ///
+    /// let codes: Vec<_> = Context::get_currencies_by_symbol("kr")
+    ///     .iter()
+    ///     .map(|c| c.code().to_string())
+    ///     .collect();
+    /// assert!(codes.contains(&"SEK".to_string()) && codes.contains(&"NOK".to_string()));
+    ///
+    /// assert!(Context::get_currencies_by_symbol("??").is_empty());
+    /// ```
+    pub fn get_currencies_by_symbol(symbol: &str) -> Vec<super::dyn_money::DynCurrency> {
+        let mut ret: Vec<_> = match CURRENCIES.read() {
+            Ok(data) => data
+                .values()
+                .filter(|curr| curr.symbol == symbol)
+                .copied()
+                .collect(),
+            Err(_) => return vec![],
+        };
+        ret.sort_by_key(|curr| curr.code);
+        ret
+    }
+
+    /// Appends currency `code` into the global preference order of `symbol`.
+    ///
+    /// When parsing symbol-only strings, e.g. with [`DynMoney::from_str_symbol`](super::DynMoney::from_str_symbol),
+    /// a symbol shared by currencies resolves into the first preferred one. Preferring a code again keeps its position.
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::ObjMoneyError`] if `code` is not registered, its symbol is not `symbol`,
+    ///   or if the internal `RwLock` is poisoned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::obj_money::Context;
+    ///
+    /// Context::prefer_symbol("$", "USD").unwrap();
+    /// assert_eq!(Context::symbol_preference("$"), ["USD"]);
+    ///
+    /// // EUR's symbol is €
+    /// assert!(Context::prefer_symbol("$", "EUR").is_err());
+    /// # Context::clear_symbol_preference("$");
+    /// ```
+    pub fn prefer_symbol(symbol: &str, code: &str) -> Result<(), MoneyError> {
+        let currency = super::symbol::checked_currency(symbol, code)?;
+
+        let mut write = SYMBOL_PREFERENCES.write().map_err(|_| {
+            MoneyError::ObjMoneyError("failed getting lock to write into SYMBOL_PREFERENCES".into())
+        })?;
+        let codes = write.entry(symbol.to_string()).or_default();
+        if !codes.contains(&currency.code) {
+            codes.push(currency.code);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the global preference order of `symbol`, empty if none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::obj_money::Context;
+    ///
+    /// assert!(Context::symbol_preference("₩").is_empty());
+    /// ```
+    pub fn symbol_preference(symbol: &str) -> Vec<&'static str> {
+        if let Ok(data) = SYMBOL_PREFERENCES.read()
+            && let Some(codes) = data.get(symbol)
+        {
+            return codes.clone();
+        }
+
+        vec![]
+    }
+
+    /// Removes the global preference order of `symbol`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::obj_money::Context;
+    ///
+    /// Context::prefer_symbol("¥", "JPY").unwrap();
+    /// Context::clear_symbol_preference("¥");
+    /// assert!(Context::symbol_preference("¥").is_empty());
+    /// ```
+    pub fn clear_symbol_preference(symbol: &str) {
+        if let Ok(mut write) = SYMBOL_PREFERENCES.write() {
+            write.remove(symbol);
+        }
+    }
+
+    /// Returns the longest registered symbol `input` starts with.
+    pub(super) fn find_symbol_prefix(input: &str) -> Option<&'static str> {
+        let data = CURRENCIES.read().ok()?;
+        data.values()
+            .map(|curr| curr.symbol)
+            .filter(|symbol| !symbol.is_empty() && input.starts_with(symbol))
+            .max_by_key(|symbol| symbol.len())
+    }
 }
@@ -1,7 +1,6 @@
 use std::fmt::{Debug, Display};
 
 use crate::{Currency, Decimal, MoneyError, RoundingStrategy, prelude::ObjMoney};
-use rust_decimal::{MathematicalOps, prelude::ToPrimitive};
 
 use super::helpers;
 
@@ -103,6 +102,175 @@ impl DynCurrency {
     }
 }
 
+impl DynCurrency {
+    /// Starts building a currency defined at runtime, e.g. store credits defined by merchants,
+    /// registered in [`Context`](super::Context) by [`DynCurrencyBuilder::register`].
+    ///
+    /// Unset metadata defaults to `code` for symbol and name, `0` for numeric code, `","` and `"."` for separators,
+    /// `"minor"` for minor unit symbol and name, empty origin and `"en-US"` locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::obj_money::DynCurrency;
+    ///
+    /// let credit = DynCurrency::builder("CREDIT", 2)
+    ///     .symbol("cr")
+    ///     .name("Store credit")
+    ///     .register()
+    ///     .unwrap();
+    /// assert_eq!(credit.code(), "CREDIT");
+    /// ```
+    pub fn builder(code: &str, minor_unit: u16) -> DynCurrencyBuilder {
+        DynCurrencyBuilder {
+            code: code.to_string(),
+            symbol: None,
+            name: None,
+            numeric: 0,
+            minor_unit,
+            minor_unit_symbol: "minor".to_string(),
+            minor_unit_name: "minor".to_string(),
+            thousand_separator: ",".to_string(),
+            decimal_separator: ".".to_string(),
+            origin: String::new(),
+            locale: "en-US".to_string(),
+        }
+    }
+}
+
+/// Length bounds of codes of currencies defined at runtime, e.g. `"USD"` to `"MRCHCRED"`.
+const MIN_CODE_LEN: usize = 3;
+const MAX_CODE_LEN: usize = 8;
+
+/// Builder of [`DynCurrency`] defined at runtime, created with [`DynCurrency::builder`].
+///
+/// Metadata is validated and registered by [`register`](Self::register).
+#[derive(Debug, Clone)]
+pub struct DynCurrencyBuilder {
+    code: String,
+    symbol: Option<String>,
+    name: Option<String>,
+    numeric: u16,
+    minor_unit: u16,
+    minor_unit_symbol: String,
+    minor_unit_name: String,
+    thousand_separator: String,
+    decimal_separator: String,
+    origin: String,
+    locale: String,
+}
+
+impl DynCurrencyBuilder {
+    /// Sets the currency symbol, e.g. `"cr"`.
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_string());
+        self
+    }
+
+    /// Sets the full name of the currency.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the numeric code of the currency.
+    pub fn numeric(mut self, numeric: u16) -> Self {
+        self.numeric = numeric;
+        self
+    }
+
+    /// Sets the symbol and name of the minor unit, e.g. `"¢"` and `"cent"`.
+    pub fn minor_unit_names(mut self, symbol: &str, name: &str) -> Self {
+        self.minor_unit_symbol = symbol.to_string();
+        self.minor_unit_name = name.to_string();
+        self
+    }
+
+    /// Sets the thousand and decimal separators of amounts.
+    pub fn separators(mut self, thousand_separator: &str, decimal_separator: &str) -> Self {
+        self.thousand_separator = thousand_separator.to_string();
+        self.decimal_separator = decimal_separator.to_string();
+        self
+    }
+
+    /// Sets the origin of the currency.
+    pub fn origin(mut self, origin: &str) -> Self {
+        self.origin = origin.to_string();
+        self
+    }
+
+    /// Sets the locale of the currency, e.g. `"de-DE"`.
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.locale = locale.to_string();
+        self
+    }
+
+    /// Validates the currency and registers it in the global [`Context`](super::Context) registry.
+    ///
+    /// Its metadata is leaked to live as long as the process like currencies in the registry, only once
+    /// registration succeeds, so rejected currencies, e.g. duplicates, don't leak.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ObjMoneyError`] if:
+    /// - code isn't 3 to 8 ASCII uppercase letters, e.g. `"USDT"`, as parsing only reads letters as codes.
+    /// - minor unit is more than `Decimal`'s 28 decimal places.
+    /// - decimal separator is empty or the same as thousand separator.
+    /// - code is already registered or the registry's lock is poisoned.
+    pub fn register(self) -> Result<DynCurrency, MoneyError> {
+        if !(MIN_CODE_LEN..=MAX_CODE_LEN).contains(&self.code.len())
+            || !self.code.chars().all(|c| c.is_ascii_uppercase())
+        {
+            return Err(MoneyError::ObjMoneyError(
+                format!(
+                    "currency code {:?} must be {} to {} ASCII uppercase letters",
+                    self.code, MIN_CODE_LEN, MAX_CODE_LEN
+                )
+                .into(),
+            ));
+        }
+        if u32::from(self.minor_unit) > Decimal::MAX_SCALE {
+            return Err(MoneyError::ObjMoneyError(
+                format!(
+                    "minor unit {} of {} exceeds maximum of {}",
+                    self.minor_unit,
+                    self.code,
+                    Decimal::MAX_SCALE
+                )
+                .into(),
+            ));
+        }
+        if self.decimal_separator.is_empty() || self.decimal_separator == self.thousand_separator {
+            return Err(MoneyError::ObjMoneyError(
+                format!(
+                    "decimal separator {:?} of {} must be non-empty and differ from thousand separator",
+                    self.decimal_separator, self.code
+                )
+                .into(),
+            ));
+        }
+
+        let code = self.code.clone();
+        super::Context::register_with(&code, || {
+            let symbol = self.symbol.unwrap_or_else(|| self.code.clone());
+            let name = self.name.unwrap_or_else(|| self.code.clone());
+            DynCurrency {
+                code: self.code.leak(),
+                symbol: symbol.leak(),
+                name: name.leak(),
+                numeric: self.numeric,
+                minor_unit: self.minor_unit,
+                minor_unit_symbol: self.minor_unit_symbol.leak(),
+                minor_unit_name: self.minor_unit_name.leak(),
+                thousand_separator: self.thousand_separator.leak(),
+                decimal_separator: self.decimal_separator.leak(),
+                origin: self.origin.leak(),
+                locale: self.locale.leak(),
+            }
+        })
+    }
+}
+
 impl<C: Currency> From<C> for DynCurrency {
     fn from(_: C) -> Self {
         Self::from_curr::<C>()
@@ -320,6 +488,29 @@ impl DynMoney {
             format!("currency {} not found", code).into(),
         ))
     }
+
+    /// Parses symbol-only money string, e.g. `"£1,234.56"`, resolving shared symbols with global preferences.
+    ///
+    /// Same as [`SymbolParser::new().parse(s)`](super::SymbolParser::parse); see [`SymbolParser`](super::SymbolParser)
+    /// for how shared symbols are resolved.
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::ParseStrError`] if `s` doesn't start with a registered symbol or its amount is invalid.
+    /// - [`MoneyError::AmbiguousSymbolError`] if the symbol is shared and no preference resolves it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{macros::dec, obj_money::{DynMoney, ObjMoney}};
+    ///
+    /// let m = DynMoney::from_str_symbol("£1,234.56").unwrap();
+    /// assert_eq!(m.code(), "GBP");
+    /// assert_eq!(m.amount(), dec!(1234.56));
+    /// ```
+    pub fn from_str_symbol(s: &str) -> Result<Self, MoneyError> {
+        super::SymbolParser::new().parse(s)
+    }
 }
 
 impl PartialEq for DynMoney {
@@ -395,10 +586,7 @@ impl super::ObjMoney for DynMoney {
 
     #[inline]
     fn minor_amount(&self) -> Option<i128> {
-        self.amount
-            .round_dp(self.currency.minor_unit.into())
-            .checked_mul(crate::dec!(10).checked_powu(self.currency.minor_unit.into())?)?
-            .to_i128()
+        crate::decimal::to_scaled_i128(self.amount, self.currency.minor_unit.into())
     }
 
     #[inline]
@@ -427,12 +615,7 @@ impl super::ObjMoney for DynMoney {
         decimal_points: u32,
         strategy: RoundingStrategy,
     ) -> Box<dyn super::ObjMoney> {
-        Box::new(
-            self.set_amount(
-                self.amount
-                    .round_dp_with_strategy(decimal_points, strategy.into()),
-            ),
-        )
+        Box::new(self.set_amount(strategy.round_dp(self.amount, decimal_points)))
     }
 
     #[inline]
@@ -697,7 +880,13 @@ impl<C: Currency> PartialOrd<RawMoney<C>> for DynMoney {
 
 impl Display for DynMoney {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.display())
+        crate::fmt::write_display(
+            f,
+            self.code(),
+            self.amount,
+            (self.thousand_separator(), self.decimal_separator()),
+            || self.display(),
+        )
     }
 }
 
@@ -707,7 +896,7 @@ impl Debug for DynMoney {
             f,
             "DynMoney({}, {}, is_raw: {})",
             self.code(),
-            self.amount,
+            crate::fmt::debug_amount(self.amount, self.minor_unit()),
             super::Context::is_raw()
         )
     }
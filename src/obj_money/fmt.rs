@@ -11,6 +11,7 @@ const CODE_FORMAT_SYMBOL: char = 'c';
 const SYMBOL_FORMAT_SYMBOL: char = 's';
 const MINOR_FORMAT_SYMBOL: char = 'm';
 const NEGATIVE_FORMAT_SYMBOL: char = 'n';
+const POSITIVE_FORMAT_SYMBOL: char = 'p';
 
 /// Returns true if `symbol` appears as an active (non-escaped, non-literal-block) format symbol
 /// in `format_str`.
@@ -44,6 +45,7 @@ fn contains_active_format_symbol(format_str: &str, symbol: char) -> bool {
 fn format_parts(
     display_amount: &str,
     is_negative: bool,
+    is_positive: bool,
     code: &str,
     symbol: &str,
     minor_unit_symbol: &str,
@@ -86,6 +88,11 @@ fn format_parts(
                         result.push('-');
                     }
                 }
+                POSITIVE_FORMAT_SYMBOL => {
+                    if is_positive {
+                        result.push('+');
+                    }
+                }
                 ' ' => result.push(' '),
                 _ => result.push(ch),
             }
@@ -112,6 +119,7 @@ pub(crate) fn format_obj_money(
     format_str: &str,
 ) -> String {
     let is_negative = amount.is_sign_negative();
+    let is_positive = amount.is_sign_positive() && !amount.is_zero();
 
     let display_amount = if contains_active_format_symbol(format_str, MINOR_FORMAT_SYMBOL) {
         let minor_result = dec!(10)
@@ -130,6 +138,7 @@ pub(crate) fn format_obj_money(
     format_parts(
         &display_amount,
         is_negative,
+        is_positive,
         code,
         symbol,
         minor_unit_symbol,
@@ -10,10 +10,16 @@ mod obj_money;
 pub use obj_money::{ObjIterOps, ObjMoney};
 
 mod dyn_money;
-pub use dyn_money::{DynCurrency, DynMoney};
+pub use dyn_money::{DynCurrency, DynCurrencyBuilder, DynMoney};
 
 mod ops;
 
+mod symbol;
+pub use symbol::SymbolParser;
+
+mod parser;
+pub use parser::{DynMoneyParser, DynMoneyParserBuilder};
+
 mod money_impl;
 
 #[cfg(feature = "raw_money")]
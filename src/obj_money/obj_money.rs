@@ -3,7 +3,7 @@ use std::any::Any;
 use super::fmt::format_obj_money;
 use crate::{
     RoundingStrategy,
-    fmt::{CODE_FORMAT, CODE_FORMAT_MINOR, SYMBOL_FORMAT, SYMBOL_FORMAT_MINOR},
+    fmt::{CODE_FORMAT, CODE_FORMAT_MINOR, MaskPolicy, SYMBOL_FORMAT, SYMBOL_FORMAT_MINOR},
 };
 
 use crate::{Decimal, MoneyError};
@@ -330,6 +330,35 @@ pub trait ObjMoney: Send + Sync {
     fn display(&self) -> String {
         self.format_code()
     }
+
+    /// Formats money in code format with amount masked by the default [`MaskPolicy`], e.g. `"USD ••••.56"`.
+    fn format_masked(&self) -> String {
+        self.format_masked_with(MaskPolicy::default())
+    }
+
+    /// Formats money in code format with amount masked by `policy`, e.g. `"USD ***"` with [`MaskPolicy::FULL`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{Money, obj_money::ObjMoney, BaseMoney, fmt::MaskPolicy, macros::dec, iso::BHD};
+    ///
+    /// let m: Box<dyn ObjMoney> = Box::new(Money::<BHD>::new(dec!(-1234.5)).unwrap());
+    /// assert_eq!(m.format_masked(), "BHD ••••.500");
+    /// assert_eq!(m.format_masked_with(MaskPolicy::FULL), "BHD ***");
+    /// ```
+    fn format_masked_with(&self, policy: MaskPolicy) -> String {
+        format!(
+            "{} {}",
+            self.code(),
+            crate::fmt::mask_amount(
+                self.amount(),
+                self.minor_unit(),
+                self.decimal_separator(),
+                policy
+            )
+        )
+    }
 }
 
 // ---- Blanket impl for Box<dyn ObjMoney> ----
@@ -494,6 +523,28 @@ pub trait ObjIterOps {
         target_currency: &str,
         rates: impl crate::exchange::ObjRate,
     ) -> Result<Box<dyn ObjMoney>, MoneyError>;
+
+    /// Formats all ObjMoney in code format padded with spaces into the same width, so their decimal separators
+    /// align vertically, honoring each currency's minor unit and separators.
+    ///
+    /// Same as [`fmt::align_column`](crate::fmt::align_column) for mixed currencies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, obj_money::{ObjIterOps, ObjMoney}};
+    ///
+    /// let portfolio: Vec<Box<dyn ObjMoney>> = vec![
+    ///     Box::new(money!(USD, 1234.5)),
+    ///     Box::new(money!(JPY, -5000)),
+    ///     Box::new(money!(BHD, 1.25)),
+    /// ];
+    /// assert_eq!(
+    ///     portfolio.align_column(),
+    ///     ["USD  1,234.50 ", "JPY -5,000    ", "BHD      1.250"]
+    /// );
+    /// ```
+    fn align_column(&self) -> Vec<String>;
 }
 
 impl<I, T> ObjIterOps for I
@@ -521,4 +572,19 @@ where
 
         Ok(total)
     }
+
+    fn align_column(&self) -> Vec<String> {
+        let cells: Vec<crate::fmt::ColumnCell<'_>> = self
+            .into_iter()
+            .map(|m| {
+                crate::fmt::ColumnCell::new(
+                    m.code(),
+                    m.amount(),
+                    (m.thousand_separator(), m.decimal_separator()),
+                    m.minor_unit(),
+                )
+            })
+            .collect();
+        crate::fmt::align_cells(&cells)
+    }
 }
@@ -2256,6 +2256,16 @@ fn test_dyn_money_obj_format_negative_custom() {
     assert_eq!(m.format("nsa"), "-$50.00");
 }
 
+#[test]
+fn test_dyn_money_obj_format_plus_sign() {
+    let m: Box<dyn ObjMoney> = Box::new(DynMoney::from_decimal::<USD>(dec!(25)));
+    assert_eq!(m.format("pnsa"), "+$25.00");
+    let m: Box<dyn ObjMoney> = Box::new(DynMoney::from_decimal::<USD>(dec!(-25)));
+    assert_eq!(m.format("pnsa"), "-$25.00");
+    let m: Box<dyn ObjMoney> = Box::new(DynMoney::from_decimal::<USD>(dec!(0)));
+    assert_eq!(m.format("pnsa"), "$0.00");
+}
+
 // ==================== DynMoney: Neg operator ====================
 
 #[test]
@@ -3345,3 +3355,482 @@ fn test_dyn_money_display_debug() {
     let display = asd.to_string();
     assert_eq!(display.as_str(), "USD 123.40");
 }
+
+#[test]
+fn test_symbol_parser_unique_symbol() {
+    let m = super::SymbolParser::new().parse("€1.234,5").unwrap();
+    assert_eq!(m.code(), "EUR");
+    assert_eq!(m.amount(), dec!(1234.50));
+
+    let m = DynMoney::from_str_symbol(" -£0.99 ").unwrap();
+    assert_eq!(m.code(), "GBP");
+    assert_eq!(m.amount(), dec!(-0.99));
+}
+
+#[test]
+fn test_symbol_parser_ambiguous_symbol() {
+    let err = super::SymbolParser::new().parse("$10").unwrap_err();
+    let MoneyError::AmbiguousSymbolError(symbol, codes) = err else {
+        panic!("expected ambiguous symbol error, got {err:?}");
+    };
+    assert_eq!(symbol, "$");
+    assert!(codes.len() > 1);
+    assert!(codes.contains(&"USD".to_string()) && codes.contains(&"CAD".to_string()));
+    assert!(codes.is_sorted());
+}
+
+#[test]
+fn test_symbol_parser_per_parser_preference() {
+    let parser = super::SymbolParser::new()
+        .prefer("$", "AUD")
+        .unwrap()
+        .prefer("$", "USD")
+        .unwrap()
+        .prefer("$", "AUD")
+        .unwrap();
+    assert_eq!(parser.preference("$"), ["AUD", "USD"]);
+    assert!(parser.preference("€").is_empty());
+
+    let m = parser.parse("-$1,000.25").unwrap();
+    assert_eq!(m.code(), "AUD");
+    assert_eq!(m.amount(), dec!(-1000.25));
+}
+
+#[test]
+fn test_symbol_parser_invalid_preference() {
+    assert!(matches!(
+        super::SymbolParser::new().prefer("$", "EUR"),
+        Err(MoneyError::ObjMoneyError(_))
+    ));
+    assert!(matches!(
+        super::SymbolParser::new().prefer("$", "XYZ"),
+        Err(MoneyError::ObjMoneyError(_))
+    ));
+    assert!(matches!(
+        super::Context::prefer_symbol("€", "USD"),
+        Err(MoneyError::ObjMoneyError(_))
+    ));
+}
+
+#[test]
+fn test_symbol_parser_global_preference() {
+    // only this test touches global preference of "kr".
+    assert!(matches!(
+        DynMoney::from_str_symbol("kr100"),
+        Err(MoneyError::AmbiguousSymbolError(..))
+    ));
+
+    super::Context::prefer_symbol("kr", "SEK").unwrap();
+    super::Context::prefer_symbol("kr", "NOK").unwrap();
+    assert_eq!(super::Context::symbol_preference("kr"), ["SEK", "NOK"]);
+    assert_eq!(DynMoney::from_str_symbol("kr100").unwrap().code(), "SEK");
+
+    // parser's own preference comes first.
+    let parser = super::SymbolParser::new().prefer("kr", "DKK").unwrap();
+    assert_eq!(parser.parse("kr100").unwrap().code(), "DKK");
+
+    super::Context::clear_symbol_preference("kr");
+    assert!(super::Context::symbol_preference("kr").is_empty());
+    assert!(DynMoney::from_str_symbol("kr100").is_err());
+}
+
+#[test]
+fn test_symbol_parser_invalid_input() {
+    let parser = super::SymbolParser::new();
+    assert!(matches!(
+        parser.parse("100"),
+        Err(MoneyError::ParseStrError(_))
+    ));
+    assert!(parser.parse("€").is_err());
+    assert!(parser.parse("€1,2,3").is_err());
+}
+
+#[test]
+fn test_get_currencies_by_symbol() {
+    let codes: Vec<_> = super::Context::get_currencies_by_symbol("¥")
+        .iter()
+        .map(|c| c.code)
+        .collect();
+    assert!(codes.contains(&"JPY") && codes.contains(&"CNY"));
+    assert!(codes.is_sorted());
+    assert!(super::Context::get_currencies_by_symbol("??").is_empty());
+}
+
+#[test]
+fn test_dyn_money_parser_default() {
+    let parser = super::DynMoneyParser::builder().build().unwrap();
+
+    // each currency's own separators.
+    let m = parser.parse("USD 1,234.56").unwrap();
+    assert_eq!((m.code(), m.amount()), ("USD", dec!(1234.56)));
+    let m = parser.parse("EUR -1.234,5").unwrap();
+    assert_eq!((m.code(), m.amount()), ("EUR", dec!(-1234.50)));
+    let m = parser.parse(" -£0.99 ").unwrap();
+    assert_eq!((m.code(), m.amount()), ("GBP", dec!(-0.99)));
+
+    // not strict, rounded into minor unit.
+    assert_eq!(parser.parse("JPY 1,234.5").unwrap().amount(), dec!(1234));
+    assert!(parser.accepts("BHD"));
+    assert!(!parser.accepts("XYZ"));
+
+    assert!(matches!(
+        parser.parse("$10"),
+        Err(MoneyError::AmbiguousSymbolError(..))
+    ));
+    assert!(matches!(
+        parser.parse("XYZ 10"),
+        Err(MoneyError::ParseStrError(_))
+    ));
+    assert!(matches!(
+        parser.parse("USD 1.234,56"),
+        Err(MoneyError::ParseStrError(_))
+    ));
+}
+
+#[test]
+fn test_dyn_money_parser_separators() {
+    let parser = super::DynMoneyParser::builder()
+        .separators("'", ".")
+        .build()
+        .unwrap();
+    assert_eq!(
+        parser.parse("EUR 1'234'567.89").unwrap().amount(),
+        dec!(1234567.89)
+    );
+    assert_eq!(parser.parse("-€0.5").unwrap().amount(), dec!(-0.50));
+    assert!(parser.parse("EUR 1.234,56").is_err());
+}
+
+#[test]
+fn test_dyn_money_parser_grouping() {
+    let parser = super::DynMoneyParser::builder()
+        .grouping(crate::Grouping::INDIAN)
+        .build()
+        .unwrap();
+    assert_eq!(
+        parser.parse("INR 12,34,567.89").unwrap().amount(),
+        dec!(1234567.89)
+    );
+    assert_eq!(parser.parse("-₹1,00,000").unwrap().amount(), dec!(-100000));
+    assert!(parser.parse("INR 1,234,567.89").is_err());
+}
+
+#[cfg(feature = "locale")]
+#[test]
+fn test_dyn_money_parser_locale() {
+    let parser = super::DynMoneyParser::builder()
+        .locale("de-DE")
+        .build()
+        .unwrap();
+    assert_eq!(
+        parser.parse("USD 1.234,56").unwrap().amount(),
+        dec!(1234.56)
+    );
+
+    // last separators win.
+    let parser = super::DynMoneyParser::builder()
+        .separators(".", ",")
+        .locale("en-US")
+        .build()
+        .unwrap();
+    assert_eq!(parser.parse("€1,234.56").unwrap().amount(), dec!(1234.56));
+
+    assert!(matches!(
+        super::DynMoneyParser::builder().locale("!!").build(),
+        Err(MoneyError::ParseLocale(_))
+    ));
+}
+
+#[test]
+fn test_dyn_money_parser_currencies() {
+    let parser = super::DynMoneyParser::builder()
+        .currencies(&["AUD", "EUR", "AUD"])
+        .build()
+        .unwrap();
+    assert!(parser.accepts("AUD") && parser.accepts("EUR"));
+    assert!(!parser.accepts("USD"));
+
+    // "$" resolves into the only accepted currency having it.
+    assert_eq!(parser.parse("$5").unwrap().code(), "AUD");
+    assert!(matches!(
+        parser.parse("USD 5"),
+        Err(MoneyError::ObjMoneyError(_))
+    ));
+    assert!(matches!(
+        parser.parse("£5"),
+        Err(MoneyError::AmbiguousSymbolError(symbol, codes)) if symbol == "£" && codes.is_empty()
+    ));
+
+    assert!(matches!(
+        super::DynMoneyParser::builder()
+            .currencies(&["XYZ"])
+            .build(),
+        Err(MoneyError::ObjMoneyError(_))
+    ));
+}
+
+#[test]
+fn test_dyn_money_parser_preference() {
+    let parser = super::DynMoneyParser::builder()
+        .currencies(&["USD", "CAD", "EUR"])
+        .prefer("$", "CAD")
+        .build()
+        .unwrap();
+    assert_eq!(parser.parse("$1,000").unwrap().code(), "CAD");
+
+    // preference of a currency not accepted is skipped.
+    let parser = super::DynMoneyParser::builder()
+        .currencies(&["USD"])
+        .prefer("$", "CAD")
+        .build()
+        .unwrap();
+    assert_eq!(parser.parse("$1,000").unwrap().code(), "USD");
+
+    assert!(matches!(
+        super::DynMoneyParser::builder().prefer("$", "EUR").build(),
+        Err(MoneyError::ObjMoneyError(_))
+    ));
+}
+
+#[test]
+fn test_dyn_money_parser_strict() {
+    let parser = super::DynMoneyParser::builder()
+        .strict(true)
+        .build()
+        .unwrap();
+    assert_eq!(parser.parse("USD 1.50").unwrap().amount(), dec!(1.5));
+    assert_eq!(parser.parse("BHD 1.234").unwrap().amount(), dec!(1.234));
+    // trailing zeros don't count.
+    assert_eq!(parser.parse("JPY 100.00").unwrap().amount(), dec!(100));
+    assert!(matches!(
+        parser.parse("USD 1.505"),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+    assert!(matches!(
+        parser.parse("€1,005"),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+}
+
+#[test]
+fn test_dyn_money_parser_shared_across_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<super::DynMoneyParser>();
+
+    let parser = std::sync::Arc::new(
+        super::DynMoneyParser::builder()
+            .currencies(&["EUR"])
+            .build()
+            .unwrap(),
+    );
+    let handles: Vec<_> = (1..=4)
+        .map(|i| {
+            let parser = parser.clone();
+            std::thread::spawn(move || parser.parse(&format!("€{},00", i)).unwrap().amount())
+        })
+        .collect();
+    let total: Decimal = handles.into_iter().map(|h| h.join().unwrap()).sum();
+    assert_eq!(total, dec!(10));
+}
+
+#[test]
+fn test_dyn_money_display_flags() {
+    let m = DynMoney::new_with_code("EUR", dec!(-1234.5)).unwrap();
+    assert_eq!(format!("{}", m), "EUR -1.234,50");
+    assert_eq!(format!("{:>15}", m), "  EUR -1.234,50");
+    assert_eq!(format!("{:_<15}", m), "EUR -1.234,50__");
+    assert_eq!(format!("{:.0}", m), "EUR -1.234");
+}
+
+#[test]
+fn test_obj_money_format_masked() {
+    use crate::fmt::MaskPolicy;
+
+    let portfolio: Vec<Box<dyn ObjMoney>> = vec![
+        Box::new(money!(USD, -1234.56)),
+        Box::new(money!(EUR, 0.5)),
+        Box::new(money!(JPY, 15000)),
+        Box::new(DynMoney::from_decimal::<BHD>(dec!(12.3456))),
+    ];
+    let masked: Vec<String> = portfolio.iter().map(|m| m.format_masked()).collect();
+    assert_eq!(
+        masked,
+        vec!["USD ••••.56", "EUR ••••,50", "JPY ••••", "BHD ••••.346"]
+    );
+    for m in &portfolio {
+        assert_eq!(
+            m.format_masked_with(MaskPolicy::FULL),
+            format!("{} ***", m.code())
+        );
+    }
+}
+
+#[cfg(feature = "redact_debug")]
+#[test]
+fn test_dyn_money_debug_redacted() {
+    let m = DynMoney::from_decimal::<USD>(dec!(-1234.56));
+    assert!(format!("{:?}", m).starts_with("DynMoney(USD, ••••.56, is_raw: "));
+}
+
+#[test]
+fn test_obj_align_column() {
+    use super::ObjIterOps;
+
+    let portfolio: Vec<Box<dyn ObjMoney>> = vec![
+        Box::new(money!(EUR, 1234.5)),
+        Box::new(money!(JPY, 150000)),
+        Box::new(DynMoney::from_decimal::<BHD>(dec!(-0.125))),
+        Box::new(money!(USD, -7)),
+    ];
+    assert_eq!(
+        portfolio.align_column(),
+        [
+            "EUR   1.234,50 ",
+            "JPY 150,000    ",
+            "BHD      -0.125",
+            "USD      -7.00 ",
+        ]
+    );
+
+    let empty: Vec<Box<dyn ObjMoney>> = vec![];
+    assert!(empty.align_column().is_empty());
+}
+
+#[test]
+fn test_dyn_money_parser_parentheses_negative() {
+    let parser = super::DynMoneyParser::builder().build().unwrap();
+    for input in ["USD (1,234.56)", "(USD 1,234.56)"] {
+        let m = parser.parse(input).unwrap();
+        assert_eq!((m.code(), m.amount()), ("USD", dec!(-1234.56)));
+    }
+    for input in ["(£0.99)", "£(0.99)"] {
+        let m = parser.parse(input).unwrap();
+        assert_eq!((m.code(), m.amount()), ("GBP", dec!(-0.99)));
+    }
+    assert!(parser.parse("EUR (-1.234,56)").is_err());
+    assert!(parser.parse("(-£0.99)").is_err());
+}
+
+#[test]
+fn test_round_with_directional() {
+    let money = DynMoney::new_with_code("CHF", dec!(-12.33)).unwrap();
+    assert_eq!(
+        money.round_with(1, RoundingStrategy::Up).amount(),
+        dec!(-12.3)
+    );
+    assert_eq!(
+        money.round_with(1, RoundingStrategy::Down).amount(),
+        dec!(-12.4)
+    );
+}
+
+// ==================== Context: runtime currency registry ====================
+
+#[test]
+fn test_dyn_currency_builder_defaults() {
+    let curr = super::DynCurrency::builder("PTSA", 0).register().unwrap();
+    assert_eq!(curr.code, "PTSA");
+    assert_eq!(curr.symbol, "PTSA");
+    assert_eq!(curr.name, "PTSA");
+    assert_eq!(curr.numeric, 0);
+    assert_eq!(curr.minor_unit, 0);
+    assert_eq!(curr.minor_unit_symbol, "minor");
+    assert_eq!(curr.minor_unit_name, "minor");
+    assert_eq!(curr.thousand_separator, ",");
+    assert_eq!(curr.decimal_separator, ".");
+    assert_eq!(curr.origin, "");
+    assert_eq!(curr.locale, "en-US");
+}
+
+#[test]
+fn test_dyn_currency_builder_invalid() {
+    for code in [
+        "",
+        "CR",
+        "STORE CREDIT",
+        "CR-1",
+        "CRD1",
+        "ÇRD",
+        "crd",
+        "MERCHANTCR",
+    ] {
+        assert!(matches!(
+            super::DynCurrency::builder(code, 2).register(),
+            Err(MoneyError::ObjMoneyError(_))
+        ));
+        assert!(!super::Context::is_currency_exist(code));
+    }
+    assert!(super::DynCurrency::builder("CRD", 29).register().is_err());
+    assert!(
+        super::DynCurrency::builder("CRD", 2)
+            .separators(".", ".")
+            .register()
+            .is_err()
+    );
+    assert!(
+        super::DynCurrency::builder("CRD", 2)
+            .separators(",", "")
+            .register()
+            .is_err()
+    );
+    assert!(!super::Context::is_currency_exist("CRD"));
+    assert!(super::DynCurrency::builder("CRD", 28).register().is_ok());
+    assert!(
+        super::DynCurrency::builder("MRCHCRED", 2)
+            .register()
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_context_register_runtime_currency() {
+    use super::{Context, DynCurrency, DynMoney, DynMoneyParser};
+
+    let credit = DynCurrency::builder("MRCHCR", 3)
+        .symbol("MC¤")
+        .name("Merchant credit")
+        .numeric(990)
+        .minor_unit_names("m", "mill")
+        .separators(".", ",")
+        .origin("Merchant")
+        .locale("de-DE")
+        .register()
+        .unwrap();
+
+    assert!(Context::is_currency_exist("MRCHCR"));
+    assert_eq!(Context::get_currency("MRCHCR").unwrap(), credit);
+    assert_eq!(Context::get_currency_by_symbol("MC¤").unwrap(), credit);
+    assert_eq!(super::DynCurrency::from_code("MRCHCR").unwrap(), credit);
+
+    let money = DynMoney::new_with_code("MRCHCR", dec!(1234.5678)).unwrap();
+    assert_eq!(money.amount(), dec!(1234.568));
+    assert_eq!(money.name(), "Merchant credit");
+    assert_eq!(money.minor_unit(), 3);
+    assert_eq!(money.numeric_code(), 990);
+    assert_eq!(money.minor_unit_name(), "mill");
+    assert_eq!(money.locale(), "de-DE");
+
+    let parser = DynMoneyParser::builder().build().unwrap();
+    let parsed = parser.parse("MRCHCR 1.234,568").unwrap();
+    assert_eq!(parsed.code(), "MRCHCR");
+    assert_eq!(parsed.amount(), dec!(1234.568));
+    let parsed = DynMoney::from_str_symbol("MC¤1.234,5").unwrap();
+    assert_eq!(parsed.code(), "MRCHCR");
+    assert_eq!(parsed.amount(), dec!(1234.5));
+
+    // already registered
+    assert!(matches!(
+        Context::register(credit),
+        Err(MoneyError::ObjMoneyError(_))
+    ));
+    assert!(matches!(
+        DynCurrency::builder("MRCHCR", 2).name("Other").register(),
+        Err(MoneyError::ObjMoneyError(_))
+    ));
+    assert_eq!(
+        Context::get_currency("MRCHCR").unwrap().name,
+        "Merchant credit"
+    );
+    assert!(DynCurrency::builder("USD", 2).register().is_err());
+    assert!(Context::register(DynCurrency::from_curr::<crate::iso::USD>()).is_err());
+}
@@ -0,0 +1,259 @@
+use std::str::FromStr;
+
+use crate::{Decimal, Grouping, MoneyError};
+
+use super::{Context, DynCurrency, DynMoney, SymbolParser};
+
+/// Reusable parser of money strings into [`DynMoney`], composing separators, accepted currencies and strictness.
+///
+/// Built once with [`DynMoneyParser::builder`], e.g. per tenant of an ingestion service, and shared: the parser is
+/// immutable and `Send + Sync`. It parses both code format, e.g. `"USD 1,234.56"`, and symbol format, e.g. `"$1,234.56"`,
+/// resolving shared symbols like [`SymbolParser`].
+///
+/// Options:
+/// - separators: from [`locale`](DynMoneyParserBuilder::locale) or [`separators`](DynMoneyParserBuilder::separators),
+///   otherwise each currency's own.
+/// - [`grouping`](DynMoneyParserBuilder::grouping): sizes of digit groups, otherwise [`Grouping::THOUSANDS`].
+/// - [`currencies`](DynMoneyParserBuilder::currencies): accepted currencies, otherwise all registered in [`Context`].
+///   Shared symbols only resolve among accepted currencies, so accepting one of them resolves the symbol.
+/// - [`strict`](DynMoneyParserBuilder::strict): rejects amounts with more decimal places than the currency's
+///   minor unit instead of rounding them like [`DynMoney::new_with_curr`].
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{MoneyError, macros::dec};
+/// use moneylib::obj_money::{DynMoneyParser, ObjMoney};
+///
+/// let parser = DynMoneyParser::builder()
+///     .separators(".", ",")
+///     .currencies(&["EUR", "CAD"])
+///     .strict(true)
+///     .build()
+///     .unwrap();
+///
+/// let money = parser.parse("$1.234,56").unwrap();
+/// assert_eq!(money.code(), "CAD");
+/// assert_eq!(money.amount(), dec!(1234.56));
+/// assert_eq!(parser.parse("EUR -0,5").unwrap().amount(), dec!(-0.5));
+///
+/// assert!(matches!(parser.parse("USD 1,00"), Err(MoneyError::ObjMoneyError(_))));
+/// assert!(matches!(parser.parse("EUR 1,005"), Err(MoneyError::MinorUnitError(_))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynMoneyParser {
+    separators: Option<(String, String)>,
+    grouping: Grouping,
+    currencies: Vec<DynCurrency>,
+    strict: bool,
+    symbols: SymbolParser,
+}
+
+/// Builder of [`DynMoneyParser`], created with [`DynMoneyParser::builder`].
+///
+/// Options are validated by [`build`](Self::build).
+#[derive(Debug, Clone, Default)]
+pub struct DynMoneyParserBuilder {
+    separators: Option<Separators>,
+    grouping: Grouping,
+    currencies: Vec<String>,
+    strict: bool,
+    preferences: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+enum Separators {
+    #[cfg(feature = "locale")]
+    Locale(String),
+    Explicit(String, String),
+}
+
+impl DynMoneyParserBuilder {
+    /// Parses amounts with thousand and decimal separators of `locale`, e.g. `"de-DE"` for `1.234,56`.
+    ///
+    /// Replaces separators set before.
+    #[cfg(feature = "locale")]
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.separators = Some(Separators::Locale(locale.to_string()));
+        self
+    }
+
+    /// Parses amounts with `thousand_separator` and `decimal_separator`.
+    ///
+    /// Replaces separators set before.
+    pub fn separators(mut self, thousand_separator: &str, decimal_separator: &str) -> Self {
+        self.separators = Some(Separators::Explicit(
+            thousand_separator.to_string(),
+            decimal_separator.to_string(),
+        ));
+        self
+    }
+
+    /// Parses amounts with digit groups of `grouping`, e.g. [`Grouping::INDIAN`] for `1,23,456.78`.
+    pub fn grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Accepts only currencies of `codes`, replacing ones set before. Empty accepts all registered currencies.
+    pub fn currencies(mut self, codes: &[&str]) -> Self {
+        self.currencies = codes.iter().map(|code| code.to_string()).collect();
+        self
+    }
+
+    /// Rejects amounts with more decimal places than the currency's minor unit if `strict`, instead of rounding them.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Appends currency `code` into the parser's preference order of `symbol`, see [`SymbolParser::prefer`].
+    pub fn prefer(mut self, symbol: &str, code: &str) -> Self {
+        self.preferences
+            .push((symbol.to_string(), code.to_string()));
+        self
+    }
+
+    /// Builds the parser.
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::ParseLocale`] if the locale is invalid.
+    /// - [`MoneyError::ObjMoneyError`] if an accepted or preferred currency is not registered,
+    ///   or a preferred currency's symbol doesn't match.
+    pub fn build(self) -> Result<DynMoneyParser, MoneyError> {
+        let separators = match self.separators {
+            #[cfg(feature = "locale")]
+            Some(Separators::Locale(locale)) => Some(crate::fmt::locale_separators(&locale)?),
+            Some(Separators::Explicit(thousand, decimal)) => Some((thousand, decimal)),
+            None => None,
+        };
+
+        let mut currencies: Vec<DynCurrency> = vec![];
+        for code in &self.currencies {
+            let currency = Context::get_currency(code).ok_or_else(|| {
+                MoneyError::ObjMoneyError(format!("currency {} not found", code).into())
+            })?;
+            if !currencies.contains(&currency) {
+                currencies.push(currency);
+            }
+        }
+
+        let mut symbols = SymbolParser::new();
+        for (symbol, code) in &self.preferences {
+            symbols = symbols.prefer(symbol, code)?;
+        }
+
+        Ok(DynMoneyParser {
+            separators,
+            grouping: self.grouping,
+            currencies,
+            strict: self.strict,
+            symbols,
+        })
+    }
+}
+
+impl DynMoneyParser {
+    /// Creates builder of parser with each currency's separators, all registered currencies accepted and rounding amounts.
+    pub fn builder() -> DynMoneyParserBuilder {
+        DynMoneyParserBuilder::default()
+    }
+
+    /// Parses money string `s` in code format, e.g. `"USD 1,234.56"`, or symbol format, e.g. `"-$1,234.56"`.
+    ///
+    /// Negative amounts may also be in parentheses, e.g. `"USD (1,234.56)"` or `"($1,234.56)"`.
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::ParseStrError`] if `s` has no registered code or symbol, or its amount is invalid.
+    /// - [`MoneyError::ObjMoneyError`] if the currency is not accepted.
+    /// - [`MoneyError::AmbiguousSymbolError`] if the symbol is shared by accepted currencies and no preference resolves it.
+    /// - [`MoneyError::MinorUnitError`] if strict and the amount has more decimal places than the currency's minor unit.
+    pub fn parse(&self, s: &str) -> Result<DynMoney, MoneyError> {
+        let trimmed = s.trim();
+        // currency is detected inside parentheses of negative amounts, e.g. `(USD 1,234.56)`.
+        let (unenclosed, _) = crate::parse::strip_parentheses(trimmed);
+        let code = unenclosed
+            .split_once(char::is_whitespace)
+            .map(|(code, _)| code)
+            .filter(|code| Context::is_currency_exist(code));
+
+        let (currency, amount) = match code {
+            Some(code) => {
+                let currency = self.accepted(code)?;
+                let (thousand_separator, decimal_separator) = self.separators_of(&currency);
+                let amount = crate::parse::parse_str_code_with(
+                    trimmed,
+                    currency.code,
+                    thousand_separator,
+                    decimal_separator,
+                    self.grouping,
+                )?;
+                (currency, amount)
+            }
+            None => {
+                let unsigned = unenclosed.strip_prefix('-').unwrap_or(unenclosed);
+                let symbol = Context::find_symbol_prefix(unsigned).ok_or_else(|| {
+                    MoneyError::ParseStrError(
+                        format!("no currency code or symbol found in {:?}", s).into(),
+                    )
+                })?;
+                let currency = self.symbols.resolve_among(symbol, |curr| {
+                    self.currencies.is_empty() || self.currencies.contains(curr)
+                })?;
+                let (thousand_separator, decimal_separator) = self.separators_of(&currency);
+                let amount = crate::parse::parse_str_symbol_with(
+                    trimmed,
+                    currency.symbol,
+                    thousand_separator,
+                    decimal_separator,
+                    self.grouping,
+                )?;
+                (currency, amount)
+            }
+        };
+
+        let amount = Decimal::from_str(&amount)
+            .map_err(|err| MoneyError::ParseStrError(err.to_string().into()))?;
+        if self.strict && amount.normalize().scale() > u32::from(currency.minor_unit) {
+            return Err(MoneyError::MinorUnitError(
+                format!(
+                    "amount {} has more decimal places than {}'s minor unit of {}",
+                    amount, currency.code, currency.minor_unit
+                )
+                .into(),
+            ));
+        }
+
+        Ok(DynMoney::new_with_curr(currency, amount))
+    }
+
+    /// Returns `true` if currency `code` is accepted.
+    pub fn accepts(&self, code: &str) -> bool {
+        self.accepted(code).is_ok()
+    }
+
+    fn accepted(&self, code: &str) -> Result<DynCurrency, MoneyError> {
+        if self.currencies.is_empty() {
+            return Context::get_currency(code).ok_or_else(|| {
+                MoneyError::ObjMoneyError(format!("currency {} not found", code).into())
+            });
+        }
+
+        self.currencies
+            .iter()
+            .find(|curr| curr.code == code)
+            .copied()
+            .ok_or_else(|| {
+                MoneyError::ObjMoneyError(format!("currency {} is not accepted", code).into())
+            })
+    }
+
+    fn separators_of<'a>(&'a self, currency: &'a DynCurrency) -> (&'a str, &'a str) {
+        match &self.separators {
+            Some((thousand, decimal)) => (thousand, decimal),
+            None => (currency.thousand_separator, currency.decimal_separator),
+        }
+    }
+}
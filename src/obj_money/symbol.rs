@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::{Decimal, MoneyError};
+
+use super::{Context, DynCurrency, DynMoney};
+
+/// Parser of symbol-only money strings, e.g. `"$1,234.56"`, into [`DynMoney`].
+///
+/// Symbols can be shared by several currencies, e.g. `"$"` by USD, CAD, AUD, etc. Such symbol is resolved
+/// by, in order:
+///
+/// 1. parser's own preferences, set with [`prefer`](Self::prefer),
+/// 2. global preferences, set with [`Context::prefer_symbol`],
+/// 3. the only currency having the symbol.
+///
+/// Otherwise parsing fails with [`MoneyError::AmbiguousSymbolError`] listing the candidate codes.
+///
+/// Amount is parsed with the resolved currency's separators and rounded like [`DynMoney::new_with_curr`].
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{MoneyError, macros::dec};
+/// use moneylib::obj_money::{ObjMoney, SymbolParser};
+///
+/// let parser = SymbolParser::new().prefer("$", "CAD").unwrap();
+/// let money = parser.parse("$1,234.56").unwrap();
+/// assert_eq!(money.code(), "CAD");
+/// assert_eq!(money.amount(), dec!(1234.56));
+///
+/// // € is only EUR's
+/// assert_eq!(SymbolParser::new().parse("-€10,5").unwrap().amount(), dec!(-10.50));
+///
+/// assert!(matches!(
+///     SymbolParser::new().parse("$10"),
+///     Err(MoneyError::AmbiguousSymbolError(symbol, codes)) if symbol == "$" && codes.contains(&"USD".to_string())
+/// ));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SymbolParser {
+    preferences: HashMap<String, Vec<&'static str>>,
+}
+
+impl SymbolParser {
+    /// Creates parser with no preferences of its own, using only the global ones.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends currency `code` into this parser's preference order of `symbol`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ObjMoneyError`] if `code` is not registered or its symbol is not `symbol`.
+    pub fn prefer(mut self, symbol: &str, code: &str) -> Result<Self, MoneyError> {
+        let currency = checked_currency(symbol, code)?;
+        let codes = self.preferences.entry(symbol.to_string()).or_default();
+        if !codes.contains(&currency.code) {
+            codes.push(currency.code);
+        }
+
+        Ok(self)
+    }
+
+    /// Returns this parser's preference order of `symbol`, empty if none.
+    pub fn preference(&self, symbol: &str) -> &[&'static str] {
+        self.preferences.get(symbol).map_or(&[], Vec::as_slice)
+    }
+
+    /// Parses symbol-only money string `s`, e.g. `"$1,234.56"` or `"-kr10"`.
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::ParseStrError`] if `s` doesn't start with a registered symbol or its amount is invalid.
+    /// - [`MoneyError::AmbiguousSymbolError`] if the symbol is shared and no preference resolves it.
+    pub fn parse(&self, s: &str) -> Result<DynMoney, MoneyError> {
+        let trimmed = s.trim();
+        let unsigned = trimmed.strip_prefix('-').unwrap_or(trimmed);
+        let symbol = Context::find_symbol_prefix(unsigned).ok_or_else(|| {
+            MoneyError::ParseStrError(format!("no currency symbol found in {:?}", s).into())
+        })?;
+        let currency = self.resolve(symbol)?;
+
+        let amount = crate::parse::parse_str_symbol_with(
+            trimmed,
+            currency.symbol,
+            currency.thousand_separator,
+            currency.decimal_separator,
+            crate::Grouping::THOUSANDS,
+        )?;
+        let amount = Decimal::from_str(&amount)
+            .map_err(|err| MoneyError::ParseStrError(err.to_string().into()))?;
+
+        Ok(DynMoney::new_with_curr(currency, amount))
+    }
+
+    /// Resolves currency of `symbol` following parser's, then global preferences.
+    fn resolve(&self, symbol: &str) -> Result<DynCurrency, MoneyError> {
+        self.resolve_among(symbol, |_| true)
+    }
+
+    /// Same as [`resolve`](Self::resolve), considering only currencies `accept`ed.
+    pub(super) fn resolve_among(
+        &self,
+        symbol: &str,
+        accept: impl Fn(&DynCurrency) -> bool,
+    ) -> Result<DynCurrency, MoneyError> {
+        let mut candidates = Context::get_currencies_by_symbol(symbol);
+        candidates.retain(accept);
+        let preferred = self
+            .preference(symbol)
+            .iter()
+            .copied()
+            .chain(Context::symbol_preference(symbol))
+            .find_map(|code| candidates.iter().find(|curr| curr.code == code));
+        if let Some(currency) = preferred {
+            return Ok(*currency);
+        }
+
+        match candidates.as_slice() {
+            [currency] => Ok(*currency),
+            _ => Err(MoneyError::AmbiguousSymbolError(
+                symbol.to_string(),
+                candidates
+                    .iter()
+                    .map(|curr| curr.code.to_string())
+                    .collect(),
+            )),
+        }
+    }
+}
+
+/// Returns registered currency `code`, checking that its symbol is `symbol`.
+pub(super) fn checked_currency(symbol: &str, code: &str) -> Result<DynCurrency, MoneyError> {
+    let currency = Context::get_currency(code)
+        .ok_or_else(|| MoneyError::ObjMoneyError(format!("currency {} not found", code).into()))?;
+    if currency.symbol != symbol {
+        return Err(MoneyError::ObjMoneyError(
+            format!(
+                "currency {} has symbol {}, not {}",
+                code, currency.symbol, symbol
+            )
+            .into(),
+        ));
+    }
+
+    Ok(currency)
+}
@@ -6,8 +6,10 @@ use crate::Money;
 ///
 /// Generates `Add`, `Sub`, `AddAssign`, `SubAssign`, `Neg`, `Add<Decimal>`,
 /// `Sub<Decimal>`, `Mul<Decimal>`, `Div<Decimal>`, `Add<$T<C>> for Decimal`,
-/// `Mul<$T<C>> for Decimal`, and `Rem<Decimal>` impls for `$T<C>` where
-/// `C: Currency`.
+/// `Mul<$T<C>> for Decimal`, and `Rem<Decimal>`/`Rem<i32>`/`Rem<i64>`/`Rem<i128>`
+/// impls for `$T<C>` where `C: Currency`.
+///
+/// All of them except `Neg` can panic, so they're only generated with `panic_ops` feature.
 ///
 /// This is an internal code-generation macro. It is exported only to allow
 /// use across modules within this crate (e.g. for `RawMoney`). Do not call
@@ -30,7 +32,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the addition overflows the internal `Decimal` representation.
-        /// For overflow-safe arithmetic, use [`BaseOps::checked_add`] instead.
+        /// For overflow-safe arithmetic, use [`BaseOps::checked_add`](crate::BaseOps::checked_add) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::Add for $T<C>
         where
             C: $crate::Currency,
@@ -50,7 +53,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the subtraction overflows the internal `Decimal` representation.
-        /// For overflow-safe arithmetic, use [`BaseOps::checked_sub`] instead.
+        /// For overflow-safe arithmetic, use [`BaseOps::checked_sub`](crate::BaseOps::checked_sub) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::Sub for $T<C>
         where
             C: $crate::Currency,
@@ -70,7 +74,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the addition overflows the internal `Decimal` representation.
-        /// For overflow-safe arithmetic, use [`BaseOps::checked_add`] instead.
+        /// For overflow-safe arithmetic, use [`BaseOps::checked_add`](crate::BaseOps::checked_add) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::AddAssign for $T<C>
         where
             C: $crate::Currency,
@@ -88,7 +93,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the subtraction overflows the internal `Decimal` representation.
-        /// For overflow-safe arithmetic, use [`BaseOps::checked_sub`] instead.
+        /// For overflow-safe arithmetic, use [`BaseOps::checked_sub`](crate::BaseOps::checked_sub) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::SubAssign for $T<C>
         where
             C: $crate::Currency,
@@ -118,7 +124,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the addition overflows the internal `Decimal` representation.
-        /// For overflow-safe arithmetic, use [`BaseOps::checked_add`] instead.
+        /// For overflow-safe arithmetic, use [`BaseOps::checked_add`](crate::BaseOps::checked_add) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::Add<$crate::Decimal> for $T<C>
         where
             C: $crate::Currency,
@@ -138,7 +145,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the subtraction overflows the internal `Decimal` representation.
-        /// For overflow-safe arithmetic, use [`BaseOps::checked_sub`] instead.
+        /// For overflow-safe arithmetic, use [`BaseOps::checked_sub`](crate::BaseOps::checked_sub) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::Sub<$crate::Decimal> for $T<C>
         where
             C: $crate::Currency,
@@ -158,7 +166,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the multiplication overflows the internal `Decimal` representation.
-        /// For overflow-safe arithmetic, use [`BaseOps::checked_mul`] instead.
+        /// For overflow-safe arithmetic, use [`BaseOps::checked_mul`](crate::BaseOps::checked_mul) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::Mul<$crate::Decimal> for $T<C>
         where
             C: $crate::Currency,
@@ -178,7 +187,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the division overflows the internal `Decimal` representation or
-        /// if `rhs` is zero. For overflow-safe arithmetic, use [`BaseOps::checked_div`] instead.
+        /// if `rhs` is zero. For overflow-safe arithmetic, use [`BaseOps::checked_div`](crate::BaseOps::checked_div) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::Div<$crate::Decimal> for $T<C>
         where
             C: $crate::Currency,
@@ -198,7 +208,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the addition overflows the internal `Decimal` representation.
-        /// For overflow-safe arithmetic, use [`BaseOps::checked_add`] instead.
+        /// For overflow-safe arithmetic, use [`BaseOps::checked_add`](crate::BaseOps::checked_add) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::Add<$T<C>> for $crate::Decimal
         where
             C: $crate::Currency,
@@ -218,7 +229,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the multiplication overflows the internal `Decimal` representation.
-        /// For overflow-safe arithmetic, use [`BaseOps::checked_mul`] instead.
+        /// For overflow-safe arithmetic, use [`BaseOps::checked_mul`](crate::BaseOps::checked_mul) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::Mul<$T<C>> for $crate::Decimal
         where
             C: $crate::Currency,
@@ -238,7 +250,8 @@ macro_rules! impl_money_ops {
         /// # Panics
         ///
         /// Panics if the remainder operation overflows or if `rhs` is zero.
-        /// For overflow-safe arithmetic, use [`BaseOps::checked_rem`] instead.
+        /// For overflow-safe arithmetic, use [`BaseOps::checked_rem`](crate::BaseOps::checked_rem) instead.
+        #[cfg(feature = "panic_ops")]
         impl<C> ::std::ops::Rem<$crate::Decimal> for $T<C>
         where
             C: $crate::Currency,
@@ -252,6 +265,32 @@ macro_rules! impl_money_ops {
                 <$T<C> as $crate::BaseMoney<C>>::from_decimal(ret)
             }
         }
+
+        $crate::impl_money_ops!(@rem $T, i32, i64, i128);
+    };
+    (@rem $T:ident, $($int:ty),+) => {
+        $(
+            /// M % n = M
+            ///
+            /// # Panics
+            ///
+            /// Panics if `rhs` is zero or out of range of `Decimal`.
+            /// For overflow-safe arithmetic, use [`BaseOps::checked_rem`](crate::BaseOps::checked_rem) instead.
+            #[cfg(feature = "panic_ops")]
+            impl<C> ::std::ops::Rem<$int> for $T<C>
+            where
+                C: $crate::Currency,
+            {
+                type Output = $T<C>;
+
+                fn rem(self, rhs: $int) -> Self::Output {
+                    let ret = $crate::BaseMoney::amount(&self)
+                        .checked_rem($crate::Decimal::from(rhs))
+                        .expect("remainder operation failed");
+                    <$T<C> as $crate::BaseMoney<C>>::from_decimal(ret)
+                }
+            }
+        )+
     };
 }
 
@@ -0,0 +1,309 @@
+//! overflow contains configurable overflow policy for money operators.
+//!
+//! Operators on [`Money`](crate::Money) and [`RawMoney`](crate::RawMoney) panic on overflow and division by zero.
+//! Wrapping money inside [`Guarded`] lets callers choose what happens instead through [`OverflowPolicy`].
+//! The panicking operators can be compiled out entirely by disabling the default `panic_ops` feature.
+
+use crate::{BaseMoney, Currency, Decimal, MoneyError};
+
+/// Policy applied by [`Guarded`] operators when an operation overflows or divides by zero.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{Guarded, OverflowPolicy, Money, BaseMoney, Decimal, money, dec, iso::USD};
+///
+/// let max = Money::<USD>::from_decimal(Decimal::MAX);
+///
+/// let saturated = Guarded::new(max, OverflowPolicy::Saturate) + money!(USD, 1);
+/// assert_eq!(saturated.into_result().unwrap(), max);
+///
+/// let errored = Guarded::new(max, OverflowPolicy::Error) + money!(USD, 1);
+/// assert!(errored.into_result().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Panics, same as operators on money.
+    #[default]
+    Panic,
+
+    /// Clamps at [`Decimal::MAX`]/[`Decimal::MIN`].
+    ///
+    /// Division or remainder by zero cannot be clamped, so it's recorded as [`MoneyError::DivisionByZeroError`].
+    Saturate,
+
+    /// Records [`MoneyError::OverflowError`] or [`MoneyError::DivisionByZeroError`] and skips all subsequent operations.
+    Error,
+}
+
+/// Money with [`OverflowPolicy`] applied to its operators.
+///
+/// Supported operators: `+`, `-` with money, guarded money, and `Decimal`; `*`, `/`, `%` with `Decimal`; `-` (negation); `+=`, `-=` with money.
+///
+/// With [`OverflowPolicy::Error`], the first failure is kept and returned by [`Guarded::into_result`],
+/// so a chain of operators never panics.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{Guarded, OverflowPolicy, MoneyError, money, dec};
+///
+/// let price = Guarded::new(money!(USD, 100), OverflowPolicy::Error);
+/// let total = (price + money!(USD, 20)) * dec!(2) / dec!(4);
+/// assert_eq!(total.into_result().unwrap(), money!(USD, 60));
+///
+/// // Division by zero doesn't panic.
+/// let price = Guarded::new(money!(USD, 100), OverflowPolicy::Error);
+/// let total = price / dec!(0) + money!(USD, 20);
+/// assert!(matches!(total.into_result(), Err(MoneyError::DivisionByZeroError)));
+/// ```
+#[derive(Debug)]
+pub struct Guarded<M> {
+    value: Result<M, MoneyError>,
+    policy: OverflowPolicy,
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl<M> Guarded<M> {
+    /// Wraps `money` with `policy`.
+    pub fn new(money: M, policy: OverflowPolicy) -> Self {
+        Self {
+            value: Ok(money),
+            policy,
+        }
+    }
+
+    /// Returns the policy of this money.
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Returns `true` if no operation has failed so far.
+    pub fn is_ok(&self) -> bool {
+        self.value.is_ok()
+    }
+
+    /// Returns reference to the money, or the error of the first failed operation.
+    pub fn as_result(&self) -> Result<&M, &MoneyError> {
+        self.value.as_ref()
+    }
+
+    /// Returns the money, or the error of the first failed operation.
+    pub fn into_result(self) -> Result<M, MoneyError> {
+        self.value
+    }
+
+    fn apply<C>(mut self, op: Op, rhs: Decimal) -> Self
+    where
+        M: BaseMoney<C>,
+        C: Currency,
+    {
+        self.apply_assign(op, rhs);
+        self
+    }
+
+    fn apply_assign<C>(&mut self, op: Op, rhs: Decimal)
+    where
+        M: BaseMoney<C>,
+        C: Currency,
+    {
+        if let Ok(money) = &self.value {
+            self.value = compute(self.policy, op, money.amount(), rhs).map(M::from_decimal);
+        }
+    }
+
+    fn apply_guarded<C>(self, op: Op, rhs: Guarded<M>) -> Self
+    where
+        M: BaseMoney<C>,
+        C: Currency,
+    {
+        match (&self.value, rhs.value) {
+            (Ok(_), Ok(rhs)) => self.apply(op, rhs.amount()),
+            (Ok(_), Err(err)) => Self {
+                value: Err(err),
+                policy: self.policy,
+            },
+            (Err(_), _) => self,
+        }
+    }
+}
+
+fn compute(
+    policy: OverflowPolicy,
+    op: Op,
+    lhs: Decimal,
+    rhs: Decimal,
+) -> Result<Decimal, MoneyError> {
+    let ret = match op {
+        Op::Add => lhs.checked_add(rhs),
+        Op::Sub => lhs.checked_sub(rhs),
+        Op::Mul => lhs.checked_mul(rhs),
+        Op::Div => lhs.checked_div(rhs),
+        Op::Rem => lhs.checked_rem(rhs),
+    };
+    if let Some(ret) = ret {
+        return Ok(ret);
+    }
+
+    let is_division = matches!(op, Op::Div | Op::Rem);
+    match policy {
+        OverflowPolicy::Panic => panic!(
+            "{}",
+            match op {
+                Op::Add => "addition operation overflow",
+                Op::Sub => "subtraction operation overflow",
+                Op::Mul => "multiplication operation overflow",
+                Op::Div => "division operation overflow",
+                Op::Rem => "remainder operation failed",
+            }
+        ),
+        _ if is_division && rhs.is_zero() => Err(MoneyError::DivisionByZeroError),
+        OverflowPolicy::Error => Err(MoneyError::OverflowError),
+        OverflowPolicy::Saturate => Ok(match op {
+            Op::Add => lhs.saturating_add(rhs),
+            Op::Sub => lhs.saturating_sub(rhs),
+            Op::Mul => lhs.saturating_mul(rhs),
+            Op::Div | Op::Rem => {
+                if lhs.is_sign_negative() == rhs.is_sign_negative() {
+                    Decimal::MAX
+                } else {
+                    Decimal::MIN
+                }
+            }
+        }),
+    }
+}
+
+macro_rules! impl_guarded_ops {
+    ($T:ident) => {
+        /// G + M = G
+        impl<C: Currency> ::std::ops::Add<$T<C>> for Guarded<$T<C>> {
+            type Output = Self;
+
+            fn add(self, rhs: $T<C>) -> Self::Output {
+                self.apply(Op::Add, rhs.amount())
+            }
+        }
+
+        /// G - M = G
+        impl<C: Currency> ::std::ops::Sub<$T<C>> for Guarded<$T<C>> {
+            type Output = Self;
+
+            fn sub(self, rhs: $T<C>) -> Self::Output {
+                self.apply(Op::Sub, rhs.amount())
+            }
+        }
+
+        /// G + G = G
+        impl<C: Currency> ::std::ops::Add for Guarded<$T<C>> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                self.apply_guarded(Op::Add, rhs)
+            }
+        }
+
+        /// G - G = G
+        impl<C: Currency> ::std::ops::Sub for Guarded<$T<C>> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.apply_guarded(Op::Sub, rhs)
+            }
+        }
+
+        /// G + d = G
+        impl<C: Currency> ::std::ops::Add<Decimal> for Guarded<$T<C>> {
+            type Output = Self;
+
+            fn add(self, rhs: Decimal) -> Self::Output {
+                self.apply(Op::Add, rhs)
+            }
+        }
+
+        /// G - d = G
+        impl<C: Currency> ::std::ops::Sub<Decimal> for Guarded<$T<C>> {
+            type Output = Self;
+
+            fn sub(self, rhs: Decimal) -> Self::Output {
+                self.apply(Op::Sub, rhs)
+            }
+        }
+
+        /// G * d = G
+        impl<C: Currency> ::std::ops::Mul<Decimal> for Guarded<$T<C>> {
+            type Output = Self;
+
+            fn mul(self, rhs: Decimal) -> Self::Output {
+                self.apply(Op::Mul, rhs)
+            }
+        }
+
+        /// G / d = G
+        impl<C: Currency> ::std::ops::Div<Decimal> for Guarded<$T<C>> {
+            type Output = Self;
+
+            fn div(self, rhs: Decimal) -> Self::Output {
+                self.apply(Op::Div, rhs)
+            }
+        }
+
+        /// G % d = G
+        impl<C: Currency> ::std::ops::Rem<Decimal> for Guarded<$T<C>> {
+            type Output = Self;
+
+            fn rem(self, rhs: Decimal) -> Self::Output {
+                self.apply(Op::Rem, rhs)
+            }
+        }
+
+        /// -G = G
+        impl<C: Currency> ::std::ops::Neg for Guarded<$T<C>> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self {
+                    value: self.value.map(|m| -m),
+                    policy: self.policy,
+                }
+            }
+        }
+
+        /// G += M
+        impl<C: Currency> ::std::ops::AddAssign<$T<C>> for Guarded<$T<C>> {
+            fn add_assign(&mut self, rhs: $T<C>) {
+                self.apply_assign(Op::Add, rhs.amount());
+            }
+        }
+
+        /// G -= M
+        impl<C: Currency> ::std::ops::SubAssign<$T<C>> for Guarded<$T<C>> {
+            fn sub_assign(&mut self, rhs: $T<C>) {
+                self.apply_assign(Op::Sub, rhs.amount());
+            }
+        }
+
+        impl<C: Currency> From<$T<C>> for Guarded<$T<C>> {
+            /// Wraps money with default policy, [`OverflowPolicy::Panic`].
+            fn from(money: $T<C>) -> Self {
+                Self::new(money, OverflowPolicy::default())
+            }
+        }
+    };
+}
+
+use crate::Money;
+impl_guarded_ops!(Money);
+
+#[cfg(feature = "raw_money")]
+use crate::RawMoney;
+#[cfg(feature = "raw_money")]
+impl_guarded_ops!(RawMoney);
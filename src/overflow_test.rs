@@ -0,0 +1,108 @@
+use crate::iso::{JPY, USD};
+use crate::macros::{dec, money, raw};
+use crate::{BaseMoney, Decimal, Guarded, Money, MoneyError, OverflowPolicy, RawMoney};
+
+#[test]
+fn test_guarded_ops_without_overflow() {
+    for policy in [
+        OverflowPolicy::Panic,
+        OverflowPolicy::Saturate,
+        OverflowPolicy::Error,
+    ] {
+        let mut g = Guarded::new(money!(USD, 10), policy);
+        g += money!(USD, 5);
+        g -= money!(USD, 1);
+        let g = -(g * dec!(3) / dec!(2) + dec!(0.005) - dec!(1));
+        let g = g + Guarded::new(money!(USD, 100), policy) - money!(USD, 50);
+        let g = g % dec!(7);
+        assert_eq!(g.policy(), policy);
+        assert!(g.is_ok());
+        assert_eq!(g.into_result().unwrap().amount(), dec!(2));
+    }
+}
+
+#[test]
+fn test_guarded_default_policy() {
+    assert_eq!(OverflowPolicy::default(), OverflowPolicy::Panic);
+    let g: Guarded<Money<USD>> = money!(USD, 1).into();
+    assert_eq!(g.policy(), OverflowPolicy::Panic);
+    assert_eq!(*g.as_result().unwrap(), money!(USD, 1));
+}
+
+#[test]
+#[should_panic(expected = "addition operation overflow")]
+fn test_guarded_panic_policy_overflow() {
+    let _ = Guarded::new(
+        Money::<USD>::from_decimal(Decimal::MAX),
+        OverflowPolicy::Panic,
+    ) + money!(USD, 1);
+}
+
+#[test]
+#[should_panic(expected = "division operation overflow")]
+fn test_guarded_panic_policy_division_by_zero() {
+    let _ = Guarded::new(money!(USD, 1), OverflowPolicy::Panic) / dec!(0);
+}
+
+#[test]
+fn test_guarded_saturate_policy() {
+    let max = Money::<USD>::from_decimal(Decimal::MAX);
+    let min = Money::<USD>::from_decimal(Decimal::MIN);
+    let g = |m| Guarded::new(m, OverflowPolicy::Saturate);
+
+    assert_eq!((g(max) + money!(USD, 1)).into_result().unwrap(), max);
+    assert_eq!((g(min) - money!(USD, 1)).into_result().unwrap(), min);
+    assert_eq!((g(max) * dec!(-2)).into_result().unwrap(), min);
+    assert_eq!((g(max) / dec!(0.1)).into_result().unwrap(), max);
+    assert_eq!((g(max) / dec!(-0.1)).into_result().unwrap(), min);
+
+    // saturated value continues to be used
+    let ret = (g(max) + money!(USD, 1) - money!(USD, 1))
+        .into_result()
+        .unwrap();
+    assert_eq!(ret.amount(), Decimal::MAX - dec!(1));
+
+    let ret = (g(money!(USD, 1)) / dec!(0)).into_result();
+    assert!(matches!(ret, Err(MoneyError::DivisionByZeroError)));
+    let ret = (g(money!(USD, 1)) % dec!(0)).into_result();
+    assert!(matches!(ret, Err(MoneyError::DivisionByZeroError)));
+}
+
+#[test]
+fn test_guarded_error_policy() {
+    let max = Money::<JPY>::from_decimal(Decimal::MAX);
+    let g = |m| Guarded::new(m, OverflowPolicy::Error);
+
+    let ret = g(max) + money!(JPY, 1);
+    assert!(!ret.is_ok());
+    assert!(matches!(ret.as_result(), Err(MoneyError::OverflowError)));
+
+    // first error is kept
+    let ret = (g(money!(JPY, 1)) / dec!(0)) * dec!(2) + max + max;
+    assert!(matches!(
+        ret.into_result(),
+        Err(MoneyError::DivisionByZeroError)
+    ));
+
+    // error from right-hand side is propagated
+    let ret = g(money!(JPY, 1)) + (g(max) * dec!(2));
+    assert!(matches!(ret.into_result(), Err(MoneyError::OverflowError)));
+
+    let mut ret = g(max);
+    ret += money!(JPY, 1);
+    ret -= money!(JPY, 1);
+    assert!(matches!(ret.into_result(), Err(MoneyError::OverflowError)));
+}
+
+#[test]
+fn test_guarded_raw_money() {
+    let g = Guarded::new(raw!(USD, 1), OverflowPolicy::Error) / dec!(3);
+    assert_eq!(
+        g.into_result().unwrap().amount(),
+        dec!(0.3333333333333333333333333333)
+    );
+
+    let max = RawMoney::<USD>::from_decimal(Decimal::MAX);
+    let g = Guarded::new(max, OverflowPolicy::Saturate) + raw!(USD, 1);
+    assert_eq!(g.into_result().unwrap(), max);
+}
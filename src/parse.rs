@@ -1,4 +1,44 @@
-use crate::{Currency, MoneyError};
+use std::str::FromStr;
+
+use crate::currency::{Grouping, ParseLocale, ParseOptions};
+use crate::fmt::{
+    AMOUNT_FORMAT_SYMBOL, CODE_FORMAT_SYMBOL, ESCAPE_SYMBOL, FORMAT_SYMBOLS, MINOR_FORMAT_SYMBOL,
+    NEGATIVE_FORMAT_SYMBOL, POSITIVE_FORMAT_SYMBOL, SYMBOL_FORMAT_SYMBOL,
+    contains_active_format_symbol,
+};
+use crate::{Currency, Decimal, MoneyError};
+
+/// Maximum length in bytes of inputs accepted by parsers.
+pub(crate) const MAX_INPUT_LEN: usize = 256;
+
+/// Maximum number of ASCII digits in inputs accepted by parsers, [`Decimal`] holds at most 29 significant digits.
+pub(crate) const MAX_DIGITS: usize = 64;
+
+/// Rejects `input` longer than [`MAX_INPUT_LEN`] or with more digits than [`MAX_DIGITS`], before any parsing,
+/// so hostile inputs fail fast.
+pub(crate) fn check_input_len(input: &[u8]) -> Result<(), MoneyError> {
+    if input.len() > MAX_INPUT_LEN {
+        return Err(MoneyError::InputTooLong(
+            format!(
+                "{} bytes, more than maximum of {} bytes",
+                input.len(),
+                MAX_INPUT_LEN
+            )
+            .into(),
+        ));
+    }
+    let digits = input.iter().filter(|byte| byte.is_ascii_digit()).count();
+    if digits > MAX_DIGITS {
+        return Err(MoneyError::InputTooLong(
+            format!(
+                "{} digits, more than maximum of {} digits",
+                digits, MAX_DIGITS
+            )
+            .into(),
+        ));
+    }
+    Ok(())
+}
 
 /// Validate and build string amount.
 /// Thousand separators removed, and decimal separator use dot.
@@ -7,6 +47,7 @@ fn parse_into_string_amount<'a>(
     integer_part: &'a str,
     decimal_part: Option<&'a str>,
     thousand_separator: &'a str,
+    grouping: Grouping,
     is_negative: bool,
 ) -> Result<String, MoneyError> {
     if integer_part.is_empty() {
@@ -18,18 +59,24 @@ fn parse_into_string_amount<'a>(
         // Validate separator-separated format
         let groups: Vec<&str> = integer_part.split(thousand_separator).collect();
 
-        // First group can be 1-3 digits
+        // First group can be 1 up to secondary size digits
         if groups[0].is_empty()
-            || groups[0].len() > 3
+            || groups[0].len() > grouping.secondary()
             || !groups[0].chars().all(|c| c.is_ascii_digit())
         {
-            return Err(MoneyError::ParseStrError(format!("first group of integer part is empty or more than 3 digits or not all ascii numbers: {}", integer_part).into()));
+            return Err(MoneyError::ParseStrError(format!("first group of integer part is empty or more than {} digits or not all ascii numbers: {}", grouping.secondary(), integer_part).into()));
         }
 
-        // All subsequent groups must be exactly 3 digits
-        for group in groups.iter().skip(1) {
-            if group.len() != 3 || !group.chars().all(|c| c.is_ascii_digit()) {
-                return Err(MoneyError::ParseStrError(format!("second and subsequent parts of integer is not 3 digits or not all ascii numbers: {}", integer_part).into()));
+        // Last group must be exactly primary size digits, groups between it and the first exactly secondary size
+        let last = groups.len() - 1;
+        for (i, group) in groups.iter().enumerate().skip(1) {
+            let size = if i == last {
+                grouping.primary()
+            } else {
+                grouping.secondary()
+            };
+            if group.len() != size || !group.chars().all(|c| c.is_ascii_digit()) {
+                return Err(MoneyError::ParseStrError(format!("second and subsequent parts of integer is not {} digits or not all ascii numbers: {}", size, integer_part).into()));
             }
         }
 
@@ -82,6 +129,299 @@ fn parse_into_string_amount<'a>(
     }
 }
 
+/// Returns `s` without enclosing parentheses, and `true` if it had them, e.g. `(1,234.56)` of accounting negatives.
+pub(crate) fn strip_parentheses(s: &str) -> (&str, bool) {
+    match s
+        .strip_prefix('(')
+        .and_then(|inner| inner.strip_suffix(')'))
+    {
+        Some(inner) => (inner, true),
+        None => (s, false),
+    }
+}
+
+/// Returns `s` without leading `-`, and `true` if it had it.
+fn strip_minus(s: &str) -> (&str, bool) {
+    match s.strip_prefix('-') {
+        Some(unsigned) => (unsigned, true),
+        None => (s, false),
+    }
+}
+
+/// Returns `true` if amount of `s` is negated by one of `negations`, e.g. `-` or enclosing parentheses.
+///
+/// Amounts negated more than once, e.g. `(-1,234.56)`, are rejected rather than read as positive.
+fn is_negated(s: &str, negations: &[bool]) -> Result<bool, MoneyError> {
+    match negations.iter().filter(|negated| **negated).count() {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(MoneyError::ParseStrError(
+            format!("amount is negated more than once: {}", s).into(),
+        )),
+    }
+}
+
+/// Parse plain number, e.g. `-1234.56`, or negative number in parentheses, e.g. `(1234.56)`.
+pub(crate) fn parse_number(s: &str) -> Result<Decimal, MoneyError> {
+    check_input_len(s.as_bytes())?;
+    let (number, is_enclosed) = strip_parentheses(s.trim());
+    // `-` is parsed with the number, so it's only checked against parentheses.
+    let (_, is_minus) = strip_minus(number);
+    is_negated(s, &[is_enclosed, is_minus])?;
+    let amount = Decimal::from_str(number).map_err(|err| {
+        MoneyError::ParseStrError(format!("failed parsing money from string: {}", err).into())
+    })?;
+    Ok(if is_enclosed { -amount } else { amount })
+}
+
+/// Parse plain number like [`parse_number`], or money string with symbol `<SYMBOL><AMOUNT>` with currency's separators
+/// if it starts with the currency's symbol after the sign, e.g. `$1,234.56` or `-$1,234.56`.
+pub(crate) fn parse_number_or_symbol<C: Currency>(s: &str) -> Result<Decimal, MoneyError> {
+    let unsigned = s.trim().trim_start_matches(['-', '(']);
+    if !unsigned.starts_with(C::SYMBOL) {
+        return parse_number(s);
+    }
+
+    let amount = parse_str_symbol::<C>(s, C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR)?;
+    Decimal::from_str(&amount).map_err(|err| {
+        MoneyError::ParseStrError(format!("failed parsing money from string: {}", err).into())
+    })
+}
+
+/// Parse ASCII bytes of plain amount, e.g. `-1234.56` or `(1234.56)`, optionally after currency code `code` and
+/// whitespace, e.g. `USD -1234.56`, without allocating.
+pub(crate) fn parse_ascii<'a>(bytes: &'a [u8], code: &str) -> Result<Decimal, MoneyError> {
+    check_input_len(bytes)?;
+    let invalid = |reason: &str| {
+        MoneyError::ParseStrError(
+            format!(
+                "{}: {}",
+                reason,
+                String::from_utf8_lossy(bytes).escape_debug()
+            )
+            .into(),
+        )
+    };
+
+    let strip_parentheses = |bytes: &'a [u8]| match bytes
+        .strip_prefix(b"(")
+        .and_then(|inner| inner.strip_suffix(b")"))
+    {
+        Some(inner) => (inner, true),
+        None => (bytes, false),
+    };
+
+    let (amount, is_enclosed) = strip_parentheses(bytes.trim_ascii());
+    let amount = match amount.strip_prefix(code.as_bytes()) {
+        Some(rest) if rest.first().is_some_and(u8::is_ascii_whitespace) => rest.trim_ascii_start(),
+        _ => {
+            // other currency's code, e.g. "EUR 12.34" for USD.
+            let code_len = amount
+                .iter()
+                .position(|byte| !byte.is_ascii_alphabetic())
+                .unwrap_or(amount.len());
+            if let Some((other_code, rest)) = amount.split_at_checked(code_len)
+                && !other_code.is_empty()
+                && rest.first().is_some_and(u8::is_ascii_whitespace)
+            {
+                return Err(MoneyError::CurrencyMismatchError(
+                    String::from_utf8_lossy(other_code).into(),
+                    code.into(),
+                ));
+            }
+            amount
+        }
+    };
+    let (amount, is_amount_enclosed) = strip_parentheses(amount);
+    let (amount, is_minus) = match amount.split_first() {
+        Some((b'-', unsigned)) => (unsigned, true),
+        Some((b'+', unsigned)) => (unsigned, false),
+        _ => (amount, false),
+    };
+    let negations = [is_enclosed, is_amount_enclosed, is_minus];
+    let is_negative = match negations.iter().filter(|negated| **negated).count() {
+        0 => false,
+        1 => true,
+        _ => return Err(invalid("amount is negated more than once")),
+    };
+
+    let mut mantissa: i128 = 0;
+    let mut scale: u32 = 0;
+    let mut digits = 0;
+    let mut is_fraction = false;
+    for &byte in amount {
+        match byte {
+            b'0'..=b'9' => {
+                mantissa = mantissa
+                    .checked_mul(10)
+                    .and_then(|mantissa| mantissa.checked_add(i128::from(byte - b'0')))
+                    .ok_or_else(|| invalid("amount is too large"))?;
+                if is_fraction {
+                    scale = scale.saturating_add(1);
+                }
+                digits += 1;
+            }
+            b'.' if !is_fraction && digits > 0 => is_fraction = true,
+            _ => {
+                return Err(invalid(
+                    "amount is not ascii digits with optional decimal point",
+                ));
+            }
+        }
+    }
+    if digits == 0 || (is_fraction && scale == 0) {
+        return Err(invalid("integer or decimal part is empty"));
+    }
+
+    let mantissa = if is_negative { -mantissa } else { mantissa };
+    Decimal::try_from_i128_with_scale(mantissa, scale)
+        .map_err(|_| invalid("amount doesn't fit decimal"))
+}
+
+/// Parse money string with `options`, in code format `<CODE> <AMOUNT>`, symbol format `<SYMBOL><AMOUNT>` or plain
+/// amount, each negated with `-` or enclosing parentheses.
+pub(crate) fn parse_with_options<C: Currency>(
+    money_str: &str,
+    options: &ParseOptions,
+) -> Result<Decimal, MoneyError> {
+    check_input_len(money_str.as_bytes())?;
+    let trimmed = money_str.trim();
+    if !options.allow_parentheses && trimmed.contains(['(', ')']) {
+        return Err(MoneyError::ParseStrError(
+            format!("parentheses are not allowed: {}", money_str).into(),
+        ));
+    }
+
+    let exponent = split_exponent(trimmed);
+    if exponent.is_some() && !options.allow_exponent {
+        return Err(MoneyError::ParseStrError(
+            format!("scientific notation is not allowed: {}", money_str).into(),
+        ));
+    }
+    let trimmed = exponent
+        .as_ref()
+        .map_or(trimmed, |(mantissa, _)| mantissa.as_str());
+
+    let thousand_separator = options
+        .thousand_separator
+        .as_deref()
+        .unwrap_or(C::THOUSAND_SEPARATOR);
+    let decimal_separator = options
+        .decimal_separator
+        .as_deref()
+        .unwrap_or(C::DECIMAL_SEPARATOR);
+
+    let (unenclosed, is_enclosed) = strip_parentheses(trimmed);
+    let code = unenclosed
+        .split_once(char::is_whitespace)
+        .filter(|(code, _)| !code.is_empty() && code.chars().all(|c| c.is_ascii_alphabetic()));
+    let has_symbol = strip_minus(unenclosed).0.starts_with(C::SYMBOL);
+
+    // plain amount is parsed as symbol format with empty symbol.
+    let parse_amount = |amount_str: &str, symbol: &str| {
+        parse_str_symbol_with(
+            amount_str,
+            symbol,
+            thousand_separator,
+            decimal_separator,
+            options.grouping,
+        )
+        .map_err(|err| match err {
+            MoneyError::CurrencyMismatchError(..) if symbol.is_empty() => {
+                MoneyError::ParseStrError(format!("amount is empty: {}", money_str).into())
+            }
+            err => err,
+        })
+    };
+
+    let amount = if let Some((code, amount_str)) = code {
+        if code != C::CODE {
+            return Err(MoneyError::CurrencyMismatchError(
+                code.into(),
+                C::CODE.into(),
+            ));
+        }
+        // amount may have thousand separator of whitespace, so it's everything after the code.
+        let amount = parse_amount(amount_str, "")?;
+        let (unsigned, is_minus) = strip_minus(&amount);
+        if is_negated(money_str, &[is_enclosed, is_minus])? {
+            format!("-{}", unsigned)
+        } else {
+            amount
+        }
+    } else if options.require_code {
+        return Err(MoneyError::ParseStrError(
+            format!("currency code {} is required: {}", C::CODE, money_str).into(),
+        ));
+    } else if has_symbol && !options.allow_symbol {
+        return Err(MoneyError::ParseStrError(
+            format!(
+                "currency symbol {} is not allowed: {}",
+                C::SYMBOL,
+                money_str
+            )
+            .into(),
+        ));
+    } else {
+        parse_amount(trimmed, if has_symbol { C::SYMBOL } else { "" })?
+    };
+
+    let amount = Decimal::from_str(&amount).map_err(|err| {
+        MoneyError::ParseStrError(format!("failed parsing {} into decimal", err).into())
+    })?;
+    let amount = match exponent {
+        Some((_, exponent)) => apply_exponent(amount, exponent, money_str)?,
+        None => amount,
+    };
+    if options.strict {
+        crate::currency::check_minor_unit::<C>(amount)?;
+    }
+    Ok(amount)
+}
+
+/// Splits exponent of scientific notation off `s`, e.g. `1.2e3` into `1.2` and `3`, keeping closing parentheses of
+/// negative amounts, e.g. `(1.2E-3)` into `(1.2)` and `-3`. Returns `None` if `s` has no exponent.
+fn split_exponent(s: &str) -> Option<(String, &str)> {
+    let body = s.trim_end_matches(')');
+    let marker = body.rfind(['e', 'E'])?;
+    let exponent = &body[marker + 1..];
+    let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+    if digits.is_empty()
+        || !digits.chars().all(|c| c.is_ascii_digit())
+        || !body[..marker].ends_with(|c: char| c.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((format!("{}{}", &body[..marker], &s[body.len()..]), exponent))
+}
+
+/// Returns `amount` times 10 to the power of `exponent` exactly, by moving its decimal point.
+fn apply_exponent(amount: Decimal, exponent: &str, s: &str) -> Result<Decimal, MoneyError> {
+    let out_of_range = || {
+        MoneyError::ParseStrError(format!("exponent {} is out of range: {}", exponent, s).into())
+    };
+    let exponent: i64 = exponent.parse().map_err(|_| out_of_range())?;
+    let magnitude = u32::try_from(exponent.unsigned_abs()).map_err(|_| out_of_range())?;
+
+    let mut amount = amount;
+    let scale = if exponent < 0 {
+        amount.scale().checked_add(magnitude)
+    } else {
+        amount.scale().checked_sub(magnitude)
+    };
+    match scale {
+        Some(scale) => amount.set_scale(scale).map_err(|_| out_of_range())?,
+        None => {
+            // moving the decimal point past the integer digits, which can't be rounded off.
+            let factor =
+                crate::decimal::pow10(magnitude - amount.scale()).ok_or_else(out_of_range)?;
+            amount.set_scale(0).map_err(|_| out_of_range())?;
+            amount = amount.checked_mul(factor).ok_or_else(out_of_range)?;
+        }
+    }
+    Ok(amount)
+}
+
 /// Parse money string with code `<CODE> <AMOUNT>`,
 /// where `<CODE>` is currency alpha code.
 ///
@@ -91,7 +431,25 @@ pub(crate) fn parse_str_code<C: Currency>(
     thousand_separator: &str,
     decimal_separator: &str,
 ) -> Result<String, MoneyError> {
-    let str_code = str_code.trim();
+    parse_str_code_with(
+        str_code,
+        C::CODE,
+        thousand_separator,
+        decimal_separator,
+        Grouping::THOUSANDS,
+    )
+}
+
+/// Same as [`parse_str_code`] with `code` and `grouping` given at runtime.
+pub(crate) fn parse_str_code_with(
+    str_code: &str,
+    code: &str,
+    thousand_separator: &str,
+    decimal_separator: &str,
+    grouping: Grouping,
+) -> Result<String, MoneyError> {
+    check_input_len(str_code.as_bytes())?;
+    let (str_code, is_enclosed) = strip_parentheses(str_code.trim());
 
     // Split by space (handles multiple spaces automatically)
     let parts: Vec<&str> = str_code.split_whitespace().collect();
@@ -110,12 +468,12 @@ pub(crate) fn parse_str_code<C: Currency>(
     }
 
     let currency_code = parts[0];
-    let amount_str = parts[1];
+    let (amount_str, is_amount_enclosed) = strip_parentheses(parts[1]);
 
-    if currency_code != C::CODE {
+    if currency_code != code {
         return Err(MoneyError::CurrencyMismatchError(
             currency_code.into(),
-            C::CODE.into(),
+            code.into(),
         ));
     }
 
@@ -131,18 +489,21 @@ pub(crate) fn parse_str_code<C: Currency>(
         ));
     }
 
-    let (integer_part, is_negative) = if let Some(neg_trimmed) = amount_parts[0].strip_prefix("-") {
-        (neg_trimmed, true)
-    } else {
-        (amount_parts[0], false)
-    };
+    let (integer_part, is_minus) = strip_minus(amount_parts[0]);
+    let is_negative = is_negated(str_code, &[is_enclosed, is_amount_enclosed, is_minus])?;
     let decimal_part = if amount_parts.len() == 2 {
         Some(amount_parts[1])
     } else {
         None
     };
 
-    parse_into_string_amount(integer_part, decimal_part, thousand_separator, is_negative)
+    parse_into_string_amount(
+        integer_part,
+        decimal_part,
+        thousand_separator,
+        grouping,
+        is_negative,
+    )
 }
 
 /// parse money string with symbol `<SYMBOL><AMOUNT>`,
@@ -154,14 +515,28 @@ pub(crate) fn parse_str_symbol<C: Currency>(
     thousand_separator: &str,
     decimal_separator: &str,
 ) -> Result<String, MoneyError> {
-    let str_symbol = str_symbol.trim();
+    parse_str_symbol_with(
+        str_symbol,
+        C::SYMBOL,
+        thousand_separator,
+        decimal_separator,
+        Grouping::THOUSANDS,
+    )
+}
 
-    let (abs_money, is_negative) = if let Some(trimmed) = str_symbol.strip_prefix('-') {
-        (trimmed, true)
-    } else {
-        (str_symbol, false)
-    };
-    let amount_str = abs_money.strip_prefix(C::SYMBOL);
+/// Same as [`parse_str_symbol`] with `symbol` and `grouping` given at runtime.
+pub(crate) fn parse_str_symbol_with(
+    str_symbol: &str,
+    symbol: &str,
+    thousand_separator: &str,
+    decimal_separator: &str,
+    grouping: Grouping,
+) -> Result<String, MoneyError> {
+    check_input_len(str_symbol.as_bytes())?;
+    let (str_symbol, is_enclosed) = strip_parentheses(str_symbol.trim());
+
+    let (abs_money, is_minus) = strip_minus(str_symbol);
+    let amount_str = abs_money.strip_prefix(symbol);
     let amount_str = if let Some(amount) = amount_str
         && !amount.is_empty()
     {
@@ -169,9 +544,15 @@ pub(crate) fn parse_str_symbol<C: Currency>(
     } else {
         return Err(MoneyError::CurrencyMismatchError(
             str_symbol.into(),
-            C::SYMBOL.into(),
+            symbol.into(),
         ));
     };
+    let (amount_str, is_amount_enclosed) = strip_parentheses(amount_str);
+    let (amount_str, is_amount_minus) = strip_minus(amount_str);
+    let is_negative = is_negated(
+        str_symbol,
+        &[is_enclosed, is_minus, is_amount_enclosed, is_amount_minus],
+    )?;
 
     let amount_parts: Vec<&str> = amount_str.split(decimal_separator).collect();
     // splitting amount part by decimal point must have at most 2 parts(integer and decimal).
@@ -191,5 +572,281 @@ pub(crate) fn parse_str_symbol<C: Currency>(
         (amount_parts[0], None)
     };
 
-    parse_into_string_amount(integer_part, decimal_part, thousand_separator, is_negative)
+    parse_into_string_amount(
+        integer_part,
+        decimal_part,
+        thousand_separator,
+        grouping,
+        is_negative,
+    )
+}
+
+// Returns currency code or symbol before or after `amount_str`, e.g. `"EUR"` of `"EUR 1 234,56"` and `"€"` of
+// `"1 234,56 €"`, not counting `thousand_separators` of the amount.
+fn currency_affix<'a>(amount_str: &'a str, thousand_separators: &[char]) -> Option<&'a str> {
+    let is_currency_char = |ch: char| {
+        ch.is_alphabetic()
+            || ch == '$'
+            || (!ch.is_ascii()
+                && !ch.is_whitespace()
+                && !ch.is_numeric()
+                && !thousand_separators.contains(&ch))
+    };
+
+    let prefix_end = amount_str
+        .find(|ch| !is_currency_char(ch))
+        .unwrap_or(amount_str.len());
+    if prefix_end > 0 {
+        return amount_str.get(..prefix_end);
+    }
+
+    let suffix_start = amount_str
+        .char_indices()
+        .rev()
+        .find(|(_, ch)| !is_currency_char(*ch))
+        .map_or(0, |(i, ch)| i + ch.len_utf8());
+    amount_str
+        .get(suffix_start..)
+        .filter(|suffix| !suffix.is_empty())
+}
+
+/// Parse money string typed in number convention `locale`, e.g. `1 234,56` or `1'234.56`, with optional currency
+/// code or symbol before or after the amount, e.g. `EUR 1 234,56` or `1 234,56 €`.
+///
+/// It returns string amount without thousand separator and with dot decimal separator.
+pub(crate) fn parse_locale<C: Currency>(
+    money_str: &str,
+    locale: ParseLocale,
+) -> Result<String, MoneyError> {
+    check_input_len(money_str.as_bytes())?;
+    let (trimmed, is_enclosed) = strip_parentheses(money_str.trim());
+    let (unsigned, is_minus) = strip_minus(trimmed);
+
+    let amount_str = [C::CODE, C::SYMBOL].iter().find_map(|currency| {
+        unsigned
+            .strip_prefix(currency)
+            .map(str::trim_start)
+            .or_else(|| unsigned.strip_suffix(currency).map(str::trim_end))
+    });
+    let amount_str = match amount_str {
+        Some(amount_str) => amount_str,
+        None => {
+            if let Some(currency) = currency_affix(unsigned, locale.thousand_separators()) {
+                return Err(MoneyError::CurrencyMismatchError(
+                    currency.into(),
+                    C::CODE.into(),
+                ));
+            }
+            unsigned
+        }
+    };
+    let (amount_str, is_amount_enclosed) = strip_parentheses(amount_str);
+    let (amount_str, is_amount_minus) = strip_minus(amount_str);
+    let is_negative = is_negated(
+        money_str,
+        &[is_enclosed, is_minus, is_amount_enclosed, is_amount_minus],
+    )?;
+
+    let separators = locale.thousand_separators();
+    let canonical: String = separators[..1].iter().collect();
+    let amount_str = amount_str.replace(separators, &canonical);
+    let (integer_part, decimal_part) = match amount_str.split_once(locale.decimal_separator()) {
+        Some((integer, decimal)) => (integer, Some(decimal)),
+        None => (amount_str.as_str(), None),
+    };
+
+    parse_into_string_amount(
+        integer_part,
+        decimal_part,
+        &canonical,
+        locale.grouping(),
+        is_negative,
+    )
+    .map_err(|err| {
+        MoneyError::ParseStrError(
+            format!("invalid amount {:?} in {:?}: {}", money_str, locale, err).into(),
+        )
+    })
+}
+
+/// Parse money string formatted with `format_str`, the same format string used by
+/// [`crate::MoneyFormatter::format`], e.g. `"a c"` for `1,234.56 USD`.
+///
+/// Literal text, code, symbol and minor symbol must appear exactly as formatted. `n` and `p` match
+/// an optional `-` and `+`. If `m` is in the format, the amount is parsed as minor amount.
+///
+/// It returns decimal amount.
+pub(crate) fn parse_str_format<C: Currency>(
+    money_str: &str,
+    format_str: &str,
+    thousand_separator: &str,
+    decimal_separator: &str,
+) -> Result<Decimal, MoneyError> {
+    check_input_len(money_str.as_bytes())?;
+    let mut input = money_str.trim();
+    let mut amount_str: Option<&str> = None;
+    let mut is_negative = false;
+
+    let mut chars = format_str.chars().peekable();
+    while let Some(ch) = chars.next() {
+        let literal = if ch == ESCAPE_SYMBOL {
+            match chars.peek() {
+                Some('{') => {
+                    chars.next(); // consume '{'
+                    let mut block = String::new();
+                    for inner_ch in chars.by_ref() {
+                        if inner_ch == '}' {
+                            break;
+                        }
+                        block.push(inner_ch);
+                    }
+                    block
+                }
+                Some(&next_ch) if FORMAT_SYMBOLS.contains(&next_ch) || next_ch == ESCAPE_SYMBOL => {
+                    chars.next();
+                    next_ch.to_string()
+                }
+                _ => ch.to_string(),
+            }
+        } else {
+            match ch {
+                AMOUNT_FORMAT_SYMBOL => {
+                    let len = amount_len(input, thousand_separator, decimal_separator);
+                    if len == 0 {
+                        return Err(MoneyError::ParseStrError(
+                            format!("expected amount, found: {}", money_str).into(),
+                        ));
+                    }
+                    let (amount, rest) = input.split_at(len);
+                    if amount_str.is_some_and(|prev| prev != amount) {
+                        return Err(MoneyError::ParseStrError(
+                            format!("amounts in {} are different", money_str).into(),
+                        ));
+                    }
+                    amount_str = Some(amount);
+                    input = rest;
+                    continue;
+                }
+                CODE_FORMAT_SYMBOL => {
+                    input = input.strip_prefix(C::CODE).ok_or_else(|| {
+                        MoneyError::CurrencyMismatchError(money_str.into(), C::CODE.into())
+                    })?;
+                    continue;
+                }
+                SYMBOL_FORMAT_SYMBOL => {
+                    input = input.strip_prefix(C::SYMBOL).ok_or_else(|| {
+                        MoneyError::CurrencyMismatchError(money_str.into(), C::SYMBOL.into())
+                    })?;
+                    continue;
+                }
+                MINOR_FORMAT_SYMBOL => C::MINOR_UNIT_SYMBOL.to_string(),
+                NEGATIVE_FORMAT_SYMBOL => {
+                    if let Some(rest) = input.strip_prefix('-') {
+                        is_negative = true;
+                        input = rest;
+                    }
+                    continue;
+                }
+                POSITIVE_FORMAT_SYMBOL => {
+                    input = input.strip_prefix('+').unwrap_or(input);
+                    continue;
+                }
+                _ => ch.to_string(),
+            }
+        };
+
+        input = input.strip_prefix(literal.as_str()).ok_or_else(|| {
+            MoneyError::ParseStrError(
+                format!(
+                    "expected {:?} in {}, with format {}",
+                    literal, money_str, format_str
+                )
+                .into(),
+            )
+        })?;
+    }
+
+    if !input.is_empty() {
+        return Err(MoneyError::ParseStrError(
+            format!(
+                "unexpected {:?} at the end of {}, with format {}",
+                input, money_str, format_str
+            )
+            .into(),
+        ));
+    }
+
+    let Some(amount_str) = amount_str else {
+        return Err(MoneyError::ParseStrError(
+            format!("format {} has no amount", format_str).into(),
+        ));
+    };
+
+    if contains_active_format_symbol(format_str, MINOR_FORMAT_SYMBOL) {
+        let minor = parse_into_string_amount(
+            amount_str,
+            None,
+            thousand_separator,
+            Grouping::THOUSANDS,
+            is_negative,
+        )?;
+        let mut amount = Decimal::from_str(&minor).map_err(|err| {
+            MoneyError::ParseStrError(format!("failed parsing {} into decimal", err).into())
+        })?;
+        amount
+            .set_scale(C::MINOR_UNIT.into())
+            .map_err(|err| MoneyError::ParseStrError(err.to_string().into()))?;
+        return Ok(amount);
+    }
+
+    let amount_parts: Vec<&str> = amount_str.split(decimal_separator).collect();
+    // splitting amount part by decimal point must have at most 2 parts(integer and decimal).
+    if amount_parts.len() > 2 {
+        return Err(MoneyError::ParseStrError(
+            format!(
+                "splitting by decimal separator({}) must not more than 2 parts: {}",
+                decimal_separator, amount_str
+            )
+            .into(),
+        ));
+    }
+    let decimal_part = amount_parts.get(1).copied();
+    let amount = parse_into_string_amount(
+        amount_parts[0],
+        decimal_part,
+        thousand_separator,
+        Grouping::THOUSANDS,
+        is_negative,
+    )?;
+
+    Decimal::from_str(&amount).map_err(|err| {
+        MoneyError::ParseStrError(format!("failed parsing {} into decimal", err).into())
+    })
+}
+
+/// Length of amount at the start of `input`, digits with separators between them.
+fn amount_len(input: &str, thousand_separator: &str, decimal_separator: &str) -> usize {
+    let starts_with_digit = |s: &str| s.starts_with(|c: char| c.is_ascii_digit());
+
+    let mut len = 0;
+    loop {
+        let rest = &input[len..];
+        if starts_with_digit(rest) {
+            len += 1;
+            continue;
+        }
+        if len == 0 {
+            break;
+        }
+        let separator = [thousand_separator, decimal_separator]
+            .into_iter()
+            .find(|sep| {
+                !sep.is_empty() && rest.starts_with(sep) && starts_with_digit(&rest[sep.len()..])
+            });
+        match separator {
+            Some(sep) => len += sep.len(),
+            None => break,
+        }
+    }
+    len
 }
@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+use proptest::prelude::*;
+
+use crate::iso::{BHD, CHF, EUR, INR, JPY, USD};
+use crate::{BaseMoney, Currency, Grouping, Money, MoneyFormatter, MoneyParser, ParseLocale};
+
+// Minor amounts up to about 10^17 major units, wide enough to cross many digit groups.
+fn minor_amounts() -> impl Strategy<Value = i128> {
+    prop_oneof![
+        -1_000i128..1_000,
+        -100_000_000_000_000_000_000i128..100_000_000_000_000_000_000,
+    ]
+}
+
+fn roundtrip_code_and_symbol<C: Currency>(minor: i128) -> Result<(), TestCaseError> {
+    let money = Money::<C>::from_minor(minor).unwrap();
+
+    let code = money.format_code();
+    prop_assert_eq!(
+        &Money::<C>::from_str_code(&code).unwrap(),
+        &money,
+        "{}",
+        code
+    );
+
+    let symbol = money.format_symbol();
+    prop_assert_eq!(
+        &Money::<C>::from_str_symbol(&symbol).unwrap(),
+        &money,
+        "{}",
+        symbol
+    );
+    prop_assert_eq!(
+        &Money::<C>::from_str(&symbol).unwrap(),
+        &money,
+        "{}",
+        symbol
+    );
+
+    let accounting = money.format("(sa)");
+    if money.is_negative() {
+        prop_assert_eq!(
+            &Money::<C>::from_str_symbol(&accounting).unwrap(),
+            &money,
+            "{}",
+            accounting
+        );
+    }
+
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn prop_roundtrip_code_and_symbol(minor in minor_amounts()) {
+        roundtrip_code_and_symbol::<USD>(minor)?;
+        roundtrip_code_and_symbol::<EUR>(minor)?;
+        roundtrip_code_and_symbol::<JPY>(minor)?;
+        roundtrip_code_and_symbol::<BHD>(minor)?;
+        roundtrip_code_and_symbol::<CHF>(minor)?;
+    }
+
+    #[test]
+    fn prop_roundtrip_plain_number(minor in minor_amounts()) {
+        let money = Money::<USD>::from_minor(minor).unwrap();
+        prop_assert_eq!(Money::<USD>::from_str(&money.amount().to_string()).unwrap(), money);
+    }
+
+    #[test]
+    fn prop_roundtrip_grouping(minor in minor_amounts()) {
+        let money = Money::<INR>::from_minor(minor).unwrap();
+        let formatted = money.format_with_grouping("nsa", ",", ".", Grouping::INDIAN);
+        prop_assert_eq!(
+            Money::<INR>::from_str_symbol_with_grouping(&formatted, ",", ".", Grouping::INDIAN).unwrap(),
+            money,
+            "{}",
+            formatted
+        );
+    }
+
+    #[test]
+    fn prop_roundtrip_locale(minor in minor_amounts()) {
+        let money = Money::<EUR>::from_minor(minor).unwrap();
+        for (locale, thousand_separator, decimal_separator) in [
+            (ParseLocale::English, ",", "."),
+            (ParseLocale::German, ".", ","),
+            (ParseLocale::Swiss, "'", "."),
+        ] {
+            let formatted = money.format_with_separator("na c", thousand_separator, decimal_separator);
+            prop_assert_eq!(
+                Money::<EUR>::parse_locale(&formatted, locale).unwrap(),
+                money,
+                "{}",
+                formatted
+            );
+        }
+    }
+
+    #[test]
+    fn prop_parse_never_panics(s in "\\PC{0,24}") {
+        let _ = Money::<USD>::from_str(&s);
+        let _ = Money::<USD>::from_str_code(&s);
+        let _ = Money::<USD>::from_str_symbol(&s);
+        let _ = Money::<USD>::from_str_format(&s, "nsa");
+        let _ = Money::<INR>::from_str_symbol_with_grouping(&s, ",", ".", Grouping::INDIAN);
+        let _ = Money::<EUR>::parse_locale(&s, ParseLocale::French);
+    }
+
+    #[test]
+    fn prop_parse_never_panics_near_valid(
+        s in "[-(]{0,2}(\\$|USD )?[-(]{0,2}[0-9,.]{0,12}[)]{0,2}"
+    ) {
+        let _ = Money::<USD>::from_str(&s);
+        let _ = Money::<USD>::from_str_code(&s);
+        let _ = Money::<USD>::from_str_symbol(&s);
+    }
+}
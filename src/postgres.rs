@@ -0,0 +1,77 @@
+//! postgres contains [`ToSql`] and [`FromSql`] implementations mapping money into Postgres `NUMERIC` columns,
+//! for [tokio-postgres](https://crates.io/crates/tokio-postgres) and [postgres](https://crates.io/crates/postgres).
+//!
+//! Implementations delegate to [`Decimal`]'s, encoding the binary `NUMERIC` wire format directly
+//! instead of round-tripping through strings. `Option` of money maps nullable columns.
+//! Only the amount is stored, the currency comes from the type.
+//!
+//! Reading `Money<C>` fails on amounts with more decimal places than `C`'s minor unit, see
+//! [storage invariants](crate#storage). Reading `RawMoney<C>` keeps the amount as is.
+//!
+//! ```ignore
+//! use moneylib::{Money, iso::USD, money};
+//!
+//! client
+//!     .execute("UPDATE invoices SET total = $1 WHERE id = $2", &[&money!(USD, 12.34), &id])
+//!     .await?;
+//!
+//! let row = client.query_one("SELECT total FROM invoices WHERE id = $1", &[&id]).await?;
+//! let total: Money<USD> = row.get("total");
+//! ```
+
+use std::error::Error;
+
+use ::bytes::BytesMut;
+use ::postgres_types::{FromSql, IsNull, ToSql, Type, to_sql_checked};
+
+use crate::{BaseMoney, Currency, Decimal, Money};
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+impl<C: Currency> ToSql for Money<C> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        self.amount().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Decimal as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a, C: Currency> FromSql<'a> for Money<C> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        let amount = Decimal::from_sql(ty, raw)?;
+        crate::currency::check_minor_unit::<C>(amount)?;
+        Ok(Self::from_decimal(amount))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Decimal as FromSql>::accepts(ty)
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> ToSql for crate::RawMoney<C> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        self.amount().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Decimal as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+#[cfg(feature = "raw_money")]
+impl<'a, C: Currency> FromSql<'a> for crate::RawMoney<C> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        Ok(Self::from_decimal(Decimal::from_sql(ty, raw)?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Decimal as FromSql>::accepts(ty)
+    }
+}
@@ -0,0 +1,89 @@
+use ::bytes::BytesMut;
+use ::postgres_types::{FromSql, IsNull, ToSql, Type};
+
+use crate::iso::{JPY, USD};
+use crate::{BaseMoney, Decimal, Money, MoneyError, macros::dec, money};
+
+fn encode(value: &dyn ToSql, ty: &Type) -> Vec<u8> {
+    let mut buf = BytesMut::new();
+    assert!(matches!(
+        value.to_sql_checked(ty, &mut buf).unwrap(),
+        IsNull::No
+    ));
+    buf.to_vec()
+}
+
+fn numeric(amount: Decimal) -> Vec<u8> {
+    encode(&amount, &Type::NUMERIC)
+}
+
+#[test]
+fn test_accepts() {
+    assert!(<Money<USD> as ToSql>::accepts(&Type::NUMERIC));
+    assert!(<Money<USD> as FromSql>::accepts(&Type::NUMERIC));
+    assert!(!<Money<USD> as ToSql>::accepts(&Type::TEXT));
+    assert!(!<Money<USD> as FromSql>::accepts(&Type::FLOAT8));
+}
+
+#[test]
+fn test_to_sql() {
+    assert_eq!(
+        encode(&money!(USD, 1234.56), &Type::NUMERIC),
+        numeric(dec!(1234.56))
+    );
+    assert_eq!(
+        encode(&money!(JPY, -1500), &Type::NUMERIC),
+        numeric(dec!(-1500))
+    );
+
+    // wrong column type is rejected by checked encoding.
+    let mut buf = BytesMut::new();
+    assert!(
+        money!(USD, 1)
+            .to_sql_checked(&Type::TEXT, &mut buf)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_from_sql() {
+    let raw = numeric(dec!(1234.5600));
+    let money = Money::<USD>::from_sql(&Type::NUMERIC, &raw).unwrap();
+    assert_eq!(money.amount(), dec!(1234.56));
+
+    let raw = numeric(dec!(-0.05));
+    let money = Money::<USD>::from_sql(&Type::NUMERIC, &raw).unwrap();
+    assert_eq!(money, money!(USD, -0.05));
+
+    let nullable = Option::<Money<USD>>::from_sql_null(&Type::NUMERIC).unwrap();
+    assert!(nullable.is_none());
+    assert!(Money::<USD>::from_sql_null(&Type::NUMERIC).is_err());
+}
+
+#[test]
+fn test_from_sql_too_precise() {
+    let raw = numeric(dec!(1.234));
+    let err = Money::<USD>::from_sql(&Type::NUMERIC, &raw).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<MoneyError>(),
+        Some(MoneyError::MinorUnitError(_))
+    ));
+
+    let raw = numeric(dec!(1.5));
+    assert!(Money::<JPY>::from_sql(&Type::NUMERIC, &raw).is_err());
+}
+
+#[cfg(feature = "raw_money")]
+#[test]
+fn test_raw_money() {
+    use crate::{RawMoney, raw};
+
+    assert_eq!(
+        encode(&raw!(USD, 1.23456), &Type::NUMERIC),
+        numeric(dec!(1.23456))
+    );
+
+    let raw_bytes = numeric(dec!(1.23456));
+    let money = RawMoney::<USD>::from_sql(&Type::NUMERIC, &raw_bytes).unwrap();
+    assert_eq!(money.amount(), dec!(1.23456));
+}
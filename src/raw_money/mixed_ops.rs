@@ -0,0 +1,208 @@
+//! Mixed arithmetic and comparisons between [`Money`] and [`RawMoney`] of the same currency.
+//!
+//! Results are always [`RawMoney`] keeping full precision, nothing is rounded, so calculation pipelines can mix both
+//! without extracting amounts. Round back with [`RawMoney::finish`] or [`RawMoney::to_money`].
+//!
+//! Only addition and subtraction are defined between two amounts of money, multiply or divide by [`Decimal`](crate::Decimal) instead.
+
+use std::cmp::Ordering;
+
+use crate::{BaseMoney, Currency, Money};
+
+use super::RawMoney;
+
+impl<C> Money<C>
+where
+    C: Currency,
+{
+    /// Adds `rhs` to this money without rounding, returning `None` on overflow.
+    ///
+    /// Unlike [`BaseOps::checked_add`](crate::BaseOps::checked_add) which returns `Money` rounded into minor unit,
+    /// this keeps the precision of `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, raw};
+    ///
+    /// assert_eq!(money!(USD, 100).checked_add_raw(raw!(USD, 0.125)), Some(raw!(USD, 100.125)));
+    /// ```
+    #[inline]
+    pub fn checked_add_raw(&self, rhs: RawMoney<C>) -> Option<RawMoney<C>> {
+        self.amount()
+            .checked_add(rhs.amount())
+            .map(RawMoney::from_decimal)
+    }
+
+    /// Subtracts `rhs` from this money without rounding, returning `None` on overflow.
+    ///
+    /// Unlike [`BaseOps::checked_sub`](crate::BaseOps::checked_sub) which returns `Money` rounded into minor unit,
+    /// this keeps the precision of `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{money, raw};
+    ///
+    /// assert_eq!(money!(USD, 100).checked_sub_raw(raw!(USD, 0.125)), Some(raw!(USD, 99.875)));
+    /// ```
+    #[inline]
+    pub fn checked_sub_raw(&self, rhs: RawMoney<C>) -> Option<RawMoney<C>> {
+        self.amount()
+            .checked_sub(rhs.amount())
+            .map(RawMoney::from_decimal)
+    }
+}
+
+/// M + R = R
+///
+/// # Panics
+///
+/// Panics if the addition overflows the internal `Decimal` representation.
+/// For overflow-safe arithmetic, use [`Money::checked_add_raw`] instead.
+#[cfg(feature = "panic_ops")]
+impl<C> ::std::ops::Add<RawMoney<C>> for Money<C>
+where
+    C: Currency,
+{
+    type Output = RawMoney<C>;
+
+    fn add(self, rhs: RawMoney<C>) -> Self::Output {
+        self.checked_add_raw(rhs)
+            .expect("addition operation overflow")
+    }
+}
+
+/// M - R = R
+///
+/// # Panics
+///
+/// Panics if the subtraction overflows the internal `Decimal` representation.
+/// For overflow-safe arithmetic, use [`Money::checked_sub_raw`] instead.
+#[cfg(feature = "panic_ops")]
+impl<C> ::std::ops::Sub<RawMoney<C>> for Money<C>
+where
+    C: Currency,
+{
+    type Output = RawMoney<C>;
+
+    fn sub(self, rhs: RawMoney<C>) -> Self::Output {
+        self.checked_sub_raw(rhs)
+            .expect("subtraction operation overflow")
+    }
+}
+
+/// R + M = R
+///
+/// # Panics
+///
+/// Panics if the addition overflows the internal `Decimal` representation.
+/// For overflow-safe arithmetic, use [`RawMoney::checked_add`] instead.
+#[cfg(feature = "panic_ops")]
+impl<C> ::std::ops::Add<Money<C>> for RawMoney<C>
+where
+    C: Currency,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Money<C>) -> Self::Output {
+        self.checked_add(rhs).expect("addition operation overflow")
+    }
+}
+
+/// R - M = R
+///
+/// # Panics
+///
+/// Panics if the subtraction overflows the internal `Decimal` representation.
+/// For overflow-safe arithmetic, use [`RawMoney::checked_sub`] instead.
+#[cfg(feature = "panic_ops")]
+impl<C> ::std::ops::Sub<Money<C>> for RawMoney<C>
+where
+    C: Currency,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Money<C>) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("subtraction operation overflow")
+    }
+}
+
+/// R += M
+///
+/// # Panics
+///
+/// Panics if the addition overflows the internal `Decimal` representation.
+/// For overflow-safe arithmetic, use [`RawMoney::checked_add`] instead.
+#[cfg(feature = "panic_ops")]
+impl<C> ::std::ops::AddAssign<Money<C>> for RawMoney<C>
+where
+    C: Currency,
+{
+    fn add_assign(&mut self, other: Money<C>) {
+        let ret = self
+            .amount()
+            .checked_add(other.amount())
+            .expect("addition operation overflow");
+        *self = Self::from_decimal(ret);
+    }
+}
+
+/// R -= M
+///
+/// # Panics
+///
+/// Panics if the subtraction overflows the internal `Decimal` representation.
+/// For overflow-safe arithmetic, use [`RawMoney::checked_sub`] instead.
+#[cfg(feature = "panic_ops")]
+impl<C> ::std::ops::SubAssign<Money<C>> for RawMoney<C>
+where
+    C: Currency,
+{
+    fn sub_assign(&mut self, other: Money<C>) {
+        let ret = self
+            .amount()
+            .checked_sub(other.amount())
+            .expect("subtraction operation overflow");
+        *self = Self::from_decimal(ret);
+    }
+}
+
+/// Compares amounts by value regardless of scale, e.g. `money!(USD, 1.5) == raw!(USD, 1.500)`.
+impl<C> PartialEq<RawMoney<C>> for Money<C>
+where
+    C: Currency,
+{
+    fn eq(&self, other: &RawMoney<C>) -> bool {
+        self.amount() == other.amount()
+    }
+}
+
+/// Compares amounts by value regardless of scale, e.g. `raw!(USD, 1.500) == money!(USD, 1.5)`.
+impl<C> PartialEq<Money<C>> for RawMoney<C>
+where
+    C: Currency,
+{
+    fn eq(&self, other: &Money<C>) -> bool {
+        self.amount() == other.amount()
+    }
+}
+
+impl<C> PartialOrd<RawMoney<C>> for Money<C>
+where
+    C: Currency,
+{
+    fn partial_cmp(&self, other: &RawMoney<C>) -> Option<Ordering> {
+        Some(self.amount().cmp(&other.amount()))
+    }
+}
+
+impl<C> PartialOrd<Money<C>> for RawMoney<C>
+where
+    C: Currency,
+{
+    fn partial_cmp(&self, other: &Money<C>) -> Option<Ordering> {
+        Some(self.amount().cmp(&other.amount()))
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    BaseMoney, BaseOps, Decimal, Money, RawMoney,
+    iso::USD,
+    macros::{dec, money, raw},
+};
+
+#[test]
+fn test_checked_add_sub_raw() {
+    let money = money!(USD, 100);
+    assert_eq!(
+        money.checked_add_raw(raw!(USD, 0.125)),
+        Some(raw!(USD, 100.125))
+    );
+    assert_eq!(
+        money.checked_sub_raw(raw!(USD, 0.125)),
+        Some(raw!(USD, 99.875))
+    );
+    assert_eq!(
+        money!(JPY, 100).checked_sub_raw(raw!(JPY, 0.5)),
+        Some(raw!(JPY, 99.5))
+    );
+
+    assert_eq!(
+        money.checked_add_raw(RawMoney::from_decimal(Decimal::MAX)),
+        None
+    );
+    assert_eq!(
+        money!(USD, -100).checked_sub_raw(RawMoney::from_decimal(Decimal::MAX)),
+        None
+    );
+
+    // BaseOps keeps the type of self, rounding into minor unit for Money.
+    assert_eq!(
+        BaseOps::checked_add(&money, raw!(USD, 0.125))
+            .unwrap()
+            .amount(),
+        dec!(100.12)
+    );
+    assert_eq!(
+        raw!(USD, 0.125).checked_add(money).unwrap().amount(),
+        dec!(100.125)
+    );
+}
+
+#[cfg(feature = "panic_ops")]
+#[test]
+fn test_mixed_operators() {
+    let money = money!(USD, 100);
+    let raw = raw!(USD, 0.125);
+
+    let ret: RawMoney<USD> = money + raw;
+    assert_eq!(ret.amount(), dec!(100.125));
+    let ret: RawMoney<USD> = money - raw;
+    assert_eq!(ret.amount(), dec!(99.875));
+    let ret: RawMoney<USD> = raw + money;
+    assert_eq!(ret.amount(), dec!(100.125));
+    let ret: RawMoney<USD> = raw - money;
+    assert_eq!(ret.amount(), dec!(-99.875));
+
+    let mut total = raw!(USD, 0.001);
+    total += money!(USD, 10);
+    total -= money!(USD, 2.5);
+    assert_eq!(total.amount(), dec!(7.501));
+
+    // pipeline mixing both, rounded once at the end
+    let tax = money * dec!(0.0725) / dec!(3);
+    let total = money.into_raw() + tax.into_raw() - money!(USD, 1);
+    assert_eq!(total.finish(), money!(USD, 101.42));
+}
+
+#[cfg(feature = "panic_ops")]
+#[test]
+#[should_panic(expected = "addition operation overflow")]
+fn test_mixed_add_overflow() {
+    let _ = money!(USD, 1) + RawMoney::<USD>::from_decimal(Decimal::MAX);
+}
+
+#[cfg(feature = "panic_ops")]
+#[test]
+#[should_panic(expected = "subtraction operation overflow")]
+fn test_mixed_sub_assign_overflow() {
+    let mut raw = RawMoney::<USD>::from_decimal(Decimal::MIN);
+    raw -= money!(USD, 1);
+}
+
+#[test]
+fn test_mixed_comparisons() {
+    assert_eq!(money!(USD, 1.5), raw!(USD, 1.500));
+    assert_eq!(raw!(USD, 1.500), money!(USD, 1.5));
+    assert_ne!(money!(USD, 1.5), raw!(USD, 1.501));
+    assert_ne!(raw!(USD, 1.501), money!(USD, 1.5));
+
+    assert!(money!(USD, 1.5) < raw!(USD, 1.501));
+    assert!(raw!(USD, 1.501) > money!(USD, 1.5));
+    assert!(money!(USD, 1.5) >= raw!(USD, 1.5));
+    assert!(raw!(USD, -1) <= Money::<USD>::default());
+}
@@ -4,5 +4,16 @@ pub use raw_money::RawMoney;
 
 mod money_ext;
 
+mod mixed_ops;
+
+mod tracked;
+pub use tracked::TrackedMoney;
+
 #[cfg(test)]
 mod raw_money_test;
+
+#[cfg(test)]
+mod mixed_ops_test;
+
+#[cfg(test)]
+mod tracked_test;
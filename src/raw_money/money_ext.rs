@@ -1,4 +1,4 @@
-use crate::{BaseMoney, Currency, Money};
+use crate::{BaseMoney, Currency, Money, MoneyError, base::DecimalNumber};
 
 use super::RawMoney;
 
@@ -33,4 +33,44 @@ where
     pub fn into_raw(self) -> RawMoney<C> {
         RawMoney::from_decimal(self.amount())
     }
+
+    /// Divides this money by `rhs` without rounding, returning `RawMoney` with full precision.
+    ///
+    /// Unlike [`Money::checked_div`] and `/` which round the quotient into minor unit, so error compounds when the
+    /// result is used in later steps, e.g. rate math. Round once at the end with [`RawMoney::finish`] or
+    /// [`RawMoney::to_money`].
+    ///
+    /// # Errors
+    ///
+    /// - [`MoneyError::DivisionByZeroError`] if `rhs` is zero.
+    /// - [`MoneyError::OverflowError`] if the division overflows or `rhs` can't be converted into `Decimal`, e.g.
+    ///   `f64::NAN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, money, raw, macros::dec};
+    ///
+    /// let third = money!(USD, 100).div_exact(3).unwrap();
+    /// assert_eq!(third.amount(), dec!(33.333333333333333333333333333));
+    /// assert_eq!((third * dec!(3)).finish(), money!(USD, 100));
+    ///
+    /// // rounding mid-calculation loses a cent
+    /// assert_eq!(money!(USD, 100).checked_div(3).unwrap().checked_mul(3).unwrap(), money!(USD, 99.99));
+    ///
+    /// assert!(money!(USD, 100).div_exact(0).is_err());
+    /// ```
+    pub fn div_exact<RHS>(&self, rhs: RHS) -> Result<RawMoney<C>, MoneyError>
+    where
+        RHS: DecimalNumber,
+    {
+        let divisor = rhs.get_decimal().ok_or(MoneyError::OverflowError)?;
+        if divisor.is_zero() {
+            return Err(MoneyError::DivisionByZeroError);
+        }
+        self.amount()
+            .checked_div(divisor)
+            .map(RawMoney::from_decimal)
+            .ok_or(MoneyError::OverflowError)
+    }
 }
@@ -1,17 +1,15 @@
 use std::{
     fmt::{Debug, Display},
-    iter::Sum,
+    hash::{Hash, Hasher},
     marker::PhantomData,
     str::FromStr,
 };
 
 use crate::{
-    BaseMoney, BaseOps, Decimal, Money, MoneyError, MoneyOps,
-    base::{Amount, MoneyParser},
-    macros::dec,
+    BaseMoney, BaseOps, Decimal, Money, MoneyError, MoneyOps, RoundingStrategy,
+    base::{Amount, DecimalNumber, MoneyParser},
 };
 use crate::{Currency, MoneyFormatter};
-use rust_decimal::{MathematicalOps, prelude::ToPrimitive};
 
 /// Represents a monetary value without automatic rounding.
 ///
@@ -29,13 +27,16 @@ use rust_decimal::{MathematicalOps, prelude::ToPrimitive};
 /// # Conversion
 ///
 /// - Convert from `Money` using [`Money::into_raw`]
-/// - Convert to `Money` using [`RawMoney::finish`] (applies rounding)
+/// - Convert to `Money` using [`RawMoney::finish`] or [`RawMoney::to_money`] (applies rounding)
+/// - Convert to `Money` without rounding using [`Money::try_from`], failing if rounding would be needed
 ///
 /// # Where Rounding Happens
 ///
 /// - [`BaseMoney::round`]: rounds using currency's minor unit (bankers rounding). Returns `RawMoney`.
 /// - [`BaseMoney::round_with`]: rounds using custom decimal points and strategy. Returns `RawMoney`.
+/// - [`RawMoney::quantize`] and [`RawMoney::rescale`]: round into exactly given decimal places. Returns `RawMoney`.
 /// - [`RawMoney::finish`]: rounds to currency's minor unit using bankers rounding back to `Money`.
+/// - [`RawMoney::to_money`]: rounds to currency's minor unit using given strategy back to `Money`.
 ///
 /// # Examples
 ///
@@ -57,13 +58,27 @@ use rust_decimal::{MathematicalOps, prelude::ToPrimitive};
 /// assert_eq!(money.amount(), dec!(100.57));
 /// ```
 ///
+/// # Formatting
+///
+/// [`MoneyFormatter`] uses the same pattern language as [`Money`], e.g. `"c na"`, `"nsa"` or `"c a m"`, showing every
+/// decimal place of the amount instead of rounding it, while `m` shows whole minor units like [`BaseMoney::minor_amount`].
+///
+/// ```
+/// use moneylib::{MoneyFormatter, raw};
+///
+/// let raw = raw!(USD, -1234.5678);
+/// assert_eq!(raw.format("c na"), "USD -1,234.5678");
+/// assert_eq!(raw.format("nsa"), "-$1,234.5678");
+/// assert_eq!(raw.format("c na m"), "USD -123,457 ¢");
+/// ```
+///
 /// # See Also
 ///
 /// - [`Money`] for automatically-rounded monetary values
 /// - [`BaseMoney`] trait for core money operations and accessors
 /// - [`BaseOps`] trait for arithmetic and comparison operations
 /// - [`MoneyFormatter`] trait for custom formatting and rounding
-#[derive(Copy, PartialEq, Eq)]
+#[derive(Copy)]
 pub struct RawMoney<C: Currency> {
     amount: Decimal,
     _currency: PhantomData<C>,
@@ -73,6 +88,57 @@ impl<C> RawMoney<C>
 where
     C: Currency,
 {
+    /// Zero money, the canonical zero at currency's scale, e.g. `0.00` for USD and `0` for JPY, same as [`Default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RawMoney, raw, iso::{JPY, USD}};
+    ///
+    /// const NO_FEE: RawMoney<USD> = RawMoney::ZERO;
+    /// assert_eq!(NO_FEE, raw!(USD, 0));
+    /// assert_eq!(NO_FEE.to_string(), "USD 0.00");
+    /// assert_eq!(RawMoney::<JPY>::ZERO.scale(), 0);
+    /// assert!(raw!(USD, 0.01) > RawMoney::ZERO);
+    /// ```
+    pub const ZERO: Self = Self {
+        amount: Decimal::from_parts(0, 0, 0, false, crate::decimal::minor_scale(C::MINOR_UNIT)),
+        _currency: PhantomData,
+    };
+
+    /// Largest money, with amount of [`Decimal::MAX`].
+    ///
+    /// Unlike [`max_representable`](Money::max_representable) it doesn't keep decimal places of the minor unit,
+    /// it's the bound where checked operations overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RawMoney, raw, iso::USD};
+    ///
+    /// assert_eq!(RawMoney::<USD>::MAX.amount(), moneylib::Decimal::MAX);
+    /// assert!(RawMoney::<USD>::MAX.checked_add(raw!(USD, 1)).is_none());
+    /// ```
+    pub const MAX: Self = Self {
+        amount: Decimal::MAX,
+        _currency: PhantomData,
+    };
+
+    /// Smallest(most negative) money, with amount of [`Decimal::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RawMoney, raw, iso::USD};
+    ///
+    /// assert_eq!(RawMoney::<USD>::MIN.amount(), moneylib::Decimal::MIN);
+    /// assert_eq!(-RawMoney::<USD>::MIN, RawMoney::<USD>::MAX);
+    /// ```
+    pub const MIN: Self = Self {
+        amount: Decimal::MIN,
+        _currency: PhantomData,
+    };
+
     /// Converts this `RawMoney` to `Money`, applying rounding.
     ///
     /// Rounds the amount to the currency's minor unit precision using the
@@ -99,21 +165,404 @@ where
     pub fn finish(self) -> Money<C> {
         Money::from_decimal(self.amount)
     }
+
+    /// Converts this `RawMoney` to `Money`, rounding to the currency's minor unit with `strategy`.
+    ///
+    /// Makes the final rounding step of a raw calculation an explicit call site, [`finish`](Self::finish) is the
+    /// same with [`RoundingStrategy::BankersRounding`]. Use [`Money::try_from`] instead to reject amounts needing
+    /// rounding at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{RoundingStrategy, money, raw};
+    ///
+    /// let raw = raw!(USD, 100.125);
+    /// assert_eq!(raw.to_money(RoundingStrategy::BankersRounding), money!(USD, 100.12));
+    /// assert_eq!(raw.to_money(RoundingStrategy::HalfUp), money!(USD, 100.13));
+    /// assert_eq!(raw.to_money(RoundingStrategy::Floor), money!(USD, 100.12));
+    ///
+    /// assert_eq!(raw!(JPY, -100.5).to_money(RoundingStrategy::Down), money!(JPY, -101));
+    /// ```
+    #[inline]
+    pub fn to_money(self, strategy: RoundingStrategy) -> Money<C> {
+        Money::from_decimal(strategy.round_dp(self.amount, C::MINOR_UNIT.into()))
+    }
+
+    /// Rounds the amount up toward positive infinity to the scale of the currency's minor unit, e.g. for fees that
+    /// must round up.
+    ///
+    /// Shorthand for `round_with(C::MINOR_UNIT.into(), RoundingStrategy::Up)`, keeping `RawMoney`. Use
+    /// [`to_money`](Self::to_money) with [`RoundingStrategy::Up`] to finish into `Money` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, raw, macros::dec};
+    ///
+    /// assert_eq!(raw!(USD, 1.231).round_up().amount(), dec!(1.24));
+    /// assert_eq!(raw!(USD, -1.239).round_up().amount(), dec!(-1.23));
+    /// assert_eq!(raw!(JPY, 100.1).round_up().amount(), dec!(101));
+    /// ```
+    #[inline]
+    pub fn round_up(self) -> Self {
+        self.round_with(C::MINOR_UNIT.into(), RoundingStrategy::Up)
+    }
+
+    /// Rounds the amount down toward negative infinity to the scale of the currency's minor unit.
+    ///
+    /// Shorthand for `round_with(C::MINOR_UNIT.into(), RoundingStrategy::Down)`, keeping `RawMoney`. Use
+    /// [`to_money`](Self::to_money) with [`RoundingStrategy::Down`] to finish into `Money` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, raw, macros::dec};
+    ///
+    /// assert_eq!(raw!(USD, 1.239).round_down().amount(), dec!(1.23));
+    /// assert_eq!(raw!(USD, -1.231).round_down().amount(), dec!(-1.24));
+    /// assert_eq!(raw!(JPY, 100.9).round_down().amount(), dec!(100));
+    /// ```
+    #[inline]
+    pub fn round_down(self) -> Self {
+        self.round_with(C::MINOR_UNIT.into(), RoundingStrategy::Down)
+    }
+
+    /// Adds `rhs` to this money, returning `None` on overflow.
+    ///
+    /// Same as [`BaseOps::checked_add`] without requiring the trait in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{raw, macros::dec};
+    ///
+    /// let m = raw!(USD, 100);
+    /// assert_eq!(m.checked_add(dec!(50.25)), Some(raw!(USD, 150.25)));
+    /// assert_eq!(m.checked_add(m), Some(raw!(USD, 200)));
+    /// assert_eq!(m.checked_add(moneylib::Decimal::MAX), None);
+    /// ```
+    #[inline]
+    pub fn checked_add<RHS>(self, rhs: RHS) -> Option<Self>
+    where
+        RHS: Amount<C>,
+    {
+        BaseOps::checked_add(&self, rhs)
+    }
+
+    /// Subtracts `rhs` from this money, returning `None` on overflow.
+    ///
+    /// Same as [`BaseOps::checked_sub`] without requiring the trait in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{raw, macros::dec};
+    ///
+    /// let m = raw!(USD, 100);
+    /// assert_eq!(m.checked_sub(dec!(150)), Some(raw!(USD, -50)));
+    /// assert_eq!(m.checked_sub(moneylib::Decimal::MIN), None);
+    /// ```
+    #[inline]
+    pub fn checked_sub<RHS>(self, rhs: RHS) -> Option<Self>
+    where
+        RHS: Amount<C>,
+    {
+        BaseOps::checked_sub(&self, rhs)
+    }
+
+    /// Multiplies this money by `rhs`, returning `None` on overflow.
+    ///
+    /// Same as [`BaseOps::checked_mul`] without requiring the trait in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{raw, macros::dec};
+    ///
+    /// let m = raw!(USD, 10);
+    /// assert_eq!(m.checked_mul(3), Some(raw!(USD, 30)));
+    /// assert_eq!(m.checked_mul(moneylib::Decimal::MAX), None);
+    /// ```
+    #[inline]
+    pub fn checked_mul<RHS>(self, rhs: RHS) -> Option<Self>
+    where
+        RHS: DecimalNumber,
+    {
+        BaseOps::checked_mul(&self, rhs)
+    }
+
+    /// Divides this money by `rhs`, returning `None` on overflow or division by zero.
+    ///
+    /// Same as [`BaseOps::checked_div`] without requiring the trait in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{raw, macros::dec};
+    ///
+    /// let m = raw!(USD, 100);
+    /// assert_eq!(m.checked_div(4), Some(raw!(USD, 25)));
+    /// assert_eq!(m.checked_div(0), None);
+    /// ```
+    #[inline]
+    pub fn checked_div<RHS>(self, rhs: RHS) -> Option<Self>
+    where
+        RHS: DecimalNumber,
+    {
+        BaseOps::checked_div(&self, rhs)
+    }
+
+    /// Negates this money.
+    ///
+    /// Negation of [`Decimal`] never overflows, so this always returns `Some`.
+    /// Provided for symmetry with other checked operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{raw, macros::dec};
+    ///
+    /// assert_eq!(raw!(USD, 100).checked_neg(), Some(raw!(USD, -100)));
+    /// assert_eq!(raw!(USD, -100).checked_neg(), Some(raw!(USD, 100)));
+    /// ```
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::from_decimal(-self.amount))
+    }
+
+    /// Returns absolute value of this money.
+    ///
+    /// Absolute value of [`Decimal`] never overflows, so this always returns `Some`.
+    /// Provided for symmetry with other checked operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{raw, macros::dec};
+    ///
+    /// assert_eq!(raw!(USD, -100).checked_abs(), Some(raw!(USD, 100)));
+    /// ```
+    #[inline]
+    pub fn checked_abs(self) -> Option<Self> {
+        Some(Self::from_decimal(self.amount.abs()))
+    }
+
+    /// Rounds this money into `dp` decimal places with `strategy`, keeping exactly `dp` decimal places.
+    ///
+    /// Unlike [`BaseMoney::round_with`] the scale is padded too, e.g. `1.5` into `1.50` with 2, so precision can be
+    /// normalized explicitly at chosen points in a calculation chain rather than only at [`finish`](Self::finish).
+    ///
+    /// Returns `None` if `dp` is more than 28 or the amount can't hold `dp` decimal places.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RoundingStrategy, raw, macros::dec};
+    ///
+    /// let rate = raw!(USD, 1.23456789);
+    /// let quantized = rate.quantize(4, RoundingStrategy::HalfUp).unwrap();
+    /// assert_eq!(quantized.amount().to_string(), "1.2346");
+    ///
+    /// let quantized = raw!(USD, 1.5).quantize(3, RoundingStrategy::Floor).unwrap();
+    /// assert_eq!(quantized.amount().to_string(), "1.500");
+    ///
+    /// assert_eq!(raw!(USD, 1).quantize(29, RoundingStrategy::HalfUp), None);
+    /// ```
+    pub fn quantize(self, dp: u32, strategy: RoundingStrategy) -> Option<Self> {
+        crate::decimal::rescale_with(self.amount, dp, strategy).map(Self::from_decimal)
+    }
+
+    /// Rescales this money into exactly `dp` decimal places, rounding with bankers rounding when reducing them.
+    ///
+    /// Same as [`quantize`](Self::quantize) with [`RoundingStrategy::BankersRounding`], the rounding of
+    /// [`finish`](Self::finish).
+    ///
+    /// Returns `None` if `dp` is more than 28 or the amount can't hold `dp` decimal places.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, raw, macros::dec};
+    ///
+    /// assert_eq!(raw!(USD, 2.345).rescale(2).unwrap().amount().to_string(), "2.34");
+    /// assert_eq!(raw!(USD, 2.5).rescale(4).unwrap().amount().to_string(), "2.5000");
+    /// assert_eq!(raw!(USD, 2.5).rescale(0).unwrap().amount().to_string(), "2");
+    /// ```
+    pub fn rescale(self, dp: u32) -> Option<Self> {
+        self.quantize(dp, RoundingStrategy::BankersRounding)
+    }
+
+    /// Formats this money in scientific notation with `sig_digits` significant digits, followed by currency code.
+    ///
+    /// Useful for very large amounts, e.g. hyperinflation currencies or aggregated market caps.
+    /// The exponent is written like Rust's `{:e}`, e.g. `1.234e12`, `5e-3`.
+    /// Digits are rounded with bankers rounding, `sig_digits` is clamped between 1 and 29, the maximum digits of [`Decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{raw, macros::dec};
+    ///
+    /// let m = raw!(USD, 1_234_000_000_000);
+    /// assert_eq!(m.format_scientific(4), "1.234e12 USD");
+    /// assert_eq!(m.format_scientific(2), "1.2e12 USD");
+    /// assert_eq!(m.format_scientific(6), "1.23400e12 USD");
+    ///
+    /// assert_eq!(raw!(IDR, -98_765_432_109_876.5).format_scientific(3), "-9.88e13 IDR");
+    /// assert_eq!(raw!(USD, 0.004567).format_scientific(2), "4.6e-3 USD");
+    /// ```
+    pub fn format_scientific(&self, sig_digits: u32) -> String {
+        crate::fmt::format_scientific::<C>(self.amount, sig_digits)
+    }
+
+    /// Displays this money in code format with exactly `scale` decimal places, e.g. `"USD 1,234.50000"` with 5.
+    ///
+    /// The amount is rounded with bankers rounding if it has more decimal places, and padded with zeros if it has fewer,
+    /// regardless of its own scale. Same as `format!("{:.scale$}", money)`. `scale` is clamped into 28, the maximum
+    /// scale of [`Decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::raw;
+    ///
+    /// let m = raw!(USD, 1234.5);
+    /// assert_eq!(m.display_with_scale(5), "USD 1,234.50000");
+    /// assert_eq!(m.display_with_scale(0), "USD 1,234");
+    ///
+    /// assert_eq!(raw!(USD, -0.125).display_with_scale(2), "USD -0.12");
+    /// ```
+    pub fn display_with_scale(&self, scale: u32) -> String {
+        crate::fmt::format_code_scaled(
+            C::CODE,
+            crate::fmt::round_to_scale(self.amount, scale),
+            (C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR),
+            0,
+        )
+    }
+
+    /// Displays this money in code format without trailing zeros, e.g. `"USD 1,234.5"` for `1234.50000`.
+    ///
+    /// Nothing is rounded, so every nonzero digit is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RawMoney, macros::dec, iso::USD};
+    ///
+    /// let m = RawMoney::<USD>::from_decimal(dec!(1234.50000));
+    /// assert_eq!(m.display(), "USD 1,234.50000");
+    /// assert_eq!(m.display_trimmed(), "USD 1,234.5");
+    ///
+    /// let m = RawMoney::<USD>::from_decimal(dec!(-1200.000));
+    /// assert_eq!(m.display_trimmed(), "USD -1,200");
+    /// ```
+    pub fn display_trimmed(&self) -> String {
+        crate::fmt::format_code_scaled(
+            C::CODE,
+            self.amount.normalize(),
+            (C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR),
+            0,
+        )
+    }
+
+    /// Displays this money in code format without trailing zeros, padded with zeros into at least `min_scale`
+    /// decimal places, e.g. `"USD 1,234.50"` for `1234.50000` with 2.
+    ///
+    /// Nothing is rounded, so amounts with more nonzero decimal places keep them all.
+    /// `min_scale` is clamped into 28, the maximum scale of [`Decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RawMoney, macros::dec, iso::USD};
+    ///
+    /// let m = RawMoney::<USD>::from_decimal(dec!(1234.50000));
+    /// assert_eq!(m.display_padded(2), "USD 1,234.50");
+    /// assert_eq!(m.display_padded(4), "USD 1,234.5000");
+    ///
+    /// let m = RawMoney::<USD>::from_decimal(dec!(1234.5678));
+    /// assert_eq!(m.display_padded(2), "USD 1,234.5678");
+    /// ```
+    pub fn display_padded(&self, min_scale: u32) -> String {
+        crate::fmt::format_code_scaled(
+            C::CODE,
+            self.amount.normalize(),
+            (C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR),
+            min_scale,
+        )
+    }
 }
 
 impl<C: Currency> Default for RawMoney<C> {
     /// Returns money with zero amount.
+    ///
+    /// Default is the canonical zero at currency's scale, e.g. `0.00` for USD and `0` for JPY,
+    /// so `#[derive(Default)]` and `#[serde(default)]` work on structs containing money.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, RawMoney, iso::{JPY, USD}};
+    ///
+    /// #[derive(Default)]
+    /// struct Account {
+    ///     balance: RawMoney<USD>,
+    ///     points: RawMoney<JPY>,
+    /// }
+    ///
+    /// let account = Account::default();
+    /// assert!(account.balance.is_zero());
+    /// assert_eq!(account.balance.to_string(), "USD 0.00");
+    /// assert_eq!(account.balance.scale(), 2);
+    /// assert_eq!(account.points.scale(), 0);
+    /// ```
     fn default() -> Self {
-        Self {
-            amount: Decimal::default(),
-            _currency: PhantomData,
-        }
+        Self::ZERO
+    }
+}
+
+impl<C> PartialEq for RawMoney<C>
+where
+    C: Currency,
+{
+    /// Compares amounts by value regardless of scale, e.g. `1.5 == 1.50`.
+    fn eq(&self, other: &Self) -> bool {
+        self.amount == other.amount
     }
 }
 
-impl<C: Currency> Ord for RawMoney<C>
+impl<C> Eq for RawMoney<C> where C: Currency {}
+
+/// Total ordering of money by amount.
+///
+/// Currency type doesn't need to implement `PartialEq`/`Ord`, so `sort()`, `BTreeMap`, `min`, and `max`
+/// work for any currency.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, RawMoney, iso::USD, macros::dec};
+/// use std::collections::BTreeMap;
+///
+/// let mut moneys = vec![
+///     RawMoney::<USD>::from_decimal(dec!(3)),
+///     RawMoney::<USD>::from_decimal(dec!(-1)),
+///     RawMoney::<USD>::from_decimal(dec!(2)),
+/// ];
+/// moneys.sort();
+/// assert_eq!(moneys[0].amount(), dec!(-1));
+/// assert_eq!(moneys[2].amount(), dec!(3));
+///
+/// let mut buckets = BTreeMap::new();
+/// buckets.insert(RawMoney::<USD>::from_decimal(dec!(100)), "high");
+/// buckets.insert(RawMoney::<USD>::from_decimal(dec!(10)), "low");
+/// assert_eq!(buckets.first_key_value().unwrap().1, &"low");
+/// ```
+impl<C> Ord for RawMoney<C>
 where
-    C: Currency + PartialEq + Eq,
+    C: Currency,
 {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.amount.cmp(&other.amount)
@@ -122,13 +571,36 @@ where
 
 impl<C> PartialOrd for RawMoney<C>
 where
-    C: Currency + PartialEq + Eq,
+    C: Currency,
 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
+/// Hashes the amount normalized, consistent with `PartialEq`.
+///
+/// Amounts equal in value but different in scale hash equally, e.g. `1.5` and `1.50`.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, RawMoney, iso::USD, macros::dec};
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(RawMoney::<USD>::from_decimal(dec!(1.5)));
+/// assert!(set.contains(&RawMoney::<USD>::from_decimal(dec!(1.50))));
+/// ```
+impl<C> Hash for RawMoney<C>
+where
+    C: Currency,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.amount.normalize().hash(state);
+    }
+}
+
 impl<C> Amount<C> for RawMoney<C>
 where
     C: Currency,
@@ -145,7 +617,10 @@ where
 {
     type Err = MoneyError;
 
-    /// Parse money from string number.
+    /// Parse money from string number, or negative number in parentheses of accounting exports, e.g. `"(12.5)"`.
+    ///
+    /// Strings starting with the currency's symbol after the sign are parsed in symbol format with the currency's
+    /// separators like [`MoneyParser::from_str_symbol`], e.g. `"$1,234.56"`, `"-$1,234.56"` and `"$-1,234.56"`.
     ///
     /// # Examples
     ///
@@ -156,13 +631,39 @@ where
     /// let money = RawMoney::<USD>::from_str("12334.4439").unwrap();
     /// assert_eq!(money, raw!(USD, 12334.4439));
     /// assert_eq!(money.amount(), dec!(12334.4439));
+    ///
+    /// assert_eq!(RawMoney::<USD>::from_str("(0.125)").unwrap(), raw!(USD, -0.125));
+    /// assert_eq!(RawMoney::<USD>::from_str("-$1,234.5678").unwrap(), raw!(USD, -1234.5678));
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        let dec_num = Decimal::from_str(s).map_err(|err| {
-            MoneyError::ParseStrError(format!("failed parsing money from string: {}", err).into())
-        })?;
-        Ok(Self::from_decimal(dec_num))
+        crate::parse::parse_number_or_symbol::<C>(s).map(Self::from_decimal)
+    }
+}
+
+/// Converts `RawMoney` into `Money` without rounding.
+///
+/// Fails with [`MoneyError::MinorUnitError`] if the amount has nonzero digits beyond the currency's minor unit, use
+/// [`RawMoney::to_money`] or [`RawMoney::finish`] to round them instead.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{Money, MoneyError, money, raw, iso::USD};
+///
+/// assert_eq!(Money::try_from(raw!(USD, 100.50)).unwrap(), money!(USD, 100.50));
+/// assert_eq!(Money::try_from(raw!(USD, 100.5000)).unwrap(), money!(USD, 100.50));
+///
+/// assert!(matches!(
+///     Money::<USD>::try_from(raw!(USD, 100.505)),
+///     Err(MoneyError::MinorUnitError(_))
+/// ));
+/// ```
+impl<C: Currency> TryFrom<RawMoney<C>> for Money<C> {
+    type Error = MoneyError;
+
+    fn try_from(value: RawMoney<C>) -> Result<Self, Self::Error> {
+        crate::currency::check_minor_unit::<C>(value.amount)?;
+        Ok(Money::from_decimal(value.amount))
     }
 }
 
@@ -188,12 +689,28 @@ impl<C: Currency> Clone for RawMoney<C> {
 /// let raw = RawMoney::<USD>::from_decimal(dec!(-1234.56));
 /// assert_eq!(format!("{}", raw), "USD -1,234.56");
 /// ```
+///
+/// Width, fill, alignment and precision are honored like [`Money`](crate::Money)'s `Display`:
+///
+/// ```
+/// use moneylib::raw;
+///
+/// let raw = raw!(USD, 1234.5678);
+/// assert_eq!(format!("{:.3}", raw), "USD 1,234.568");
+/// assert_eq!(format!("{:>16.2}", raw), "    USD 1,234.57");
+/// ```
 impl<C> Display for RawMoney<C>
 where
     C: Currency,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.display())
+        crate::fmt::write_display(
+            f,
+            C::CODE,
+            self.amount,
+            (C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR),
+            || self.display(),
+        )
     }
 }
 
@@ -202,11 +719,17 @@ where
     C: Currency,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "RawMoney({}, {})", C::CODE, self.amount)
+        write!(
+            f,
+            "RawMoney({}, {})",
+            C::CODE,
+            crate::fmt::debug_amount(self.amount, C::MINOR_UNIT)
+        )
     }
 }
 
-impl<C: Currency> Sum for RawMoney<C> {
+#[cfg(feature = "panic_ops")]
+impl<C: Currency> ::std::iter::Sum for RawMoney<C> {
     /// Sum all moneys
     ///
     /// WARN: PANIC! if overflowed.
@@ -215,7 +738,8 @@ impl<C: Currency> Sum for RawMoney<C> {
     }
 }
 
-impl<'a, C: Currency> Sum<&'a RawMoney<C>> for RawMoney<C> {
+#[cfg(feature = "panic_ops")]
+impl<'a, C: Currency> ::std::iter::Sum<&'a RawMoney<C>> for RawMoney<C> {
     /// Sum all moneys(borrowed)
     ///
     /// WARN: PANIC!!! if overflowed.
@@ -243,10 +767,7 @@ where
 
     #[inline(always)]
     fn minor_amount(&self) -> Option<i128> {
-        self.amount()
-            .round_dp(C::MINOR_UNIT.into())
-            .checked_mul(dec!(10).checked_powu(C::MINOR_UNIT.into())?)?
-            .to_i128()
+        crate::decimal::to_scaled_i128(self.amount(), C::MINOR_UNIT.into())
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::iso::{AUD, BDT, BHD, CHF, EUR, GBP, IDR, INR, JPY, SAR, SGD, USD};
 
-use crate::macros::dec;
+use crate::macros::{dec, money};
 use crate::{
     BaseMoney, BaseOps, Money, MoneyError, MoneyFormatter, MoneyParser, RawMoney, RoundingStrategy,
     raw,
@@ -51,6 +51,11 @@ fn test_new_jpy_no_rounding() {
 fn test_raw_money_default() {
     let money = RawMoney::<IDR>::default();
     assert!(money.is_zero());
+
+    assert_eq!(RawMoney::<USD>::default().scale(), 2);
+    assert_eq!(RawMoney::<JPY>::default().scale(), 0);
+    assert_eq!(RawMoney::<BHD>::default().amount().to_string(), "0.000");
+    assert_eq!(RawMoney::<USD>::default().finish(), Money::<USD>::default());
 }
 
 #[test]
@@ -193,6 +198,17 @@ fn test_from_minor_negative() {
     assert_eq!(raw.amount(), dec!(-123.45));
 }
 
+// ==================== RawMoney::from_implied_decimal_str() Tests ====================
+
+#[test]
+fn test_raw_from_implied_decimal_str() {
+    let money = RawMoney::<USD>::from_implied_decimal_str("-123456").unwrap();
+    assert_eq!(money.amount(), dec!(-1234.56));
+    assert_eq!(money.amount().scale(), 2);
+
+    assert!(RawMoney::<USD>::from_implied_decimal_str("12a4").is_err());
+}
+
 // ==================== RawMoney::finish() Tests ====================
 
 #[test]
@@ -300,6 +316,14 @@ fn test_remainder() {
     assert_eq!(ret.amount(), dec!(1));
 }
 
+#[test]
+fn test_remainder_integer() {
+    let money = raw!(USD, 97.505);
+    assert_eq!((money % 25_i32).amount(), dec!(22.505));
+    assert_eq!((money % 25_i64).amount(), dec!(22.505));
+    assert_eq!((money % 25_i128).amount(), dec!(22.505));
+}
+
 // ==================== Decimal Operations (reversed) Tests ====================
 
 #[test]
@@ -494,6 +518,39 @@ fn test_round_with_half_up() {
     assert_eq!(rounded.amount(), dec!(100.57));
 }
 
+#[test]
+fn test_round_returning_delta() {
+    let (rounded, delta) = RawMoney::<USD>::new(dec!(100.565))
+        .unwrap()
+        .round_returning_delta();
+    assert_eq!(rounded.amount(), dec!(100.56));
+    assert_eq!(delta, dec!(-0.005));
+
+    let (rounded, delta) = RawMoney::<USD>::new(dec!(-0.125))
+        .unwrap()
+        .round_returning_delta();
+    assert_eq!(rounded.amount(), dec!(-0.12));
+    assert_eq!(delta, dec!(0.005));
+
+    let (rounded, delta) = RawMoney::<USD>::new(dec!(7.5))
+        .unwrap()
+        .round_returning_delta();
+    assert_eq!(rounded.amount(), dec!(7.5));
+    assert_eq!(delta, dec!(0));
+}
+
+#[test]
+fn test_round_with_returning_delta() {
+    let raw = RawMoney::<USD>::new(dec!(100.5651)).unwrap();
+    let (rounded, delta) = raw.round_with_returning_delta(2, RoundingStrategy::Ceil);
+    assert_eq!(rounded.amount(), dec!(100.57));
+    assert_eq!(delta, dec!(0.0049));
+    let (rounded, delta) = raw.round_with_returning_delta(2, RoundingStrategy::Floor);
+    assert_eq!(rounded.amount(), dec!(100.56));
+    assert_eq!(delta, dec!(-0.0051));
+    assert_eq!(rounded.amount() - delta, raw.amount());
+}
+
 // ==================== BaseMoney Trait Method Tests ====================
 
 #[test]
@@ -2391,3 +2448,664 @@ fn test_raw_parse_multiple_decimal_separators_via_code() {
     let result = RawMoney::<USD>::from_str_code_with("USD 1.2.3", ",", ".");
     assert!(matches!(result, Err(MoneyError::ParseStrError(_))));
 }
+
+// ==================== Inherent checked arithmetic Tests ====================
+
+#[test]
+fn test_inherent_checked_arithmetic() {
+    let m = raw!(USD, 100.50);
+    assert_eq!(m.checked_add(dec!(0.005)).unwrap().amount(), dec!(100.505));
+    assert_eq!(m.checked_add(raw!(USD, 1)).unwrap().amount(), dec!(101.50));
+    assert_eq!(m.checked_sub(200).unwrap().amount(), dec!(-99.50));
+    assert_eq!(m.checked_mul(dec!(0.333)).unwrap().amount(), dec!(33.46650));
+    assert_eq!(m.checked_div(4).unwrap().amount(), dec!(25.125));
+    assert_eq!(m.checked_neg().unwrap().amount(), dec!(-100.50));
+    assert_eq!(m.checked_neg().unwrap().checked_abs(), Some(m));
+
+    let max = RawMoney::<USD>::from_decimal(crate::Decimal::MAX);
+    assert!(max.checked_add(1).is_none());
+    assert!(max.checked_neg().unwrap().checked_sub(1).is_none());
+    assert!(max.checked_mul(2).is_none());
+    assert!(m.checked_div(0).is_none());
+}
+
+// ==================== Saturating arithmetic Tests ====================
+
+#[test]
+fn test_saturating_arithmetic() {
+    let m = raw!(USD, 100.50);
+    assert_eq!(
+        m.saturating_add(dec!(0.255)).unwrap().amount(),
+        dec!(100.755)
+    );
+    assert_eq!(
+        m.saturating_sub(raw!(USD, 200)).unwrap().amount(),
+        dec!(-99.50)
+    );
+    assert_eq!(
+        m.saturating_mul(dec!(0.333)).unwrap().amount(),
+        dec!(33.46650)
+    );
+
+    let max = RawMoney::<USD>::from_decimal(crate::Decimal::MAX);
+    let min = RawMoney::<USD>::from_decimal(crate::Decimal::MIN);
+    assert_eq!(max.saturating_add(m).unwrap(), max);
+    assert_eq!(min.saturating_sub(m).unwrap(), min);
+    assert_eq!(max.saturating_mul(-2).unwrap(), min);
+
+    assert!(m.saturating_add(f64::NAN).is_none());
+}
+
+// ==================== Hash Tests ====================
+
+#[test]
+fn test_hash_consistent_with_eq() {
+    use std::collections::HashSet;
+    use std::hash::{BuildHasher, RandomState};
+
+    let a = raw!(USD, 1.5);
+    let b = raw!(USD, 1.50000);
+    assert_eq!(a, b);
+
+    let state = RandomState::new();
+    assert_eq!(state.hash_one(a), state.hash_one(b));
+
+    let set: HashSet<RawMoney<USD>> = [a, b, raw!(USD, 1.505)].into();
+    assert_eq!(set.len(), 2);
+}
+
+// ==================== Total ordering Tests ====================
+
+#[test]
+fn test_total_ordering() {
+    let mut moneys = vec![raw!(USD, 0.003), raw!(USD, -0.001), raw!(USD, 0.0030)];
+    moneys.sort();
+    assert_eq!(
+        moneys,
+        vec![raw!(USD, -0.001), raw!(USD, 0.003), raw!(USD, 0.003)]
+    );
+
+    let a = RawMoney::<MyCurrency>::from_decimal(dec!(1.0001));
+    let b = RawMoney::<MyCurrency>::from_decimal(dec!(1.0002));
+    assert!(a < b);
+    assert_eq!(a.max(b.clone()), b);
+}
+
+// ==================== format_scientific Tests ====================
+
+#[test]
+fn test_format_scientific() {
+    let raw = RawMoney::<USD>::from_decimal(dec!(1_234_000_000_000));
+    assert_eq!(raw.format_scientific(4), "1.234e12 USD");
+    assert_eq!(raw.format_scientific(1), "1e12 USD");
+    assert_eq!(raw.format_scientific(5), "1.2340e12 USD");
+
+    let raw = RawMoney::<EUR>::from_decimal(dec!(-1234.5678));
+    assert_eq!(raw.format_scientific(3), "-1.23e3 EUR");
+
+    let raw = RawMoney::<JPY>::from_decimal(dec!(7));
+    assert_eq!(raw.format_scientific(3), "7.00e0 JPY");
+
+    let raw = RawMoney::<USD>::from_decimal(dec!(0.000123));
+    assert_eq!(raw.format_scientific(2), "1.2e-4 USD");
+}
+
+#[test]
+fn test_format_scientific_rounding() {
+    // bankers rounding
+    assert_eq!(raw!(USD, 1250).format_scientific(2), "1.2e3 USD");
+    assert_eq!(raw!(USD, 1350).format_scientific(2), "1.4e3 USD");
+    assert_eq!(raw!(USD, 1251).format_scientific(2), "1.3e3 USD");
+    assert_eq!(raw!(USD, -1249.99).format_scientific(2), "-1.2e3 USD");
+
+    // carried into next exponent
+    assert_eq!(raw!(USD, 9.995).format_scientific(3), "1.00e1 USD");
+    assert_eq!(raw!(USD, 999_999).format_scientific(2), "1.0e6 USD");
+}
+
+#[test]
+fn test_format_scientific_edge_cases() {
+    assert_eq!(raw!(USD, 0).format_scientific(3), "0.00e0 USD");
+    assert_eq!(raw!(USD, 0.00).format_scientific(1), "0e0 USD");
+    // zero digits is displayed as 1 digit
+    assert_eq!(raw!(USD, 123).format_scientific(0), "1e2 USD");
+
+    let max = RawMoney::<USD>::from_decimal(crate::Decimal::MAX);
+    assert_eq!(max.format_scientific(3), "7.92e28 USD");
+    assert_eq!(max.format_scientific(1), "8e28 USD");
+    assert_eq!(
+        max.format_scientific(u32::MAX),
+        "7.9228162514264337593543950335e28 USD"
+    );
+
+    let min = RawMoney::<USD>::from_decimal(crate::Decimal::MIN);
+    assert_eq!(min.format_scientific(2), "-7.9e28 USD");
+
+    let tiny = RawMoney::<USD>::from_decimal(crate::Decimal::new(1, 28));
+    assert_eq!(tiny.format_scientific(2), "1.0e-28 USD");
+}
+
+// ==================== format_fit Tests ====================
+
+#[test]
+fn test_format_fit() {
+    let raw = RawMoney::<USD>::from_decimal(dec!(1234.5678));
+    assert_eq!(raw.format_fit(11).unwrap(), "$1,234.5678");
+    assert_eq!(raw.format_fit(10).unwrap(), "$1234.5678");
+    assert_eq!(raw.format_fit(6).unwrap(), "$1235");
+    assert_eq!(raw.format_fit(4).unwrap(), "$1K");
+}
+
+#[test]
+fn test_display_with_scale() {
+    let m = RawMoney::<USD>::from_decimal(dec!(1234.50000));
+    assert_eq!(m.display_with_scale(5), "USD 1,234.50000");
+    assert_eq!(m.display_with_scale(2), "USD 1,234.50");
+    assert_eq!(m.display_with_scale(1), "USD 1,234.5");
+    assert_eq!(m.display_with_scale(0), "USD 1,234");
+    assert_eq!(m.display_with_scale(3), format!("{:.3}", m));
+
+    // bankers rounding
+    assert_eq!(raw!(USD, 0.125).display_with_scale(2), "USD 0.12");
+    assert_eq!(raw!(USD, -0.135).display_with_scale(2), "USD -0.14");
+    assert_eq!(raw!(USD, -0.001).display_with_scale(2), "USD 0.00");
+
+    // currency's separators and clamped scale
+    assert_eq!(
+        RawMoney::<EUR>::from_decimal(dec!(-1234.5)).display_with_scale(3),
+        "EUR -1.234,500"
+    );
+    assert_eq!(
+        raw!(JPY, 1).display_with_scale(100),
+        format!("JPY 1.{}", "0".repeat(28))
+    );
+}
+
+#[test]
+fn test_display_trimmed_and_padded() {
+    let m = RawMoney::<USD>::from_decimal(dec!(1234.50000));
+    assert_eq!(m.display_trimmed(), "USD 1,234.5");
+    assert_eq!(m.display_padded(0), "USD 1,234.5");
+    assert_eq!(m.display_padded(2), "USD 1,234.50");
+    assert_eq!(m.display_padded(6), "USD 1,234.500000");
+
+    let m = RawMoney::<USD>::from_decimal(dec!(-1234.5678));
+    assert_eq!(m.display_trimmed(), "USD -1,234.5678");
+    assert_eq!(m.display_padded(2), "USD -1,234.5678");
+
+    let m = RawMoney::<BHD>::from_decimal(dec!(1000.000));
+    assert_eq!(m.display_trimmed(), "BHD 1,000");
+    assert_eq!(m.display_padded(3), "BHD 1,000.000");
+
+    let zero = RawMoney::<USD>::from_decimal(dec!(-0.000));
+    assert_eq!(zero.display_trimmed(), "USD 0");
+    assert_eq!(zero.display_padded(2), "USD 0.00");
+
+    assert_eq!(
+        RawMoney::<EUR>::from_decimal(dec!(1234567.10)).display_padded(1),
+        "EUR 1.234.567,1"
+    );
+}
+
+#[cfg(feature = "redact_debug")]
+#[test]
+fn test_debug_redacted() {
+    assert_eq!(
+        format!("{:?}", raw!(USD, -1234.5678)),
+        "RawMoney(USD, ••••.57)"
+    );
+}
+
+#[test]
+fn test_from_str_symbol_prefixed() {
+    assert_eq!(
+        RawMoney::<USD>::from_str("$1,234.5678").unwrap(),
+        raw!(USD, 1234.5678)
+    );
+    assert_eq!(
+        RawMoney::<USD>::from_str("$-0.125").unwrap(),
+        raw!(USD, -0.125)
+    );
+    assert_eq!(
+        RawMoney::<EUR>::from_str("-€1.234,5").unwrap(),
+        RawMoney::<EUR>::from_decimal(dec!(-1234.5))
+    );
+    assert!(RawMoney::<USD>::from_str("€1").is_err());
+}
+
+#[test]
+fn test_parse_with() {
+    let options = crate::ParseOptions::new();
+    assert_eq!(
+        RawMoney::<USD>::parse_with("($1,234.5678)", &options).unwrap(),
+        raw!(USD, -1234.5678)
+    );
+    assert!(RawMoney::<USD>::parse_with("USD 1.5678", &options.strict(true)).is_err());
+}
+
+#[test]
+fn test_parse_with_exponent() {
+    let options = crate::ParseOptions::new().allow_exponent(true);
+    assert_eq!(
+        RawMoney::<USD>::parse_with("1.23456e-2", &options).unwrap(),
+        raw!(USD, 0.0123456)
+    );
+    assert!(RawMoney::<USD>::parse_with("1.23456e-2", &crate::ParseOptions::new()).is_err());
+}
+
+#[test]
+fn test_from_ascii() {
+    assert_eq!(
+        RawMoney::<USD>::from_ascii(b"USD -1234.5678").unwrap(),
+        raw!(USD, -1234.5678)
+    );
+    assert!(RawMoney::<USD>::from_ascii(b"-1,234.5678").is_err());
+}
+
+#[test]
+fn test_round_cash_midpoints() {
+    for (amount, strategy, expected) in [
+        (dec!(12.325), RoundingStrategy::HalfUp, dec!(12.35)),
+        (dec!(12.325), RoundingStrategy::HalfDown, dec!(12.30)),
+        (dec!(12.325), RoundingStrategy::BankersRounding, dec!(12.30)),
+        (dec!(12.375), RoundingStrategy::BankersRounding, dec!(12.40)),
+        (dec!(-12.325), RoundingStrategy::HalfUp, dec!(-12.35)),
+        (dec!(12.3001), RoundingStrategy::Ceil, dec!(12.35)),
+    ] {
+        assert_eq!(
+            RawMoney::<CHF>::from_decimal(amount)
+                .round_cash(dec!(0.05), strategy)
+                .unwrap()
+                .amount(),
+            expected,
+            "{} {:?}",
+            amount,
+            strategy
+        );
+    }
+    assert_eq!(
+        RawMoney::<CHF>::from_decimal(dec!(9.975))
+            .round_cash_default(RoundingStrategy::HalfUp)
+            .unwrap(),
+        raw!(CHF, 10.00)
+    );
+}
+
+#[test]
+fn test_quantize() {
+    let raw = raw!(USD, 1.23456789);
+    assert_eq!(
+        raw.quantize(4, RoundingStrategy::HalfUp)
+            .unwrap()
+            .amount()
+            .to_string(),
+        "1.2346"
+    );
+    assert_eq!(
+        raw.quantize(4, RoundingStrategy::Floor)
+            .unwrap()
+            .amount()
+            .to_string(),
+        "1.2345"
+    );
+    assert_eq!(
+        raw.quantize(10, RoundingStrategy::Floor)
+            .unwrap()
+            .amount()
+            .to_string(),
+        "1.2345678900"
+    );
+    assert_eq!(
+        raw!(USD, -2.5)
+            .quantize(0, RoundingStrategy::HalfUp)
+            .unwrap(),
+        raw!(USD, -3)
+    );
+    assert_eq!(raw!(USD, 1).quantize(29, RoundingStrategy::HalfUp), None);
+    assert_eq!(
+        RawMoney::<USD>::from_decimal(crate::Decimal::MAX).quantize(2, RoundingStrategy::HalfUp),
+        None
+    );
+}
+
+#[test]
+fn test_quantize_in_calculation_chain() {
+    // rate normalized into 6 decimal places before applying it.
+    let rate = (raw!(USD, 1) / dec!(3))
+        .quantize(6, RoundingStrategy::HalfUp)
+        .unwrap();
+    assert_eq!(rate.amount(), dec!(0.333333));
+    let interest = raw!(USD, 1_000_000) * rate.amount();
+    assert_eq!(interest.amount(), dec!(333333));
+    assert_eq!(interest.finish().amount().to_string(), "333333.00");
+}
+
+#[test]
+fn test_rescale() {
+    assert_eq!(
+        raw!(USD, 2.345).rescale(2).unwrap().amount().to_string(),
+        "2.34"
+    );
+    assert_eq!(
+        raw!(USD, 2.355).rescale(2).unwrap().amount().to_string(),
+        "2.36"
+    );
+    assert_eq!(
+        raw!(USD, 2.5).rescale(4).unwrap().amount().to_string(),
+        "2.5000"
+    );
+    assert_eq!(raw!(JPY, 2.5).rescale(0).unwrap(), raw!(JPY, 2));
+    assert_eq!(raw!(USD, 2.5).rescale(29), None);
+}
+
+#[test]
+fn test_to_money() {
+    let raw = raw!(USD, 100.125);
+    assert_eq!(
+        raw.to_money(RoundingStrategy::BankersRounding),
+        raw.finish()
+    );
+    assert_eq!(
+        raw.to_money(RoundingStrategy::HalfUp).amount(),
+        dec!(100.13)
+    );
+    assert_eq!(
+        raw.to_money(RoundingStrategy::HalfDown).amount(),
+        dec!(100.12)
+    );
+    assert_eq!(raw.to_money(RoundingStrategy::Ceil).amount(), dec!(100.13));
+    assert_eq!(raw.to_money(RoundingStrategy::Floor).amount(), dec!(100.12));
+    assert_eq!(
+        raw!(USD, -100.121).to_money(RoundingStrategy::Up).amount(),
+        dec!(-100.12)
+    );
+    assert_eq!(
+        raw!(USD, -100.121)
+            .to_money(RoundingStrategy::Down)
+            .amount(),
+        dec!(-100.13)
+    );
+    assert_eq!(
+        raw!(JPY, 100.5).to_money(RoundingStrategy::HalfUp).amount(),
+        dec!(101)
+    );
+    assert_eq!(
+        raw!(BHD, 1.0005)
+            .to_money(RoundingStrategy::BankersRounding)
+            .amount(),
+        dec!(1.000)
+    );
+}
+
+#[test]
+fn test_try_from_raw_money() {
+    assert_eq!(
+        Money::<USD>::try_from(raw!(USD, 100.5)).unwrap().amount(),
+        dec!(100.50)
+    );
+    assert_eq!(
+        Money::<USD>::try_from(RawMoney::<USD>::from_decimal(dec!(100.500000))).unwrap(),
+        Money::<USD>::new(dec!(100.50)).unwrap()
+    );
+    assert_eq!(
+        Money::<JPY>::try_from(raw!(JPY, -100)).unwrap().amount(),
+        dec!(-100)
+    );
+
+    let ret = Money::<USD>::try_from(raw!(USD, 100.505));
+    assert!(matches!(ret, Err(MoneyError::MinorUnitError(_))));
+    assert!(matches!(
+        Money::<JPY>::try_from(raw!(JPY, 100.5)),
+        Err(MoneyError::MinorUnitError(_))
+    ));
+}
+
+#[test]
+fn test_div_exact() {
+    let third = money!(USD, 100).div_exact(3).unwrap();
+    assert_eq!(third.amount(), dec!(100) / dec!(3));
+    assert_eq!(third.checked_mul(3).unwrap().finish(), money!(USD, 100));
+    assert_eq!(
+        money!(USD, 100)
+            .checked_div(3)
+            .unwrap()
+            .checked_mul(3)
+            .unwrap(),
+        money!(USD, 99.99)
+    );
+
+    assert_eq!(
+        money!(JPY, 10).div_exact(dec!(4)).unwrap().amount(),
+        dec!(2.5)
+    );
+    assert_eq!(
+        money!(USD, -1).div_exact(8_i64).unwrap().amount(),
+        dec!(-0.125)
+    );
+    assert_eq!(money!(USD, 1).div_exact(0.5_f64).unwrap().amount(), dec!(2));
+
+    assert!(matches!(
+        money!(USD, 100).div_exact(0),
+        Err(MoneyError::DivisionByZeroError)
+    ));
+    assert!(matches!(
+        money!(USD, 100).div_exact(dec!(0.00)),
+        Err(MoneyError::DivisionByZeroError)
+    ));
+    assert!(matches!(
+        money!(USD, 100).div_exact(f64::NAN),
+        Err(MoneyError::OverflowError)
+    ));
+    assert!(matches!(
+        Money::<USD>::from_decimal(crate::Decimal::MAX).div_exact(dec!(0.1)),
+        Err(MoneyError::OverflowError)
+    ));
+}
+
+#[test]
+fn test_scale_mantissa_normalize() {
+    let raw = RawMoney::<USD>::from_decimal(dec!(1234.5000));
+    assert_eq!(raw.scale(), 4);
+    assert_eq!(raw.mantissa(), 12_345_000);
+
+    let normalized = raw.normalize();
+    assert_eq!(normalized, raw);
+    assert_eq!(normalized.scale(), 1);
+    assert_eq!(normalized.mantissa(), 12_345);
+    assert_eq!(normalized.amount().to_string(), "1234.5");
+    assert_eq!(normalized.normalize().amount().to_string(), "1234.5");
+
+    assert_eq!(raw!(USD, 1200).normalize().scale(), 0);
+    assert_eq!(raw!(USD, 1200).normalize().mantissa(), 1_200);
+    assert_eq!(raw!(USD, -0.10).normalize().mantissa(), -1);
+
+    let negative_zero = RawMoney::<USD>::from_decimal(-dec!(0.000));
+    assert!(negative_zero.amount().is_sign_negative());
+    let zero = negative_zero.normalize();
+    assert!(!zero.amount().is_sign_negative());
+    assert_eq!(zero.scale(), 0);
+    assert_eq!(zero, RawMoney::<USD>::default());
+}
+
+#[test]
+fn test_normalize_hash_and_display_stable() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let hash = |m: &RawMoney<USD>| {
+        let mut hasher = DefaultHasher::new();
+        m.hash(&mut hasher);
+        hasher.finish()
+    };
+    let a = RawMoney::<USD>::from_decimal(dec!(1.5));
+    let b = RawMoney::<USD>::from_decimal(dec!(1.500));
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(a.amount().to_string(), b.amount().to_string());
+    assert_eq!(
+        a.normalize().amount().to_string(),
+        b.normalize().amount().to_string()
+    );
+}
+
+#[test]
+fn test_format_pattern() {
+    let raw = raw!(USD, -1234.5678);
+    assert_eq!(raw.format("c na"), "USD -1,234.5678");
+    assert_eq!(raw.format("nsa"), "-$1,234.5678");
+    assert_eq!(raw.format("sa"), "$1,234.5678");
+    assert_eq!(raw.format("\\{Total:} c na"), "Total: USD -1,234.5678");
+    assert_eq!(
+        raw.format_with_separator("na c", ".", ","),
+        "-1.234,5678 USD"
+    );
+    // minor amount is in whole minor units, rounded with bankers rounding like `minor_amount`.
+    assert_eq!(raw.format("c na m"), "USD -123,457 ¢");
+
+    let raw = raw!(USD, 1234.5);
+    assert_eq!(raw.format("c na"), "USD 1,234.50");
+    assert_eq!(raw.format("c a m"), "USD 123,450 ¢");
+    assert_eq!(raw.format("pnsa"), "+$1,234.50");
+
+    // same pattern language as Money, differing only by the kept precision.
+    let money = money!(EUR, 1234.5);
+    for pattern in ["c na", "nsa", "c a m", "pnsa", "\\{a, c, s} a"] {
+        assert_eq!(
+            money.into_raw().format(pattern),
+            money.format(pattern),
+            "{pattern}"
+        );
+    }
+}
+
+#[test]
+fn test_associated_constants() {
+    const ZERO: RawMoney<USD> = RawMoney::ZERO;
+
+    assert_eq!(ZERO, RawMoney::<USD>::default());
+    assert_eq!(ZERO.scale(), 2);
+    assert_eq!(RawMoney::<JPY>::ZERO.scale(), 0);
+    assert_eq!(RawMoney::<USD>::MAX.amount(), crate::Decimal::MAX);
+    assert_eq!(RawMoney::<USD>::MIN.amount(), crate::Decimal::MIN);
+    assert_eq!(RawMoney::<USD>::MAX.checked_add(dec!(1)), None);
+    assert_eq!(Money::<USD>::ZERO.into_raw(), RawMoney::<USD>::ZERO);
+}
+
+#[test]
+fn test_from_major_minor() {
+    assert_eq!(
+        RawMoney::<USD>::from_major_minor(12, 34).unwrap(),
+        raw!(USD, 12.34)
+    );
+    assert_eq!(
+        RawMoney::<BHD>::from_major_minor(-1, 5).unwrap(),
+        raw!(BHD, -1.005)
+    );
+    assert!(RawMoney::<USD>::from_major_minor(1, 100).is_err());
+}
+
+#[test]
+fn test_into_parts_trunc_fract() {
+    // rounded into minor unit first
+    assert_eq!(raw!(USD, 12.345).into_parts(), Some((12, 34)));
+    assert_eq!(raw!(USD, 12.999).into_parts(), Some((13, 0)));
+    assert_eq!(raw!(USD, -12.999).into_parts(), Some((-13, 0)));
+    assert_eq!(raw!(USD, 12.3456).trunc(), raw!(USD, 12));
+    assert_eq!(raw!(USD, -12.3456).fract(), raw!(USD, -0.3456));
+}
+
+#[test]
+fn test_checked_abs_diff() {
+    assert_eq!(
+        raw!(USD, 0.125).checked_abs_diff(raw!(USD, 0.1)),
+        Some(raw!(USD, 0.025))
+    );
+    assert_eq!(
+        raw!(USD, 0.1).checked_abs_diff(raw!(USD, 0.125)),
+        Some(raw!(USD, 0.025))
+    );
+    assert_eq!(
+        raw!(USD, -0.0001).checked_abs_diff(raw!(USD, 0.0001)),
+        Some(raw!(USD, 0.0002))
+    );
+    assert_eq!(RawMoney::<USD>::MAX.checked_abs_diff(RawMoney::MIN), None);
+}
+
+#[test]
+fn test_ratio_to() {
+    assert_eq!(
+        raw!(USD, 0.005).ratio_to(raw!(USD, 0.01)).unwrap(),
+        dec!(0.5)
+    );
+    assert!(matches!(
+        raw!(USD, 1).ratio_to(raw!(USD, 0.000)),
+        Err(MoneyError::DivisionByZeroError)
+    ));
+}
+
+#[test]
+fn test_round_up_and_round_down() {
+    assert_eq!(raw!(USD, 1.231).round_up().amount(), dec!(1.24));
+    assert_eq!(raw!(USD, 1.230).round_up().amount(), dec!(1.23));
+    assert_eq!(raw!(USD, -1.239).round_up().amount(), dec!(-1.23));
+    assert_eq!(raw!(USD, 1.239).round_down().amount(), dec!(1.23));
+    assert_eq!(raw!(USD, -1.231).round_down().amount(), dec!(-1.24));
+    assert_eq!(raw!(USD, -1.230).round_down().amount(), dec!(-1.23));
+
+    assert_eq!(raw!(JPY, 0.001).round_up().amount(), dec!(1));
+    assert_eq!(raw!(JPY, -0.001).round_down().amount(), dec!(-1));
+    assert_eq!(raw!(BHD, 0.0001).round_up().amount(), dec!(0.001));
+    assert_eq!(raw!(BHD, 0.0009).round_down().amount(), dec!(0));
+}
+
+#[test]
+fn test_mul_add() {
+    let price = raw!(USD, 19.99);
+    assert_eq!(
+        price.mul_add(dec!(0.0725), price).unwrap().amount(),
+        dec!(21.439275)
+    );
+}
+
+#[test]
+fn test_div_rem() {
+    // quotient is in minor unit, leftover keeps raw precision
+    let (quotient, remainder) = raw!(USD, 100.005).div_rem(3).unwrap();
+    assert_eq!(quotient.amount(), dec!(33.33));
+    assert_eq!(remainder.amount(), dec!(0.015));
+}
+
+#[test]
+fn test_checked_clamp() {
+    let money = raw!(USD, 10.125);
+    assert_eq!(
+        money.checked_clamp(raw!(USD, 10.1255), raw!(USD, 11)),
+        Some(raw!(USD, 10.1255))
+    );
+    assert_eq!(
+        money.checked_clamp(dec!(0), dec!(10.12)),
+        Some(raw!(USD, 10.12))
+    );
+    assert_eq!(money.checked_clamp(0, 20), Some(money));
+    assert_eq!(money.checked_clamp(dec!(11), dec!(10)), None);
+}
+
+#[test]
+fn test_map_amount() {
+    // raw amount is not rounded
+    assert_eq!(
+        raw!(USD, 10).map_amount(|d| d * dec!(0.0125)).amount(),
+        dec!(0.125)
+    );
+    assert_eq!(
+        raw!(USD, 10)
+            .try_map_amount(|d| d.checked_div(dec!(8)))
+            .unwrap()
+            .amount(),
+        dec!(1.25)
+    );
+    assert!(
+        raw!(USD, 10)
+            .try_map_amount(|d| d.checked_div(dec!(0)))
+            .is_err()
+    );
+}
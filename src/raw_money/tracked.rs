@@ -0,0 +1,326 @@
+use std::fmt::{Debug, Display};
+
+use rust_decimal::prelude::FromPrimitive;
+
+use crate::{BaseMoney, Currency, Decimal, Money, MoneyError, RawMoney};
+
+/// `RawMoney` tracking the maximum accumulated representation error of its amount.
+///
+/// Amounts constructed from `f64` are not exact: the decimal amount differs from the binary value of the float.
+/// `TrackedMoney` records an upper bound of that difference, and propagates it through arithmetic,
+/// so pipelines mixing float inputs can check whether the final rounding into [`Money`] is certain.
+///
+/// The true amount is always within `value() ± error_bound()`.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{TrackedMoney, BaseMoney, macros::dec, iso::USD};
+///
+/// let price = TrackedMoney::<USD>::from_f64(0.1).unwrap();
+/// assert_eq!(price.value().amount(), dec!(0.1));
+/// assert!(price.error_bound() > dec!(0));
+///
+/// let total = price * dec!(3) + TrackedMoney::from_f64(0.2).unwrap();
+/// assert_eq!(total.value().amount(), dec!(0.5));
+/// assert!(total.is_rounding_safe());
+/// assert_eq!(total.checked_finish().unwrap().amount(), dec!(0.50));
+///
+/// // Exactly on the rounding midpoint, the uncertainty makes the rounding ambiguous.
+/// let half_cent = TrackedMoney::<USD>::from_f64(0.005).unwrap();
+/// assert!(!half_cent.is_rounding_safe());
+/// assert!(half_cent.checked_finish().is_none());
+/// ```
+#[derive(Copy)]
+pub struct TrackedMoney<C: Currency> {
+    value: RawMoney<C>,
+    error_bound: Decimal,
+}
+
+impl<C: Currency> TrackedMoney<C> {
+    /// Creates tracked money from exact `value`, with zero error bound.
+    #[inline]
+    pub fn exact(value: RawMoney<C>) -> Self {
+        Self {
+            value,
+            error_bound: Decimal::ZERO,
+        }
+    }
+
+    /// Creates tracked money from `value` known to be within `error_bound` of the true amount.
+    ///
+    /// Negative `error_bound` is taken as its absolute value.
+    #[inline]
+    pub fn with_error_bound(value: RawMoney<C>, error_bound: Decimal) -> Self {
+        Self {
+            value,
+            error_bound: error_bound.abs(),
+        }
+    }
+
+    /// Creates tracked money from `f64`, the same way as `RawMoney::new(amount)`.
+    ///
+    /// The error bound is the difference between the decimal amount and the exact binary value of `amount`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::OverflowError`] if `amount` is not finite or cannot be represented as `Decimal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{TrackedMoney, BaseMoney, macros::dec, iso::USD};
+    ///
+    /// // 0.5 is exact in binary.
+    /// let m = TrackedMoney::<USD>::from_f64(0.5).unwrap();
+    /// assert_eq!(m.error_bound(), dec!(0));
+    ///
+    /// // 0.1 is not.
+    /// let m = TrackedMoney::<USD>::from_f64(0.1).unwrap();
+    /// assert_eq!(m.value().amount(), dec!(0.1));
+    /// assert_eq!(m.error_bound(), dec!(0.0000000000000000055511151232));
+    ///
+    /// assert!(TrackedMoney::<USD>::from_f64(f64::NAN).is_err());
+    /// ```
+    pub fn from_f64(amount: f64) -> Result<Self, MoneyError> {
+        let decimal = Decimal::from_f64(amount).ok_or(MoneyError::OverflowError)?;
+        let exact = Decimal::from_f64_retain(amount).ok_or(MoneyError::OverflowError)?;
+        let error = exact
+            .checked_sub(decimal)
+            .ok_or(MoneyError::OverflowError)?
+            .abs();
+        // `exact` keeps at most 28 decimal places, so its own truncation is covered by one more unit.
+        let error_bound = if error.is_zero() {
+            error
+        } else {
+            error
+                .checked_add(ulp(exact))
+                .ok_or(MoneyError::OverflowError)?
+        };
+
+        Ok(Self {
+            value: RawMoney::from_decimal(decimal),
+            error_bound,
+        })
+    }
+
+    /// Returns the tracked amount.
+    #[inline]
+    pub fn value(&self) -> RawMoney<C> {
+        self.value.clone()
+    }
+
+    /// Returns the maximum accumulated representation error of the amount.
+    #[inline]
+    pub fn error_bound(&self) -> Decimal {
+        self.error_bound
+    }
+
+    /// Returns `true` if every amount within `value() ± error_bound()` rounds into the same `Money`.
+    pub fn is_rounding_safe(&self) -> bool {
+        self.rounded_bounds().is_some_and(|(lo, hi)| lo == hi)
+    }
+
+    /// Rounds into `Money` like [`RawMoney::finish`], returning `None` if the rounding is not certain,
+    /// i.e. [`is_rounding_safe`](Self::is_rounding_safe) is `false`.
+    pub fn checked_finish(self) -> Option<Money<C>> {
+        let (lo, hi) = self.rounded_bounds()?;
+        (lo == hi).then(|| self.value.finish())
+    }
+
+    /// Adds `rhs`, returning `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let amount = self.value.amount().checked_add(rhs.value.amount())?;
+        let scale = self.value.amount().scale().max(rhs.value.amount().scale());
+        let error_bound = add_up(self.error_bound, rhs.error_bound)?;
+        Some(Self {
+            value: RawMoney::from_decimal(amount),
+            error_bound: with_rounding_error(error_bound, amount, scale)?,
+        })
+    }
+
+    /// Subtracts `rhs`, returning `None` on overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    /// Multiplies by exact `rhs`, returning `None` on overflow.
+    pub fn checked_mul(self, rhs: Decimal) -> Option<Self> {
+        let amount = self.value.amount().checked_mul(rhs)?;
+        let scale = self.value.amount().scale().checked_add(rhs.scale())?;
+        let error_bound = mul_up(self.error_bound, rhs.abs())?;
+        Some(Self {
+            value: RawMoney::from_decimal(amount),
+            error_bound: with_rounding_error(error_bound, amount, scale)?,
+        })
+    }
+
+    /// Divides by exact `rhs`, returning `None` on overflow or division by zero.
+    pub fn checked_div(self, rhs: Decimal) -> Option<Self> {
+        let amount = self.value.amount().checked_div(rhs)?;
+        let error_bound = div_up(self.error_bound, rhs.abs())?;
+        let error_bound = if amount.checked_mul(rhs) == Some(self.value.amount()) {
+            error_bound
+        } else {
+            add_up(error_bound, ulp(amount))?
+        };
+        Some(Self {
+            value: RawMoney::from_decimal(amount),
+            error_bound,
+        })
+    }
+
+    // Rounds both ends of the uncertainty interval into currency's minor unit.
+    fn rounded_bounds(&self) -> Option<(Decimal, Decimal)> {
+        let amount = self.value.amount();
+        let lo = amount.checked_sub(self.error_bound)?;
+        let hi = amount.checked_add(self.error_bound)?;
+        Some((
+            RawMoney::<C>::from_decimal(lo).finish().amount(),
+            RawMoney::<C>::from_decimal(hi).finish().amount(),
+        ))
+    }
+}
+
+// One unit in the last place of `d`.
+fn ulp(d: Decimal) -> Decimal {
+    Decimal::new(1, d.scale())
+}
+
+// Adds non-negative error bounds, rounding up if the sum is inexact.
+fn add_up(a: Decimal, b: Decimal) -> Option<Decimal> {
+    let ret = a.checked_add(b)?;
+    with_rounding_error(ret, ret, a.scale().max(b.scale()))
+}
+
+// Multiplies non-negative error bounds, rounding up if the product is inexact.
+fn mul_up(a: Decimal, b: Decimal) -> Option<Decimal> {
+    let ret = a.checked_mul(b)?;
+    with_rounding_error(ret, ret, a.scale().checked_add(b.scale())?)
+}
+
+// Divides non-negative error bounds, rounding up if the quotient is inexact.
+fn div_up(a: Decimal, b: Decimal) -> Option<Decimal> {
+    let ret = a.checked_div(b)?;
+    if ret.checked_mul(b) == Some(a) {
+        Some(ret)
+    } else {
+        ret.checked_add(ulp(ret))
+    }
+}
+
+// `Decimal` keeps at most 28 decimal places, if `result` has less scale than the exact `scale`, it was rounded
+// by at most one unit in its last place.
+fn with_rounding_error(error_bound: Decimal, result: Decimal, scale: u32) -> Option<Decimal> {
+    if result.scale() < scale && !result.is_zero() {
+        error_bound.checked_add(ulp(result))
+    } else {
+        Some(error_bound)
+    }
+}
+
+impl<C: Currency> Clone for TrackedMoney<C> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            error_bound: self.error_bound,
+        }
+    }
+}
+
+impl<C: Currency> PartialEq for TrackedMoney<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.error_bound == other.error_bound
+    }
+}
+
+impl<C: Currency> Eq for TrackedMoney<C> {}
+
+impl<C: Currency> From<RawMoney<C>> for TrackedMoney<C> {
+    /// Tracks exact `value`, with zero error bound.
+    fn from(value: RawMoney<C>) -> Self {
+        Self::exact(value)
+    }
+}
+
+/// T + T = T
+///
+/// # Panics
+///
+/// Panics if the addition overflows the internal `Decimal` representation.
+#[cfg(feature = "panic_ops")]
+impl<C: Currency> ::std::ops::Add for TrackedMoney<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("addition operation overflow")
+    }
+}
+
+/// T - T = T
+///
+/// # Panics
+///
+/// Panics if the subtraction overflows the internal `Decimal` representation.
+#[cfg(feature = "panic_ops")]
+impl<C: Currency> ::std::ops::Sub for TrackedMoney<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("subtraction operation overflow")
+    }
+}
+
+/// T * d = T
+///
+/// # Panics
+///
+/// Panics if the multiplication overflows the internal `Decimal` representation.
+#[cfg(feature = "panic_ops")]
+impl<C: Currency> ::std::ops::Mul<Decimal> for TrackedMoney<C> {
+    type Output = Self;
+
+    fn mul(self, rhs: Decimal) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("multiplication operation overflow")
+    }
+}
+
+/// T / d = T
+///
+/// # Panics
+///
+/// Panics if the division overflows or divides by zero.
+#[cfg(feature = "panic_ops")]
+impl<C: Currency> ::std::ops::Div<Decimal> for TrackedMoney<C> {
+    type Output = Self;
+
+    fn div(self, rhs: Decimal) -> Self::Output {
+        self.checked_div(rhs).expect("division operation overflow")
+    }
+}
+
+/// -T = T
+impl<C: Currency> ::std::ops::Neg for TrackedMoney<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            value: -self.value,
+            error_bound: self.error_bound,
+        }
+    }
+}
+
+impl<C: Currency> Display for TrackedMoney<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ± {}", self.value, self.error_bound)
+    }
+}
+
+impl<C: Currency> Debug for TrackedMoney<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TrackedMoney({:?} ± {})", self.value, self.error_bound)
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    BaseMoney, Decimal, RawMoney, TrackedMoney,
+    iso::{JPY, USD},
+    macros::{dec, raw},
+};
+
+#[test]
+fn test_exact() {
+    let m = TrackedMoney::exact(raw!(USD, 12.345));
+    assert_eq!(m.value(), raw!(USD, 12.345));
+    assert_eq!(m.error_bound(), Decimal::ZERO);
+    assert!(m.is_rounding_safe());
+    assert_eq!(m.checked_finish().unwrap().amount(), dec!(12.34));
+
+    let m: TrackedMoney<USD> = raw!(USD, 1).into();
+    assert_eq!(m, TrackedMoney::exact(raw!(USD, 1)));
+}
+
+#[test]
+fn test_with_error_bound() {
+    let m = TrackedMoney::with_error_bound(raw!(USD, 1.232), dec!(-0.001));
+    assert_eq!(m.error_bound(), dec!(0.001));
+    assert!(m.is_rounding_safe());
+
+    let m = TrackedMoney::with_error_bound(raw!(USD, 1.234), dec!(0.002));
+    assert!(!m.is_rounding_safe());
+    assert_eq!(m.checked_finish(), None);
+}
+
+#[test]
+fn test_from_f64() {
+    let m = TrackedMoney::<USD>::from_f64(0.25).unwrap();
+    assert_eq!(m.value().amount(), dec!(0.25));
+    assert_eq!(m.error_bound(), Decimal::ZERO);
+
+    let m = TrackedMoney::<USD>::from_f64(1234.56).unwrap();
+    assert_eq!(m.value().amount(), dec!(1234.56));
+    assert!(m.error_bound() > Decimal::ZERO);
+    assert!(m.error_bound() < dec!(0.000000000001));
+
+    let m = TrackedMoney::<USD>::from_f64(-0.1).unwrap();
+    assert_eq!(m.value().amount(), dec!(-0.1));
+    assert!(m.error_bound() > Decimal::ZERO);
+
+    assert!(TrackedMoney::<USD>::from_f64(f64::NAN).is_err());
+    assert!(TrackedMoney::<USD>::from_f64(f64::INFINITY).is_err());
+    assert!(TrackedMoney::<USD>::from_f64(1e30).is_err());
+}
+
+#[test]
+fn test_from_f64_matches_raw_money() {
+    for v in [0.1, 0.2, 0.3, 1.005, 99.99, -42.125, 1e-10] {
+        let m = TrackedMoney::<USD>::from_f64(v).unwrap();
+        assert_eq!(m.value(), RawMoney::<USD>::new(v).unwrap());
+    }
+}
+
+#[test]
+fn test_add_sub_accumulate() {
+    let a = TrackedMoney::<USD>::from_f64(0.1).unwrap();
+    let b = TrackedMoney::<USD>::from_f64(0.2).unwrap();
+
+    let sum = a + b;
+    assert_eq!(sum.value().amount(), dec!(0.3));
+    assert_eq!(sum.error_bound(), a.error_bound() + b.error_bound());
+
+    let diff = a - b;
+    assert_eq!(diff.value().amount(), dec!(-0.1));
+    assert_eq!(diff.error_bound(), a.error_bound() + b.error_bound());
+
+    let exact = TrackedMoney::exact(raw!(USD, 10));
+    assert_eq!((a + exact).error_bound(), a.error_bound());
+}
+
+#[test]
+fn test_mul_div() {
+    let a = TrackedMoney::<USD>::from_f64(0.1).unwrap();
+
+    let m = a * dec!(-3);
+    assert_eq!(m.value().amount(), dec!(-0.3));
+    assert_eq!(m.error_bound(), a.error_bound() * dec!(3));
+
+    let d = a / dec!(2);
+    assert_eq!(d.value().amount(), dec!(0.05));
+    assert!(d.error_bound() >= a.error_bound() / dec!(2));
+
+    // Inexact division adds its own rounding error.
+    let exact = TrackedMoney::exact(raw!(USD, 1));
+    let d = exact / dec!(3);
+    assert!(d.error_bound() > Decimal::ZERO);
+    assert!(d.is_rounding_safe());
+    assert_eq!(d.checked_finish().unwrap().amount(), dec!(0.33));
+
+    assert!(exact.checked_div(Decimal::ZERO).is_none());
+    assert!(exact.checked_mul(Decimal::MAX).is_some());
+    assert!(
+        exact
+            .checked_mul(Decimal::MAX)
+            .unwrap()
+            .checked_mul(dec!(2))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_neg() {
+    let a = TrackedMoney::<USD>::from_f64(0.1).unwrap();
+    let n = -a;
+    assert_eq!(n.value().amount(), dec!(-0.1));
+    assert_eq!(n.error_bound(), a.error_bound());
+}
+
+#[test]
+fn test_rounding_safety() {
+    // 2.675 in binary is slightly below 2.675.
+    let m = TrackedMoney::<USD>::from_f64(2.675).unwrap();
+    assert_eq!(m.value().amount(), dec!(2.675));
+    assert!(!m.is_rounding_safe());
+    assert!(m.checked_finish().is_none());
+
+    let m = TrackedMoney::<USD>::from_f64(2.674).unwrap();
+    assert!(m.is_rounding_safe());
+    assert_eq!(m.checked_finish().unwrap().amount(), dec!(2.67));
+
+    let m = TrackedMoney::<JPY>::from_f64(100.5).unwrap();
+    assert_eq!(m.error_bound(), Decimal::ZERO);
+    assert!(m.is_rounding_safe());
+    assert_eq!(m.checked_finish().unwrap().amount(), dec!(100));
+}
+
+#[test]
+fn test_pipeline() {
+    let prices = [19.99, 5.01, 0.1, 0.7];
+    let total = prices
+        .iter()
+        .map(|&p| TrackedMoney::<USD>::from_f64(p).unwrap())
+        .fold(TrackedMoney::exact(raw!(USD, 0)), |acc, p| acc + p);
+    assert_eq!(total.value().amount(), dec!(25.80));
+    let with_tax = total * dec!(1.1);
+    assert!(with_tax.is_rounding_safe());
+    assert_eq!(with_tax.checked_finish().unwrap().amount(), dec!(28.38));
+}
+
+#[test]
+fn test_display_debug() {
+    let m = TrackedMoney::with_error_bound(raw!(USD, 1.5), dec!(0.001));
+    assert_eq!(format!("{}", m), format!("{} ± 0.001", raw!(USD, 1.5)));
+    assert_eq!(
+        format!("{:?}", m),
+        format!("TrackedMoney({:?} ± 0.001)", raw!(USD, 1.5))
+    );
+}
+
+#[test]
+#[should_panic(expected = "addition operation overflow")]
+fn test_add_overflow_panics() {
+    let m = TrackedMoney::exact(RawMoney::<USD>::from_decimal(Decimal::MAX));
+    let _ = m + m;
+}
@@ -0,0 +1,133 @@
+//! running_ops contains iterator adaptors producing running totals of money.
+//!
+//! It has blanket implementation for iterators yielding types implementing BaseMoney.
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use crate::{BaseMoney, BaseOps, Currency, MoneyError};
+
+/// Trait for iterator adaptors producing running totals of money.
+///
+/// It has blanket implementation for iterators yielding types implementing BaseMoney.
+pub trait RunningOps<C: Currency>: Iterator + Sized {
+    /// Turns iterator of money into iterator of running totals starting from zero.
+    ///
+    /// Each item is `Ok(total)` of all entries so far. Entries can be negative.
+    /// If adding an entry overflows, `Err(MoneyError::OverflowError)` is yielded once and iteration stops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, RunningOps, iso::USD, macros::{dec, money}};
+    ///
+    /// let entries = vec![money!(USD, 100), money!(USD, -30.50), money!(USD, 20)];
+    /// let totals: Vec<Money<USD>> = entries
+    ///     .into_iter()
+    ///     .cumulative_sum()
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(totals, vec![money!(USD, 100), money!(USD, 69.50), money!(USD, 89.50)]);
+    ///
+    /// // Stops on overflow
+    /// let entries = vec![money!(USD, 1), Money::<USD>::from_decimal(moneylib::Decimal::MAX)];
+    /// let totals: Vec<_> = entries.into_iter().cumulative_sum().collect();
+    /// assert_eq!(totals.len(), 2);
+    /// assert!(totals[1].is_err());
+    /// ```
+    fn cumulative_sum(self) -> RunningBalance<Self, Self::Item, C>
+    where
+        Self::Item: BaseMoney<C> + BaseOps<C> + Default,
+    {
+        self.running_balance(Self::Item::default())
+    }
+
+    /// Turns iterator of money into iterator of running balances starting from `opening`.
+    ///
+    /// Each item is `Ok(balance)` after applying the entry to previous balance. Entries can be negative.
+    /// Opening balance itself is not yielded.
+    /// If applying an entry overflows, `Err(MoneyError::OverflowError)` is yielded once and iteration stops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::{BaseMoney, Money, RunningOps, iso::USD, macros::{dec, money}};
+    ///
+    /// let opening = money!(USD, 1_000);
+    /// let entries = [money!(USD, -250), money!(USD, 75.25), money!(USD, -900)];
+    /// let balances: Vec<Money<USD>> = entries
+    ///     .iter()
+    ///     .copied()
+    ///     .running_balance(opening)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(balances, vec![money!(USD, 750), money!(USD, 825.25), money!(USD, -74.75)]);
+    /// ```
+    fn running_balance(self, opening: Self::Item) -> RunningBalance<Self, Self::Item, C>
+    where
+        Self::Item: BaseMoney<C> + BaseOps<C>,
+    {
+        RunningBalance {
+            iter: self,
+            balance: Some(opening),
+            _currency: PhantomData,
+        }
+    }
+}
+
+impl<I, C> RunningOps<C> for I
+where
+    I: Iterator,
+    C: Currency,
+{
+}
+
+/// Iterator of running balances.
+///
+/// Created by [`RunningOps::running_balance`] and [`RunningOps::cumulative_sum`].
+#[derive(Debug, Clone)]
+pub struct RunningBalance<I, T, C> {
+    iter: I,
+    // None after overflow.
+    balance: Option<T>,
+    _currency: PhantomData<C>,
+}
+
+impl<I, T, C> Iterator for RunningBalance<I, T, C>
+where
+    I: Iterator<Item = T>,
+    T: BaseMoney<C> + BaseOps<C>,
+    C: Currency,
+{
+    type Item = Result<T, MoneyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let balance = self.balance.as_ref()?;
+        let entry = self.iter.next()?;
+        match balance.checked_add(entry.amount()) {
+            Some(next) => {
+                self.balance = Some(next.clone());
+                Some(Ok(next))
+            }
+            None => {
+                self.balance = None;
+                Some(Err(MoneyError::OverflowError))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.balance.is_none() {
+            return (0, Some(0));
+        }
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, C> FusedIterator for RunningBalance<I, T, C>
+where
+    I: FusedIterator<Item = T>,
+    T: BaseMoney<C> + BaseOps<C>,
+    C: Currency,
+{
+}
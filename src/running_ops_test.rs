@@ -0,0 +1,81 @@
+use crate::iso::USD;
+use crate::macros::{dec, money, raw};
+use crate::{BaseMoney, Decimal, Money, MoneyError, RawMoney, RunningOps};
+
+#[test]
+fn test_cumulative_sum() {
+    let entries = vec![
+        money!(USD, 10),
+        money!(USD, 20.25),
+        money!(USD, -5.50),
+        money!(USD, 0),
+    ];
+    let totals: Vec<Money<USD>> = entries
+        .into_iter()
+        .cumulative_sum()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        totals,
+        vec![
+            money!(USD, 10),
+            money!(USD, 30.25),
+            money!(USD, 24.75),
+            money!(USD, 24.75)
+        ]
+    );
+
+    let empty: Vec<Money<USD>> = vec![];
+    assert_eq!(empty.into_iter().cumulative_sum().count(), 0);
+}
+
+#[test]
+fn test_running_balance() {
+    let opening = money!(USD, 100);
+    let entries = [money!(USD, -150), money!(USD, 25.01)];
+    let balances: Vec<Money<USD>> = entries
+        .iter()
+        .copied()
+        .running_balance(opening)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(balances, vec![money!(USD, -50), money!(USD, -24.99)]);
+
+    let entries = [money!(USD, 1)];
+    assert_eq!(
+        entries.into_iter().running_balance(opening).size_hint(),
+        (1, Some(1))
+    );
+}
+
+#[test]
+fn test_running_balance_raw_money() {
+    let entries = vec![raw!(USD, 0.001), raw!(USD, 0.0025)];
+    let totals: Vec<RawMoney<USD>> = entries
+        .into_iter()
+        .running_balance(raw!(USD, 1))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(totals[0].amount(), dec!(1.001));
+    assert_eq!(totals[1].amount(), dec!(1.0035));
+}
+
+#[test]
+fn test_running_balance_overflow() {
+    let entries = vec![
+        money!(USD, 1),
+        Money::<USD>::from_decimal(Decimal::MAX),
+        money!(USD, 1),
+        money!(USD, 1),
+    ];
+    let mut iter = entries.into_iter().cumulative_sum();
+    assert_eq!(iter.next().unwrap().unwrap(), money!(USD, 1));
+    assert!(matches!(iter.next(), Some(Err(MoneyError::OverflowError))));
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let entries = vec![money!(USD, 1), Money::<USD>::from_decimal(Decimal::MAX)];
+    let res: Result<Vec<_>, _> = entries.into_iter().cumulative_sum().collect();
+    assert!(matches!(res, Err(MoneyError::OverflowError)));
+}
@@ -0,0 +1,86 @@
+//! rusqlite contains [`ToSql`] and [`FromSql`] implementations storing money in SQLite.
+//!
+//! SQLite has no decimal type, so amounts are stored as `INTEGER` minor units, e.g. USD 12.34 as `1234`.
+//! Amounts not fitting that, e.g. `RawMoney` with more decimal places than the currency's minor unit or
+//! minor units beyond `i64`, fall back into `TEXT` decimal string, e.g. `'12.3456'`. `REAL` is never used.
+//! Only the amount is stored, the currency comes from the type.
+//!
+//! Both `INTEGER` and `TEXT` are read back. Reading `Money<C>` fails on `TEXT` amounts with more decimal places
+//! than `C`'s minor unit, see [storage invariants](crate#storage).
+//!
+//! ```ignore
+//! use moneylib::{Money, iso::USD, money};
+//! use rusqlite::Connection;
+//!
+//! let conn = Connection::open_in_memory()?;
+//! conn.execute("CREATE TABLE invoices (total INTEGER NOT NULL)", ())?;
+//! conn.execute("INSERT INTO invoices (total) VALUES (?1)", (money!(USD, 12.34),))?;
+//!
+//! let total: Money<USD> = conn.query_row("SELECT total FROM invoices", (), |row| row.get(0))?;
+//! ```
+
+use crate::{BaseMoney, Currency, Decimal, Money, MoneyError};
+use ::rusqlite::types::{
+    FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, Value, ValueRef,
+};
+
+// `INTEGER` minor units of `amount` if it has no more decimal places than `C`'s minor unit and fits `i64`.
+fn minor_units<C: Currency>(amount: Decimal) -> Option<i64> {
+    if amount.normalize().scale() > u32::from(C::MINOR_UNIT) {
+        return None;
+    }
+    crate::decimal::to_scaled_i128(amount, C::MINOR_UNIT.into())?
+        .try_into()
+        .ok()
+}
+
+// Stores `amount` as `INTEGER` minor units, falling back into `TEXT`.
+fn to_sql_output<C: Currency>(amount: Decimal) -> ToSqlOutput<'static> {
+    match minor_units::<C>(amount) {
+        Some(minor) => ToSqlOutput::Owned(Value::Integer(minor)),
+        None => ToSqlOutput::Owned(Value::Text(amount.to_string())),
+    }
+}
+
+// Reads `INTEGER` minor units or `TEXT` decimal string.
+fn column_amount<C: Currency>(value: ValueRef<'_>) -> FromSqlResult<Decimal> {
+    match value {
+        ValueRef::Integer(minor) => {
+            crate::decimal::from_scaled_i128(minor.into(), C::MINOR_UNIT.into())
+                .ok_or(FromSqlError::OutOfRange(minor))
+        }
+        ValueRef::Text(_) => Decimal::from_str_exact(value.as_str()?.trim()).map_err(|err| {
+            FromSqlError::Other(Box::new(MoneyError::ParseStrError(err.to_string().into())))
+        }),
+        _ => Err(FromSqlError::InvalidType),
+    }
+}
+
+impl<C: Currency> ToSql for Money<C> {
+    fn to_sql(&self) -> ::rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(to_sql_output::<C>(self.amount()))
+    }
+}
+
+impl<C: Currency> FromSql for Money<C> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let amount = column_amount::<C>(value)?;
+        crate::currency::check_minor_unit::<C>(amount)
+            .map_err(|err| FromSqlError::Other(Box::new(err)))?;
+        Ok(Self::from_decimal(amount))
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> ToSql for crate::RawMoney<C> {
+    fn to_sql(&self) -> ::rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(to_sql_output::<C>(self.amount()))
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> FromSql for crate::RawMoney<C> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        Ok(Self::from_decimal(column_amount::<C>(value)?))
+    }
+}
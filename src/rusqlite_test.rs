@@ -0,0 +1,149 @@
+use ::rusqlite::Connection;
+use ::rusqlite::types::{FromSql, FromSqlError, ToSql, ToSqlOutput, Value, ValueRef};
+
+use crate::iso::{JPY, USD};
+use crate::{BaseMoney, Money, MoneyError, macros::dec, money};
+
+fn stored(value: &impl ToSql) -> Value {
+    match value.to_sql().unwrap() {
+        ToSqlOutput::Owned(value) => value,
+        ToSqlOutput::Borrowed(value) => value.into(),
+        _ => panic!("unexpected sql output"),
+    }
+}
+
+fn connection() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE ledger (id INTEGER PRIMARY KEY, amount)", ())
+        .unwrap();
+    conn
+}
+
+#[test]
+fn test_to_sql_minor_units() {
+    assert_eq!(stored(&money!(USD, 12.34)), Value::Integer(1234));
+    assert_eq!(stored(&money!(USD, -0.05)), Value::Integer(-5));
+    assert_eq!(stored(&money!(JPY, 1500)), Value::Integer(1500));
+    assert_eq!(stored(&money!(BHD, 1.5)), Value::Integer(1500));
+}
+
+#[test]
+fn test_to_sql_text_fallback_beyond_i64() {
+    let money = Money::<USD>::new(dec!(100_000_000_000_000_000)).unwrap();
+    assert_eq!(
+        stored(&money),
+        Value::Text("100000000000000000".to_string())
+    );
+}
+
+#[test]
+fn test_from_sql() {
+    let money = Money::<USD>::column_result(ValueRef::Integer(1234)).unwrap();
+    assert_eq!(money.amount(), dec!(12.34));
+
+    let money = Money::<JPY>::column_result(ValueRef::Integer(-1500)).unwrap();
+    assert_eq!(money.amount(), dec!(-1500));
+
+    let money = Money::<USD>::column_result(ValueRef::Text(b"12.3400")).unwrap();
+    assert_eq!(money.amount(), dec!(12.34));
+}
+
+#[test]
+fn test_from_sql_invalid() {
+    assert!(matches!(
+        Money::<USD>::column_result(ValueRef::Real(12.34)),
+        Err(FromSqlError::InvalidType)
+    ));
+    assert!(matches!(
+        Money::<USD>::column_result(ValueRef::Null),
+        Err(FromSqlError::InvalidType)
+    ));
+    assert!(matches!(
+        Money::<USD>::column_result(ValueRef::Text(b"12,34")),
+        Err(FromSqlError::Other(_))
+    ));
+
+    let Err(FromSqlError::Other(err)) = Money::<USD>::column_result(ValueRef::Text(b"12.345"))
+    else {
+        panic!("expected minor unit error");
+    };
+    assert!(matches!(
+        err.downcast_ref::<MoneyError>(),
+        Some(MoneyError::MinorUnitError(_))
+    ));
+}
+
+#[test]
+fn test_round_trip() {
+    let conn = connection();
+    conn.execute(
+        "INSERT INTO ledger (id, amount) VALUES (1, ?1), (2, ?2)",
+        (money!(USD, 1234.56), money!(USD, -7.01)),
+    )
+    .unwrap();
+
+    let kind: String = conn
+        .query_row(
+            "SELECT typeof(amount) FROM ledger WHERE id = 1",
+            (),
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(kind, "integer");
+
+    let amounts: Vec<Money<USD>> = conn
+        .prepare("SELECT amount FROM ledger ORDER BY id")
+        .unwrap()
+        .query_map((), |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(amounts, [money!(USD, 1234.56), money!(USD, -7.01)]);
+
+    let total: Money<USD> = conn
+        .query_row("SELECT SUM(amount) FROM ledger", (), |row| row.get(0))
+        .unwrap();
+    assert_eq!(total, money!(USD, 1227.55));
+
+    let missing: Option<Money<USD>> = conn
+        .query_row("SELECT SUM(amount) FROM ledger WHERE id > 2", (), |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert!(missing.is_none());
+}
+
+#[cfg(feature = "raw_money")]
+#[test]
+fn test_raw_money_round_trip() {
+    use crate::{RawMoney, raw};
+
+    assert_eq!(stored(&raw!(USD, 12.30)), Value::Integer(1230));
+    assert_eq!(
+        stored(&raw!(USD, 12.3456)),
+        Value::Text("12.3456".to_string())
+    );
+
+    let conn = connection();
+    conn.execute(
+        "INSERT INTO ledger (id, amount) VALUES (1, ?1), (2, ?2)",
+        (raw!(USD, 12.3456), raw!(USD, 99.99)),
+    )
+    .unwrap();
+
+    let amounts: Vec<RawMoney<USD>> = conn
+        .prepare("SELECT amount FROM ledger ORDER BY id")
+        .unwrap()
+        .query_map((), |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(amounts, [raw!(USD, 12.3456), raw!(USD, 99.99)]);
+
+    // precise text amount isn't silently rounded into Money.
+    let money: Result<Money<USD>, _> =
+        conn.query_row("SELECT amount FROM ledger WHERE id = 1", (), |row| {
+            row.get(0)
+        });
+    assert!(money.is_err());
+}
@@ -0,0 +1,207 @@
+//! schemars contains [`JsonSchema`] implementations describing formats of the serde implementations.
+//!
+//! `Money<C>` and `RawMoney<C>` are described as JSON numbers, their default serde format.
+//! Formats of the `serde` with-modules are described by marker types in this module, used with
+//! `#[schemars(with = "...")]`:
+//!
+//! ```
+//! use moneylib::{Money, iso::USD, schemars::{Minor, StrCode}};
+//!
+//! #[derive(::serde::Serialize, ::schemars::JsonSchema)]
+//! struct Invoice {
+//!     total: Money<USD>,
+//!
+//!     #[serde(with = "moneylib::serde::money::str_code")]
+//!     #[schemars(with = "StrCode<USD>")]
+//!     display: Money<USD>,
+//!
+//!     #[serde(with = "moneylib::serde::money::option_minor")]
+//!     #[schemars(with = "Option<Minor<USD>>")]
+//!     fee: Option<Money<USD>>,
+//! }
+//!
+//! let schema = ::schemars::schema_for!(Invoice);
+//! let value = schema.as_value();
+//! assert_eq!(value["properties"]["display"]["$ref"], "#/$defs/StrCode_USD");
+//! assert_eq!(value["$defs"]["StrCode_USD"]["pattern"], r"^USD -?[0-9]{1,3}(,[0-9]{3})*(\.[0-9]+)?$");
+//! ```
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use ::schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use crate::{Currency, Money};
+
+const DECIMAL_PATTERN: &str = r"^-?[0-9]+(\.[0-9]+)?$";
+const INTEGER_PATTERN: &str = r"^-?[0-9]+$";
+
+// Escapes regex syntax characters in `s`. Other characters, e.g. `-`, must not be escaped,
+// since ECMA-262 patterns with `u` flag reject identity escapes outside the syntax characters.
+fn escape(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len());
+    for c in s.chars() {
+        if r"\^$.|?*+()[]{}".contains(c) {
+            ret.push('\\');
+        }
+        ret.push(c);
+    }
+    ret
+}
+
+// Pattern of formatted amount with thousands grouping, without anchors.
+fn grouped_amount(thousand_separator: &str, decimal_separator: &str) -> String {
+    let integer = if thousand_separator.is_empty() {
+        String::from("[0-9]+")
+    } else {
+        format!("[0-9]{{1,3}}({}[0-9]{{3}})*", escape(thousand_separator))
+    };
+    format!("{}({}[0-9]+)?", integer, escape(decimal_separator))
+}
+
+fn code_pattern<C: Currency>(thousand_separator: &str, decimal_separator: &str) -> String {
+    format!(
+        "^{} -?{}$",
+        escape(C::CODE),
+        grouped_amount(thousand_separator, decimal_separator)
+    )
+}
+
+fn symbol_pattern<C: Currency>(thousand_separator: &str, decimal_separator: &str) -> String {
+    format!(
+        "^-?{}{}$",
+        escape(C::SYMBOL),
+        grouped_amount(thousand_separator, decimal_separator)
+    )
+}
+
+fn number_schema<C: Currency>(kind: &str) -> Schema {
+    json_schema!({
+        "type": "number",
+        "description": format!("{} amount of {}", kind, C::CODE),
+    })
+}
+
+impl<C: Currency> JsonSchema for Money<C> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("Money_{}", C::CODE).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("moneylib::Money<{}>", C::CODE).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        number_schema::<C>("Money")
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> JsonSchema for crate::RawMoney<C> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("RawMoney_{}", C::CODE).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("moneylib::RawMoney<{}>", C::CODE).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        number_schema::<C>("RawMoney")
+    }
+}
+
+macro_rules! impl_format_schemas {
+    ($($(#[$doc:meta])* $name:ident => |$c:ident| $schema:expr;)*) => {
+        $(
+            $(#[$doc])*
+            pub struct $name<C: Currency>(PhantomData<C>);
+
+            impl<$c: Currency> JsonSchema for $name<$c> {
+                fn schema_name() -> Cow<'static, str> {
+                    format!("{}_{}", stringify!($name), $c::CODE).into()
+                }
+
+                fn schema_id() -> Cow<'static, str> {
+                    format!("moneylib::schemars::{}<{}>", stringify!($name), $c::CODE).into()
+                }
+
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    $schema
+                }
+            }
+        )*
+    };
+}
+
+impl_format_schemas! {
+    /// Schema of `str_code` format, e.g. `"USD 1,234.56"`, with currency's separators.
+    StrCode => |C| json_schema!({
+        "type": "string",
+        "pattern": code_pattern::<C>(C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR),
+    });
+
+    /// Schema of `str_symbol` format, e.g. `"$1,234.56"`, with currency's separators.
+    StrSymbol => |C| json_schema!({
+        "type": "string",
+        "pattern": symbol_pattern::<C>(C::THOUSAND_SEPARATOR, C::DECIMAL_SEPARATOR),
+    });
+
+    /// Schema of `comma_str_code` format, e.g. `"USD 1,234.56"`.
+    CommaStrCode => |C| json_schema!({
+        "type": "string",
+        "pattern": code_pattern::<C>(",", "."),
+    });
+
+    /// Schema of `comma_str_symbol` format, e.g. `"$1,234.56"`.
+    CommaStrSymbol => |C| json_schema!({
+        "type": "string",
+        "pattern": symbol_pattern::<C>(",", "."),
+    });
+
+    /// Schema of `dot_str_code` format, e.g. `"EUR 1.234,56"`.
+    DotStrCode => |C| json_schema!({
+        "type": "string",
+        "pattern": code_pattern::<C>(".", ","),
+    });
+
+    /// Schema of `dot_str_symbol` format, e.g. `"€1.234,56"`.
+    DotStrSymbol => |C| json_schema!({
+        "type": "string",
+        "pattern": symbol_pattern::<C>(".", ","),
+    });
+
+    /// Schema of `minor` format, integer of minor amount, e.g. `123456`.
+    Minor => |C| json_schema!({
+        "type": "integer",
+        "description": format!("minor amount of {}", C::CODE),
+    });
+
+    /// Schema of `strict` format, decimal string, e.g. `"1234.56"`.
+    Strict => |C| json_schema!({
+        "type": "string",
+        "pattern": DECIMAL_PATTERN,
+        "description": format!("amount of {}", C::CODE),
+    });
+
+    /// Schema of `object` format, e.g. `{ "currency": "USD", "amount": "1234.56" }`.
+    Object => |C| json_schema!({
+        "type": "object",
+        "properties": {
+            "currency": { "const": C::CODE },
+            "amount": { "type": "string", "pattern": DECIMAL_PATTERN },
+        },
+        "required": ["currency", "amount"],
+    });
+
+    /// Schema of `proto` format, `google.type.Money` JSON, e.g. `{ "currencyCode": "USD", "units": "1234", "nanos": 560000000 }`.
+    Proto => |C| json_schema!({
+        "type": "object",
+        "properties": {
+            "currencyCode": { "const": C::CODE },
+            "units": { "type": "string", "pattern": INTEGER_PATTERN },
+            "nanos": { "type": "integer", "minimum": -999_999_999, "maximum": 999_999_999 },
+        },
+        "required": ["currencyCode"],
+    });
+}
@@ -0,0 +1,176 @@
+use schemars::{JsonSchema, schema_for};
+use serde_json::json;
+
+use crate::iso::{EUR, JPY, USD};
+use crate::schemars::{
+    CommaStrCode, CommaStrSymbol, DotStrCode, DotStrSymbol, Minor, Object, Proto, StrCode,
+    StrSymbol, Strict,
+};
+use crate::{Money, RawMoney};
+
+#[test]
+fn test_money_schema() {
+    let schema = schema_for!(Money<USD>);
+    assert_eq!(schema.as_value()["type"], "number");
+    assert_eq!(schema.as_value()["title"], "Money_USD");
+    assert_eq!(schema.as_value()["description"], "Money amount of USD");
+    assert_eq!(Money::<EUR>::schema_name(), "Money_EUR");
+    assert_eq!(Money::<EUR>::schema_id(), "moneylib::Money<EUR>");
+}
+
+#[test]
+fn test_raw_money_schema() {
+    let schema = schema_for!(RawMoney<JPY>);
+    assert_eq!(schema.as_value()["type"], "number");
+    assert_eq!(schema.as_value()["title"], "RawMoney_JPY");
+    assert_eq!(schema.as_value()["description"], "RawMoney amount of JPY");
+}
+
+#[test]
+fn test_str_code_schema() {
+    let schema = schema_for!(StrCode<USD>);
+    assert_eq!(schema.as_value()["type"], "string");
+    assert_eq!(
+        schema.as_value()["pattern"],
+        r"^USD -?[0-9]{1,3}(,[0-9]{3})*(\.[0-9]+)?$"
+    );
+
+    let schema = schema_for!(CommaStrCode<EUR>);
+    assert_eq!(
+        schema.as_value()["pattern"],
+        r"^EUR -?[0-9]{1,3}(,[0-9]{3})*(\.[0-9]+)?$"
+    );
+
+    let schema = schema_for!(DotStrCode<USD>);
+    assert_eq!(
+        schema.as_value()["pattern"],
+        r"^USD -?[0-9]{1,3}(\.[0-9]{3})*(,[0-9]+)?$"
+    );
+}
+
+#[test]
+fn test_str_symbol_schema() {
+    let schema = schema_for!(StrSymbol<USD>);
+    assert_eq!(schema.as_value()["type"], "string");
+    assert_eq!(
+        schema.as_value()["pattern"],
+        r"^-?\$[0-9]{1,3}(,[0-9]{3})*(\.[0-9]+)?$"
+    );
+
+    let schema = schema_for!(CommaStrSymbol<EUR>);
+    assert_eq!(
+        schema.as_value()["pattern"],
+        r"^-?€[0-9]{1,3}(,[0-9]{3})*(\.[0-9]+)?$"
+    );
+
+    let schema = schema_for!(DotStrSymbol<EUR>);
+    assert_eq!(
+        schema.as_value()["pattern"],
+        r"^-?€[0-9]{1,3}(\.[0-9]{3})*(,[0-9]+)?$"
+    );
+}
+
+// Hyphen in separator and symbol, not a regex syntax character outside of classes.
+#[derive(Clone)]
+struct Hyphenated;
+impl crate::Currency for Hyphenated {
+    const CODE: &'static str = "HYP";
+    const SYMBOL: &'static str = "H-$";
+    const NAME: &'static str = "Hyphenated";
+    const NUMERIC: u16 = 999;
+    const MINOR_UNIT: u16 = 2;
+    const MINOR_UNIT_SYMBOL: &'static str = "h";
+    const MINOR_UNIT_NAME: &'static str = "hyp";
+    const THOUSAND_SEPARATOR: &'static str = "-";
+    const DECIMAL_SEPARATOR: &'static str = ".";
+    const ORIGIN: &'static str = "Hyphen Land";
+    const LOCALE: &'static str = "en-US";
+}
+
+#[test]
+fn test_pattern_hyphen_not_escaped() {
+    let schema = schema_for!(StrCode<Hyphenated>);
+    assert_eq!(
+        schema.as_value()["pattern"],
+        r"^HYP -?[0-9]{1,3}(-[0-9]{3})*(\.[0-9]+)?$"
+    );
+
+    let schema = schema_for!(StrSymbol<Hyphenated>);
+    assert_eq!(
+        schema.as_value()["pattern"],
+        r"^-?H-\$[0-9]{1,3}(-[0-9]{3})*(\.[0-9]+)?$"
+    );
+}
+
+#[test]
+fn test_minor_schema() {
+    let schema = schema_for!(Minor<USD>);
+    assert_eq!(schema.as_value()["type"], "integer");
+    assert_eq!(schema.as_value()["description"], "minor amount of USD");
+}
+
+#[test]
+fn test_strict_schema() {
+    let schema = schema_for!(Strict<USD>);
+    assert_eq!(schema.as_value()["type"], "string");
+    assert_eq!(schema.as_value()["pattern"], r"^-?[0-9]+(\.[0-9]+)?$");
+}
+
+#[test]
+fn test_object_schema() {
+    let schema = schema_for!(Object<USD>);
+    assert_eq!(schema.as_value()["type"], "object");
+    assert_eq!(
+        schema.as_value()["properties"]["currency"],
+        json!({ "const": "USD" })
+    );
+    assert_eq!(schema.as_value()["properties"]["amount"]["type"], "string");
+    assert_eq!(schema.as_value()["required"], json!(["currency", "amount"]));
+}
+
+#[test]
+fn test_proto_schema() {
+    let schema = schema_for!(Proto<USD>);
+    assert_eq!(schema.as_value()["type"], "object");
+    assert_eq!(
+        schema.as_value()["properties"]["currencyCode"],
+        json!({ "const": "USD" })
+    );
+    assert_eq!(
+        schema.as_value()["properties"]["units"]["pattern"],
+        r"^-?[0-9]+$"
+    );
+    assert_eq!(
+        schema.as_value()["properties"]["nanos"]["maximum"],
+        999_999_999
+    );
+    assert_eq!(schema.as_value()["required"], json!(["currencyCode"]));
+}
+
+#[derive(::serde::Serialize, JsonSchema)]
+#[allow(dead_code)]
+struct Invoice {
+    total: Money<USD>,
+
+    #[serde(with = "crate::serde::money::str_code")]
+    #[schemars(with = "StrCode<USD>")]
+    display: Money<USD>,
+
+    #[serde(with = "crate::serde::money::option_minor")]
+    #[schemars(with = "Option<Minor<USD>>")]
+    fee: Option<Money<USD>>,
+}
+
+#[test]
+fn test_struct_schema() {
+    let schema = schema_for!(Invoice);
+    let value = schema.as_value();
+    assert_eq!(value["properties"]["total"]["$ref"], "#/$defs/Money_USD");
+    assert_eq!(
+        value["properties"]["display"]["$ref"],
+        "#/$defs/StrCode_USD"
+    );
+    assert_eq!(value["$defs"]["Money_USD"]["type"], "number");
+    assert_eq!(value["$defs"]["StrCode_USD"]["type"], "string");
+    assert_eq!(value["required"], json!(["total", "display"]));
+}
@@ -0,0 +1,119 @@
+//! sea_orm contains [SeaORM](https://crates.io/crates/sea-orm) implementations mapping money into decimal columns.
+//!
+//! With them, `Money<C>` and `RawMoney<C>` can be used as fields of entity models of decimal columns,
+//! `Option` of them for nullable columns, set in `ActiveValue` and bound as query parameters.
+//! Implementations delegate to [`Decimal`]'s, so only the amount is stored, the currency comes from the type.
+//!
+//! Reading `Money<C>` fails on amounts with more decimal places than `C`'s minor unit, see
+//! [storage invariants](crate#storage). Reading `RawMoney<C>` keeps the amount as is.
+//!
+//! ```ignore
+//! use moneylib::{Money, iso::USD, money};
+//! use sea_orm::{ActiveValue::Set, entity::prelude::*};
+//!
+//! #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+//! #[sea_orm(table_name = "invoices")]
+//! pub struct Model {
+//!     #[sea_orm(primary_key)]
+//!     pub id: i32,
+//!     #[sea_orm(column_type = "Decimal(Some((19, 2)))")]
+//!     pub total: Money<USD>,
+//!     pub discount: Option<Money<USD>>,
+//! }
+//!
+//! let invoice = ActiveModel {
+//!     total: Set(money!(USD, 12.34)),
+//!     ..Default::default()
+//! };
+//!
+//! let large = Entity::find()
+//!     .filter(Column::Total.gt(money!(USD, 1000)))
+//!     .all(db)
+//!     .await?;
+//! ```
+
+use ::sea_orm::sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+use ::sea_orm::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+
+use crate::{BaseMoney, Currency, Decimal, Money};
+
+impl<C: Currency> From<Money<C>> for Value {
+    fn from(value: Money<C>) -> Self {
+        value.amount().into()
+    }
+}
+
+impl<C: Currency> TryGetable for Money<C> {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let amount = Decimal::try_get_by(res, index)?;
+        crate::currency::check_minor_unit::<C>(amount)
+            .map_err(|err| TryGetError::DbErr(DbErr::Type(err.to_string())))?;
+        Ok(Self::from_decimal(amount))
+    }
+}
+
+impl<C: Currency> ValueType for Money<C> {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        let amount = <Decimal as ValueType>::try_from(v)?;
+        crate::currency::check_minor_unit::<C>(amount).map_err(|_| ValueTypeErr)?;
+        Ok(Self::from_decimal(amount))
+    }
+
+    fn type_name() -> String {
+        format!("Money<{}>", C::CODE)
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Decimal
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Decimal(None)
+    }
+}
+
+impl<C: Currency> Nullable for Money<C> {
+    fn null() -> Value {
+        Value::Decimal(None)
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> From<crate::RawMoney<C>> for Value {
+    fn from(value: crate::RawMoney<C>) -> Self {
+        value.amount().into()
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> TryGetable for crate::RawMoney<C> {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        Ok(Self::from_decimal(Decimal::try_get_by(res, index)?))
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> ValueType for crate::RawMoney<C> {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        Ok(Self::from_decimal(<Decimal as ValueType>::try_from(v)?))
+    }
+
+    fn type_name() -> String {
+        format!("RawMoney<{}>", C::CODE)
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Decimal
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Decimal(None)
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> Nullable for crate::RawMoney<C> {
+    fn null() -> Value {
+        Value::Decimal(None)
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use ::sea_orm::sea_query::{ColumnType, Nullable, Value, ValueType};
+use ::sea_orm::{
+    ActiveValue, ConnectionTrait, DatabaseConnection, DbBackend, MockDatabase, QueryResult,
+    Statement,
+};
+
+use crate::iso::{JPY, USD};
+use crate::{Decimal, Money, macros::dec, money};
+
+fn row(value: Value) -> QueryResult {
+    let db: DatabaseConnection = MockDatabase::new(DbBackend::Postgres)
+        .append_query_results([[BTreeMap::from([("total", value)])]])
+        .into_connection();
+    pollster::block_on(db.query_one(Statement::from_string(
+        DbBackend::Postgres,
+        "SELECT total FROM invoices",
+    )))
+    .unwrap()
+    .unwrap()
+}
+
+#[test]
+fn test_into_value() {
+    assert_eq!(Value::from(money!(USD, 12.34)), Value::from(dec!(12.34)));
+    assert_eq!(<Money<USD> as Nullable>::null(), Value::Decimal(None));
+    assert_eq!(
+        Value::from(Option::<Money<USD>>::None),
+        Value::Decimal(None)
+    );
+
+    let set = ActiveValue::Set(money!(JPY, 1500));
+    assert_eq!(set.into_value(), Some(Value::from(dec!(1500))));
+
+    let stmt = Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "UPDATE invoices SET total = $1",
+        [money!(USD, 1.5).into()],
+    );
+    assert_eq!(stmt.values.unwrap().0, [Value::from(dec!(1.50))]);
+}
+
+#[test]
+fn test_value_type() {
+    let money = <Money<USD> as ValueType>::try_from(Value::from(dec!(1.2300))).unwrap();
+    assert_eq!(money, money!(USD, 1.23));
+
+    assert!(<Money<USD> as ValueType>::try_from(Value::from(dec!(1.234))).is_err());
+    assert!(<Money<USD> as ValueType>::try_from(Value::from("1.23")).is_err());
+    assert!(<Money<USD> as ValueType>::try_from(Value::Decimal(None)).is_err());
+
+    assert_eq!(<Money<USD> as ValueType>::type_name(), "Money<USD>");
+    assert_eq!(
+        <Money<USD> as ValueType>::column_type(),
+        ColumnType::Decimal(None)
+    );
+}
+
+#[test]
+fn test_try_get() {
+    let res = row(Value::from(dec!(99.90)));
+    let total: Money<USD> = res.try_get("", "total").unwrap();
+    assert_eq!(total, money!(USD, 99.9));
+
+    let res = row(Value::Decimal(None));
+    let total: Option<Money<USD>> = res.try_get("", "total").unwrap();
+    assert!(total.is_none());
+    assert!(res.try_get::<Money<USD>>("", "total").is_err());
+
+    // too precise amount isn't silently rounded.
+    let res = row(Value::from(dec!(0.5)));
+    assert!(res.try_get::<Money<JPY>>("", "total").is_err());
+    assert_eq!(res.try_get::<Decimal>("", "total").unwrap(), dec!(0.5));
+}
+
+#[cfg(feature = "raw_money")]
+#[test]
+fn test_raw_money() {
+    use crate::{RawMoney, raw};
+
+    assert_eq!(Value::from(raw!(USD, 1.2345)), Value::from(dec!(1.2345)));
+    let money = <RawMoney<USD> as ValueType>::try_from(Value::from(dec!(1.2345))).unwrap();
+    assert_eq!(money, raw!(USD, 1.2345));
+
+    let total: RawMoney<USD> = row(Value::from(dec!(1.2345))).try_get("", "total").unwrap();
+    assert_eq!(total, raw!(USD, 1.2345));
+}
@@ -0,0 +1,105 @@
+//! `PendingAdjustment<C>` serde implementations.
+//!
+//! Serialized as an object of `target`, `delta`, and `resulting` money in their default format.
+//! Deserialization fails if `resulting` is not `target + delta`.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use ::serde::ser::SerializeStruct;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::adjustment::PendingAdjustment;
+use crate::{Currency, Money};
+
+const NAME: &str = "PendingAdjustment";
+const TARGET: &str = "target";
+const DELTA: &str = "delta";
+const RESULTING: &str = "resulting";
+const FIELDS: &[&str] = &[TARGET, DELTA, RESULTING];
+
+impl<C: Currency> Serialize for PendingAdjustment<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct(NAME, 3)?;
+        state.serialize_field(TARGET, &self.target())?;
+        state.serialize_field(DELTA, &self.delta())?;
+        state.serialize_field(RESULTING, &self.resulting())?;
+        state.end()
+    }
+}
+
+struct Visitor<C>(PhantomData<C>);
+
+impl<C: Currency> Visitor<C> {
+    fn build<E: de::Error>(
+        target: Money<C>,
+        delta: Money<C>,
+        resulting: Money<C>,
+    ) -> Result<PendingAdjustment<C>, E> {
+        let adjustment = PendingAdjustment::new(target, delta).map_err(de::Error::custom)?;
+        if adjustment.resulting() != resulting {
+            return Err(de::Error::custom(format!(
+                "resulting {} is not target {} plus delta {}",
+                resulting,
+                adjustment.target(),
+                adjustment.delta()
+            )));
+        }
+        Ok(adjustment)
+    }
+}
+
+impl<'de, C: Currency> de::Visitor<'de> for Visitor<C> {
+    type Value = PendingAdjustment<C>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an object with target, delta, and resulting money")
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut target: Option<Money<C>> = None;
+        let mut delta: Option<Money<C>> = None;
+        let mut resulting: Option<Money<C>> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            let (field, name) = match key.as_str() {
+                TARGET => (&mut target, TARGET),
+                DELTA => (&mut delta, DELTA),
+                RESULTING => (&mut resulting, RESULTING),
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                    continue;
+                }
+            };
+            if field.is_some() {
+                return Err(de::Error::duplicate_field(name));
+            }
+            *field = Some(map.next_value()?);
+        }
+
+        Self::build(
+            target.ok_or_else(|| de::Error::missing_field(TARGET))?,
+            delta.ok_or_else(|| de::Error::missing_field(DELTA))?,
+            resulting.ok_or_else(|| de::Error::missing_field(RESULTING))?,
+        )
+    }
+
+    // Non self-describing formats serialize struct as sequence of fields.
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let target = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let delta = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let resulting = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        Self::build(target, delta, resulting)
+    }
+}
+
+impl<'de, C: Currency> Deserialize<'de> for PendingAdjustment<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct(NAME, FIELDS, Visitor(PhantomData))
+    }
+}
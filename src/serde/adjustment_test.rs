@@ -0,0 +1,56 @@
+use crate::adjustment::PendingAdjustment;
+use crate::iso::USD;
+use crate::macros::money;
+
+#[test]
+fn test_adjustment_serialize() {
+    let pending = PendingAdjustment::new(money!(USD, 100), money!(USD, -40.5)).unwrap();
+    let json = serde_json::to_string(&pending).unwrap();
+    assert_eq!(json, r#"{"target":100,"delta":-40.5,"resulting":59.5}"#);
+    let _ = pending.abort();
+}
+
+#[test]
+fn test_adjustment_deserialize() {
+    let pending: PendingAdjustment<USD> =
+        serde_json::from_str(r#"{"target":100,"delta":"-40.50","resulting":59.5}"#).unwrap();
+    assert_eq!(pending.target(), money!(USD, 100));
+    assert_eq!(pending.delta(), money!(USD, -40.5));
+    assert_eq!(pending.commit(), money!(USD, 59.5));
+}
+
+#[test]
+fn test_adjustment_deserialize_invalid() {
+    let err = serde_json::from_str::<PendingAdjustment<USD>>(
+        r#"{"target":100,"delta":-40.5,"resulting":60}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("is not target"));
+
+    let err = serde_json::from_str::<PendingAdjustment<USD>>(r#"{"target":100,"delta":-40.5}"#)
+        .unwrap_err();
+    assert!(err.to_string().contains("missing field `resulting`"));
+
+    let err = serde_json::from_str::<PendingAdjustment<USD>>(
+        r#"{"target":100,"target":1,"delta":-40.5,"resulting":59.5}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("duplicate field `target`"));
+}
+
+#[test]
+fn test_adjustment_roundtrip() {
+    let original = PendingAdjustment::new(money!(USD, 1234.56), money!(USD, 0.44)).unwrap();
+
+    let json = serde_json::to_string(&original).unwrap();
+    let deserialized: PendingAdjustment<USD> = serde_json::from_str(&json).unwrap();
+    assert_eq!(original, deserialized);
+
+    let bytes = bincode::serialize(&original).unwrap();
+    let deserialized: PendingAdjustment<USD> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(original, deserialized);
+
+    let bytes = postcard::to_allocvec(&original).unwrap();
+    let deserialized: PendingAdjustment<USD> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized.commit(), original.commit());
+}
@@ -18,12 +18,18 @@ use crate::{BaseMoney, Currency, Decimal, MoneyParser};
 // ---------------------------------------------------------------------------
 
 /// Serialize any `BaseMoney<C>` implementation as a JSON precise number.
+///
+/// Non-human-readable formats, e.g. bincode and postcard, get the amount as decimal string instead.
 pub fn serialize_as_number<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
 where
     C: Currency,
     M: BaseMoney<C>,
     S: Serializer,
 {
+    if !serializer.is_human_readable() {
+        return serializer.serialize_str(&value.amount().to_string());
+    }
+
     let n = serde_json::Number::from_str(&value.amount().to_string())
         .map_err(|_| ::serde::ser::Error::custom("cannot convert Decimal to JSON Number"))?;
     n.serialize(serializer)
@@ -92,12 +98,19 @@ where
 }
 
 /// Deserialize any `BaseMoney<C>` + `MoneyParser<C>` implementation from a JSON number.
+///
+/// Non-human-readable formats don't support `deserialize_any`, the amount is read as decimal string
+/// written by [`serialize_as_number`].
 pub fn deserialize_as_number<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
 where
     C: Currency,
     M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = crate::MoneyError>,
     D: Deserializer<'de>,
 {
+    if !deserializer.is_human_readable() {
+        return deserializer.deserialize_str(BaseMoneyVisitor::<M, C>(PhantomData));
+    }
+
     deserializer.deserialize_any(BaseMoneyVisitor::<M, C>(PhantomData))
 }
 
@@ -756,127 +769,1703 @@ pub mod option_str_symbol {
 }
 
 // ---------------------------------------------------------------------------
-// minor: serialize/deserialize as minor amount (integer)
+// or_number: deserialize from formatted string or number
 // ---------------------------------------------------------------------------
 
-pub mod minor {
+/// Building blocks of `*_or_number` modules, deserializing from formatted string or from number.
+///
+/// Numbers are deserialized like [`deserialize_as_number`].
+pub mod or_number {
     use std::fmt;
     use std::marker::PhantomData;
+    use std::str::FromStr;
 
-    use ::serde::{Deserializer, Serializer, de};
+    use ::serde::{Deserializer, de};
 
-    use crate::{BaseMoney, Currency, MoneyError};
+    use crate::{BaseMoney, Currency, MoneyError, MoneyParser};
 
-    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        C: Currency,
-        M: BaseMoney<C>,
-        S: Serializer,
-    {
-        let minor = value
-            .minor_amount()
-            .ok_or(::serde::ser::Error::custom(MoneyError::OverflowError))?;
-        serializer.serialize_i128(minor)
-    }
+    use super::BaseMoneyVisitor;
 
-    pub struct Visitor<M, C>(pub PhantomData<(M, C)>);
+    pub struct Visitor<M, C, F>(pub &'static str, pub F, pub PhantomData<(M, C)>);
 
-    impl<'de, C, M> de::Visitor<'de> for Visitor<M, C>
+    impl<'de, C, M, F> de::Visitor<'de> for Visitor<M, C, F>
     where
         C: Currency,
-        M: BaseMoney<C>,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        F: FnOnce(&str) -> Result<M, MoneyError>,
     {
         type Value = M;
 
         fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            f.write_str("an integer representing the minor amount")
+            f.write_str(self.0)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            (self.1)(v).map_err(de::Error::custom)
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            BaseMoneyVisitor::<M, C>(PhantomData).visit_f64(v)
         }
 
         fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
-            M::from_minor(i128::from(v)).map_err(de::Error::custom)
+            BaseMoneyVisitor::<M, C>(PhantomData).visit_i64(v)
         }
 
         fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
-            M::from_minor(i128::from(v)).map_err(de::Error::custom)
+            BaseMoneyVisitor::<M, C>(PhantomData).visit_u64(v)
         }
 
         fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
-            M::from_minor(v).map_err(de::Error::custom)
+            BaseMoneyVisitor::<M, C>(PhantomData).visit_i128(v)
         }
 
         fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
-            i128::try_from(v)
-                .map_err(|_| de::Error::custom("value too large for minor amount"))
-                .and_then(|n| M::from_minor(n).map_err(de::Error::custom))
+            BaseMoneyVisitor::<M, C>(PhantomData).visit_u128(v)
+        }
+
+        // Handles serde_json's arbitrary_precision number format
+        fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            BaseMoneyVisitor::<M, C>(PhantomData).visit_map(map)
+        }
+    }
+
+    /// Deserialize from string parsed with `parse`, or from number.
+    ///
+    /// Non-human-readable formats don't support `deserialize_any`, only string is read.
+    pub fn deserialize<'de, C, M, D, F>(
+        deserializer: D,
+        expecting: &'static str,
+        parse: F,
+    ) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+        F: FnOnce(&str) -> Result<M, MoneyError>,
+    {
+        let visitor = Visitor::<M, C, F>(expecting, parse, PhantomData);
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_str(visitor);
+        }
+
+        deserializer.deserialize_any(visitor)
+    }
+
+    pub struct OptionVisitor<M, C, F>(pub &'static str, pub F, pub PhantomData<(M, C)>);
+
+    impl<'de, C, M, F> de::Visitor<'de> for OptionVisitor<M, C, F>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        F: FnOnce(&str) -> Result<M, MoneyError>,
+    {
+        type Value = Option<M>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} or null", self.0)
         }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+            deserialize::<C, M, D, F>(d, self.0, self.1).map(Some)
+        }
+    }
+
+    /// Deserialize from string parsed with `parse`, from number, or from `null`.
+    pub fn deserialize_option<'de, C, M, D, F>(
+        deserializer: D,
+        expecting: &'static str,
+        parse: F,
+    ) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+        F: FnOnce(&str) -> Result<M, MoneyError>,
+    {
+        deserializer.deserialize_option(OptionVisitor::<M, C, F>(expecting, parse, PhantomData))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// comma_str_code_or_number: comma_str_code, deserialize from number too
+// ---------------------------------------------------------------------------
+
+pub mod comma_str_code_or_number {
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: MoneyFormatter<C>,
+        S: Serializer,
+    {
+        super::comma_str_code::serialize::<C, M, S>(value, serializer)
     }
 
     pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
     where
         C: Currency,
-        M: BaseMoney<C>,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_any(Visitor::<M, C>(PhantomData))
+        super::or_number::deserialize::<C, M, D, _>(
+            deserializer,
+            "a string like 'CCC 1,234.56' or a number",
+            |v| M::from_str_code_with(v, ",", "."),
+        )
     }
 }
 
 // ---------------------------------------------------------------------------
-// option_minor: optional variant of minor
+// option_comma_str_code_or_number: optional variant of comma_str_code_or_number
 // ---------------------------------------------------------------------------
 
-pub mod option_minor {
-    use std::fmt;
-    use std::marker::PhantomData;
+pub mod option_comma_str_code_or_number {
+    use std::str::FromStr;
 
-    use ::serde::{Deserializer, Serializer, de};
+    use ::serde::{Deserializer, Serializer};
 
-    use crate::{BaseMoney, Currency};
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
 
     pub fn serialize<C, M, S>(value: &Option<M>, serializer: S) -> Result<S::Ok, S::Error>
     where
         C: Currency,
-        M: BaseMoney<C>,
+        M: BaseMoney<C> + MoneyFormatter<C>,
         S: Serializer,
     {
-        match value {
-            Some(m) => super::minor::serialize::<C, M, S>(m, serializer),
-            None => serializer.serialize_none(),
-        }
+        super::option_comma_str_code::serialize::<C, M, S>(value, serializer)
     }
 
-    pub struct Visitor<M, C>(pub PhantomData<(M, C)>);
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        super::or_number::deserialize_option::<C, M, D, _>(
+            deserializer,
+            "a string like 'CCC 1,234.56' or a number",
+            |v| M::from_str_code_with(v, ",", "."),
+        )
+    }
+}
 
-    impl<'de, C, M> de::Visitor<'de> for Visitor<M, C>
+// ---------------------------------------------------------------------------
+// comma_str_symbol_or_number: comma_str_symbol, deserialize from number too
+// ---------------------------------------------------------------------------
+
+pub mod comma_str_symbol_or_number {
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
     where
         C: Currency,
-        M: BaseMoney<C>,
+        M: MoneyFormatter<C>,
+        S: Serializer,
     {
-        type Value = Option<M>;
+        super::comma_str_symbol::serialize::<C, M, S>(value, serializer)
+    }
 
-        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            f.write_str("an integer representing the minor amount, or null")
-        }
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        super::or_number::deserialize::<C, M, D, _>(
+            deserializer,
+            "a string like '$1,234.56' or a number",
+            |v| M::from_str_symbol_with(v, ",", "."),
+        )
+    }
+}
 
-        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
-            Ok(None)
-        }
+// ---------------------------------------------------------------------------
+// option_comma_str_symbol_or_number: optional variant of comma_str_symbol_or_number
+// ---------------------------------------------------------------------------
 
-        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
-            Ok(None)
-        }
+pub mod option_comma_str_symbol_or_number {
+    use std::str::FromStr;
 
-        fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
-            super::minor::deserialize::<C, M, D>(d).map(Some)
-        }
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &Option<M>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyFormatter<C>,
+        S: Serializer,
+    {
+        super::option_comma_str_symbol::serialize::<C, M, S>(value, serializer)
     }
 
     pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
     where
         C: Currency,
-        M: BaseMoney<C>,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_option(Visitor::<M, C>(PhantomData))
+        super::or_number::deserialize_option::<C, M, D, _>(
+            deserializer,
+            "a string like '$1,234.56' or a number",
+            |v| M::from_str_symbol_with(v, ",", "."),
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// dot_str_code_or_number: dot_str_code, deserialize from number too
+// ---------------------------------------------------------------------------
+
+pub mod dot_str_code_or_number {
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: MoneyFormatter<C>,
+        S: Serializer,
+    {
+        super::dot_str_code::serialize::<C, M, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        super::or_number::deserialize::<C, M, D, _>(
+            deserializer,
+            "a string like 'CCC 1.234,56' or a number",
+            |v| M::from_str_code_with(v, ".", ","),
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// option_dot_str_code_or_number: optional variant of dot_str_code_or_number
+// ---------------------------------------------------------------------------
+
+pub mod option_dot_str_code_or_number {
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &Option<M>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyFormatter<C>,
+        S: Serializer,
+    {
+        super::option_dot_str_code::serialize::<C, M, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        super::or_number::deserialize_option::<C, M, D, _>(
+            deserializer,
+            "a string like 'CCC 1.234,56' or a number",
+            |v| M::from_str_code_with(v, ".", ","),
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// dot_str_symbol_or_number: dot_str_symbol, deserialize from number too
+// ---------------------------------------------------------------------------
+
+pub mod dot_str_symbol_or_number {
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: MoneyFormatter<C>,
+        S: Serializer,
+    {
+        super::dot_str_symbol::serialize::<C, M, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        super::or_number::deserialize::<C, M, D, _>(
+            deserializer,
+            "a string like '€1.234,56' or a number",
+            |v| M::from_str_symbol_with(v, ".", ","),
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// option_dot_str_symbol_or_number: optional variant of dot_str_symbol_or_number
+// ---------------------------------------------------------------------------
+
+pub mod option_dot_str_symbol_or_number {
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &Option<M>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyFormatter<C>,
+        S: Serializer,
+    {
+        super::option_dot_str_symbol::serialize::<C, M, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        super::or_number::deserialize_option::<C, M, D, _>(
+            deserializer,
+            "a string like '€1.234,56' or a number",
+            |v| M::from_str_symbol_with(v, ".", ","),
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// str_code_or_number: str_code, deserialize from number too
+// ---------------------------------------------------------------------------
+
+pub mod str_code_or_number {
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: MoneyFormatter<C>,
+        S: Serializer,
+    {
+        super::str_code::serialize::<C, M, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        super::or_number::deserialize::<C, M, D, _>(
+            deserializer,
+            "a string like 'CCC 1,234.56' with currency's separators or a number",
+            |v| M::from_str_code(v),
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// option_str_code_or_number: optional variant of str_code_or_number
+// ---------------------------------------------------------------------------
+
+pub mod option_str_code_or_number {
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &Option<M>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyFormatter<C>,
+        S: Serializer,
+    {
+        super::option_str_code::serialize::<C, M, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        super::or_number::deserialize_option::<C, M, D, _>(
+            deserializer,
+            "a string like 'CCC 1,234.56' with currency's separators or a number",
+            |v| M::from_str_code(v),
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// str_symbol_or_number: str_symbol, deserialize from number too
+// ---------------------------------------------------------------------------
+
+pub mod str_symbol_or_number {
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: MoneyFormatter<C>,
+        S: Serializer,
+    {
+        super::str_symbol::serialize::<C, M, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        super::or_number::deserialize::<C, M, D, _>(
+            deserializer,
+            "a string like '$1,234.56' with currency's separators or a number",
+            |v| M::from_str_symbol(v),
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// option_str_symbol_or_number: optional variant of str_symbol_or_number
+// ---------------------------------------------------------------------------
+
+pub mod option_str_symbol_or_number {
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &Option<M>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyFormatter<C>,
+        S: Serializer,
+    {
+        super::option_str_symbol::serialize::<C, M, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        super::or_number::deserialize_option::<C, M, D, _>(
+            deserializer,
+            "a string like '$1,234.56' with currency's separators or a number",
+            |v| M::from_str_symbol(v),
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// seq_* / map_*: Vec<M> and HashMap<K, M> in string formats
+// ---------------------------------------------------------------------------
+
+// Generates `seq_*` and `map_*` modules applying a single money format module to each element.
+macro_rules! collection_formats {
+    ($($format:ident => $seq:ident, $map:ident;)*) => {
+        $(
+            pub mod $seq {
+                use std::fmt;
+                use std::marker::PhantomData;
+
+                use ::serde::{Deserializer, Serializer, de};
+
+                use crate::{Currency, MoneyFormatter, MoneyParser};
+
+                pub fn serialize<C, M, S>(value: &[M], serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    C: Currency,
+                    M: MoneyFormatter<C>,
+                    S: Serializer,
+                {
+                    serializer.collect_seq(value.iter().map(|m| Item::<M, C>(m, PhantomData)))
+                }
+
+                pub struct Visitor<M, C>(pub PhantomData<(M, C)>);
+
+                impl<'de, C, M> de::Visitor<'de> for Visitor<M, C>
+                where
+                    C: Currency,
+                    M: MoneyParser<C>,
+                {
+                    type Value = Vec<M>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str(concat!("a sequence of ", stringify!($format), " money"))
+                    }
+
+                    fn visit_seq<A: de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Self::Value, A::Error> {
+                        // size hint comes from the input, don't trust it for huge allocations.
+                        let mut ret = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+                        while let Some(m) = seq.next_element_seed(Seed::<M, C>(PhantomData))? {
+                            ret.push(m);
+                        }
+                        Ok(ret)
+                    }
+                }
+
+                pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Vec<M>, D::Error>
+                where
+                    C: Currency,
+                    M: MoneyParser<C>,
+                    D: Deserializer<'de>,
+                {
+                    deserializer.deserialize_seq(Visitor::<M, C>(PhantomData))
+                }
+
+                pub(super) struct Item<'a, M, C>(pub &'a M, pub PhantomData<C>);
+
+                impl<C, M> ::serde::Serialize for Item<'_, M, C>
+                where
+                    C: Currency,
+                    M: MoneyFormatter<C>,
+                {
+                    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        super::$format::serialize::<C, M, S>(self.0, serializer)
+                    }
+                }
+
+                pub(super) struct Seed<M, C>(pub PhantomData<(M, C)>);
+
+                impl<'de, C, M> de::DeserializeSeed<'de> for Seed<M, C>
+                where
+                    C: Currency,
+                    M: MoneyParser<C>,
+                {
+                    type Value = M;
+
+                    fn deserialize<D: Deserializer<'de>>(self, d: D) -> Result<M, D::Error> {
+                        super::$format::deserialize::<C, M, D>(d)
+                    }
+                }
+            }
+
+            pub mod $map {
+                use std::collections::HashMap;
+                use std::fmt;
+                use std::hash::Hash;
+                use std::marker::PhantomData;
+
+                use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+                use crate::{Currency, MoneyFormatter, MoneyParser};
+
+                use super::$seq::{Item, Seed};
+
+                pub fn serialize<C, M, K, S>(
+                    value: &HashMap<K, M>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error>
+                where
+                    C: Currency,
+                    M: MoneyFormatter<C>,
+                    K: Serialize,
+                    S: Serializer,
+                {
+                    serializer.collect_map(
+                        value
+                            .iter()
+                            .map(|(k, m)| (k, Item::<M, C>(m, PhantomData))),
+                    )
+                }
+
+                pub struct Visitor<M, C, K>(pub PhantomData<(M, C, K)>);
+
+                impl<'de, C, M, K> de::Visitor<'de> for Visitor<M, C, K>
+                where
+                    C: Currency,
+                    M: MoneyParser<C>,
+                    K: Deserialize<'de> + Eq + Hash,
+                {
+                    type Value = HashMap<K, M>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str(concat!("a map of ", stringify!($format), " money"))
+                    }
+
+                    fn visit_map<A: de::MapAccess<'de>>(
+                        self,
+                        mut map: A,
+                    ) -> Result<Self::Value, A::Error> {
+                        // size hint comes from the input, don't trust it for huge allocations.
+                        let mut ret = HashMap::with_capacity(map.size_hint().unwrap_or(0).min(4096));
+                        while let Some((k, m)) =
+                            map.next_entry_seed(PhantomData::<K>, Seed::<M, C>(PhantomData))?
+                        {
+                            ret.insert(k, m);
+                        }
+                        Ok(ret)
+                    }
+                }
+
+                pub fn deserialize<'de, C, M, K, D>(deserializer: D) -> Result<HashMap<K, M>, D::Error>
+                where
+                    C: Currency,
+                    M: MoneyParser<C>,
+                    K: Deserialize<'de> + Eq + Hash,
+                    D: Deserializer<'de>,
+                {
+                    deserializer.deserialize_map(Visitor::<M, C, K>(PhantomData))
+                }
+            }
+        )*
+    };
+}
+
+collection_formats! {
+    comma_str_code => seq_comma_str_code, map_comma_str_code;
+    comma_str_symbol => seq_comma_str_symbol, map_comma_str_symbol;
+    dot_str_code => seq_dot_str_code, map_dot_str_code;
+    dot_str_symbol => seq_dot_str_symbol, map_dot_str_symbol;
+    str_code => seq_str_code, map_str_code;
+    str_symbol => seq_str_symbol, map_str_symbol;
+}
+
+// ---------------------------------------------------------------------------
+// minor: serialize/deserialize as minor amount (integer)
+// ---------------------------------------------------------------------------
+
+pub mod minor {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer, de};
+
+    use crate::{BaseMoney, Currency, MoneyError};
+
+    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        S: Serializer,
+    {
+        let minor = value
+            .minor_amount()
+            .ok_or(::serde::ser::Error::custom(MoneyError::OverflowError))?;
+        serializer.serialize_i128(minor)
+    }
+
+    /// Expected value of currency `C`, e.g. "integer cent amount for USD".
+    pub(crate) fn expected<C: Currency>() -> String {
+        format!("integer {} amount for {}", C::MINOR_UNIT_NAME, C::CODE)
+    }
+
+    fn from_minor<C, M, E>(v: i128) -> Result<M, E>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        E: de::Error,
+    {
+        M::from_minor(v).map_err(|_| {
+            de::Error::custom(format!(
+                "minor amount {} is out of range, expected {}",
+                v,
+                expected::<C>()
+            ))
+        })
+    }
+
+    pub struct Visitor<M, C>(pub PhantomData<(M, C)>);
+
+    impl<'de, C, M> de::Visitor<'de> for Visitor<M, C>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+    {
+        type Value = M;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(&expected::<C>())
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            from_minor::<C, M, E>(i128::from(v))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            from_minor::<C, M, E>(i128::from(v))
+        }
+
+        fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+            from_minor::<C, M, E>(v)
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            let v = i128::try_from(v).map_err(|_| {
+                de::Error::custom(format!(
+                    "minor amount {} is too large, expected {}",
+                    v,
+                    expected::<C>()
+                ))
+            })?;
+            from_minor::<C, M, E>(v)
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            Err(de::Error::custom(format!(
+                "expected {}, found float {}",
+                expected::<C>(),
+                v
+            )))
+        }
+
+        // Handles serde_json's arbitrary_precision number format, only integers are accepted.
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            const ARBITRARY_NUMBER_KEY: &str = "$serde_json::private::Number";
+
+            if let Ok(Some(key)) = map.next_key::<String>()
+                && key == ARBITRARY_NUMBER_KEY
+            {
+                let value: String = map.next_value()?;
+                if !value
+                    .trim_start_matches('-')
+                    .chars()
+                    .all(|c| c.is_ascii_digit())
+                {
+                    return Err(de::Error::custom(format!(
+                        "expected {}, found float {}",
+                        expected::<C>(),
+                        value
+                    )));
+                }
+                let v = value.parse::<i128>().map_err(|_| {
+                    de::Error::custom(format!(
+                        "minor amount {} is out of range, expected {}",
+                        value,
+                        expected::<C>()
+                    ))
+                })?;
+                from_minor::<C, M, A::Error>(v)
+            } else {
+                Err(de::Error::custom(format!(
+                    "expected {}, found map",
+                    expected::<C>()
+                )))
+            }
+        }
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        D: Deserializer<'de>,
+    {
+        // Non-human-readable formats don't support `deserialize_any`.
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_i128(Visitor::<M, C>(PhantomData));
+        }
+
+        deserializer.deserialize_any(Visitor::<M, C>(PhantomData))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// option_minor: optional variant of minor
+// ---------------------------------------------------------------------------
+
+pub mod option_minor {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer, de};
+
+    use crate::{BaseMoney, Currency};
+
+    pub fn serialize<C, M, S>(value: &Option<M>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        S: Serializer,
+    {
+        match value {
+            Some(m) => serializer.serialize_some(&Wrapper::<C, M>(m, PhantomData)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    // Serializes inner value with [`super::minor`] inside `serialize_some`, as non-human-readable formats tag it.
+    struct Wrapper<'a, C, M>(&'a M, PhantomData<C>);
+
+    impl<C: Currency, M: BaseMoney<C>> ::serde::Serialize for Wrapper<'_, C, M> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::minor::serialize::<C, M, S>(self.0, serializer)
+        }
+    }
+
+    pub struct Visitor<M, C>(pub PhantomData<(M, C)>);
+
+    impl<'de, C, M> de::Visitor<'de> for Visitor<M, C>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+    {
+        type Value = Option<M>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} or null", super::minor::expected::<C>())
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+            super::minor::deserialize::<C, M, D>(d).map(Some)
+        }
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(Visitor::<M, C>(PhantomData))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// object: serialize/deserialize as { "currency": "USD", "amount": "1234.56" }
+// ---------------------------------------------------------------------------
+
+pub mod object {
+    use std::fmt;
+    use std::str::FromStr;
+
+    use ::serde::ser::SerializeStruct;
+    use ::serde::{Deserializer, Serializer, de};
+
+    use crate::{BaseMoney, Currency, Decimal, MoneyError};
+
+    pub const NAME: &str = "Money";
+    pub const CURRENCY: &str = "currency";
+    pub const AMOUNT: &str = "amount";
+    pub const FIELDS: &[&str] = &[CURRENCY, AMOUNT];
+
+    /// Serialize currency `code` and `amount` as two-field object, amount as string.
+    pub fn serialize_fields<S: Serializer>(
+        code: &str,
+        amount: Decimal,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct(NAME, 2)?;
+        state.serialize_field(CURRENCY, code)?;
+        state.serialize_field(AMOUNT, &amount.to_string())?;
+        state.end()
+    }
+
+    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        S: Serializer,
+    {
+        serialize_fields(C::CODE, value.amount(), serializer)
+    }
+
+    /// Visitor of amount field, accepting string or number.
+    pub struct AmountVisitor;
+
+    impl<'de> de::Visitor<'de> for AmountVisitor {
+        type Value = Decimal;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a decimal string or number")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Decimal::from_str(v).map_err(|_| de::Error::custom(format!("invalid decimal: {}", v)))
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            self.visit_str(&v.to_string())
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            Ok(Decimal::from(v))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(Decimal::from(v))
+        }
+
+        fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+            Decimal::try_from_i128_with_scale(v, 0).map_err(de::Error::custom)
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            i128::try_from(v)
+                .map_err(|_| de::Error::custom("value too large for Decimal"))
+                .and_then(|n| self.visit_i128(n))
+        }
+
+        // Handles serde_json's arbitrary_precision number format
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            const ARBITRARY_NUMBER_KEY: &str = "$serde_json::private::Number";
+
+            if let Ok(Some(key)) = map.next_key::<String>()
+                && key == ARBITRARY_NUMBER_KEY
+            {
+                let value: String = map.next_value()?;
+                self.visit_str(&value)
+            } else {
+                Err(de::Error::custom("unexpected key"))
+            }
+        }
+    }
+
+    struct Amount(Decimal);
+
+    impl<'de> de::Deserialize<'de> for Amount {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(AmountVisitor).map(Amount)
+        }
+    }
+
+    /// Visitor of the two-field object, producing currency code and amount.
+    pub struct FieldsVisitor;
+
+    impl<'de> de::Visitor<'de> for FieldsVisitor {
+        type Value = (String, Decimal);
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(r#"an object like { "currency": "USD", "amount": "1234.56" }"#)
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut currency: Option<String> = None;
+            let mut amount: Option<Decimal> = None;
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    CURRENCY => {
+                        if currency.is_some() {
+                            return Err(de::Error::duplicate_field(CURRENCY));
+                        }
+                        currency = Some(map.next_value()?);
+                    }
+                    AMOUNT => {
+                        if amount.is_some() {
+                            return Err(de::Error::duplicate_field(AMOUNT));
+                        }
+                        amount = Some(map.next_value::<Amount>()?.0);
+                    }
+                    _ => {
+                        map.next_value::<de::IgnoredAny>()?;
+                    }
+                }
+            }
+
+            let currency = currency.ok_or_else(|| de::Error::missing_field(CURRENCY))?;
+            let amount = amount.ok_or_else(|| de::Error::missing_field(AMOUNT))?;
+            Ok((currency, amount))
+        }
+
+        // Non self-describing formats serialize struct as sequence of fields.
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let currency: String = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let amount: String = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+            Ok((currency, AmountVisitor.visit_str(&amount)?))
+        }
+    }
+
+    /// Deserialize two-field object into currency code and amount.
+    pub fn deserialize_fields<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<(String, Decimal), D::Error> {
+        deserializer.deserialize_struct(NAME, FIELDS, FieldsVisitor)
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        D: Deserializer<'de>,
+    {
+        let (currency, amount) = deserialize_fields(deserializer)?;
+        if currency != C::CODE {
+            return Err(de::Error::custom(MoneyError::CurrencyMismatchError(
+                currency,
+                C::CODE.to_string(),
+            )));
+        }
+        Ok(M::from_decimal(amount))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// option_object: optional variant of object
+// ---------------------------------------------------------------------------
+
+pub mod option_object {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer, de};
+
+    use crate::{BaseMoney, Currency};
+
+    pub fn serialize<C, M, S>(value: &Option<M>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        S: Serializer,
+    {
+        match value {
+            Some(m) => serializer.serialize_some(&Wrapper::<C, M>(m, PhantomData)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    // Serializes inner value with [`super::object`] inside `serialize_some`, as non-human-readable formats tag it.
+    struct Wrapper<'a, C, M>(&'a M, PhantomData<C>);
+
+    impl<C: Currency, M: BaseMoney<C>> ::serde::Serialize for Wrapper<'_, C, M> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::object::serialize::<C, M, S>(self.0, serializer)
+        }
+    }
+
+    pub struct Visitor<M, C>(pub PhantomData<(M, C)>);
+
+    impl<'de, C, M> de::Visitor<'de> for Visitor<M, C>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+    {
+        type Value = Option<M>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(r#"an object like { "currency": "USD", "amount": "1234.56" }, or null"#)
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+            super::object::deserialize::<C, M, D>(d).map(Some)
+        }
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(Visitor::<M, C>(PhantomData))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// proto: serialize/deserialize as google.type.Money JSON
+// ---------------------------------------------------------------------------
+
+pub mod proto {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use ::serde::ser::SerializeStruct;
+    use ::serde::{Deserializer, Serializer, de};
+    use rust_decimal::prelude::ToPrimitive;
+
+    use crate::{BaseMoney, Currency, Decimal, MoneyError};
+
+    pub const NAME: &str = "Money";
+    pub const CURRENCY_CODE: &str = "currencyCode";
+    pub const CURRENCY_CODE_PROTO: &str = "currency_code";
+    pub const UNITS: &str = "units";
+    pub const NANOS: &str = "nanos";
+    pub const FIELDS: &[&str] = &[CURRENCY_CODE, UNITS, NANOS];
+
+    const NANOS_SCALE: u32 = 9;
+    const NANOS_PER_UNIT: Decimal = Decimal::from_parts(1_000_000_000, 0, 0, false, 0);
+
+    /// Splits `amount` into whole `units` and `nanos`, both having the same sign.
+    pub fn to_units_nanos(amount: Decimal) -> Result<(i64, i32), MoneyError> {
+        let units = amount.trunc();
+        let nanos = amount
+            .checked_sub(units)
+            .and_then(|frac| frac.checked_mul(NANOS_PER_UNIT))
+            .ok_or(MoneyError::OverflowError)?;
+        if !nanos.fract().is_zero() {
+            return Err(MoneyError::ParseStrError(
+                format!(
+                    "amount {} has more than {} decimal places",
+                    amount, NANOS_SCALE
+                )
+                .into(),
+            ));
+        }
+        Ok((
+            units.to_i64().ok_or(MoneyError::OverflowError)?,
+            nanos.to_i32().ok_or(MoneyError::OverflowError)?,
+        ))
+    }
+
+    /// Joins `units` and `nanos` into amount, validating nanos range and sign.
+    pub fn from_units_nanos(units: i64, nanos: i32) -> Result<Decimal, MoneyError> {
+        if !(-999_999_999..=999_999_999).contains(&nanos) {
+            return Err(MoneyError::ParseStrError(
+                format!("nanos {} is out of range", nanos).into(),
+            ));
+        }
+        if (units > 0 && nanos < 0) || (units < 0 && nanos > 0) {
+            return Err(MoneyError::ParseStrError(
+                format!("units {} and nanos {} have different signs", units, nanos).into(),
+            ));
+        }
+        Decimal::from(units)
+            .checked_add(Decimal::new(nanos.into(), NANOS_SCALE))
+            .ok_or(MoneyError::OverflowError)
+    }
+
+    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        S: Serializer,
+    {
+        let (units, nanos) = to_units_nanos(value.amount()).map_err(::serde::ser::Error::custom)?;
+        let mut state = serializer.serialize_struct(NAME, 3)?;
+        state.serialize_field(CURRENCY_CODE, C::CODE)?;
+        // int64 is a JSON string in proto3 JSON mapping.
+        state.serialize_field(UNITS, &units.to_string())?;
+        state.serialize_field(NANOS, &nanos)?;
+        state.end()
+    }
+
+    /// Visitor of units field, accepting string or number.
+    struct UnitsVisitor;
+
+    impl de::Visitor<'_> for UnitsVisitor {
+        type Value = i64;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an int64 as string or number")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse()
+                .map_err(|_| de::Error::custom(format!("invalid units: {}", v)))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            i64::try_from(v).map_err(|_| de::Error::custom(format!("invalid units: {}", v)))
+        }
+    }
+
+    struct Units(i64);
+
+    impl<'de> de::Deserialize<'de> for Units {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(UnitsVisitor).map(Units)
+        }
+    }
+
+    pub struct Visitor<M, C>(pub PhantomData<(M, C)>);
+
+    impl<'de, C, M> de::Visitor<'de> for Visitor<M, C>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+    {
+        type Value = M;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(
+                r#"a google.type.Money object like { "currencyCode": "USD", "units": "1234", "nanos": 560000000 }"#,
+            )
+        }
+
+        // Missing units or nanos are zero, as proto3 JSON omits default values.
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut currency: Option<String> = None;
+            let mut units: Option<i64> = None;
+            let mut nanos: Option<i32> = None;
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    CURRENCY_CODE | CURRENCY_CODE_PROTO => {
+                        if currency.is_some() {
+                            return Err(de::Error::duplicate_field(CURRENCY_CODE));
+                        }
+                        currency = Some(map.next_value()?);
+                    }
+                    UNITS => {
+                        if units.is_some() {
+                            return Err(de::Error::duplicate_field(UNITS));
+                        }
+                        units = Some(map.next_value::<Units>()?.0);
+                    }
+                    NANOS => {
+                        if nanos.is_some() {
+                            return Err(de::Error::duplicate_field(NANOS));
+                        }
+                        nanos = Some(map.next_value()?);
+                    }
+                    _ => {
+                        map.next_value::<de::IgnoredAny>()?;
+                    }
+                }
+            }
+
+            let currency = currency.ok_or_else(|| de::Error::missing_field(CURRENCY_CODE))?;
+            if currency != C::CODE {
+                return Err(de::Error::custom(MoneyError::CurrencyMismatchError(
+                    currency,
+                    C::CODE.to_string(),
+                )));
+            }
+            let amount = from_units_nanos(units.unwrap_or_default(), nanos.unwrap_or_default())
+                .map_err(de::Error::custom)?;
+            Ok(M::from_decimal(amount))
+        }
+
+        // Non self-describing formats serialize struct as sequence of fields.
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let currency: String = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let units: String = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+            let nanos: i32 = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+            if currency != C::CODE {
+                return Err(de::Error::custom(MoneyError::CurrencyMismatchError(
+                    currency,
+                    C::CODE.to_string(),
+                )));
+            }
+            let units = UnitsVisitor.visit_str(&units)?;
+            let amount = from_units_nanos(units, nanos).map_err(de::Error::custom)?;
+            Ok(M::from_decimal(amount))
+        }
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(NAME, FIELDS, Visitor::<M, C>(PhantomData))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// option_proto: optional variant of proto
+// ---------------------------------------------------------------------------
+
+pub mod option_proto {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer, de};
+
+    use crate::{BaseMoney, Currency};
+
+    pub fn serialize<C, M, S>(value: &Option<M>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        S: Serializer,
+    {
+        match value {
+            Some(m) => serializer.serialize_some(&Wrapper::<C, M>(m, PhantomData)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    // Serializes inner value with [`super::proto`] inside `serialize_some`, as non-human-readable formats tag it.
+    struct Wrapper<'a, C, M>(&'a M, PhantomData<C>);
+
+    impl<C: Currency, M: BaseMoney<C>> ::serde::Serialize for Wrapper<'_, C, M> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::proto::serialize::<C, M, S>(self.0, serializer)
+        }
+    }
+
+    pub struct Visitor<M, C>(pub PhantomData<(M, C)>);
+
+    impl<'de, C, M> de::Visitor<'de> for Visitor<M, C>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+    {
+        type Value = Option<M>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a google.type.Money object, or null")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+            super::proto::deserialize::<C, M, D>(d).map(Some)
+        }
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(Visitor::<M, C>(PhantomData))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// strict: serialize as string, deserialize only from string or integer
+// ---------------------------------------------------------------------------
+
+pub mod strict {
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer, de};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        S: Serializer,
+    {
+        serializer.serialize_str(&value.amount().to_string())
+    }
+
+    pub struct Visitor<M, C>(pub PhantomData<(M, C)>);
+
+    impl<M, C> Visitor<M, C> {
+        fn float_error<E: de::Error>(v: impl fmt::Display) -> E {
+            de::Error::custom(format!(
+                "float {} is not accepted, use string or integer instead",
+                v
+            ))
+        }
+    }
+
+    impl<'de, C, M> de::Visitor<'de> for Visitor<M, C>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+    {
+        type Value = M;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a decimal string or an integer")
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            Err(Self::float_error(v))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            M::new(v).map_err(de::Error::custom)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            M::new(i128::from(v)).map_err(de::Error::custom)
+        }
+
+        fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+            M::new(v).map_err(de::Error::custom)
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            i128::try_from(v)
+                .map_err(|_| de::Error::custom("value too large for money"))
+                .and_then(|n| self.visit_i128(n))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            M::from_str(v).map_err(|_| de::Error::custom(format!("invalid decimal: {}", v)))
+        }
+
+        // Handles serde_json's arbitrary_precision number format, only integers are accepted.
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            const ARBITRARY_NUMBER_KEY: &str = "$serde_json::private::Number";
+
+            if let Ok(Some(key)) = map.next_key::<String>()
+                && key == ARBITRARY_NUMBER_KEY
+            {
+                let value: String = map.next_value()?;
+                if value.contains(['.', 'e', 'E']) {
+                    return Err(Self::float_error(value));
+                }
+                self.visit_str(&value)
+            } else {
+                Err(de::Error::custom("unexpected key"))
+            }
+        }
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        // Non-human-readable formats don't support `deserialize_any`.
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_str(Visitor::<M, C>(PhantomData));
+        }
+
+        deserializer.deserialize_any(Visitor::<M, C>(PhantomData))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// option_strict: optional variant of strict
+// ---------------------------------------------------------------------------
+
+pub mod option_strict {
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    use ::serde::{Deserializer, Serializer, de};
+
+    use crate::{BaseMoney, Currency, MoneyError, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &Option<M>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C>,
+        S: Serializer,
+    {
+        match value {
+            Some(m) => serializer.serialize_some(m.amount().to_string().as_str()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub struct Visitor<M, C>(pub PhantomData<(M, C)>);
+
+    impl<'de, C, M> de::Visitor<'de> for Visitor<M, C>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+    {
+        type Value = Option<M>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a decimal string, an integer, or null")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+            super::strict::deserialize::<C, M, D>(d).map(Some)
+        }
+    }
+
+    pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: BaseMoney<C> + MoneyParser<C> + FromStr<Err = MoneyError>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(Visitor::<M, C>(PhantomData))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// format: serialize/deserialize with custom format string, used by money_serde_format!
+// ---------------------------------------------------------------------------
+
+pub mod format {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer, de};
+
+    use crate::{Currency, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(value: &M, format_str: &str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: MoneyFormatter<C>,
+        S: Serializer,
+    {
+        serializer.serialize_str(&value.format(format_str))
+    }
+
+    pub struct Visitor<'f, M, C>(pub &'f str, pub PhantomData<(M, C)>);
+
+    impl<'de, C, M> de::Visitor<'de> for Visitor<'_, M, C>
+    where
+        C: Currency,
+        M: MoneyParser<C>,
+    {
+        type Value = M;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a string formatted with '{}'", self.0)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            M::from_str_format(v, self.0).map_err(de::Error::custom)
+        }
+    }
+
+    pub fn deserialize<'de, C, M, D>(format_str: &str, deserializer: D) -> Result<M, D::Error>
+    where
+        C: Currency,
+        M: MoneyParser<C>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(Visitor::<M, C>(format_str, PhantomData))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// option_format: optional variant of format
+// ---------------------------------------------------------------------------
+
+pub mod option_format {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer, de};
+
+    use crate::{Currency, MoneyFormatter, MoneyParser};
+
+    pub fn serialize<C, M, S>(
+        value: &Option<M>,
+        format_str: &str,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        C: Currency,
+        M: MoneyFormatter<C>,
+        S: Serializer,
+    {
+        match value {
+            Some(m) => serializer.serialize_some(m.format(format_str).as_str()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub struct Visitor<'f, M, C>(pub &'f str, pub PhantomData<(M, C)>);
+
+    impl<'de, C, M> de::Visitor<'de> for Visitor<'_, M, C>
+    where
+        C: Currency,
+        M: MoneyParser<C>,
+    {
+        type Value = Option<M>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a string formatted with '{}' or null", self.0)
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+            super::format::deserialize::<C, M, D>(self.0, d).map(Some)
+        }
+    }
+
+    pub fn deserialize<'de, C, M, D>(
+        format_str: &str,
+        deserializer: D,
+    ) -> Result<Option<M>, D::Error>
+    where
+        C: Currency,
+        M: MoneyParser<C>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(Visitor::<M, C>(format_str, PhantomData))
     }
 }
@@ -0,0 +1,342 @@
+//! Money-aware diff and merge of JSON documents, e.g. audit trail of invoice edits.
+//!
+//! Money is recognized in the object format, `{ "currency": "USD", "amount": "1234.56" }`,
+//! see [`super::money::object`]. A money amount changed in the same currency is reported as
+//! [`Change::Amount`] with its delta, e.g. `changed by +USD 12.00`, instead of a string replace.
+//! Other changes, including money changing currency, are reported like JSON Patch operations.
+//!
+//! Paths are JSON Pointers (RFC 6901), e.g. `/lines/0/total`.
+//!
+//! [`apply`] merges changes into a document. Amount changes are applied as deltas onto the document's
+//! current amount, so edits to the same money field made concurrently add up instead of overwriting each other.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use super::base::object::{AMOUNT, CURRENCY};
+use crate::{Decimal, MoneyError};
+
+/// Change between two JSON documents found by [`diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// Money amount at `path` changed in the same `currency`.
+    Amount {
+        path: String,
+        currency: String,
+        before: Decimal,
+        after: Decimal,
+    },
+
+    /// Value at `path` replaced, including money changing currency.
+    Replace {
+        path: String,
+        before: Value,
+        after: Value,
+    },
+
+    /// Value added at `path`.
+    Add { path: String, value: Value },
+
+    /// Value removed from `path`.
+    Remove { path: String, value: Value },
+}
+
+impl Change {
+    /// Returns JSON Pointer of the changed value.
+    pub fn path(&self) -> &str {
+        match self {
+            Change::Amount { path, .. }
+            | Change::Replace { path, .. }
+            | Change::Add { path, .. }
+            | Change::Remove { path, .. } => path,
+        }
+    }
+
+    /// Returns `after - before` of amount change, `None` for other changes or if overflowed.
+    pub fn delta(&self) -> Option<Decimal> {
+        match self {
+            Change::Amount { before, after, .. } => after.checked_sub(*before),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Amount {
+                path,
+                currency,
+                before,
+                after,
+            } => match self.delta() {
+                Some(delta) => {
+                    let sign = if delta.is_sign_negative() { '-' } else { '+' };
+                    write!(f, "{path}: changed by {sign}{currency} {}", delta.abs())
+                }
+                None => write!(
+                    f,
+                    "{path}: changed from {currency} {before} to {currency} {after}"
+                ),
+            },
+            Change::Replace {
+                path,
+                before,
+                after,
+            } => write!(f, "{path}: replaced {before} with {after}"),
+            Change::Add { path, value } => write!(f, "{path}: added {value}"),
+            Change::Remove { path, value } => write!(f, "{path}: removed {value}"),
+        }
+    }
+}
+
+/// Compares JSON documents `before` and `after`, returning changes turning `before` into `after`.
+///
+/// Money amounts are compared numerically, so `"12.0"` and `"12.00"` are equal.
+/// Array elements are compared by index.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::serde::diff::{Change, diff};
+/// use serde_json::json;
+///
+/// let before = json!({
+///     "id": 7,
+///     "total": { "currency": "USD", "amount": "100.00" },
+///     "fee": { "currency": "USD", "amount": "1.00" },
+/// });
+/// let after = json!({
+///     "id": 7,
+///     "total": { "currency": "USD", "amount": "112.00" },
+///     "fee": { "currency": "EUR", "amount": "1.00" },
+///     "note": "late",
+/// });
+///
+/// let changes: Vec<String> = diff(&before, &after).iter().map(Change::to_string).collect();
+/// assert_eq!(
+///     changes,
+///     [
+///         r#"/fee: replaced {"amount":"1.00","currency":"USD"} with {"amount":"1.00","currency":"EUR"}"#,
+///         r#"/total: changed by +USD 12.00"#,
+///         r#"/note: added "late""#,
+///     ]
+/// );
+/// ```
+pub fn diff(before: &Value, after: &Value) -> Vec<Change> {
+    let mut changes = vec![];
+    diff_at(String::new(), before, after, &mut changes);
+    changes
+}
+
+fn diff_at(path: String, before: &Value, after: &Value, changes: &mut Vec<Change>) {
+    match (as_money(before), as_money(after)) {
+        (Some((before_code, before_amount)), Some((after_code, after_amount)))
+            if before_code == after_code =>
+        {
+            if before_amount != after_amount {
+                changes.push(Change::Amount {
+                    path,
+                    currency: before_code.to_string(),
+                    before: before_amount,
+                    after: after_amount,
+                });
+            }
+            return;
+        }
+        (None, None) => {}
+        // money changed currency, or replaced by other value.
+        _ => {
+            changes.push(Change::Replace {
+                path,
+                before: before.clone(),
+                after: after.clone(),
+            });
+            return;
+        }
+    }
+
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) => {
+            for (key, before_value) in before {
+                let child = format!("{}/{}", path, escape(key));
+                match after.get(key) {
+                    Some(after_value) => diff_at(child, before_value, after_value, changes),
+                    None => changes.push(Change::Remove {
+                        path: child,
+                        value: before_value.clone(),
+                    }),
+                }
+            }
+            for (key, after_value) in after {
+                if !before.contains_key(key) {
+                    changes.push(Change::Add {
+                        path: format!("{}/{}", path, escape(key)),
+                        value: after_value.clone(),
+                    });
+                }
+            }
+        }
+        (Value::Array(before), Value::Array(after)) => {
+            for (i, (before_value, after_value)) in before.iter().zip(after).enumerate() {
+                diff_at(
+                    format!("{}/{}", path, i),
+                    before_value,
+                    after_value,
+                    changes,
+                );
+            }
+            // removed from the end, so each index is still valid when applied in order.
+            for (i, value) in before.iter().enumerate().skip(after.len()).rev() {
+                changes.push(Change::Remove {
+                    path: format!("{}/{}", path, i),
+                    value: value.clone(),
+                });
+            }
+            for (i, value) in after.iter().enumerate().skip(before.len()) {
+                changes.push(Change::Add {
+                    path: format!("{}/{}", path, i),
+                    value: value.clone(),
+                });
+            }
+        }
+        _ if before != after => changes.push(Change::Replace {
+            path,
+            before: before.clone(),
+            after: after.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/// Applies `changes` found by [`diff`] into `doc` in order.
+///
+/// Amount changes add their delta onto the current amount in `doc`, keeping the amount as string or number.
+/// Other changes are applied like JSON Patch operations.
+///
+/// Changes applied before an error are kept in `doc`.
+///
+/// # Errors
+///
+/// - [`MoneyError::ParseStrError`] if a path is not in `doc`, or amount change's path is not money.
+/// - [`MoneyError::CurrencyMismatchError`] if money at amount change's path is in other currency.
+/// - [`MoneyError::OverflowError`] if the new amount overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::serde::diff::{apply, diff};
+/// use serde_json::json;
+///
+/// let base = json!({ "total": { "currency": "USD", "amount": "100.00" } });
+/// let edited = json!({ "total": { "currency": "USD", "amount": "112.00" } });
+///
+/// // meanwhile, total of the stored document has changed.
+/// let mut stored = json!({ "total": { "currency": "USD", "amount": "90.00" } });
+/// apply(&mut stored, &diff(&base, &edited)).unwrap();
+/// assert_eq!(stored, json!({ "total": { "currency": "USD", "amount": "102.00" } }));
+/// ```
+pub fn apply(doc: &mut Value, changes: &[Change]) -> Result<(), MoneyError> {
+    for change in changes {
+        match change {
+            Change::Amount { path, currency, .. } => {
+                let delta = change.delta().ok_or(MoneyError::OverflowError)?;
+                let target = doc.pointer_mut(path).ok_or_else(|| not_found(path))?;
+                let (code, amount) = as_money(target).ok_or_else(|| {
+                    MoneyError::ParseStrError(format!("value at {} is not money", path).into())
+                })?;
+                if code != currency {
+                    return Err(MoneyError::CurrencyMismatchError(
+                        code.to_string(),
+                        currency.clone(),
+                    ));
+                }
+                let amount = amount.checked_add(delta).ok_or(MoneyError::OverflowError)?;
+                set_amount(target, amount)?;
+            }
+            Change::Replace { path, after, .. } => {
+                *doc.pointer_mut(path).ok_or_else(|| not_found(path))? = after.clone();
+            }
+            Change::Add { path, value } => {
+                let (parent, key) = split_path(path)?;
+                match doc.pointer_mut(parent) {
+                    Some(Value::Object(map)) => {
+                        map.insert(key, value.clone());
+                    }
+                    Some(Value::Array(vec)) => {
+                        let i = array_index(&key, vec.len()).ok_or_else(|| not_found(path))?;
+                        vec.insert(i, value.clone());
+                    }
+                    _ => return Err(not_found(path)),
+                }
+            }
+            Change::Remove { path, .. } => {
+                let (parent, key) = split_path(path)?;
+                let removed = match doc.pointer_mut(parent) {
+                    Some(Value::Object(map)) => map.remove(&key),
+                    Some(Value::Array(vec)) => array_index(&key, vec.len().saturating_sub(1))
+                        .filter(|_| !vec.is_empty())
+                        .map(|i| vec.remove(i)),
+                    _ => None,
+                };
+                removed.ok_or_else(|| not_found(path))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Currency code and amount of money in object format.
+fn as_money(value: &Value) -> Option<(&str, Decimal)> {
+    let map = value.as_object()?;
+    if map.len() != 2 {
+        return None;
+    }
+    let code = map.get(CURRENCY)?.as_str()?;
+    let amount = match map.get(AMOUNT)? {
+        Value::String(amount) => Decimal::from_str(amount).ok()?,
+        Value::Number(amount) => Decimal::from_str(&amount.to_string()).ok()?,
+        _ => return None,
+    };
+    Some((code, amount))
+}
+
+// Sets amount of money in object format, keeping it as string or number.
+fn set_amount(money: &mut Value, amount: Decimal) -> Result<(), MoneyError> {
+    let Some(field) = money.get_mut(AMOUNT) else {
+        return Err(MoneyError::ParseStrError("money has no amount".into()));
+    };
+    *field = match field {
+        Value::Number(_) => serde_json::from_str(&amount.to_string())
+            .map_err(|err| MoneyError::ParseStrError(err.to_string().into()))?,
+        _ => Value::String(amount.to_string()),
+    };
+    Ok(())
+}
+
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+// Splits `path` into parent pointer and unescaped last token.
+fn split_path(path: &str) -> Result<(&str, String), MoneyError> {
+    path.rsplit_once('/')
+        .map(|(parent, token)| (parent, unescape(token)))
+        .ok_or_else(|| not_found(path))
+}
+
+// Index of array token, at most `max`.
+fn array_index(token: &str, max: usize) -> Option<usize> {
+    token.parse().ok().filter(|&i| i <= max)
+}
+
+fn not_found(path: &str) -> MoneyError {
+    MoneyError::ParseStrError(format!("path {:?} not found in document", path).into())
+}
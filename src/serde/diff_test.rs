@@ -0,0 +1,156 @@
+use serde::Serialize;
+use serde_json::json;
+
+use crate::iso::{EUR, USD};
+use crate::macros::{dec, money};
+use crate::serde::diff::{Change, apply, diff};
+use crate::{Money, MoneyError};
+
+#[derive(Serialize)]
+struct Invoice {
+    #[serde(with = "crate::serde::money::object")]
+    total: Money<USD>,
+    #[serde(with = "crate::serde::money::object")]
+    fee: Money<EUR>,
+    lines: Vec<&'static str>,
+}
+
+#[test]
+fn test_diff_serialized_money() {
+    let before = serde_json::to_value(Invoice {
+        total: money!(USD, 100),
+        fee: money!(EUR, 2.5),
+        lines: vec!["a", "b"],
+    })
+    .unwrap();
+    let after = serde_json::to_value(Invoice {
+        total: money!(USD, 88.75),
+        fee: money!(EUR, 2.5),
+        lines: vec!["a", "c", "d"],
+    })
+    .unwrap();
+
+    let changes = diff(&before, &after);
+    assert_eq!(
+        changes,
+        [
+            Change::Replace {
+                path: "/lines/1".into(),
+                before: json!("b"),
+                after: json!("c"),
+            },
+            Change::Add {
+                path: "/lines/2".into(),
+                value: json!("d"),
+            },
+            Change::Amount {
+                path: "/total".into(),
+                currency: "USD".into(),
+                before: dec!(100),
+                after: dec!(88.75),
+            },
+        ]
+    );
+    assert_eq!(changes[2].delta(), Some(dec!(-11.25)));
+    assert_eq!(changes[2].to_string(), "/total: changed by -USD 11.25");
+    assert_eq!(changes[0].delta(), None);
+
+    let mut doc = before.clone();
+    apply(&mut doc, &changes).unwrap();
+    assert_eq!(doc, after);
+}
+
+#[test]
+fn test_diff_amount_compared_numerically() {
+    let before = json!({ "currency": "USD", "amount": "12.0" });
+    let after = json!({ "currency": "USD", "amount": 12.00 });
+    assert!(diff(&before, &after).is_empty());
+    assert!(diff(&before, &before).is_empty());
+}
+
+#[test]
+fn test_diff_money_root_and_currency_change() {
+    let before = json!({ "currency": "USD", "amount": "10.00" });
+    let after = json!({ "currency": "USD", "amount": "22.00" });
+    assert_eq!(
+        diff(&before, &after)[0].to_string(),
+        ": changed by +USD 12.00"
+    );
+
+    let after = json!({ "currency": "EUR", "amount": "10.00" });
+    assert!(matches!(
+        diff(&before, &after).as_slice(),
+        [Change::Replace { path, .. }] if path.is_empty()
+    ));
+
+    // money replaced by plain value.
+    let changes = diff(&json!({ "price": before }), &json!({ "price": "10.00" }));
+    assert!(matches!(
+        changes.as_slice(),
+        [Change::Replace { path, .. }] if path == "/price"
+    ));
+}
+
+#[test]
+fn test_diff_remove_and_escaped_paths() {
+    let before = json!({ "a/b": 1, "c~d": [1, 2, 3], "gone": true });
+    let after = json!({ "a/b": 2, "c~d": [1] });
+    let changes = diff(&before, &after);
+    let paths: Vec<&str> = changes.iter().map(Change::path).collect();
+    assert_eq!(paths, ["/a~1b", "/c~0d/2", "/c~0d/1", "/gone"]);
+    assert_eq!(changes[3].to_string(), "/gone: removed true");
+
+    let mut doc = before.clone();
+    apply(&mut doc, &changes).unwrap();
+    assert_eq!(doc, after);
+}
+
+#[test]
+fn test_apply_merges_amount_deltas() {
+    let base = json!({ "total": { "currency": "USD", "amount": 100 } });
+    let edit = json!({ "total": { "currency": "USD", "amount": 95.5 } });
+    let other_edit = json!({ "total": { "currency": "USD", "amount": 110 } });
+
+    let mut doc = base.clone();
+    apply(&mut doc, &diff(&base, &edit)).unwrap();
+    apply(&mut doc, &diff(&base, &other_edit)).unwrap();
+    // number stays number.
+    assert_eq!(
+        doc,
+        json!({ "total": { "currency": "USD", "amount": 105.5 } })
+    );
+}
+
+#[test]
+fn test_apply_errors() {
+    let base = json!({ "total": { "currency": "USD", "amount": "1" } });
+    let edit = json!({ "total": { "currency": "USD", "amount": "2" } });
+    let changes = diff(&base, &edit);
+
+    let mut doc = json!({ "total": { "currency": "EUR", "amount": "1" } });
+    assert!(matches!(
+        apply(&mut doc, &changes),
+        Err(MoneyError::CurrencyMismatchError(got, expected)) if got == "EUR" && expected == "USD"
+    ));
+
+    let mut doc = json!({ "total": "1" });
+    assert!(matches!(
+        apply(&mut doc, &changes),
+        Err(MoneyError::ParseStrError(_))
+    ));
+
+    let mut doc = json!({});
+    assert!(apply(&mut doc, &changes).is_err());
+
+    let remove = [Change::Remove {
+        path: "/items/0".into(),
+        value: json!(1),
+    }];
+    assert!(apply(&mut json!({ "items": [] }), &remove).is_err());
+
+    let add = [Change::Add {
+        path: "/items/2".into(),
+        value: json!(1),
+    }];
+    assert!(apply(&mut json!({ "items": [0] }), &add).is_err());
+}
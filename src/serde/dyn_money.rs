@@ -0,0 +1,24 @@
+//! `DynMoney` serde implementations.
+//!
+//! `DynMoney` carries its currency at runtime, so it's serialized as an object of currency code and amount string,
+//! e.g. `{ "currency": "USD", "amount": "1234.56" }`, the same format as [`super::money::object`].
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::obj_money::{DynMoney, ObjMoney};
+
+use super::base;
+
+impl Serialize for DynMoney {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        base::object::serialize_fields(self.code(), self.amount(), serializer)
+    }
+}
+
+/// Deserialized currency must be registered in [`Context`](crate::obj_money::Context).
+impl<'de> Deserialize<'de> for DynMoney {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (currency, amount) = base::object::deserialize_fields(deserializer)?;
+        DynMoney::new_with_code(&currency, amount).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::iso::{JPY, USD};
+use crate::macros::dec;
+use crate::obj_money::{DynMoney, ObjMoney};
+
+#[test]
+fn test_dyn_money_serialize() {
+    let money = DynMoney::from_decimal::<USD>(dec!(1234.56));
+    let json = serde_json::to_string(&money).unwrap();
+    assert_eq!(json, r#"{"currency":"USD","amount":"1234.56"}"#);
+
+    let money = DynMoney::from_decimal::<JPY>(dec!(-1234));
+    let json = serde_json::to_string(&money).unwrap();
+    assert_eq!(json, r#"{"currency":"JPY","amount":"-1234"}"#);
+}
+
+#[test]
+fn test_dyn_money_deserialize() {
+    let money: DynMoney =
+        serde_json::from_str(r#"{"currency":"USD","amount":"1234.567"}"#).unwrap();
+    assert_eq!(money.code(), "USD");
+    assert_eq!(money.amount(), dec!(1234.57));
+
+    let money: DynMoney = serde_json::from_str(r#"{"amount":1234,"currency":"JPY"}"#).unwrap();
+    assert_eq!(money.code(), "JPY");
+    assert_eq!(money.amount(), dec!(1234));
+}
+
+#[test]
+fn test_dyn_money_deserialize_invalid() {
+    let err = serde_json::from_str::<DynMoney>(r#"{"currency":"XYZ","amount":"1"}"#).unwrap_err();
+    assert!(err.to_string().contains("currency XYZ not found"));
+
+    assert!(serde_json::from_str::<DynMoney>(r#"{"currency":"USD"}"#).is_err());
+    assert!(serde_json::from_str::<DynMoney>(r#"{"currency":"USD","amount":"x"}"#).is_err());
+    assert!(serde_json::from_str::<DynMoney>(r#""USD 1,234.56""#).is_err());
+}
+
+#[test]
+fn test_dyn_money_roundtrip() {
+    let original = vec![
+        DynMoney::from_decimal::<USD>(dec!(1234.56)),
+        DynMoney::from_decimal::<JPY>(dec!(789)),
+    ];
+    let json = serde_json::to_string(&original).unwrap();
+    let deserialized: Vec<DynMoney> = serde_json::from_str(&json).unwrap();
+    assert_eq!(original, deserialized);
+}
+
+#[test]
+fn test_dyn_money_binary_roundtrip() {
+    let original = vec![
+        DynMoney::from_decimal::<USD>(dec!(-1234.56)),
+        DynMoney::from_decimal::<JPY>(dec!(789)),
+    ];
+
+    let bytes = bincode::serialize(&original).unwrap();
+    let deserialized: Vec<DynMoney> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(original, deserialized);
+
+    let bytes = postcard::to_allocvec(&original).unwrap();
+    let deserialized: Vec<DynMoney> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(original, deserialized);
+}
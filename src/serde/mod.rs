@@ -8,8 +8,168 @@ pub mod money;
 /// `RawMoney<C>` serde implementations
 pub mod raw_money;
 
+#[cfg(feature = "obj_money")]
+mod dyn_money;
+
+mod adjustment;
+
+pub mod diff;
+
 #[cfg(test)]
 mod money_test;
 
 #[cfg(all(test, feature = "raw_money"))]
 mod raw_money_test;
+
+#[cfg(all(test, feature = "obj_money"))]
+mod dyn_money_test;
+
+#[cfg(test)]
+mod adjustment_test;
+
+#[cfg(test)]
+mod diff_test;
+
+#[doc(hidden)]
+pub use ::serde as __serde;
+
+/// Returns true if `format_str` contains the negative sign symbol `n` outside escapes and literal blocks,
+/// used by [`money_serde_format!`](crate::money_serde_format) to reject formats dropping the sign.
+#[doc(hidden)]
+pub const fn __has_negative_symbol(format_str: &str) -> bool {
+    let bytes = format_str.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' {
+            if i + 1 < bytes.len() && bytes[i + 1] == b'{' {
+                i += 2;
+                while i < bytes.len() && bytes[i] != b'}' {
+                    i += 1;
+                }
+            } else {
+                i += 1;
+            }
+        } else if bytes[i] == b'n' {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Generates serde with-module serializing money with a custom format string.
+///
+/// Serialization uses [`crate::MoneyFormatter::format`] and deserialization uses
+/// [`crate::MoneyParser::from_str_format`] with the same format string, so the currency's
+/// separators are used. See [`crate::MoneyFormatter::format`] for the format symbols.
+///
+/// The generated module works for both `Money<C>` and `RawMoney<C>` of any currency, and contains
+/// `option` submodule for nullable money.
+///
+/// The format string must contain the negative sign symbol `n`, otherwise negative amounts would lose their sign
+/// and not round-trip, so a format without it fails to compile:
+///
+/// ```compile_fail
+/// moneylib::money_serde_format!(amount_code, "a c");
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, Money, money_serde_format, iso::{EUR, USD}, macros::dec};
+/// use serde::{Deserialize, Serialize};
+///
+/// money_serde_format!(amount_code, "na c");
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Payment {
+///     #[serde(with = "amount_code")]
+///     amount: Money<USD>,
+///     #[serde(with = "amount_code::option")]
+///     fee: Option<Money<EUR>>,
+/// }
+///
+/// let payment = Payment {
+///     amount: Money::from_decimal(dec!(1234.56)),
+///     fee: Some(Money::from_decimal(dec!(-2.5))),
+/// };
+/// let json = serde_json::to_string(&payment).unwrap();
+/// assert_eq!(json, r#"{"amount":"1,234.56 USD","fee":"-2,50 EUR"}"#);
+///
+/// let back: Payment = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back.fee.unwrap().amount(), dec!(-2.5));
+/// ```
+///
+/// Visibility and attributes can be given before the module name:
+///
+/// ```
+/// moneylib::money_serde_format!(
+///     /// Partner format, e.g. "USD: +1,234.56"
+///     pub partner, "c: pna"
+/// );
+/// ```
+#[macro_export]
+macro_rules! money_serde_format {
+    ($(#[$attr:meta])* $vis:vis $name:ident, $format:expr $(,)?) => {
+        $(#[$attr])*
+        $vis mod $name {
+            use $crate::serde::__serde::{Deserializer, Serializer};
+            use $crate::serde::base;
+            use $crate::{Currency, MoneyFormatter, MoneyParser};
+
+            /// Format string of this module.
+            pub const FORMAT: &str = $format;
+
+            const _: () = assert!(
+                $crate::serde::__has_negative_symbol(FORMAT),
+                "money_serde_format! format must contain the negative sign symbol `n`"
+            );
+
+            pub fn serialize<C, M, S>(value: &M, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                C: Currency,
+                M: MoneyFormatter<C>,
+                S: Serializer,
+            {
+                base::format::serialize::<C, M, S>(value, FORMAT, serializer)
+            }
+
+            pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<M, D::Error>
+            where
+                C: Currency,
+                M: MoneyParser<C>,
+                D: Deserializer<'de>,
+            {
+                base::format::deserialize::<C, M, D>(FORMAT, deserializer)
+            }
+
+            /// Nullable variant.
+            pub mod option {
+                use $crate::serde::__serde::{Deserializer, Serializer};
+                use $crate::serde::base;
+                use $crate::{Currency, MoneyFormatter, MoneyParser};
+
+                pub fn serialize<C, M, S>(
+                    value: &Option<M>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error>
+                where
+                    C: Currency,
+                    M: MoneyFormatter<C>,
+                    S: Serializer,
+                {
+                    base::option_format::serialize::<C, M, S>(value, super::FORMAT, serializer)
+                }
+
+                pub fn deserialize<'de, C, M, D>(deserializer: D) -> Result<Option<M>, D::Error>
+                where
+                    C: Currency,
+                    M: MoneyParser<C>,
+                    D: Deserializer<'de>,
+                {
+                    base::option_format::deserialize::<C, M, D>(super::FORMAT, deserializer)
+                }
+            }
+        }
+    };
+}
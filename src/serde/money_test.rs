@@ -26,6 +26,14 @@ fn test_default_serialize_negative() {
     assert_eq!(json, "-1234.56");
 }
 
+#[test]
+fn test_default_serialize_default_money() {
+    let json = serde_json::to_string(&Money::<USD>::default()).unwrap();
+    assert_eq!(json, "0.00");
+    let json = serde_json::to_string(&Money::<JPY>::default()).unwrap();
+    assert_eq!(json, "0");
+}
+
 #[test]
 fn test_default_deserialize_from_float() {
     let money: Money<USD> = serde_json::from_str("1234.56").unwrap();
@@ -1646,6 +1654,69 @@ fn test_option_minor_roundtrip() {
     assert_eq!(original.amount, deserialized.amount);
 }
 
+// ---------------------------------------------------------------------------
+// minor_units/option_minor_units serialize/deserialize
+// ---------------------------------------------------------------------------
+
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+struct PaymentMinorUnits {
+    #[serde(with = "crate::serde::money::minor_units")]
+    amount: Money<USD>,
+    #[serde(with = "crate::serde::money::option_minor_units")]
+    fee: Option<Money<USD>>,
+}
+
+#[test]
+fn test_minor_units_serialize() {
+    let p = PaymentMinorUnits {
+        amount: Money::<USD>::from_decimal(dec!(12.34)),
+        fee: Some(Money::<USD>::from_decimal(dec!(0.3))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"amount":1234,"fee":30}"#);
+
+    let p = PaymentMinorUnits {
+        amount: Money::<USD>::from_decimal(dec!(-12.34)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"amount":-1234,"fee":null}"#);
+}
+
+#[test]
+fn test_minor_units_deserialize() {
+    let p: PaymentMinorUnits = serde_json::from_str(r#"{"amount":1234,"fee":30}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(12.34));
+    assert_eq!(p.fee.unwrap().amount(), dec!(0.30));
+
+    let p: PaymentMinorUnits = serde_json::from_str(r#"{"amount":0,"fee":null}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(0));
+    assert!(p.fee.is_none());
+}
+
+#[test]
+fn test_minor_units_deserialize_rejects_non_integer() {
+    assert!(serde_json::from_str::<PaymentMinorUnits>(r#"{"amount":12.34,"fee":null}"#).is_err());
+    assert!(serde_json::from_str::<PaymentMinorUnits>(r#"{"amount":"1234","fee":null}"#).is_err());
+    assert!(serde_json::from_str::<PaymentMinorUnits>(r#"{"amount":1234,"fee":0.5}"#).is_err());
+}
+
+#[test]
+fn test_minor_units_roundtrip_jpy() {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    struct PaymentMinorUnitsJPY {
+        #[serde(with = "crate::serde::money::minor_units")]
+        amount: Money<JPY>,
+    }
+    let original = PaymentMinorUnitsJPY {
+        amount: Money::<JPY>::from_decimal(dec!(1234)),
+    };
+    let json = serde_json::to_string(&original).unwrap();
+    assert_eq!(json, r#"{"amount":1234}"#);
+    let deserialized: PaymentMinorUnitsJPY = serde_json::from_str(&json).unwrap();
+    assert_eq!(original.amount, deserialized.amount);
+}
+
 // ---------------------------------------------------------------------------
 // visit_unit: option visitors accept unit (None) from unit-based deserializers
 // ---------------------------------------------------------------------------
@@ -2440,3 +2511,881 @@ fn test_minor_expecting() {
     let result: Result<W, _> = serde_json::from_str(r#"{"amount":"not-a-number"}"#);
     assert!(result.is_err());
 }
+
+// ---------------------------------------------------------------------------
+// object/option_object serialize/deserialize
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, ::serde::Serialize, ::serde::Deserialize)]
+struct PaymentObject {
+    #[serde(with = "crate::serde::money::object")]
+    amount: Money<USD>,
+    #[serde(with = "crate::serde::money::option_object", default)]
+    fee: Option<Money<USD>>,
+}
+
+#[test]
+fn test_object_serialize() {
+    let p = PaymentObject {
+        amount: Money::<USD>::from_decimal(dec!(1234.56)),
+        fee: Some(Money::<USD>::from_decimal(dec!(-0.5))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currency":"USD","amount":"1234.56"},"fee":{"currency":"USD","amount":"-0.5"}}"#
+    );
+
+    let p = PaymentObject {
+        amount: Money::<USD>::from_decimal(dec!(0)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currency":"USD","amount":"0"},"fee":null}"#
+    );
+}
+
+#[test]
+fn test_object_deserialize() {
+    let p: PaymentObject = serde_json::from_str(
+        r#"{"amount":{"currency":"USD","amount":"1234.56"},"fee":{"amount":"0.30","currency":"USD"}}"#,
+    )
+    .unwrap();
+    assert_eq!(p.amount.amount(), dec!(1234.56));
+    assert_eq!(p.fee.unwrap().amount(), dec!(0.30));
+
+    // amount as number, unknown fields ignored, missing option defaults to None.
+    let p: PaymentObject =
+        serde_json::from_str(r#"{"amount":{"currency":"USD","amount":1234.56,"note":"x"}}"#)
+            .unwrap();
+    assert_eq!(p.amount.amount(), dec!(1234.56));
+    assert!(p.fee.is_none());
+
+    let p: PaymentObject =
+        serde_json::from_str(r#"{"amount":{"currency":"USD","amount":-7},"fee":null}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(-7));
+    assert!(p.fee.is_none());
+}
+
+#[test]
+fn test_object_deserialize_invalid() {
+    // currency mismatch
+    let err = serde_json::from_str::<PaymentObject>(
+        r#"{"amount":{"currency":"EUR","amount":"1234.56"}}"#,
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("currency mismatch: got EUR, expected USD")
+    );
+
+    // missing fields
+    let err = serde_json::from_str::<PaymentObject>(r#"{"amount":{"amount":"1"}}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `currency`"));
+    let err =
+        serde_json::from_str::<PaymentObject>(r#"{"amount":{"currency":"USD"}}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `amount`"));
+
+    // duplicate field
+    let err = serde_json::from_str::<PaymentObject>(
+        r#"{"amount":{"currency":"USD","amount":"1","amount":"2"}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("duplicate field `amount`"));
+
+    // invalid amount
+    assert!(
+        serde_json::from_str::<PaymentObject>(r#"{"amount":{"currency":"USD","amount":"abc"}}"#)
+            .is_err()
+    );
+    assert!(serde_json::from_str::<PaymentObject>(r#"{"amount":"1234.56"}"#).is_err());
+}
+
+#[test]
+fn test_object_roundtrip() {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    struct PaymentObjectJPY {
+        #[serde(with = "crate::serde::money::object")]
+        amount: Money<JPY>,
+    }
+    let original = PaymentObjectJPY {
+        amount: Money::<JPY>::from_decimal(dec!(1234)),
+    };
+    let json = serde_json::to_string(&original).unwrap();
+    assert_eq!(json, r#"{"amount":{"currency":"JPY","amount":"1234"}}"#);
+    let deserialized: PaymentObjectJPY = serde_json::from_str(&json).unwrap();
+    assert_eq!(original.amount, deserialized.amount);
+
+    let original = PaymentObject {
+        amount: Money::<USD>::from_decimal(dec!(1234.56)),
+        fee: Some(Money::<USD>::from_decimal(dec!(1))),
+    };
+    let yaml = serde_yaml::to_string(&original).unwrap();
+    let deserialized: PaymentObject = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(original.amount, deserialized.amount);
+    assert_eq!(original.fee, deserialized.fee);
+}
+
+// ---------------------------------------------------------------------------
+// proto/option_proto serialize/deserialize
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, ::serde::Serialize, ::serde::Deserialize)]
+struct PaymentProto {
+    #[serde(with = "crate::serde::money::proto")]
+    amount: Money<USD>,
+    #[serde(with = "crate::serde::money::option_proto", default)]
+    fee: Option<Money<USD>>,
+}
+
+#[test]
+fn test_proto_serialize() {
+    let p = PaymentProto {
+        amount: Money::<USD>::from_decimal(dec!(1234.56)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currencyCode":"USD","units":"1234","nanos":560000000},"fee":null}"#
+    );
+
+    // units and nanos have the same sign.
+    let p = PaymentProto {
+        amount: Money::<USD>::from_decimal(dec!(-1234.56)),
+        fee: Some(Money::<USD>::from_decimal(dec!(-0.75))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currencyCode":"USD","units":"-1234","nanos":-560000000},"fee":{"currencyCode":"USD","units":"0","nanos":-750000000}}"#
+    );
+
+    let p = PaymentProto {
+        amount: Money::<USD>::from_decimal(dec!(0)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currencyCode":"USD","units":"0","nanos":0},"fee":null}"#
+    );
+}
+
+#[test]
+fn test_proto_serialize_overflow() {
+    let p = PaymentProto {
+        amount: Money::<USD>::from_decimal(dec!(100000000000000000000)),
+        fee: None,
+    };
+    assert!(serde_json::to_string(&p).is_err());
+}
+
+#[test]
+fn test_proto_deserialize() {
+    let p: PaymentProto = serde_json::from_str(
+        r#"{"amount":{"currencyCode":"USD","units":"1234","nanos":560000000}}"#,
+    )
+    .unwrap();
+    assert_eq!(p.amount.amount(), dec!(1234.56));
+    assert!(p.fee.is_none());
+
+    // proto field name, units as number, missing nanos.
+    let p: PaymentProto = serde_json::from_str(
+        r#"{"amount":{"currency_code":"USD","units":-7},"fee":{"currencyCode":"USD","nanos":-10000000}}"#,
+    )
+    .unwrap();
+    assert_eq!(p.amount.amount(), dec!(-7));
+    assert_eq!(p.fee.unwrap().amount(), dec!(-0.01));
+
+    // zero fields omitted.
+    let p: PaymentProto = serde_json::from_str(r#"{"amount":{"currencyCode":"USD"}}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(0));
+}
+
+#[test]
+fn test_proto_deserialize_invalid() {
+    let err =
+        serde_json::from_str::<PaymentProto>(r#"{"amount":{"currencyCode":"EUR","units":"1"}}"#)
+            .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("currency mismatch: got EUR, expected USD")
+    );
+
+    let err = serde_json::from_str::<PaymentProto>(r#"{"amount":{"units":"1"}}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `currencyCode`"));
+
+    let err = serde_json::from_str::<PaymentProto>(
+        r#"{"amount":{"currencyCode":"USD","units":"1","nanos":-500000000}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("different signs"));
+
+    let err = serde_json::from_str::<PaymentProto>(
+        r#"{"amount":{"currencyCode":"USD","units":"-1","nanos":500000000}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("different signs"));
+
+    let err = serde_json::from_str::<PaymentProto>(
+        r#"{"amount":{"currencyCode":"USD","nanos":1000000000}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("out of range"));
+
+    assert!(
+        serde_json::from_str::<PaymentProto>(r#"{"amount":{"currencyCode":"USD","units":"1.5"}}"#)
+            .is_err()
+    );
+    assert!(
+        serde_json::from_str::<PaymentProto>(
+            r#"{"amount":{"currencyCode":"USD","units":"1","units":"2"}}"#
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_proto_roundtrip() {
+    for amount in [
+        dec!(0.01),
+        dec!(-0.01),
+        dec!(1234.56),
+        dec!(-99999999.99),
+        dec!(42),
+    ] {
+        let original = PaymentProto {
+            amount: Money::<USD>::from_decimal(amount),
+            fee: Some(Money::<USD>::from_decimal(-amount)),
+        };
+        let json = serde_json::to_string(&original).unwrap();
+        let deserialized: PaymentProto = serde_json::from_str(&json).unwrap();
+        assert_eq!(original.amount, deserialized.amount);
+        assert_eq!(original.fee, deserialized.fee);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// non-self-describing formats: bincode, postcard
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+struct PaymentBinary {
+    amount: Money<USD>,
+    opt_amount: Option<Money<EUR>>,
+    #[serde(with = "crate::serde::money::minor")]
+    minor: Money<USD>,
+    #[serde(with = "crate::serde::money::option_minor")]
+    opt_minor: Option<Money<USD>>,
+    #[serde(with = "crate::serde::money::object")]
+    object: Money<USD>,
+    #[serde(with = "crate::serde::money::option_object")]
+    opt_object: Option<Money<USD>>,
+    #[serde(with = "crate::serde::money::proto")]
+    proto: Money<USD>,
+    #[serde(with = "crate::serde::money::option_proto")]
+    opt_proto: Option<Money<USD>>,
+    #[serde(with = "crate::serde::money::str_code")]
+    str_code: Money<USD>,
+    #[serde(with = "crate::serde::money::option_str_code")]
+    opt_str_code: Option<Money<USD>>,
+}
+
+fn payment_binary(some: bool) -> PaymentBinary {
+    let amount = Money::<USD>::from_decimal(dec!(1234.56));
+    let negative = Money::<USD>::from_decimal(dec!(-0.01));
+    PaymentBinary {
+        amount,
+        opt_amount: some.then(|| Money::<EUR>::from_decimal(dec!(-99.5))),
+        minor: Money::<USD>::from_decimal(dec!(1234.56)),
+        opt_minor: some.then_some(negative),
+        object: amount,
+        opt_object: some.then_some(negative),
+        proto: Money::<USD>::from_decimal(dec!(-1234.56)),
+        opt_proto: some.then_some(negative),
+        str_code: amount,
+        opt_str_code: some.then_some(negative),
+    }
+}
+
+#[test]
+fn test_bincode_roundtrip() {
+    for some in [true, false] {
+        let original = payment_binary(some);
+        let bytes = bincode::serialize(&original).unwrap();
+        let deserialized: PaymentBinary = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(original, deserialized);
+    }
+}
+
+#[test]
+fn test_postcard_roundtrip() {
+    for some in [true, false] {
+        let original = payment_binary(some);
+        let bytes = postcard::to_allocvec(&original).unwrap();
+        let deserialized: PaymentBinary = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(original, deserialized);
+    }
+}
+
+#[test]
+fn test_bincode_default_as_string() {
+    let money = Money::<USD>::from_decimal(dec!(-1234.56));
+    let bytes = bincode::serialize(&money).unwrap();
+    assert_eq!(bytes, bincode::serialize("-1234.56").unwrap());
+    let deserialized: Money<USD> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(deserialized, money);
+
+    // Invalid amount.
+    let bytes = bincode::serialize("abc").unwrap();
+    assert!(bincode::deserialize::<Money<USD>>(&bytes).is_err());
+}
+
+#[test]
+fn test_bincode_object_currency_mismatch() {
+    #[derive(::serde::Serialize)]
+    struct Object<'a> {
+        currency: &'a str,
+        amount: &'a str,
+    }
+    let bytes = bincode::serialize(&Object {
+        currency: "EUR",
+        amount: "1",
+    })
+    .unwrap();
+
+    #[derive(Debug, ::serde::Deserialize)]
+    struct Payment {
+        #[serde(with = "crate::serde::money::object")]
+        #[allow(dead_code)]
+        amount: Money<USD>,
+    }
+    assert!(bincode::deserialize::<Payment>(&bytes).is_err());
+}
+
+// ---------------------------------------------------------------------------
+// strict/option_strict serialize/deserialize
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+struct PaymentStrict {
+    #[serde(with = "crate::serde::money::strict")]
+    amount: Money<USD>,
+    #[serde(with = "crate::serde::money::option_strict", default)]
+    fee: Option<Money<USD>>,
+}
+
+#[test]
+fn test_strict_serialize() {
+    let p = PaymentStrict {
+        amount: Money::<USD>::from_decimal(dec!(1234.56)),
+        fee: Some(Money::<USD>::from_decimal(dec!(-0.3))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"amount":"1234.56","fee":"-0.3"}"#);
+
+    let p = PaymentStrict {
+        amount: Money::<USD>::from_decimal(dec!(1)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"amount":"1","fee":null}"#);
+}
+
+#[test]
+fn test_strict_deserialize() {
+    let p: PaymentStrict = serde_json::from_str(r#"{"amount":"1234.56","fee":"0.30"}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(1234.56));
+    assert_eq!(p.fee.unwrap().amount(), dec!(0.30));
+
+    let p: PaymentStrict = serde_json::from_str(r#"{"amount":-1234,"fee":null}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(-1234));
+    assert!(p.fee.is_none());
+
+    let p: PaymentStrict = serde_json::from_str(r#"{"amount":18446744073709551615}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(18446744073709551615));
+}
+
+#[test]
+fn test_strict_deserialize_rejects_float() {
+    for json in [
+        r#"{"amount":1234.56}"#,
+        r#"{"amount":1234.0}"#,
+        r#"{"amount":1e3}"#,
+        r#"{"amount":"1","fee":0.3}"#,
+    ] {
+        let err = serde_json::from_str::<PaymentStrict>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("is not accepted, use string or integer instead"),
+            "{}",
+            err
+        );
+    }
+
+    // Without serde_json's arbitrary precision numbers, e.g. YAML.
+    let err = serde_yaml::from_str::<PaymentStrict>("amount: 1234.56").unwrap_err();
+    assert!(err.to_string().contains("float 1234.56 is not accepted"));
+    let p: PaymentStrict = serde_yaml::from_str("amount: '1234.56'").unwrap();
+    assert_eq!(p.amount.amount(), dec!(1234.56));
+
+    assert!(serde_json::from_str::<PaymentStrict>(r#"{"amount":"abc"}"#).is_err());
+}
+
+#[test]
+fn test_strict_roundtrip() {
+    let original = PaymentStrict {
+        amount: Money::<USD>::from_decimal(dec!(-1234.56)),
+        fee: Some(Money::<USD>::from_decimal(dec!(0.01))),
+    };
+    let json = serde_json::to_string(&original).unwrap();
+    assert_eq!(
+        serde_json::from_str::<PaymentStrict>(&json).unwrap(),
+        original
+    );
+
+    let bytes = bincode::serialize(&original).unwrap();
+    assert_eq!(
+        bincode::deserialize::<PaymentStrict>(&bytes).unwrap(),
+        original
+    );
+
+    let bytes = postcard::to_allocvec(&original).unwrap();
+    assert_eq!(
+        postcard::from_bytes::<PaymentStrict>(&bytes).unwrap(),
+        original
+    );
+}
+
+// ---------------------------------------------------------------------------
+// locale_str/option_locale_str serialize/deserialize
+// ---------------------------------------------------------------------------
+
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+struct MixedLocaleStr {
+    #[serde(with = "crate::serde::money::locale_str")]
+    usd: Money<USD>,
+    #[serde(with = "crate::serde::money::locale_str")]
+    eur: Money<EUR>,
+    #[serde(with = "crate::serde::money::option_locale_str")]
+    chf: Option<Money<CHF>>,
+}
+
+#[test]
+fn test_locale_str_serialize() {
+    let p = MixedLocaleStr {
+        usd: Money::<USD>::from_decimal(dec!(1234.56)),
+        eur: Money::<EUR>::from_decimal(dec!(-1234.56)),
+        chf: Some(Money::<CHF>::from_decimal(dec!(1234.56))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"usd":"USD 1,234.56","eur":"EUR -1.234,56","chf":"CHF 1'234.56"}"#
+    );
+
+    let p = MixedLocaleStr { chf: None, ..p };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"usd":"USD 1,234.56","eur":"EUR -1.234,56","chf":null}"#
+    );
+}
+
+#[test]
+fn test_locale_str_deserialize() {
+    let p: MixedLocaleStr = serde_json::from_str(
+        r#"{"usd":"USD 1,234.56","eur":"EUR 1.234,56","chf":"CHF -1'234.56"}"#,
+    )
+    .unwrap();
+    assert_eq!(p.usd.amount(), dec!(1234.56));
+    assert_eq!(p.eur.amount(), dec!(1234.56));
+    assert_eq!(p.chf.unwrap().amount(), dec!(-1234.56));
+
+    let p: MixedLocaleStr =
+        serde_json::from_str(r#"{"usd":"USD 1","eur":"EUR 2","chf":null}"#).unwrap();
+    assert!(p.chf.is_none());
+}
+
+#[test]
+fn test_locale_str_deserialize_rejects_other_separators() {
+    // EUR uses dot thousands and comma decimal.
+    assert!(
+        serde_json::from_str::<MixedLocaleStr>(
+            r#"{"usd":"USD 1,234.56","eur":"EUR 1,234.56","chf":null}"#
+        )
+        .is_err()
+    );
+    assert!(
+        serde_json::from_str::<MixedLocaleStr>(
+            r#"{"usd":"EUR 1,234.56","eur":"EUR 1.234,56","chf":null}"#
+        )
+        .is_err()
+    );
+}
+
+// ---------------------------------------------------------------------------
+// money_serde_format!: custom format with-module
+// ---------------------------------------------------------------------------
+
+crate::money_serde_format!(amount_code, "na c");
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CustomFormat {
+    #[serde(with = "amount_code")]
+    usd: Money<USD>,
+    #[serde(with = "amount_code")]
+    eur: Money<EUR>,
+    #[serde(with = "amount_code::option")]
+    jpy: Option<Money<JPY>>,
+}
+
+#[test]
+fn test_money_serde_format_serialize() {
+    let p = CustomFormat {
+        usd: Money::<USD>::from_decimal(dec!(1234.56)),
+        eur: Money::<EUR>::from_decimal(dec!(-1234.56)),
+        jpy: Some(Money::<JPY>::from_decimal(dec!(1234))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"usd":"1,234.56 USD","eur":"-1.234,56 EUR","jpy":"1,234 JPY"}"#
+    );
+
+    let p = CustomFormat { jpy: None, ..p };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"usd":"1,234.56 USD","eur":"-1.234,56 EUR","jpy":null}"#
+    );
+}
+
+#[test]
+fn test_money_serde_format_deserialize() {
+    let p: CustomFormat =
+        serde_json::from_str(r#"{"usd":"-1,234.56 USD","eur":"1.234,56 EUR","jpy":"1,234 JPY"}"#)
+            .unwrap();
+    assert_eq!(p.usd.amount(), dec!(-1234.56));
+    assert_eq!(p.eur.amount(), dec!(1234.56));
+    assert_eq!(p.jpy.unwrap().amount(), dec!(1234));
+
+    let p: CustomFormat =
+        serde_json::from_str(r#"{"usd":"1 USD","eur":"2 EUR","jpy":null}"#).unwrap();
+    assert!(p.jpy.is_none());
+
+    assert_eq!(amount_code::FORMAT, "na c");
+}
+
+#[test]
+fn test_money_serde_format_deserialize_error() {
+    let err = serde_json::from_str::<CustomFormat>(
+        r#"{"usd":"USD 1,234.56","eur":"1.234,56 EUR","jpy":null}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("USD 1,234.56"));
+
+    let err =
+        serde_json::from_str::<CustomFormat>(r#"{"usd":1234.56,"eur":"1.234,56 EUR","jpy":null}"#)
+            .unwrap_err();
+    assert!(err.to_string().contains("a string formatted with 'na c'"));
+}
+
+#[test]
+fn test_money_serde_format_negative_symbol() {
+    assert!(crate::serde::__has_negative_symbol("na c"));
+    assert!(crate::serde::__has_negative_symbol("c: pna"));
+    assert!(crate::serde::__has_negative_symbol("\\{n}na"));
+    assert!(!crate::serde::__has_negative_symbol("a c"));
+    assert!(!crate::serde::__has_negative_symbol("\\na"));
+    assert!(!crate::serde::__has_negative_symbol("\\{na} a"));
+    assert!(!crate::serde::__has_negative_symbol("\\{n"));
+    assert!(!crate::serde::__has_negative_symbol(""));
+}
+
+// ---------------------------------------------------------------------------
+// *_or_number: string formats, deserialize from number too
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Lenient {
+    #[serde(with = "crate::serde::money::comma_str_code_or_number")]
+    code: Money<USD>,
+    #[serde(with = "crate::serde::money::dot_str_symbol_or_number")]
+    symbol: Money<EUR>,
+    #[serde(with = "crate::serde::money::option_str_code_or_number")]
+    locale: Option<Money<CHF>>,
+}
+
+#[test]
+fn test_or_number_serialize_as_string() {
+    let p = Lenient {
+        code: Money::<USD>::from_decimal(dec!(1234.56)),
+        symbol: Money::<EUR>::from_decimal(dec!(-1234.56)),
+        locale: Some(Money::<CHF>::from_decimal(dec!(1234.56))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"code":"USD 1,234.56","symbol":"-€1.234,56","locale":"CHF 1'234.56"}"#
+    );
+
+    let p = Lenient { locale: None, ..p };
+    let json = serde_json::to_string(&p).unwrap();
+    assert!(json.ends_with(r#""locale":null}"#));
+}
+
+#[test]
+fn test_or_number_deserialize_from_string() {
+    let p: Lenient = serde_json::from_str(
+        r#"{"code":"USD 1,234.56","symbol":"€1.234,56","locale":"CHF -1'234.56"}"#,
+    )
+    .unwrap();
+    assert_eq!(p.code.amount(), dec!(1234.56));
+    assert_eq!(p.symbol.amount(), dec!(1234.56));
+    assert_eq!(p.locale.unwrap().amount(), dec!(-1234.56));
+}
+
+#[test]
+fn test_or_number_deserialize_from_number() {
+    let p: Lenient =
+        serde_json::from_str(r#"{"code":1234.56,"symbol":-1234,"locale":1234.567}"#).unwrap();
+    assert_eq!(p.code.amount(), dec!(1234.56));
+    assert_eq!(p.symbol.amount(), dec!(-1234));
+    assert_eq!(p.locale.unwrap().amount(), dec!(1234.57));
+
+    let p: Lenient =
+        serde_json::from_str(r#"{"code":0.1,"symbol":"€0,20","locale":null}"#).unwrap();
+    assert_eq!(p.code.amount(), dec!(0.1));
+    assert_eq!(p.symbol.amount(), dec!(0.2));
+    assert!(p.locale.is_none());
+}
+
+#[test]
+fn test_or_number_deserialize_rejects_invalid() {
+    // wrong separators
+    assert!(
+        serde_json::from_str::<Lenient>(r#"{"code":"USD 1.234,56","symbol":1,"locale":null}"#)
+            .is_err()
+    );
+    // wrong currency
+    assert!(
+        serde_json::from_str::<Lenient>(r#"{"code":"EUR 1,234.56","symbol":1,"locale":null}"#)
+            .is_err()
+    );
+    // decimal string isn't a number
+    assert!(
+        serde_json::from_str::<Lenient>(r#"{"code":"1234.56","symbol":1,"locale":null}"#).is_err()
+    );
+    let err =
+        serde_json::from_str::<Lenient>(r#"{"code":true,"symbol":1,"locale":null}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("a string like 'CCC 1,234.56' or a number")
+    );
+}
+
+#[test]
+fn test_or_number_bincode_roundtrip() {
+    let p = Lenient {
+        code: Money::<USD>::from_decimal(dec!(1234.56)),
+        symbol: Money::<EUR>::from_decimal(dec!(-1234.56)),
+        locale: Some(Money::<CHF>::from_decimal(dec!(1234.56))),
+    };
+    let bytes = bincode::serialize(&p).unwrap();
+    let back: Lenient = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back, p);
+}
+
+// ---------------------------------------------------------------------------
+// minor: errors naming currency and unit
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_minor_rejects_float_naming_currency() {
+    let err = serde_json::from_str::<PaymentMinor>(r#"{"amount":12.34}"#)
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .contains("expected integer cent amount for USD, found float 12.34"),
+        "{}",
+        err
+    );
+
+    // floats with integer value are floats too
+    let err = serde_json::from_str::<PaymentMinor>(r#"{"amount":1234.0}"#)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("found float 1234.0"), "{}", err);
+
+    let err = serde_json::from_str::<PaymentMinor>(r#"{"amount":1e3}"#)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("found float"), "{}", err);
+
+    let err = serde_json::from_str::<PaymentMinor>(r#"{"amount":"1234"}"#)
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .contains("expected integer cent amount for USD"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_minor_out_of_range_naming_currency() {
+    // i128 but beyond Decimal's 96 bits
+    let err =
+        serde_json::from_str::<PaymentMinor>(r#"{"amount":100000000000000000000000000000000}"#)
+            .err()
+            .unwrap();
+    assert!(
+        err.to_string().contains(
+            "minor amount 100000000000000000000000000000000 is out of range, expected integer cent amount for USD"
+        ),
+        "{}",
+        err
+    );
+
+    // beyond i128
+    let err = serde_json::from_str::<PaymentMinor>(
+        r#"{"amount":-1000000000000000000000000000000000000000}"#,
+    )
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("out of range"), "{}", err);
+
+    // i64 bounds are fine
+    let p: PaymentMinor = serde_json::from_str(r#"{"amount":-9223372036854775808}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(-92233720368547758.08));
+}
+
+#[test]
+fn test_option_minor_error_naming_currency() {
+    let err = serde_json::from_str::<PaymentOptMinor>(r#"{"amount":true}"#)
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .contains("expected integer cent amount for USD"),
+        "{}",
+        err
+    );
+
+    let err = serde_json::from_str::<PaymentOptMinor>(r#"{"amount":0.5}"#)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("found float 0.5"), "{}", err);
+}
+
+// ---------------------------------------------------------------------------
+// seq_* / map_*: collections in string formats
+// ---------------------------------------------------------------------------
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+struct Ledger {
+    #[serde(with = "crate::serde::money::seq_comma_str_code")]
+    items: Vec<Money<USD>>,
+    #[serde(with = "crate::serde::money::seq_dot_str_symbol")]
+    fees: Vec<Money<EUR>>,
+    #[serde(with = "crate::serde::money::map_str_code")]
+    balances: std::collections::HashMap<String, Money<CHF>>,
+}
+
+#[test]
+fn test_seq_map_roundtrip() {
+    let ledger = Ledger {
+        items: vec![
+            Money::<USD>::from_decimal(dec!(1234.56)),
+            Money::<USD>::from_decimal(dec!(-7)),
+        ],
+        fees: vec![Money::<EUR>::from_decimal(dec!(1234.5))],
+        balances: [(
+            "alice".to_string(),
+            Money::<CHF>::from_decimal(dec!(1234.56)),
+        )]
+        .into(),
+    };
+    let json = serde_json::to_string(&ledger).unwrap();
+    assert_eq!(
+        json,
+        r#"{"items":["USD 1,234.56","USD -7.00"],"fees":["€1.234,50"],"balances":{"alice":"CHF 1'234.56"}}"#
+    );
+    let back: Ledger = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, ledger);
+}
+
+#[test]
+fn test_seq_map_empty() {
+    let ledger: Ledger = serde_json::from_str(r#"{"items":[],"fees":[],"balances":{}}"#).unwrap();
+    assert!(ledger.items.is_empty());
+    assert!(ledger.fees.is_empty());
+    assert!(ledger.balances.is_empty());
+    assert_eq!(
+        serde_json::to_string(&ledger).unwrap(),
+        r#"{"items":[],"fees":[],"balances":{}}"#
+    );
+}
+
+#[test]
+fn test_seq_map_invalid() {
+    let err = serde_json::from_str::<Ledger>(
+        r#"{"items":["USD 1,234.56","EUR 1"],"fees":[],"balances":{}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("line 1"), "{}", err);
+
+    let err =
+        serde_json::from_str::<Ledger>(r#"{"items":"USD 1","fees":[],"balances":{}}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("expected a sequence of comma_str_code money"),
+        "{}",
+        err
+    );
+
+    let err =
+        serde_json::from_str::<Ledger>(r#"{"items":[],"fees":[],"balances":[]}"#).unwrap_err();
+    assert!(
+        err.to_string().contains("expected a map of str_code money"),
+        "{}",
+        err
+    );
+
+    assert!(serde_json::from_str::<Ledger>(r#"{"items":[1],"fees":[],"balances":{}}"#).is_err());
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+struct Sheet {
+    #[serde(with = "crate::serde::money::seq_str_symbol")]
+    items: Vec<Money<USD>>,
+    #[serde(with = "crate::serde::money::seq_dot_str_code")]
+    eur: Vec<Money<EUR>>,
+    #[serde(with = "crate::serde::money::map_comma_str_symbol")]
+    by_id: std::collections::HashMap<u32, Money<USD>>,
+}
+
+#[test]
+fn test_seq_map_other_formats() {
+    let sheet = Sheet {
+        items: vec![Money::<USD>::from_decimal(dec!(0.5))],
+        eur: vec![
+            Money::<EUR>::from_decimal(dec!(1)),
+            Money::<EUR>::from_decimal(dec!(1000000)),
+        ],
+        by_id: [(7, Money::<USD>::from_decimal(dec!(-1234)))].into(),
+    };
+    let json = serde_json::to_string(&sheet).unwrap();
+    assert_eq!(
+        json,
+        r#"{"items":["$0.50"],"eur":["EUR 1,00","EUR 1.000.000,00"],"by_id":{"7":"-$1,234.00"}}"#
+    );
+    let back: Sheet = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, sheet);
+}
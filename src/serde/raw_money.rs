@@ -501,3 +501,1030 @@ pub mod option_minor {
         base::option_minor::deserialize::<C, RawMoney<C>, D>(deserializer)
     }
 }
+
+// ---------------------------------------------------------------------------
+// *_or_number: string formats, deserialize from number too
+// ---------------------------------------------------------------------------
+
+/// Serialize/deserialize `RawMoney<C>` like [`comma_str_code`], also deserializing from a number, e.g. `1234.56`.
+///
+/// Useful when producers are inconsistent, sending either "USD 1,234.56789" or a bare number.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::comma_str_code_or_number")]
+/// amount: RawMoney<USD>,
+/// ```
+pub mod comma_str_code_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &RawMoney<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::comma_str_code_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RawMoney<C>, D::Error> {
+        base::comma_str_code_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Option<RawMoney<C>>` using [`comma_str_code_or_number`] format or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_comma_str_code_or_number")]
+/// amount: Option<RawMoney<USD>>,
+/// ```
+pub mod option_comma_str_code_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &Option<RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::option_comma_str_code_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RawMoney<C>>, D::Error> {
+        base::option_comma_str_code_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `RawMoney<C>` like [`comma_str_symbol`], also deserializing from a number, e.g. `1234.56`.
+///
+/// Useful when producers are inconsistent, sending either "$1,234.56789" or a bare number.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::comma_str_symbol_or_number")]
+/// amount: RawMoney<USD>,
+/// ```
+pub mod comma_str_symbol_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &RawMoney<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::comma_str_symbol_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RawMoney<C>, D::Error> {
+        base::comma_str_symbol_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Option<RawMoney<C>>` using [`comma_str_symbol_or_number`] format or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_comma_str_symbol_or_number")]
+/// amount: Option<RawMoney<USD>>,
+/// ```
+pub mod option_comma_str_symbol_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &Option<RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::option_comma_str_symbol_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RawMoney<C>>, D::Error> {
+        base::option_comma_str_symbol_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `RawMoney<C>` like [`dot_str_code`], also deserializing from a number, e.g. `1234.56`.
+///
+/// Useful when producers are inconsistent, sending either "EUR 1.234,56789" or a bare number.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::dot_str_code_or_number")]
+/// amount: RawMoney<USD>,
+/// ```
+pub mod dot_str_code_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &RawMoney<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::dot_str_code_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RawMoney<C>, D::Error> {
+        base::dot_str_code_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Option<RawMoney<C>>` using [`dot_str_code_or_number`] format or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_dot_str_code_or_number")]
+/// amount: Option<RawMoney<USD>>,
+/// ```
+pub mod option_dot_str_code_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &Option<RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::option_dot_str_code_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RawMoney<C>>, D::Error> {
+        base::option_dot_str_code_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `RawMoney<C>` like [`dot_str_symbol`], also deserializing from a number, e.g. `1234.56`.
+///
+/// Useful when producers are inconsistent, sending either "€1.234,56789" or a bare number.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::dot_str_symbol_or_number")]
+/// amount: RawMoney<USD>,
+/// ```
+pub mod dot_str_symbol_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &RawMoney<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::dot_str_symbol_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RawMoney<C>, D::Error> {
+        base::dot_str_symbol_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Option<RawMoney<C>>` using [`dot_str_symbol_or_number`] format or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_dot_str_symbol_or_number")]
+/// amount: Option<RawMoney<USD>>,
+/// ```
+pub mod option_dot_str_symbol_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &Option<RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::option_dot_str_symbol_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RawMoney<C>>, D::Error> {
+        base::option_dot_str_symbol_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `RawMoney<C>` like [`str_code`], also deserializing from a number, e.g. `1234.56`.
+///
+/// Useful when producers are inconsistent, sending either "USD 1,234.56789" or a bare number.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::str_code_or_number")]
+/// amount: RawMoney<USD>,
+/// ```
+pub mod str_code_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &RawMoney<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::str_code_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RawMoney<C>, D::Error> {
+        base::str_code_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Option<RawMoney<C>>` using [`str_code_or_number`] format or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_str_code_or_number")]
+/// amount: Option<RawMoney<USD>>,
+/// ```
+pub mod option_str_code_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &Option<RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::option_str_code_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RawMoney<C>>, D::Error> {
+        base::option_str_code_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `RawMoney<C>` like [`str_symbol`], also deserializing from a number, e.g. `1234.56`.
+///
+/// Useful when producers are inconsistent, sending either "$1,234.56789" or a bare number.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::str_symbol_or_number")]
+/// amount: RawMoney<USD>,
+/// ```
+pub mod str_symbol_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &RawMoney<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::str_symbol_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RawMoney<C>, D::Error> {
+        base::str_symbol_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Option<RawMoney<C>>` using [`str_symbol_or_number`] format or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_str_symbol_or_number")]
+/// amount: Option<RawMoney<USD>>,
+/// ```
+pub mod option_str_symbol_or_number {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &Option<RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::option_str_symbol_or_number::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RawMoney<C>>, D::Error> {
+        base::option_str_symbol_or_number::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// seq_* / map_*: collections in string formats
+// ---------------------------------------------------------------------------
+
+/// Serialize/deserialize `Vec<RawMoney<C>>` as a sequence of [`comma_str_code`] strings.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::seq_comma_str_code")]
+/// amounts: Vec<RawMoney<USD>>,
+/// ```
+pub mod seq_comma_str_code {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &[RawMoney<C>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::seq_comma_str_code::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<RawMoney<C>>, D::Error> {
+        base::seq_comma_str_code::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `HashMap<K, RawMoney<C>>` as a map of [`comma_str_code`] strings, e.g. `HashMap<String, RawMoney<C>>`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::map_comma_str_code")]
+/// amounts: HashMap<String, RawMoney<USD>>,
+/// ```
+pub mod map_comma_str_code {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, K: Serialize, S: Serializer>(
+        value: &HashMap<K, RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::map_comma_str_code::serialize::<C, RawMoney<C>, K, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, K, D>(deserializer: D) -> Result<HashMap<K, RawMoney<C>>, D::Error>
+    where
+        C: Currency,
+        K: Deserialize<'de> + Eq + Hash,
+        D: Deserializer<'de>,
+    {
+        base::map_comma_str_code::deserialize::<C, RawMoney<C>, K, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Vec<RawMoney<C>>` as a sequence of [`comma_str_symbol`] strings.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::seq_comma_str_symbol")]
+/// amounts: Vec<RawMoney<USD>>,
+/// ```
+pub mod seq_comma_str_symbol {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &[RawMoney<C>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::seq_comma_str_symbol::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<RawMoney<C>>, D::Error> {
+        base::seq_comma_str_symbol::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `HashMap<K, RawMoney<C>>` as a map of [`comma_str_symbol`] strings, e.g. `HashMap<String, RawMoney<C>>`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::map_comma_str_symbol")]
+/// amounts: HashMap<String, RawMoney<USD>>,
+/// ```
+pub mod map_comma_str_symbol {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, K: Serialize, S: Serializer>(
+        value: &HashMap<K, RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::map_comma_str_symbol::serialize::<C, RawMoney<C>, K, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, K, D>(deserializer: D) -> Result<HashMap<K, RawMoney<C>>, D::Error>
+    where
+        C: Currency,
+        K: Deserialize<'de> + Eq + Hash,
+        D: Deserializer<'de>,
+    {
+        base::map_comma_str_symbol::deserialize::<C, RawMoney<C>, K, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Vec<RawMoney<C>>` as a sequence of [`dot_str_code`] strings.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::seq_dot_str_code")]
+/// amounts: Vec<RawMoney<EUR>>,
+/// ```
+pub mod seq_dot_str_code {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &[RawMoney<C>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::seq_dot_str_code::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<RawMoney<C>>, D::Error> {
+        base::seq_dot_str_code::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `HashMap<K, RawMoney<C>>` as a map of [`dot_str_code`] strings, e.g. `HashMap<String, RawMoney<C>>`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::map_dot_str_code")]
+/// amounts: HashMap<String, RawMoney<EUR>>,
+/// ```
+pub mod map_dot_str_code {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, K: Serialize, S: Serializer>(
+        value: &HashMap<K, RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::map_dot_str_code::serialize::<C, RawMoney<C>, K, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, K, D>(deserializer: D) -> Result<HashMap<K, RawMoney<C>>, D::Error>
+    where
+        C: Currency,
+        K: Deserialize<'de> + Eq + Hash,
+        D: Deserializer<'de>,
+    {
+        base::map_dot_str_code::deserialize::<C, RawMoney<C>, K, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Vec<RawMoney<C>>` as a sequence of [`dot_str_symbol`] strings.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::seq_dot_str_symbol")]
+/// amounts: Vec<RawMoney<EUR>>,
+/// ```
+pub mod seq_dot_str_symbol {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &[RawMoney<C>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::seq_dot_str_symbol::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<RawMoney<C>>, D::Error> {
+        base::seq_dot_str_symbol::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `HashMap<K, RawMoney<C>>` as a map of [`dot_str_symbol`] strings, e.g. `HashMap<String, RawMoney<C>>`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::map_dot_str_symbol")]
+/// amounts: HashMap<String, RawMoney<EUR>>,
+/// ```
+pub mod map_dot_str_symbol {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, K: Serialize, S: Serializer>(
+        value: &HashMap<K, RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::map_dot_str_symbol::serialize::<C, RawMoney<C>, K, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, K, D>(deserializer: D) -> Result<HashMap<K, RawMoney<C>>, D::Error>
+    where
+        C: Currency,
+        K: Deserialize<'de> + Eq + Hash,
+        D: Deserializer<'de>,
+    {
+        base::map_dot_str_symbol::deserialize::<C, RawMoney<C>, K, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Vec<RawMoney<C>>` as a sequence of [`str_code`] strings.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::seq_str_code")]
+/// amounts: Vec<RawMoney<USD>>,
+/// ```
+pub mod seq_str_code {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &[RawMoney<C>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::seq_str_code::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<RawMoney<C>>, D::Error> {
+        base::seq_str_code::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `HashMap<K, RawMoney<C>>` as a map of [`str_code`] strings, e.g. `HashMap<String, RawMoney<C>>`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::map_str_code")]
+/// amounts: HashMap<String, RawMoney<USD>>,
+/// ```
+pub mod map_str_code {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, K: Serialize, S: Serializer>(
+        value: &HashMap<K, RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::map_str_code::serialize::<C, RawMoney<C>, K, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, K, D>(deserializer: D) -> Result<HashMap<K, RawMoney<C>>, D::Error>
+    where
+        C: Currency,
+        K: Deserialize<'de> + Eq + Hash,
+        D: Deserializer<'de>,
+    {
+        base::map_str_code::deserialize::<C, RawMoney<C>, K, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Vec<RawMoney<C>>` as a sequence of [`str_symbol`] strings.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::seq_str_symbol")]
+/// amounts: Vec<RawMoney<USD>>,
+/// ```
+pub mod seq_str_symbol {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &[RawMoney<C>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::seq_str_symbol::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<RawMoney<C>>, D::Error> {
+        base::seq_str_symbol::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `HashMap<K, RawMoney<C>>` as a map of [`str_symbol`] strings, e.g. `HashMap<String, RawMoney<C>>`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::map_str_symbol")]
+/// amounts: HashMap<String, RawMoney<USD>>,
+/// ```
+pub mod map_str_symbol {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, K: Serialize, S: Serializer>(
+        value: &HashMap<K, RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::map_str_symbol::serialize::<C, RawMoney<C>, K, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C, K, D>(deserializer: D) -> Result<HashMap<K, RawMoney<C>>, D::Error>
+    where
+        C: Currency,
+        K: Deserialize<'de> + Eq + Hash,
+        D: Deserializer<'de>,
+    {
+        base::map_str_symbol::deserialize::<C, RawMoney<C>, K, D>(deserializer)
+    }
+}
+
+// ---------------------------------------------------------------------------------
+// locale_str: alias of str_code
+// ---------------------------------------------------------------------------------
+
+/// Alias of [`str_code`], serialize/deserialize `RawMoney<C>` as code format with separators from currency's metadata,
+/// e.g. `"USD 1,234.56"` and `"EUR 1.234,56"`, so fields of different currencies share one with-module.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::locale_str")]
+/// amount: RawMoney<EUR>,
+/// ```
+pub use str_code as locale_str;
+
+/// Alias of [`option_str_code`], serialize/deserialize `Option<RawMoney<C>>` as code format with separators from
+/// currency's metadata or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_locale_str")]
+/// amount: Option<RawMoney<EUR>>,
+/// ```
+pub use option_str_code as option_locale_str;
+
+// ---------------------------------------------------------------------------------
+// minor_units: alias of minor
+// ---------------------------------------------------------------------------------
+
+/// Alias of [`minor`], serialize/deserialize `RawMoney<C>` as integer minor units, e.g. cents.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::minor_units")]
+/// amount: RawMoney<USD>,
+/// ```
+pub use minor as minor_units;
+
+/// Alias of [`option_minor`], serialize/deserialize `Option<RawMoney<C>>` as integer minor units or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_minor_units")]
+/// amount: Option<RawMoney<USD>>,
+/// ```
+pub use option_minor as option_minor_units;
+
+// ---------------------------------------------------------------------------------
+// object: serialize/deserialize as { "currency": "USD", "amount": "1234.56" }
+// ---------------------------------------------------------------------------------
+
+/// Serialize/deserialize `RawMoney<C>` as an object of currency code and amount string,
+/// e.g. `{ "currency": "USD", "amount": "1234.56" }`.
+///
+/// Deserialization accepts amount as string or number, ignores unknown fields,
+/// and fails if the currency is not `C`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::object")]
+/// amount: RawMoney<USD>,
+/// ```
+pub mod object {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &RawMoney<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::object::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RawMoney<C>, D::Error> {
+        base::object::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Option<RawMoney<C>>` using [`object`] format or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_object")]
+/// amount: Option<RawMoney<USD>>,
+/// ```
+pub mod option_object {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &Option<RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::option_object::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RawMoney<C>>, D::Error> {
+        base::option_object::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+// ---------------------------------------------------------------------------------
+// proto: serialize/deserialize as google.type.Money JSON
+// ---------------------------------------------------------------------------------
+
+/// Serialize/deserialize `RawMoney<C>` as Google's protobuf
+/// [`google.type.Money`](https://github.com/googleapis/googleapis/blob/master/google/type/money.proto) JSON,
+/// e.g. USD -1,234.56 -> `{ "currencyCode": "USD", "units": "-1234", "nanos": -560000000 }`.
+///
+/// `units` is serialized as string following proto3 JSON mapping of int64, `units` and `nanos` have the same sign.
+/// Serialization fails if the amount has more than 9 decimal places or doesn't fit in `units`.
+///
+/// Deserialization accepts `currencyCode` or `currency_code`, `units` as string or number, treats missing `units`
+/// and `nanos` as zero, and fails if the currency is not `C`, `nanos` is out of range, or signs differ.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::proto")]
+/// amount: RawMoney<USD>,
+/// ```
+pub mod proto {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &RawMoney<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::proto::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RawMoney<C>, D::Error> {
+        base::proto::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Option<RawMoney<C>>` using [`proto`] format or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_proto")]
+/// amount: Option<RawMoney<USD>>,
+/// ```
+pub mod option_proto {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &Option<RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::option_proto::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RawMoney<C>>, D::Error> {
+        base::option_proto::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+// ---------------------------------------------------------------------------------
+// strict: serialize as string, deserialize only from string or integer
+// ---------------------------------------------------------------------------------
+
+/// Serialize `RawMoney<C>` as decimal string, e.g. `"1234.56"`, and deserialize only from decimal string or integer.
+///
+/// Float numbers like `1234.56` are rejected with an error, for ingestion pipelines that must not accept
+/// lossy float inputs.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::strict")]
+/// amount: RawMoney<USD>,
+/// ```
+pub mod strict {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &RawMoney<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::strict::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RawMoney<C>, D::Error> {
+        base::strict::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
+
+/// Serialize/deserialize `Option<RawMoney<C>>` using [`strict`] format or `null`.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[serde(with = "moneylib::serde::raw_money::option_strict")]
+/// amount: Option<RawMoney<USD>>,
+/// ```
+pub mod option_strict {
+
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{Currency, RawMoney};
+
+    use crate::serde::base;
+
+    pub fn serialize<C: Currency, S: Serializer>(
+        value: &Option<RawMoney<C>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        base::option_strict::serialize::<C, RawMoney<C>, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RawMoney<C>>, D::Error> {
+        base::option_strict::deserialize::<C, RawMoney<C>, D>(deserializer)
+    }
+}
@@ -1639,6 +1639,69 @@ fn test_option_minor_roundtrip() {
     assert_eq!(original.amount, deserialized.amount);
 }
 
+// ---------------------------------------------------------------------------
+// minor_units/option_minor_units serialize/deserialize
+// ---------------------------------------------------------------------------
+
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+struct PaymentMinorUnits {
+    #[serde(with = "crate::serde::raw_money::minor_units")]
+    amount: RawMoney<USD>,
+    #[serde(with = "crate::serde::raw_money::option_minor_units")]
+    fee: Option<RawMoney<USD>>,
+}
+
+#[test]
+fn test_minor_units_serialize() {
+    let p = PaymentMinorUnits {
+        amount: RawMoney::<USD>::from_decimal(dec!(12.34)),
+        fee: Some(RawMoney::<USD>::from_decimal(dec!(0.3))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"amount":1234,"fee":30}"#);
+
+    let p = PaymentMinorUnits {
+        amount: RawMoney::<USD>::from_decimal(dec!(-12.34)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"amount":-1234,"fee":null}"#);
+}
+
+#[test]
+fn test_minor_units_deserialize() {
+    let p: PaymentMinorUnits = serde_json::from_str(r#"{"amount":1234,"fee":30}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(12.34));
+    assert_eq!(p.fee.unwrap().amount(), dec!(0.30));
+
+    let p: PaymentMinorUnits = serde_json::from_str(r#"{"amount":0,"fee":null}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(0));
+    assert!(p.fee.is_none());
+}
+
+#[test]
+fn test_minor_units_deserialize_rejects_non_integer() {
+    assert!(serde_json::from_str::<PaymentMinorUnits>(r#"{"amount":12.34,"fee":null}"#).is_err());
+    assert!(serde_json::from_str::<PaymentMinorUnits>(r#"{"amount":"1234","fee":null}"#).is_err());
+    assert!(serde_json::from_str::<PaymentMinorUnits>(r#"{"amount":1234,"fee":0.5}"#).is_err());
+}
+
+#[test]
+fn test_minor_units_roundtrip_jpy() {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    struct PaymentMinorUnitsJPY {
+        #[serde(with = "crate::serde::raw_money::minor_units")]
+        amount: RawMoney<JPY>,
+    }
+    let original = PaymentMinorUnitsJPY {
+        amount: RawMoney::<JPY>::from_decimal(dec!(1234)),
+    };
+    let json = serde_json::to_string(&original).unwrap();
+    assert_eq!(json, r#"{"amount":1234}"#);
+    let deserialized: PaymentMinorUnitsJPY = serde_json::from_str(&json).unwrap();
+    assert_eq!(original.amount, deserialized.amount);
+}
+
 // ---------------------------------------------------------------------------
 // visit_unit: option visitors accept unit (None) from unit-based deserializers
 // ---------------------------------------------------------------------------
@@ -2435,3 +2498,649 @@ fn test_minor_expecting() {
     let result: Result<W, _> = serde_json::from_str(r#"{"amount":"not-a-number"}"#);
     assert!(result.is_err());
 }
+
+// ---------------------------------------------------------------------------
+// object/option_object serialize/deserialize
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, ::serde::Serialize, ::serde::Deserialize)]
+struct PaymentObject {
+    #[serde(with = "crate::serde::raw_money::object")]
+    amount: RawMoney<USD>,
+    #[serde(with = "crate::serde::raw_money::option_object", default)]
+    fee: Option<RawMoney<USD>>,
+}
+
+#[test]
+fn test_object_serialize() {
+    let p = PaymentObject {
+        amount: RawMoney::<USD>::from_decimal(dec!(1234.56)),
+        fee: Some(RawMoney::<USD>::from_decimal(dec!(-0.5))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currency":"USD","amount":"1234.56"},"fee":{"currency":"USD","amount":"-0.5"}}"#
+    );
+
+    let p = PaymentObject {
+        amount: RawMoney::<USD>::from_decimal(dec!(0)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currency":"USD","amount":"0"},"fee":null}"#
+    );
+}
+
+#[test]
+fn test_object_deserialize() {
+    let p: PaymentObject = serde_json::from_str(
+        r#"{"amount":{"currency":"USD","amount":"1234.56"},"fee":{"amount":"0.30","currency":"USD"}}"#,
+    )
+    .unwrap();
+    assert_eq!(p.amount.amount(), dec!(1234.56));
+    assert_eq!(p.fee.unwrap().amount(), dec!(0.30));
+
+    // amount as number, unknown fields ignored, missing option defaults to None.
+    let p: PaymentObject =
+        serde_json::from_str(r#"{"amount":{"currency":"USD","amount":1234.56,"note":"x"}}"#)
+            .unwrap();
+    assert_eq!(p.amount.amount(), dec!(1234.56));
+    assert!(p.fee.is_none());
+
+    let p: PaymentObject =
+        serde_json::from_str(r#"{"amount":{"currency":"USD","amount":-7},"fee":null}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(-7));
+    assert!(p.fee.is_none());
+}
+
+#[test]
+fn test_object_deserialize_invalid() {
+    // currency mismatch
+    let err = serde_json::from_str::<PaymentObject>(
+        r#"{"amount":{"currency":"EUR","amount":"1234.56"}}"#,
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("currency mismatch: got EUR, expected USD")
+    );
+
+    // missing fields
+    let err = serde_json::from_str::<PaymentObject>(r#"{"amount":{"amount":"1"}}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `currency`"));
+    let err =
+        serde_json::from_str::<PaymentObject>(r#"{"amount":{"currency":"USD"}}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `amount`"));
+
+    // duplicate field
+    let err = serde_json::from_str::<PaymentObject>(
+        r#"{"amount":{"currency":"USD","amount":"1","amount":"2"}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("duplicate field `amount`"));
+
+    // invalid amount
+    assert!(
+        serde_json::from_str::<PaymentObject>(r#"{"amount":{"currency":"USD","amount":"abc"}}"#)
+            .is_err()
+    );
+    assert!(serde_json::from_str::<PaymentObject>(r#"{"amount":"1234.56"}"#).is_err());
+}
+
+#[test]
+fn test_object_roundtrip() {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    struct PaymentObjectJPY {
+        #[serde(with = "crate::serde::raw_money::object")]
+        amount: RawMoney<JPY>,
+    }
+    let original = PaymentObjectJPY {
+        amount: RawMoney::<JPY>::from_decimal(dec!(1234)),
+    };
+    let json = serde_json::to_string(&original).unwrap();
+    assert_eq!(json, r#"{"amount":{"currency":"JPY","amount":"1234"}}"#);
+    let deserialized: PaymentObjectJPY = serde_json::from_str(&json).unwrap();
+    assert_eq!(original.amount, deserialized.amount);
+
+    let original = PaymentObject {
+        amount: RawMoney::<USD>::from_decimal(dec!(1234.56)),
+        fee: Some(RawMoney::<USD>::from_decimal(dec!(1))),
+    };
+    let yaml = serde_yaml::to_string(&original).unwrap();
+    let deserialized: PaymentObject = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(original.amount, deserialized.amount);
+    assert_eq!(original.fee, deserialized.fee);
+}
+
+// ---------------------------------------------------------------------------
+// proto/option_proto serialize/deserialize
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, ::serde::Serialize, ::serde::Deserialize)]
+struct PaymentProto {
+    #[serde(with = "crate::serde::raw_money::proto")]
+    amount: RawMoney<USD>,
+    #[serde(with = "crate::serde::raw_money::option_proto", default)]
+    fee: Option<RawMoney<USD>>,
+}
+
+#[test]
+fn test_proto_serialize() {
+    let p = PaymentProto {
+        amount: RawMoney::<USD>::from_decimal(dec!(1234.56)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currencyCode":"USD","units":"1234","nanos":560000000},"fee":null}"#
+    );
+
+    // units and nanos have the same sign.
+    let p = PaymentProto {
+        amount: RawMoney::<USD>::from_decimal(dec!(-1234.56)),
+        fee: Some(RawMoney::<USD>::from_decimal(dec!(-0.75))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currencyCode":"USD","units":"-1234","nanos":-560000000},"fee":{"currencyCode":"USD","units":"0","nanos":-750000000}}"#
+    );
+
+    let p = PaymentProto {
+        amount: RawMoney::<USD>::from_decimal(dec!(0)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currencyCode":"USD","units":"0","nanos":0},"fee":null}"#
+    );
+}
+
+#[test]
+fn test_proto_serialize_overflow() {
+    let p = PaymentProto {
+        amount: RawMoney::<USD>::from_decimal(dec!(100000000000000000000)),
+        fee: None,
+    };
+    assert!(serde_json::to_string(&p).is_err());
+}
+
+#[test]
+fn test_proto_deserialize() {
+    let p: PaymentProto = serde_json::from_str(
+        r#"{"amount":{"currencyCode":"USD","units":"1234","nanos":560000000}}"#,
+    )
+    .unwrap();
+    assert_eq!(p.amount.amount(), dec!(1234.56));
+    assert!(p.fee.is_none());
+
+    // proto field name, units as number, missing nanos.
+    let p: PaymentProto = serde_json::from_str(
+        r#"{"amount":{"currency_code":"USD","units":-7},"fee":{"currencyCode":"USD","nanos":-10000000}}"#,
+    )
+    .unwrap();
+    assert_eq!(p.amount.amount(), dec!(-7));
+    assert_eq!(p.fee.unwrap().amount(), dec!(-0.01));
+
+    // zero fields omitted.
+    let p: PaymentProto = serde_json::from_str(r#"{"amount":{"currencyCode":"USD"}}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(0));
+}
+
+#[test]
+fn test_proto_deserialize_invalid() {
+    let err =
+        serde_json::from_str::<PaymentProto>(r#"{"amount":{"currencyCode":"EUR","units":"1"}}"#)
+            .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("currency mismatch: got EUR, expected USD")
+    );
+
+    let err = serde_json::from_str::<PaymentProto>(r#"{"amount":{"units":"1"}}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `currencyCode`"));
+
+    let err = serde_json::from_str::<PaymentProto>(
+        r#"{"amount":{"currencyCode":"USD","units":"1","nanos":-500000000}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("different signs"));
+
+    let err = serde_json::from_str::<PaymentProto>(
+        r#"{"amount":{"currencyCode":"USD","units":"-1","nanos":500000000}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("different signs"));
+
+    let err = serde_json::from_str::<PaymentProto>(
+        r#"{"amount":{"currencyCode":"USD","nanos":1000000000}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("out of range"));
+
+    assert!(
+        serde_json::from_str::<PaymentProto>(r#"{"amount":{"currencyCode":"USD","units":"1.5"}}"#)
+            .is_err()
+    );
+    assert!(
+        serde_json::from_str::<PaymentProto>(
+            r#"{"amount":{"currencyCode":"USD","units":"1","units":"2"}}"#
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_proto_roundtrip() {
+    for amount in [
+        dec!(0.01),
+        dec!(-0.01),
+        dec!(1234.56),
+        dec!(-99999999.99),
+        dec!(42),
+    ] {
+        let original = PaymentProto {
+            amount: RawMoney::<USD>::from_decimal(amount),
+            fee: Some(RawMoney::<USD>::from_decimal(-amount)),
+        };
+        let json = serde_json::to_string(&original).unwrap();
+        let deserialized: PaymentProto = serde_json::from_str(&json).unwrap();
+        assert_eq!(original.amount, deserialized.amount);
+        assert_eq!(original.fee, deserialized.fee);
+    }
+}
+
+#[test]
+fn test_proto_raw_precision() {
+    let p = PaymentProto {
+        amount: RawMoney::<USD>::from_decimal(dec!(1.123456789)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"amount":{"currencyCode":"USD","units":"1","nanos":123456789},"fee":null}"#
+    );
+    let deserialized: PaymentProto = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.amount.amount(), dec!(1.123456789));
+
+    // More precision than nanos.
+    let p = PaymentProto {
+        amount: RawMoney::<USD>::from_decimal(dec!(1.1234567891)),
+        fee: None,
+    };
+    let err = serde_json::to_string(&p).unwrap_err();
+    assert!(err.to_string().contains("more than 9 decimal places"));
+}
+
+// ---------------------------------------------------------------------------
+// non-self-describing formats: bincode, postcard
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+struct PaymentBinary {
+    amount: RawMoney<USD>,
+    opt_amount: Option<RawMoney<EUR>>,
+    #[serde(with = "crate::serde::raw_money::minor")]
+    minor: RawMoney<USD>,
+    #[serde(with = "crate::serde::raw_money::option_minor")]
+    opt_minor: Option<RawMoney<USD>>,
+    #[serde(with = "crate::serde::raw_money::object")]
+    object: RawMoney<USD>,
+    #[serde(with = "crate::serde::raw_money::option_object")]
+    opt_object: Option<RawMoney<USD>>,
+    #[serde(with = "crate::serde::raw_money::proto")]
+    proto: RawMoney<USD>,
+    #[serde(with = "crate::serde::raw_money::option_proto")]
+    opt_proto: Option<RawMoney<USD>>,
+    #[serde(with = "crate::serde::raw_money::str_code")]
+    str_code: RawMoney<USD>,
+    #[serde(with = "crate::serde::raw_money::option_str_code")]
+    opt_str_code: Option<RawMoney<USD>>,
+}
+
+fn payment_binary(some: bool) -> PaymentBinary {
+    let amount = RawMoney::<USD>::from_decimal(dec!(1234.5678));
+    let negative = RawMoney::<USD>::from_decimal(dec!(-0.01));
+    PaymentBinary {
+        amount,
+        opt_amount: some.then(|| RawMoney::<EUR>::from_decimal(dec!(-99.5))),
+        minor: RawMoney::<USD>::from_decimal(dec!(1234.56)),
+        opt_minor: some.then_some(negative),
+        object: amount,
+        opt_object: some.then_some(negative),
+        proto: RawMoney::<USD>::from_decimal(dec!(-1234.56)),
+        opt_proto: some.then_some(negative),
+        str_code: amount,
+        opt_str_code: some.then_some(negative),
+    }
+}
+
+#[test]
+fn test_bincode_roundtrip() {
+    for some in [true, false] {
+        let original = payment_binary(some);
+        let bytes = bincode::serialize(&original).unwrap();
+        let deserialized: PaymentBinary = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(original, deserialized);
+    }
+}
+
+#[test]
+fn test_postcard_roundtrip() {
+    for some in [true, false] {
+        let original = payment_binary(some);
+        let bytes = postcard::to_allocvec(&original).unwrap();
+        let deserialized: PaymentBinary = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(original, deserialized);
+    }
+}
+
+#[test]
+fn test_bincode_default_as_string() {
+    let money = RawMoney::<USD>::from_decimal(dec!(-1234.56));
+    let bytes = bincode::serialize(&money).unwrap();
+    assert_eq!(bytes, bincode::serialize("-1234.56").unwrap());
+    let deserialized: RawMoney<USD> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(deserialized, money);
+
+    // Invalid amount.
+    let bytes = bincode::serialize("abc").unwrap();
+    assert!(bincode::deserialize::<RawMoney<USD>>(&bytes).is_err());
+}
+
+#[test]
+fn test_bincode_object_currency_mismatch() {
+    #[derive(::serde::Serialize)]
+    struct Object<'a> {
+        currency: &'a str,
+        amount: &'a str,
+    }
+    let bytes = bincode::serialize(&Object {
+        currency: "EUR",
+        amount: "1",
+    })
+    .unwrap();
+
+    #[derive(Debug, ::serde::Deserialize)]
+    struct Payment {
+        #[serde(with = "crate::serde::raw_money::object")]
+        #[allow(dead_code)]
+        amount: RawMoney<USD>,
+    }
+    assert!(bincode::deserialize::<Payment>(&bytes).is_err());
+}
+
+// ---------------------------------------------------------------------------
+// strict/option_strict serialize/deserialize
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+struct PaymentStrict {
+    #[serde(with = "crate::serde::raw_money::strict")]
+    amount: RawMoney<USD>,
+    #[serde(with = "crate::serde::raw_money::option_strict", default)]
+    fee: Option<RawMoney<USD>>,
+}
+
+#[test]
+fn test_strict_serialize() {
+    let p = PaymentStrict {
+        amount: RawMoney::<USD>::from_decimal(dec!(1234.56)),
+        fee: Some(RawMoney::<USD>::from_decimal(dec!(-0.3))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"amount":"1234.56","fee":"-0.3"}"#);
+
+    let p = PaymentStrict {
+        amount: RawMoney::<USD>::from_decimal(dec!(1)),
+        fee: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"amount":"1","fee":null}"#);
+}
+
+#[test]
+fn test_strict_deserialize() {
+    let p: PaymentStrict = serde_json::from_str(r#"{"amount":"1234.56","fee":"0.30"}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(1234.56));
+    assert_eq!(p.fee.unwrap().amount(), dec!(0.30));
+
+    let p: PaymentStrict = serde_json::from_str(r#"{"amount":-1234,"fee":null}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(-1234));
+    assert!(p.fee.is_none());
+
+    let p: PaymentStrict = serde_json::from_str(r#"{"amount":18446744073709551615}"#).unwrap();
+    assert_eq!(p.amount.amount(), dec!(18446744073709551615));
+}
+
+#[test]
+fn test_strict_deserialize_rejects_float() {
+    for json in [
+        r#"{"amount":1234.56}"#,
+        r#"{"amount":1234.0}"#,
+        r#"{"amount":1e3}"#,
+        r#"{"amount":"1","fee":0.3}"#,
+    ] {
+        let err = serde_json::from_str::<PaymentStrict>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("is not accepted, use string or integer instead"),
+            "{}",
+            err
+        );
+    }
+
+    // Without serde_json's arbitrary precision numbers, e.g. YAML.
+    let err = serde_yaml::from_str::<PaymentStrict>("amount: 1234.56").unwrap_err();
+    assert!(err.to_string().contains("float 1234.56 is not accepted"));
+    let p: PaymentStrict = serde_yaml::from_str("amount: '1234.56'").unwrap();
+    assert_eq!(p.amount.amount(), dec!(1234.56));
+
+    assert!(serde_json::from_str::<PaymentStrict>(r#"{"amount":"abc"}"#).is_err());
+}
+
+#[test]
+fn test_strict_roundtrip() {
+    let original = PaymentStrict {
+        amount: RawMoney::<USD>::from_decimal(dec!(-1234.56)),
+        fee: Some(RawMoney::<USD>::from_decimal(dec!(0.01))),
+    };
+    let json = serde_json::to_string(&original).unwrap();
+    assert_eq!(
+        serde_json::from_str::<PaymentStrict>(&json).unwrap(),
+        original
+    );
+
+    let bytes = bincode::serialize(&original).unwrap();
+    assert_eq!(
+        bincode::deserialize::<PaymentStrict>(&bytes).unwrap(),
+        original
+    );
+
+    let bytes = postcard::to_allocvec(&original).unwrap();
+    assert_eq!(
+        postcard::from_bytes::<PaymentStrict>(&bytes).unwrap(),
+        original
+    );
+}
+
+// ---------------------------------------------------------------------------
+// locale_str/option_locale_str serialize/deserialize
+// ---------------------------------------------------------------------------
+
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+struct MixedLocaleStr {
+    #[serde(with = "crate::serde::raw_money::locale_str")]
+    usd: RawMoney<USD>,
+    #[serde(with = "crate::serde::raw_money::locale_str")]
+    eur: RawMoney<EUR>,
+    #[serde(with = "crate::serde::raw_money::option_locale_str")]
+    chf: Option<RawMoney<CHF>>,
+}
+
+#[test]
+fn test_locale_str_serialize() {
+    let p = MixedLocaleStr {
+        usd: RawMoney::<USD>::from_decimal(dec!(1234.567)),
+        eur: RawMoney::<EUR>::from_decimal(dec!(-1234.567)),
+        chf: Some(RawMoney::<CHF>::from_decimal(dec!(1234.567))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"usd":"USD 1,234.567","eur":"EUR -1.234,567","chf":"CHF 1'234.567"}"#
+    );
+
+    let p = MixedLocaleStr { chf: None, ..p };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"usd":"USD 1,234.567","eur":"EUR -1.234,567","chf":null}"#
+    );
+}
+
+#[test]
+fn test_locale_str_deserialize() {
+    let p: MixedLocaleStr = serde_json::from_str(
+        r#"{"usd":"USD 1,234.567","eur":"EUR 1.234,567","chf":"CHF -1'234.567"}"#,
+    )
+    .unwrap();
+    assert_eq!(p.usd.amount(), dec!(1234.567));
+    assert_eq!(p.eur.amount(), dec!(1234.567));
+    assert_eq!(p.chf.unwrap().amount(), dec!(-1234.567));
+
+    let p: MixedLocaleStr =
+        serde_json::from_str(r#"{"usd":"USD 1","eur":"EUR 2","chf":null}"#).unwrap();
+    assert!(p.chf.is_none());
+}
+
+#[test]
+fn test_locale_str_deserialize_rejects_other_separators() {
+    // EUR uses dot thousands and comma decimal.
+    assert!(
+        serde_json::from_str::<MixedLocaleStr>(
+            r#"{"usd":"USD 1,234.567","eur":"EUR 1,234.567","chf":null}"#
+        )
+        .is_err()
+    );
+    assert!(
+        serde_json::from_str::<MixedLocaleStr>(
+            r#"{"usd":"EUR 1,234.567","eur":"EUR 1.234,567","chf":null}"#
+        )
+        .is_err()
+    );
+}
+
+// ---------------------------------------------------------------------------
+// money_serde_format!: custom format with-module
+// ---------------------------------------------------------------------------
+
+crate::money_serde_format!(symbol_minor, "nsa m");
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CustomFormat {
+    #[serde(with = "symbol_minor")]
+    usd: RawMoney<USD>,
+    #[serde(with = "symbol_minor::option")]
+    eur: Option<RawMoney<EUR>>,
+}
+
+#[test]
+fn test_money_serde_format_roundtrip() {
+    let p = CustomFormat {
+        usd: RawMoney::<USD>::from_decimal(dec!(-1234.56)),
+        eur: Some(RawMoney::<EUR>::from_decimal(dec!(0.5))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"usd":"-$123,456 ¢","eur":"€50 c"}"#);
+
+    let back: CustomFormat = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.usd.amount(), dec!(-1234.56));
+    assert_eq!(back.eur.unwrap().amount(), dec!(0.5));
+
+    let back: CustomFormat = serde_json::from_str(r#"{"usd":"$1 ¢","eur":null}"#).unwrap();
+    assert_eq!(back.usd.amount(), dec!(0.01));
+    assert!(back.eur.is_none());
+}
+
+// ---------------------------------------------------------------------------
+// *_or_number: string formats, deserialize from number too
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct Lenient {
+    #[serde(with = "crate::serde::raw_money::comma_str_symbol_or_number")]
+    symbol: RawMoney<USD>,
+    #[serde(with = "crate::serde::raw_money::option_dot_str_code_or_number")]
+    code: Option<RawMoney<EUR>>,
+}
+
+#[test]
+fn test_or_number_roundtrip() {
+    let p = Lenient {
+        symbol: RawMoney::<USD>::from_decimal(dec!(1234.5678)),
+        code: Some(RawMoney::<EUR>::from_decimal(dec!(-1234.5678))),
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"symbol":"$1,234.5678","code":"EUR -1.234,5678"}"#);
+
+    let back: Lenient = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.symbol.amount(), dec!(1234.5678));
+    assert_eq!(back.code.unwrap().amount(), dec!(-1234.5678));
+}
+
+#[test]
+fn test_or_number_deserialize_from_number() {
+    let p: Lenient = serde_json::from_str(r#"{"symbol":1234.5678,"code":-1}"#).unwrap();
+    assert_eq!(p.symbol.amount(), dec!(1234.5678));
+    assert_eq!(p.code.unwrap().amount(), dec!(-1));
+
+    let p: Lenient =
+        serde_json::from_str(r#"{"symbol":18446744073709551615,"code":null}"#).unwrap();
+    assert_eq!(p.symbol.amount(), dec!(18446744073709551615));
+    assert!(p.code.is_none());
+
+    assert!(serde_json::from_str::<Lenient>(r#"{"symbol":"1234","code":null}"#).is_err());
+}
+
+// ---------------------------------------------------------------------------
+// seq_* / map_*: collections in string formats
+// ---------------------------------------------------------------------------
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+struct Ledger {
+    #[serde(with = "crate::serde::raw_money::seq_comma_str_code")]
+    items: Vec<RawMoney<USD>>,
+    #[serde(with = "crate::serde::raw_money::map_dot_str_symbol")]
+    balances: std::collections::HashMap<String, RawMoney<EUR>>,
+}
+
+#[test]
+fn test_seq_map_roundtrip() {
+    let ledger = Ledger {
+        items: vec![
+            RawMoney::<USD>::from_decimal(dec!(1234.56789)),
+            RawMoney::<USD>::from_decimal(dec!(-0.001)),
+        ],
+        balances: [(
+            "bob".to_string(),
+            RawMoney::<EUR>::from_decimal(dec!(1234.5678)),
+        )]
+        .into(),
+    };
+    let json = serde_json::to_string(&ledger).unwrap();
+    assert_eq!(
+        json,
+        r#"{"items":["USD 1,234.56789","USD -0.001"],"balances":{"bob":"€1.234,5678"}}"#
+    );
+    let back: Ledger = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, ledger);
+
+    assert!(serde_json::from_str::<Ledger>(r#"{"items":["$1"],"balances":{}}"#).is_err());
+}
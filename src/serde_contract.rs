@@ -0,0 +1,268 @@
+//! serde_contract emits canonical fixtures of money's serde formats, for contract tests of services exchanging money.
+//!
+//! [`emit_fixtures`] serializes the same sample amounts with the default implementation and every module of
+//! [`serde::money`](crate::serde::money), and [`serde::raw_money`](crate::serde::raw_money) with `raw_money` feature,
+//! into JSON, YAML and TOML files. Downstream services vendor the files, deserialize them in their tests
+//! and regenerate them when upgrading moneylib: a diff in the fixtures is a change of the wire format.
+//!
+//! Files are laid out as `<path>/<type>/<module>.<format>`, e.g. `money/comma_str_code.json`, with `number`
+//! for the default implementation. `number` is only emitted as JSON, since the default implementation writes
+//! serde_json's arbitrary precision numbers, which YAML and TOML have no equivalent of.
+//! Each file is a document of named samples:
+//!
+//! ```json
+//! {
+//!   "positive": "USD 1,234.56",
+//!   "negative": "USD -1,234.56",
+//!   ...
+//! }
+//! ```
+//!
+//! `option_*` documents have a `some` and a `none` sample, `seq_*` a list of samples and `map_*` a single entry.
+//! TOML has no null, so `none` samples are absent from TOML documents.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ::serde::Serialize;
+
+/// File extensions of emitted formats.
+pub const FORMATS: &[&str] = &["json", "yaml", "toml"];
+
+// Formats of the default implementation's fixtures.
+const NUMBER_FORMATS: &[&str] = &["json"];
+
+// Default implementation, as a with-module.
+mod number {
+    use ::serde::{Serialize, Serializer};
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+}
+
+// Serializes samples of money type `$money` with with-module `$module`, as unit struct `Fixture`.
+macro_rules! fixture {
+    (single $money:ident, $($module:tt)+) => {
+        struct Fixture;
+
+        impl Serialize for Fixture {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeStruct;
+                use $($module)+ as module;
+                use crate::BaseMoney;
+                use crate::iso::{BHD, JPY, USD};
+                use crate::macros::dec;
+
+                struct Sample<C: crate::Currency>($money<C>);
+
+                impl<C: crate::Currency> Serialize for Sample<C> {
+                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        module::serialize(&self.0, serializer)
+                    }
+                }
+
+                let mut doc = serializer.serialize_struct("Fixture", 7)?;
+                doc.serialize_field("positive", &Sample($money::<USD>::from_decimal(dec!(1234.56))))?;
+                doc.serialize_field("negative", &Sample($money::<USD>::from_decimal(dec!(-1234.56))))?;
+                doc.serialize_field("zero", &Sample($money::<USD>::from_decimal(dec!(0))))?;
+                doc.serialize_field("cents", &Sample($money::<USD>::from_decimal(dec!(0.05))))?;
+                doc.serialize_field("large", &Sample($money::<USD>::from_decimal(dec!(1234567890123.45))))?;
+                doc.serialize_field("no_minor_unit", &Sample($money::<JPY>::from_decimal(dec!(1234))))?;
+                doc.serialize_field("three_minor_units", &Sample($money::<BHD>::from_decimal(dec!(1.234))))?;
+                doc.end()
+            }
+        }
+    };
+    (option $money:ident, $($module:tt)+) => {
+        struct Fixture;
+
+        impl Serialize for Fixture {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeStruct;
+                use $($module)+ as module;
+                use crate::BaseMoney;
+                use crate::iso::USD;
+                use crate::macros::dec;
+
+                struct Sample(Option<$money<USD>>);
+
+                impl Serialize for Sample {
+                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        module::serialize(&self.0, serializer)
+                    }
+                }
+
+                let mut doc = serializer.serialize_struct("Fixture", 2)?;
+                doc.serialize_field("some", &Sample(Some($money::from_decimal(dec!(1234.56)))))?;
+                doc.serialize_field("none", &Sample(None))?;
+                doc.end()
+            }
+        }
+    };
+    (seq $money:ident, $($module:tt)+) => {
+        struct Fixture;
+
+        impl Serialize for Fixture {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeStruct;
+                use $($module)+ as module;
+                use crate::BaseMoney;
+                use crate::iso::USD;
+                use crate::macros::dec;
+
+                struct Sample(Vec<$money<USD>>);
+
+                impl Serialize for Sample {
+                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        module::serialize(&self.0, serializer)
+                    }
+                }
+
+                let values = [dec!(1234.56), dec!(-7), dec!(0.05)].map($money::from_decimal);
+                let mut doc = serializer.serialize_struct("Fixture", 1)?;
+                doc.serialize_field("values", &Sample(values.to_vec()))?;
+                doc.end()
+            }
+        }
+    };
+    (map $money:ident, $($module:tt)+) => {
+        struct Fixture;
+
+        impl Serialize for Fixture {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeStruct;
+                use $($module)+ as module;
+                use crate::BaseMoney;
+                use crate::iso::USD;
+                use crate::macros::dec;
+
+                struct Sample(HashMap<&'static str, $money<USD>>);
+
+                impl Serialize for Sample {
+                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        module::serialize(&self.0, serializer)
+                    }
+                }
+
+                // single entry, as map order is not stable.
+                let values = HashMap::from([("rent", $money::from_decimal(dec!(1234.56)))]);
+                let mut doc = serializer.serialize_struct("Fixture", 1)?;
+                doc.serialize_field("values", &Sample(values))?;
+                doc.end()
+            }
+        }
+    };
+}
+
+// Emits fixtures of money type `$money` for each module of `crate::serde::$base` listed by kind.
+macro_rules! emit {
+    ($dir:expr, $money:ident, $base:ident, { $($kind:ident: [$($module:ident),* $(,)?]),* $(,)? }) => {{
+        let dir: &Path = $dir;
+        let mut written = vec![];
+        {
+            fixture!(single $money, self::number);
+            written.extend(write(dir, "number", NUMBER_FORMATS, &Fixture)?);
+        }
+        $($({
+            fixture!($kind $money, crate::serde::$base::$module);
+            written.extend(write(dir, stringify!($module), FORMATS, &Fixture)?);
+        })*)*
+        written
+    }};
+}
+
+// Modules of `crate::serde::money` and `crate::serde::raw_money`, by kind.
+macro_rules! emit_modules {
+    ($dir:expr, $money:ident, $base:ident) => {
+        emit!($dir, $money, $base, {
+            single: [
+                comma_str_code, comma_str_symbol, dot_str_code, dot_str_symbol, str_code, str_symbol,
+                comma_str_code_or_number, comma_str_symbol_or_number, dot_str_code_or_number,
+                dot_str_symbol_or_number, str_code_or_number, str_symbol_or_number,
+                minor, object, proto, strict,
+            ],
+            option: [
+                option_comma_str_code, option_comma_str_symbol, option_dot_str_code, option_dot_str_symbol,
+                option_str_code, option_str_symbol, option_comma_str_code_or_number,
+                option_comma_str_symbol_or_number, option_dot_str_code_or_number,
+                option_dot_str_symbol_or_number, option_str_code_or_number, option_str_symbol_or_number,
+                option_minor, option_object, option_proto, option_strict,
+            ],
+            seq: [
+                seq_comma_str_code, seq_comma_str_symbol, seq_dot_str_code, seq_dot_str_symbol,
+                seq_str_code, seq_str_symbol,
+            ],
+            map: [
+                map_comma_str_code, map_comma_str_symbol, map_dot_str_code, map_dot_str_symbol,
+                map_str_code, map_str_symbol,
+            ],
+        })
+    };
+}
+
+/// Writes fixtures of every serde format into directory `path`, creating it if missing, and returns paths of written files.
+///
+/// Existing fixtures are overwritten. Emitted fixtures only change when the wire format does.
+///
+/// # Errors
+///
+/// Returns error if a directory or file can't be written, or a fixture can't be serialized.
+///
+/// # Examples
+///
+/// ```no_run
+/// moneylib::serde_contract::emit_fixtures("tests/fixtures/moneylib").unwrap();
+/// ```
+pub fn emit_fixtures(path: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+    use crate::Money;
+
+    let path = path.as_ref();
+    let mut written = vec![];
+    written.extend(emit_modules!(&path.join("money"), Money, money));
+
+    #[cfg(feature = "raw_money")]
+    {
+        use crate::RawMoney;
+        written.extend(emit_modules!(&path.join("raw_money"), RawMoney, raw_money));
+    }
+
+    Ok(written)
+}
+
+fn to_format<T: Serialize>(doc: &T, format: &str) -> io::Result<String> {
+    match format {
+        "json" => serde_json::to_string_pretty(doc)
+            .map(|json| json + "\n")
+            .map_err(io::Error::other),
+        "yaml" => serde_yaml::to_string(doc).map_err(io::Error::other),
+        "toml" => toml::to_string(doc).map_err(io::Error::other),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unsupported fixture format: {}", format),
+        )),
+    }
+}
+
+// Writes `doc` in each of `formats` into `dir/name.<format>`, returning the written paths.
+fn write<T: Serialize>(
+    dir: &Path,
+    name: &str,
+    formats: &[&str],
+    doc: &T,
+) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut written = vec![];
+    for format in formats {
+        let content = to_format(doc, format)?;
+        let path = dir.join(format!("{}.{}", name, format));
+        fs::write(&path, content)?;
+        written.push(path);
+    }
+    Ok(written)
+}
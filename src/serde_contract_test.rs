@@ -0,0 +1,193 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Money;
+use crate::iso::{BHD, JPY, USD};
+use crate::macros::money;
+use crate::serde_contract::{FORMATS, emit_fixtures};
+
+fn fixtures_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("moneylib_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn read(dir: &Path, file: &str) -> String {
+    fs::read_to_string(dir.join(file)).unwrap()
+}
+
+#[test]
+fn test_emit_fixtures_all_modules() {
+    let dir = fixtures_dir("all_modules");
+    let written = emit_fixtures(&dir).unwrap();
+
+    // 16 single, 16 option, 6 seq and 6 map modules, and JSON of default number, for each money type.
+    let types = if cfg!(feature = "raw_money") { 2 } else { 1 };
+    assert_eq!(written.len(), types * (44 * FORMATS.len() + 1));
+    assert!(written.contains(&dir.join("money/number.json")));
+    for format in FORMATS {
+        for file in [
+            "money/comma_str_code",
+            "money/option_proto",
+            "money/seq_dot_str_symbol",
+            "money/map_str_code",
+            #[cfg(feature = "raw_money")]
+            "raw_money/strict",
+            #[cfg(feature = "raw_money")]
+            "raw_money/option_object",
+        ] {
+            let path = dir.join(format!("{}.{}", file, format));
+            assert!(written.contains(&path), "{} not written", path.display());
+        }
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_emit_fixtures_number_json_only() {
+    let dir = fixtures_dir("number");
+    emit_fixtures(&dir).unwrap();
+
+    assert_eq!(
+        read(&dir, "money/number.json"),
+        r#"{
+  "positive": 1234.56,
+  "negative": -1234.56,
+  "zero": 0,
+  "cents": 0.05,
+  "large": 1234567890123.45,
+  "no_minor_unit": 1234,
+  "three_minor_units": 1.234
+}
+"#
+    );
+    assert!(!dir.join("money/number.yaml").exists());
+    assert!(!dir.join("money/number.toml").exists());
+    #[cfg(feature = "raw_money")]
+    {
+        assert!(dir.join("raw_money/number.json").exists());
+        assert!(!dir.join("raw_money/number.yaml").exists());
+        assert!(!dir.join("raw_money/number.toml").exists());
+    }
+
+    // other fixtures hold no serde_json internals.
+    for format in ["yaml", "toml"] {
+        let content = read(&dir, &format!("money/object.{}", format));
+        assert!(!content.contains("serde_json"), "{}", content);
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_emit_fixtures_content() {
+    let dir = fixtures_dir("content");
+    emit_fixtures(&dir).unwrap();
+
+    assert_eq!(
+        read(&dir, "money/comma_str_code.json"),
+        r#"{
+  "positive": "USD 1,234.56",
+  "negative": "USD -1,234.56",
+  "zero": "USD 0.00",
+  "cents": "USD 0.05",
+  "large": "USD 1,234,567,890,123.45",
+  "no_minor_unit": "JPY 1,234",
+  "three_minor_units": "BHD 1.234"
+}
+"#
+    );
+    assert_eq!(
+        read(&dir, "money/option_minor.yaml"),
+        "some: 123456\nnone: null\n"
+    );
+    assert_eq!(read(&dir, "money/option_minor.toml"), "some = 123456\n");
+    assert_eq!(
+        read(&dir, "money/seq_str_code.toml"),
+        "values = [\"USD 1,234.56\", \"USD -7.00\", \"USD 0.05\"]\n"
+    );
+    assert_eq!(
+        read(&dir, "money/map_dot_str_symbol.json"),
+        "{\n  \"values\": {\n    \"rent\": \"$1.234,56\"\n  }\n}\n"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_emit_fixtures_stable() {
+    let first = fixtures_dir("stable_first");
+    let second = fixtures_dir("stable_second");
+    let written = emit_fixtures(&first).unwrap();
+    emit_fixtures(&second).unwrap();
+
+    for path in written {
+        let file = path.strip_prefix(&first).unwrap();
+        assert_eq!(
+            fs::read(&path).unwrap(),
+            fs::read(second.join(file)).unwrap(),
+            "{} differs",
+            file.display()
+        );
+    }
+
+    fs::remove_dir_all(&first).unwrap();
+    fs::remove_dir_all(&second).unwrap();
+}
+
+#[test]
+fn test_emit_fixtures_deserialize() {
+    #[derive(Debug, ::serde::Deserialize)]
+    struct Fixture {
+        #[serde(with = "crate::serde::money::comma_str_code")]
+        positive: Money<USD>,
+        #[serde(with = "crate::serde::money::comma_str_code")]
+        negative: Money<USD>,
+        #[serde(with = "crate::serde::money::comma_str_code")]
+        no_minor_unit: Money<JPY>,
+        #[serde(with = "crate::serde::money::comma_str_code")]
+        three_minor_units: Money<BHD>,
+    }
+
+    let dir = fixtures_dir("deserialize");
+    emit_fixtures(&dir).unwrap();
+
+    let fixtures: [Fixture; 3] = [
+        serde_json::from_str(&read(&dir, "money/comma_str_code.json")).unwrap(),
+        serde_yaml::from_str(&read(&dir, "money/comma_str_code.yaml")).unwrap(),
+        toml::from_str(&read(&dir, "money/comma_str_code.toml")).unwrap(),
+    ];
+    for fixture in fixtures {
+        assert_eq!(fixture.positive, money!(USD, 1234.56));
+        assert_eq!(fixture.negative, money!(USD, -1234.56));
+        assert_eq!(fixture.no_minor_unit, money!(JPY, 1234));
+        assert_eq!(fixture.three_minor_units, money!(BHD, 1.234));
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "raw_money")]
+#[test]
+fn test_emit_fixtures_deserialize_raw_money() {
+    use crate::RawMoney;
+    use crate::macros::raw;
+
+    #[derive(Debug, ::serde::Deserialize)]
+    struct Fixture {
+        #[serde(with = "crate::serde::raw_money::object")]
+        large: RawMoney<USD>,
+        #[serde(with = "crate::serde::raw_money::object")]
+        three_minor_units: RawMoney<BHD>,
+    }
+
+    let dir = fixtures_dir("deserialize_raw_money");
+    emit_fixtures(&dir).unwrap();
+
+    let fixture: Fixture = serde_json::from_str(&read(&dir, "raw_money/object.json")).unwrap();
+    assert_eq!(fixture.large, raw!(USD, 1234567890123.45));
+    assert_eq!(fixture.three_minor_units, raw!(BHD, 1.234));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,173 @@
+//! settlement contains business-day aware value dating, e.g. T+2 FX spot settlement.
+//!
+//! Business days are days that are neither weekend nor holiday in a [`HolidayCalendar`].
+//! Calendars are pluggable: implement [`HolidayCalendar`], use [`HolidayList`] with fixed dates,
+//! or pass a closure `Fn(NaiveDate) -> bool` returning `true` for holidays.
+
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+/// Maximum number of consecutive non-business days skipped before giving up, around 10 years.
+pub const MAX_SKIPPED_DAYS: u32 = 3660;
+
+/// Calendar deciding which dates are business days.
+pub trait HolidayCalendar {
+    /// Returns `true` if `date` is a holiday.
+    fn is_holiday(&self, date: NaiveDate) -> bool;
+
+    /// Returns `true` if `date` is a weekend, Saturday and Sunday by default.
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+    }
+
+    /// Returns `true` if `date` is neither weekend nor holiday.
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+}
+
+impl<F> HolidayCalendar for F
+where
+    F: Fn(NaiveDate) -> bool,
+{
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self(date)
+    }
+}
+
+/// Calendar without holidays, only weekends are skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WeekendsOnly;
+
+impl HolidayCalendar for WeekendsOnly {
+    fn is_holiday(&self, _date: NaiveDate) -> bool {
+        false
+    }
+}
+
+/// Calendar with a fixed list of holidays on top of weekends.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use moneylib::settlement::{HolidayCalendar, HolidayList};
+///
+/// let new_year = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+/// let calendar = HolidayList::from([new_year]);
+/// assert!(calendar.is_holiday(new_year));
+/// assert!(!calendar.is_business_day(new_year));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HolidayList {
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl HolidayList {
+    /// Creates calendar without holidays.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `date` as holiday, returning `false` if it's already a holiday.
+    pub fn insert(&mut self, date: NaiveDate) -> bool {
+        self.holidays.insert(date)
+    }
+
+    /// Removes `date` from holidays, returning `false` if it's not a holiday.
+    pub fn remove(&mut self, date: NaiveDate) -> bool {
+        self.holidays.remove(&date)
+    }
+
+    /// Returns number of holidays.
+    pub fn len(&self) -> usize {
+        self.holidays.len()
+    }
+
+    /// Returns `true` if there are no holidays.
+    pub fn is_empty(&self) -> bool {
+        self.holidays.is_empty()
+    }
+}
+
+impl HolidayCalendar for HolidayList {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+}
+
+impl<I> From<I> for HolidayList
+where
+    I: IntoIterator<Item = NaiveDate>,
+{
+    fn from(holidays: I) -> Self {
+        Self {
+            holidays: holidays.into_iter().collect(),
+        }
+    }
+}
+
+/// Returns `date` if it's a business day, otherwise the first business day after it.
+///
+/// Returns `None` if no business day is found within [`MAX_SKIPPED_DAYS`] or the date overflows.
+pub fn next_business_day(date: NaiveDate, calendar: &impl HolidayCalendar) -> Option<NaiveDate> {
+    let mut date = date;
+    for _ in 0..=MAX_SKIPPED_DAYS {
+        if calendar.is_business_day(date) {
+            return Some(date);
+        }
+        date = date.succ_opt()?;
+    }
+
+    None
+}
+
+/// Returns the date `days` business days after `date`.
+///
+/// Non-business `date` counts from the next business day, so with zero `days` it's [`next_business_day`].
+///
+/// Returns `None` if a business day is not found within [`MAX_SKIPPED_DAYS`] or the date overflows.
+pub fn add_business_days(
+    date: NaiveDate,
+    days: u32,
+    calendar: &impl HolidayCalendar,
+) -> Option<NaiveDate> {
+    let mut date = next_business_day(date, calendar)?;
+    for _ in 0..days {
+        date = next_business_day(date.succ_opt()?, calendar)?;
+    }
+
+    Some(date)
+}
+
+/// Returns value date of a trade on `trade_date` settling `spot_days` business days later, e.g. 2 for T+2.
+///
+/// Returns `None` if a business day is not found within [`MAX_SKIPPED_DAYS`] or the date overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use moneylib::settlement::{self, HolidayList, WeekendsOnly};
+///
+/// let date = |d| NaiveDate::from_ymd_opt(2024, 12, d).unwrap();
+///
+/// // Thursday T+2 skips the weekend.
+/// assert_eq!(settlement::value_date(date(19), 2, &WeekendsOnly), Some(date(23)));
+///
+/// // Christmas holidays are skipped too.
+/// let calendar = HolidayList::from([date(25), date(26)]);
+/// assert_eq!(settlement::value_date(date(23), 2, &calendar), Some(date(27)));
+///
+/// // Closure as calendar.
+/// let holiday = |d: NaiveDate| d == date(23);
+/// assert_eq!(settlement::value_date(date(20), 1, &holiday), Some(date(24)));
+/// ```
+pub fn value_date(
+    trade_date: NaiveDate,
+    spot_days: u32,
+    calendar: &impl HolidayCalendar,
+) -> Option<NaiveDate> {
+    add_business_days(trade_date, spot_days, calendar)
+}
@@ -0,0 +1,150 @@
+use chrono::NaiveDate;
+
+use crate::settlement::{
+    self, HolidayCalendar, HolidayList, MAX_SKIPPED_DAYS, WeekendsOnly, add_business_days,
+    next_business_day,
+};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_weekends_only() {
+    // 2025-01-03 is Friday.
+    assert!(WeekendsOnly.is_business_day(date(2025, 1, 3)));
+    assert!(!WeekendsOnly.is_business_day(date(2025, 1, 4)));
+    assert!(!WeekendsOnly.is_business_day(date(2025, 1, 5)));
+    assert!(WeekendsOnly.is_business_day(date(2025, 1, 6)));
+    assert!(!WeekendsOnly.is_holiday(date(2025, 1, 1)));
+}
+
+#[test]
+fn test_holiday_list() {
+    let mut calendar = HolidayList::new();
+    assert!(calendar.is_empty());
+    assert!(calendar.insert(date(2025, 1, 1)));
+    assert!(!calendar.insert(date(2025, 1, 1)));
+    assert!(calendar.insert(date(2025, 12, 25)));
+    assert_eq!(calendar.len(), 2);
+    assert!(calendar.is_holiday(date(2025, 12, 25)));
+    assert!(!calendar.is_business_day(date(2025, 1, 1)));
+
+    assert!(calendar.remove(date(2025, 12, 25)));
+    assert!(!calendar.remove(date(2025, 12, 25)));
+    assert!(calendar.is_business_day(date(2025, 12, 25)));
+
+    assert_eq!(
+        HolidayList::from(vec![date(2025, 1, 1)]),
+        HolidayList::from([date(2025, 1, 1)])
+    );
+}
+
+#[test]
+fn test_custom_weekend() {
+    // Friday-Saturday weekend.
+    struct FriSat;
+    impl HolidayCalendar for FriSat {
+        fn is_holiday(&self, _date: NaiveDate) -> bool {
+            false
+        }
+
+        fn is_weekend(&self, date: NaiveDate) -> bool {
+            use chrono::{Datelike, Weekday};
+            matches!(date.weekday(), Weekday::Fri | Weekday::Sat)
+        }
+    }
+
+    // Thursday T+2 -> Sunday, Monday.
+    assert_eq!(
+        settlement::value_date(date(2025, 1, 2), 2, &FriSat),
+        Some(date(2025, 1, 6))
+    );
+    assert_eq!(
+        settlement::value_date(date(2025, 1, 2), 1, &FriSat),
+        Some(date(2025, 1, 5))
+    );
+}
+
+#[test]
+fn test_next_business_day() {
+    let calendar = HolidayList::from([date(2025, 1, 6)]);
+    assert_eq!(
+        next_business_day(date(2025, 1, 3), &calendar),
+        Some(date(2025, 1, 3))
+    );
+    // Saturday -> skip Sunday and Monday holiday.
+    assert_eq!(
+        next_business_day(date(2025, 1, 4), &calendar),
+        Some(date(2025, 1, 7))
+    );
+}
+
+#[test]
+fn test_add_business_days() {
+    assert_eq!(
+        add_business_days(date(2025, 1, 3), 0, &WeekendsOnly),
+        Some(date(2025, 1, 3))
+    );
+    assert_eq!(
+        add_business_days(date(2025, 1, 3), 1, &WeekendsOnly),
+        Some(date(2025, 1, 6))
+    );
+    assert_eq!(
+        add_business_days(date(2025, 1, 3), 5, &WeekendsOnly),
+        Some(date(2025, 1, 10))
+    );
+    // Trade on Saturday counts from Monday.
+    assert_eq!(
+        add_business_days(date(2025, 1, 4), 0, &WeekendsOnly),
+        Some(date(2025, 1, 6))
+    );
+    assert_eq!(
+        add_business_days(date(2025, 1, 4), 2, &WeekendsOnly),
+        Some(date(2025, 1, 8))
+    );
+}
+
+#[test]
+fn test_value_date_t_plus_2() {
+    let calendar = HolidayList::from([date(2024, 12, 25), date(2024, 12, 26), date(2025, 1, 1)]);
+    assert_eq!(
+        settlement::value_date(date(2024, 12, 23), 2, &calendar),
+        Some(date(2024, 12, 27))
+    );
+    assert_eq!(
+        settlement::value_date(date(2024, 12, 27), 2, &calendar),
+        Some(date(2024, 12, 31))
+    );
+    assert_eq!(
+        settlement::value_date(date(2024, 12, 30), 2, &calendar),
+        Some(date(2025, 1, 2))
+    );
+}
+
+#[test]
+fn test_value_date_closure_calendar() {
+    let calendar = |d: NaiveDate| d == date(2025, 1, 6);
+    assert_eq!(
+        settlement::value_date(date(2025, 1, 3), 1, &calendar),
+        Some(date(2025, 1, 7))
+    );
+}
+
+#[test]
+fn test_value_date_none() {
+    // Every day is a holiday.
+    let calendar = |_: NaiveDate| true;
+    assert_eq!(settlement::value_date(date(2025, 1, 3), 0, &calendar), None);
+
+    // Holidays longer than MAX_SKIPPED_DAYS.
+    let start = date(2025, 1, 1);
+    let end = start + chrono::Days::new(u64::from(MAX_SKIPPED_DAYS) + 10);
+    let calendar = move |d: NaiveDate| d >= start && d < end;
+    assert_eq!(settlement::value_date(start, 2, &calendar), None);
+
+    assert_eq!(
+        settlement::value_date(NaiveDate::MAX, 1, &WeekendsOnly),
+        None
+    );
+}
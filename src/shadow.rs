@@ -0,0 +1,136 @@
+//! shadow contains shadow rounding, measuring impact of switching rounding strategy before switching it.
+//!
+//! When enabled with [`enable`], every amount `Money` rounds into its currency's minor unit, i.e. results of
+//! arithmetic and constructed amounts, is also rounded with the alternate strategy. Each time both roundings
+//! differ, the callback is called with the [`Divergence`], e.g. to log it or sum the deltas into metrics.
+//! `Money` itself always keeps the bankers-rounded amount, so enabling it never changes results.
+//!
+//! Shadow rounding is process-wide and disabled by default. While disabled, the cost is one atomic load per rounding.
+//! Roundings made inside the callback are not shadowed.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::{Arc, Mutex};
+//! use moneylib::{RoundingStrategy, macros::dec, money, shadow};
+//!
+//! let deltas = Arc::new(Mutex::new(vec![]));
+//! let recorded = deltas.clone();
+//! shadow::enable(RoundingStrategy::HalfUp, move |divergence| {
+//!     if divergence.code == "JPY" {
+//!         recorded.lock().unwrap().push(divergence.delta());
+//!     }
+//! });
+//!
+//! // 2.5 is rounded into 2 by bankers rounding, and 3 by half-up.
+//! let money = money!(JPY, 5) / dec!(2);
+//! assert_eq!(money, money!(JPY, 2));
+//! shadow::disable();
+//!
+//! assert!(deltas.lock().unwrap().contains(&dec!(1)));
+//! ```
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::{Currency, Decimal, RoundingStrategy};
+
+type Callback = Arc<dyn Fn(&Divergence) + Send + Sync>;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static SHADOW: RwLock<Option<(RoundingStrategy, Callback)>> = RwLock::new(None);
+
+thread_local! {
+    static IN_CALLBACK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Divergence between the amount `Money` rounded into and the amount rounded with the shadow strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Divergence {
+    /// Currency code of the money.
+    pub code: &'static str,
+
+    /// Amount before rounding.
+    pub raw: Decimal,
+
+    /// Amount rounded with bankers rounding, kept by `Money`.
+    pub rounded: Decimal,
+
+    /// Amount rounded with the shadow strategy.
+    pub shadow: Decimal,
+
+    /// The shadow strategy.
+    pub strategy: RoundingStrategy,
+}
+
+impl Divergence {
+    /// Returns `shadow - rounded`, what switching into the shadow strategy would change the amount by.
+    pub fn delta(&self) -> Decimal {
+        self.shadow - self.rounded
+    }
+}
+
+/// Enables shadow rounding with `strategy`, calling `callback` on each divergence. Replaces the previous one if any.
+pub fn enable(strategy: RoundingStrategy, callback: impl Fn(&Divergence) + Send + Sync + 'static) {
+    if let Ok(mut shadow) = SHADOW.write() {
+        *shadow = Some((strategy, Arc::new(callback)));
+        ENABLED.store(true, Ordering::Release);
+    }
+}
+
+/// Disables shadow rounding, dropping its callback.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Release);
+    if let Ok(mut shadow) = SHADOW.write() {
+        *shadow = None;
+    }
+}
+
+/// Returns `true` if shadow rounding is enabled.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+// Resets the flag even if the callback panics.
+struct CallbackGuard;
+
+impl Drop for CallbackGuard {
+    fn drop(&mut self) {
+        IN_CALLBACK.with(|flag| flag.set(false));
+    }
+}
+
+/// Shadows rounding of `raw` into `rounded` in `C`'s minor unit.
+#[inline]
+pub(crate) fn observe<C: Currency>(raw: Decimal, rounded: Decimal) {
+    if !is_enabled() || raw == rounded {
+        return;
+    }
+    shadow::<C>(raw, rounded);
+}
+
+#[cold]
+fn shadow<C: Currency>(raw: Decimal, rounded: Decimal) {
+    if IN_CALLBACK.with(|flag| flag.replace(true)) {
+        return;
+    }
+    let _guard = CallbackGuard;
+
+    // cloned out, so the callback runs without holding the lock.
+    let Some((strategy, callback)) = SHADOW.read().ok().and_then(|shadow| shadow.clone()) else {
+        return;
+    };
+    let shadow = strategy.round_dp(raw, C::MINOR_UNIT.into());
+    if shadow != rounded {
+        callback(&Divergence {
+            code: C::CODE,
+            raw,
+            rounded,
+            shadow,
+            strategy,
+        });
+    }
+}
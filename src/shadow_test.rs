@@ -0,0 +1,103 @@
+use std::sync::{Arc, Mutex};
+
+use crate::macros::dec;
+use crate::shadow::{self, Divergence};
+use crate::{BaseMoney, Currency, Money, RoundingStrategy};
+
+// Test-only currency, so divergences of money made by other tests running concurrently are filtered out.
+#[derive(Debug, Clone, Copy)]
+struct Shd;
+
+impl Currency for Shd {
+    const CODE: &'static str = "SHD";
+    const SYMBOL: &'static str = "S";
+    const NAME: &'static str = "Shadow";
+    const NUMERIC: u16 = 998;
+    const MINOR_UNIT: u16 = 2;
+    const MINOR_UNIT_SYMBOL: &'static str = "s";
+    const MINOR_UNIT_NAME: &'static str = "cent";
+    const THOUSAND_SEPARATOR: &'static str = ",";
+    const DECIMAL_SEPARATOR: &'static str = ".";
+    const ORIGIN: &'static str = "Shadow Land";
+    const LOCALE: &'static str = "en-US";
+}
+
+// Shadow rounding is process-wide, so tests enabling it run one at a time.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn record(strategy: RoundingStrategy) -> Arc<Mutex<Vec<Divergence>>> {
+    let divergences = Arc::new(Mutex::new(vec![]));
+    let recorded = divergences.clone();
+    shadow::enable(strategy, move |divergence| {
+        if divergence.code == Shd::CODE {
+            recorded.lock().unwrap().push(*divergence);
+        }
+    });
+    divergences
+}
+
+#[test]
+fn test_shadow_records_divergences() {
+    let _lock = LOCK.lock().unwrap();
+    let divergences = record(RoundingStrategy::HalfUp);
+    assert!(shadow::is_enabled());
+
+    let money = Money::<Shd>::new(dec!(10)).unwrap();
+    // 10 / 8 = 1.25, no rounding needed.
+    let divided = money / dec!(8);
+    // 10 * 0.0125 = 0.125, 0.12 by bankers rounding, 0.13 by half-up.
+    let multiplied = money.checked_mul(dec!(0.0125)).unwrap();
+    // 1.235, bankers rounding and half-up agree on 1.24.
+    let agreed = Money::<Shd>::new(dec!(1.235)).unwrap();
+    shadow::disable();
+    assert!(!shadow::is_enabled());
+
+    // results keep bankers rounding.
+    assert_eq!(divided.amount(), dec!(1.25));
+    assert_eq!(multiplied.amount(), dec!(0.12));
+    assert_eq!(agreed.amount(), dec!(1.24));
+
+    let divergences = divergences.lock().unwrap();
+    assert_eq!(divergences.len(), 1);
+    let divergence = divergences[0];
+    assert_eq!(divergence.raw, dec!(0.125));
+    assert_eq!(divergence.rounded, dec!(0.12));
+    assert_eq!(divergence.shadow, dec!(0.13));
+    assert_eq!(divergence.strategy, RoundingStrategy::HalfUp);
+    assert_eq!(divergence.delta(), dec!(0.01));
+}
+
+#[test]
+fn test_shadow_floor_and_disabled() {
+    let _lock = LOCK.lock().unwrap();
+    let divergences = record(RoundingStrategy::Floor);
+
+    let _ = Money::<Shd>::new(dec!(-1.006)).unwrap();
+    shadow::disable();
+    let _ = Money::<Shd>::new(dec!(-1.006)).unwrap();
+
+    let divergences = divergences.lock().unwrap();
+    assert_eq!(divergences.len(), 1);
+    assert_eq!(divergences[0].rounded, dec!(-1.01));
+    assert_eq!(divergences[0].shadow, dec!(-1.00));
+    assert_eq!(divergences[0].delta(), dec!(0.01));
+}
+
+#[test]
+fn test_shadow_callback_not_reentered() {
+    let _lock = LOCK.lock().unwrap();
+    let calls = Arc::new(Mutex::new(0));
+    let counted = calls.clone();
+    shadow::enable(RoundingStrategy::Ceil, move |divergence| {
+        if divergence.code == Shd::CODE {
+            *counted.lock().unwrap() += 1;
+            // diverges too, but isn't shadowed inside the callback.
+            let _ = Money::<Shd>::new(dec!(0.001)).unwrap();
+        }
+    });
+
+    let _ = Money::<Shd>::new(dec!(0.001)).unwrap();
+    shadow::disable();
+
+    assert_eq!(*calls.lock().unwrap(), 1);
+}
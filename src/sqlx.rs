@@ -0,0 +1,170 @@
+//! sqlx contains [`Type`], [`Encode`] and [`Decode`] implementations mapping money into decimal columns,
+//! e.g. Postgres `NUMERIC` and MySQL `DECIMAL`.
+//!
+//! Implementations delegate to [`Decimal`]'s, so sqlx's `rust_decimal` feature must be enabled along with
+//! the database driver. Only the amount is stored, the currency comes from the type, e.g. `Money<USD>`.
+//!
+//! Decoding `Money<C>` validates the amount with [`validate_amount`], failing on amounts with more decimal places
+//! than `C`'s minor unit, see [storage invariants](crate#storage). Decoding `RawMoney<C>` keeps the amount as is.
+//!
+//! Currency code column stored next to the amount can be checked with [`validate_currency`] in `FromRow`:
+//!
+//! ```ignore
+//! use moneylib::{Money, iso::USD, sqlx::validate_currency};
+//! use sqlx::{FromRow, Row, postgres::PgRow};
+//!
+//! struct Payment {
+//!     amount: Money<USD>,
+//! }
+//!
+//! impl FromRow<'_, PgRow> for Payment {
+//!     fn from_row(row: &PgRow) -> sqlx::Result<Self> {
+//!         validate_currency::<USD>(row.try_get("currency")?)
+//!             .map_err(|err| sqlx::Error::Decode(err.into()))?;
+//!         Ok(Self { amount: row.try_get("amount")? })
+//!     }
+//! }
+//! ```
+
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Decode, Encode, Type};
+
+use crate::{BaseMoney, Currency, Decimal, Money, MoneyError};
+
+/// Validates that `amount` decoded from database fits `C`'s minor unit, returning it as `Money<C>`.
+///
+/// Trailing zeros are ignored, e.g. `NUMERIC(19, 4)` value `1.2300` is valid for USD.
+///
+/// # Errors
+///
+/// Returns [`MoneyError::MinorUnitError`] if `amount` has more decimal places than `C`'s minor unit.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{BaseMoney, macros::dec, iso::{JPY, USD}, sqlx::validate_amount};
+///
+/// assert_eq!(validate_amount::<USD>(dec!(1.2300)).unwrap().amount(), dec!(1.23));
+/// assert!(validate_amount::<USD>(dec!(1.234)).is_err());
+/// assert!(validate_amount::<JPY>(dec!(1.5)).is_err());
+/// ```
+pub fn validate_amount<C: Currency>(amount: Decimal) -> Result<Money<C>, MoneyError> {
+    crate::currency::check_minor_unit::<C>(amount)?;
+    Ok(Money::from_decimal(amount))
+}
+
+/// Validates that currency `code` read from database is `C`'s code.
+///
+/// # Errors
+///
+/// Returns [`MoneyError::CurrencyMismatchError`] if `code` is not `C`'s code.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{iso::USD, sqlx::validate_currency};
+///
+/// assert!(validate_currency::<USD>("USD").is_ok());
+/// assert!(validate_currency::<USD>("EUR").is_err());
+/// ```
+pub fn validate_currency<C: Currency>(code: &str) -> Result<(), MoneyError> {
+    if code != C::CODE {
+        return Err(MoneyError::CurrencyMismatchError(
+            code.to_string(),
+            C::CODE.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl<C, DB> Type<DB> for Money<C>
+where
+    C: Currency,
+    DB: Database,
+    Decimal: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Decimal as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Decimal as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, C, DB> Encode<'q, DB> for Money<C>
+where
+    C: Currency,
+    DB: Database,
+    Decimal: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.amount().encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.amount().size_hint()
+    }
+}
+
+impl<'r, C, DB> Decode<'r, DB> for Money<C>
+where
+    C: Currency,
+    DB: Database,
+    Decimal: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(validate_amount(Decimal::decode(value)?)?)
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C, DB> Type<DB> for crate::RawMoney<C>
+where
+    C: Currency,
+    DB: Database,
+    Decimal: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Decimal as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Decimal as Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<'q, C, DB> Encode<'q, DB> for crate::RawMoney<C>
+where
+    C: Currency,
+    DB: Database,
+    Decimal: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.amount().encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.amount().size_hint()
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<'r, C, DB> Decode<'r, DB> for crate::RawMoney<C>
+where
+    C: Currency,
+    DB: Database,
+    Decimal: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(Self::from_decimal(Decimal::decode(value)?))
+    }
+}
@@ -0,0 +1,109 @@
+use ::sqlx::postgres::{PgArgumentBuffer, PgTypeInfo};
+use ::sqlx::{Encode, MySql, Postgres, Type, TypeInfo};
+
+use crate::iso::{BHD, JPY, USD};
+use crate::sqlx::{validate_amount, validate_currency};
+use crate::{BaseMoney, Decimal, Money, MoneyError, macros::dec};
+
+#[test]
+fn test_type_info() {
+    assert_eq!(
+        <Money<USD> as Type<Postgres>>::type_info(),
+        <Decimal as Type<Postgres>>::type_info()
+    );
+    assert!(<Money<USD> as Type<Postgres>>::compatible(
+        &PgTypeInfo::with_name("NUMERIC")
+    ));
+    assert_eq!(<Money<USD> as Type<MySql>>::type_info().name(), "DECIMAL");
+}
+
+#[test]
+fn test_encode_postgres() {
+    let money = Money::<USD>::from_decimal(dec!(1234.56));
+
+    let mut buf = PgArgumentBuffer::default();
+    let _ = Encode::<Postgres>::encode_by_ref(&money, &mut buf).unwrap();
+
+    let mut expected = PgArgumentBuffer::default();
+    let _ = Encode::<Postgres>::encode_by_ref(&dec!(1234.56), &mut expected).unwrap();
+
+    assert!(!buf.is_empty());
+    assert_eq!(buf.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn test_encode_mysql() {
+    let money = Money::<JPY>::from_decimal(dec!(-1234));
+
+    let mut buf = Vec::new();
+    let _ = Encode::<MySql>::encode_by_ref(&money, &mut buf).unwrap();
+
+    let mut expected = Vec::new();
+    let _ = Encode::<MySql>::encode_by_ref(&dec!(-1234), &mut expected).unwrap();
+
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn test_validate_amount() {
+    assert_eq!(
+        validate_amount::<USD>(dec!(1234.56)).unwrap().amount(),
+        dec!(1234.56)
+    );
+    assert_eq!(
+        validate_amount::<USD>(dec!(1.2300)).unwrap().amount(),
+        dec!(1.23)
+    );
+    assert_eq!(validate_amount::<USD>(dec!(-7)).unwrap().amount(), dec!(-7));
+    assert_eq!(
+        validate_amount::<JPY>(dec!(100.000)).unwrap().amount(),
+        dec!(100)
+    );
+    assert_eq!(
+        validate_amount::<BHD>(dec!(1.234)).unwrap().amount(),
+        dec!(1.234)
+    );
+    assert!(validate_amount::<USD>(Decimal::ZERO).is_ok());
+}
+
+#[test]
+fn test_validate_amount_too_precise() {
+    let err = validate_amount::<USD>(dec!(1.234)).unwrap_err();
+    assert!(matches!(err, MoneyError::MinorUnitError(_)));
+    assert_eq!(
+        err.to_string(),
+        "[MONEYLIB] minor unit error: amount 1.234 has more decimal places than USD's minor unit of 2"
+    );
+
+    assert!(validate_amount::<JPY>(dec!(1.5)).is_err());
+    assert!(validate_amount::<BHD>(dec!(0.0001)).is_err());
+}
+
+#[test]
+fn test_validate_currency() {
+    assert!(validate_currency::<USD>("USD").is_ok());
+    assert!(matches!(
+        validate_currency::<USD>("usd"),
+        Err(MoneyError::CurrencyMismatchError(got, expected)) if got == "usd" && expected == "USD"
+    ));
+    assert!(validate_currency::<JPY>("").is_err());
+}
+
+#[cfg(feature = "raw_money")]
+#[test]
+fn test_raw_money() {
+    use crate::RawMoney;
+
+    assert_eq!(
+        <RawMoney<USD> as Type<Postgres>>::type_info(),
+        <Decimal as Type<Postgres>>::type_info()
+    );
+
+    let raw = RawMoney::<USD>::from_decimal(dec!(1.23456));
+    let mut buf = PgArgumentBuffer::default();
+    let _ = Encode::<Postgres>::encode_by_ref(&raw, &mut buf).unwrap();
+
+    let mut expected = PgArgumentBuffer::default();
+    let _ = Encode::<Postgres>::encode_by_ref(&dec!(1.23456), &mut expected).unwrap();
+    assert_eq!(buf.as_slice(), expected.as_slice());
+}
@@ -0,0 +1,226 @@
+//! stats contains descriptive statistics over slices of [`Money`].
+//!
+//! All computations are done in [`Decimal`], no floating point involved.
+//! Functions returning money take explicit [`RoundingStrategy`] used to round the result into currency's minor unit.
+//! All functions return `None` if the slice is empty or the computation overflows.
+
+use rust_decimal::MathematicalOps;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+
+use crate::{BaseMoney, Currency, Decimal, Money, RoundingStrategy, macros::dec};
+
+/// Returns arithmetic mean of `moneys` rounded into currency's minor unit using `strategy`.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{Money, BaseMoney, RoundingStrategy, money, stats, iso::USD};
+/// use moneylib::macros::dec;
+///
+/// let moneys = [money!(USD, 10), money!(USD, 10), money!(USD, 10.01)];
+/// let mean = stats::mean(&moneys, RoundingStrategy::Ceil).unwrap();
+/// assert_eq!(mean.amount(), dec!(10.01));
+///
+/// let mean = stats::mean(&moneys, RoundingStrategy::Floor).unwrap();
+/// assert_eq!(mean.amount(), dec!(10.00));
+///
+/// assert!(stats::mean::<USD>(&[], RoundingStrategy::HalfUp).is_none());
+/// ```
+pub fn mean<C: Currency>(moneys: &[Money<C>], strategy: RoundingStrategy) -> Option<Money<C>> {
+    Some(round(mean_decimal(moneys)?, strategy))
+}
+
+/// Returns median of `moneys` rounded into currency's minor unit using `strategy`.
+///
+/// For even number of elements, median is the mean of the two middle elements.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{Money, BaseMoney, RoundingStrategy, money, stats, iso::USD};
+/// use moneylib::macros::dec;
+///
+/// let moneys = [money!(USD, 3), money!(USD, 1), money!(USD, 2)];
+/// let median = stats::median(&moneys, RoundingStrategy::HalfUp).unwrap();
+/// assert_eq!(median.amount(), dec!(2));
+///
+/// let moneys = [money!(USD, 0.01), money!(USD, 0.02)];
+/// let median = stats::median(&moneys, RoundingStrategy::HalfUp).unwrap();
+/// assert_eq!(median.amount(), dec!(0.02));
+/// ```
+pub fn median<C: Currency>(moneys: &[Money<C>], strategy: RoundingStrategy) -> Option<Money<C>> {
+    percentile(moneys, dec!(50), strategy)
+}
+
+/// Returns population variance of `moneys`.
+///
+/// The result is in squared currency unit, hence it is returned as unrounded [`Decimal`].
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{Money, RoundingStrategy, money, stats, iso::USD};
+/// use moneylib::macros::dec;
+///
+/// let moneys = [
+///     money!(USD, 2), money!(USD, 4), money!(USD, 4), money!(USD, 4),
+///     money!(USD, 5), money!(USD, 5), money!(USD, 7), money!(USD, 9),
+/// ];
+/// assert_eq!(stats::variance(&moneys).unwrap(), dec!(4));
+/// ```
+pub fn variance<C: Currency>(moneys: &[Money<C>]) -> Option<Decimal> {
+    let mean = mean_decimal(moneys)?;
+    let sum_sq = moneys.iter().try_fold(Decimal::ZERO, |acc, m| {
+        let diff = m.amount().checked_sub(mean)?;
+        acc.checked_add(diff.checked_mul(diff)?)
+    })?;
+    sum_sq.checked_div(Decimal::from_usize(moneys.len())?)
+}
+
+/// Returns population standard deviation of `moneys` rounded into currency's minor unit using `strategy`.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{Money, BaseMoney, RoundingStrategy, money, stats, iso::USD};
+/// use moneylib::macros::dec;
+///
+/// let moneys = [
+///     money!(USD, 2), money!(USD, 4), money!(USD, 4), money!(USD, 4),
+///     money!(USD, 5), money!(USD, 5), money!(USD, 7), money!(USD, 9),
+/// ];
+/// let std_dev = stats::std_dev(&moneys, RoundingStrategy::HalfUp).unwrap();
+/// assert_eq!(std_dev.amount(), dec!(2));
+/// ```
+pub fn std_dev<C: Currency>(moneys: &[Money<C>], strategy: RoundingStrategy) -> Option<Money<C>> {
+    Some(round(variance(moneys)?.sqrt()?, strategy))
+}
+
+/// Returns `p`-th percentile of `moneys` rounded into currency's minor unit using `strategy`.
+///
+/// `p` must be within `0..=100`, otherwise `None` is returned.
+/// Values between two ranks are linearly interpolated.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{Money, BaseMoney, RoundingStrategy, money, stats, iso::USD};
+/// use moneylib::macros::dec;
+///
+/// let moneys = [money!(USD, 40), money!(USD, 10), money!(USD, 30), money!(USD, 20)];
+/// let p = stats::percentile(&moneys, dec!(0), RoundingStrategy::HalfUp).unwrap();
+/// assert_eq!(p.amount(), dec!(10));
+///
+/// let p = stats::percentile(&moneys, dec!(90), RoundingStrategy::HalfUp).unwrap();
+/// assert_eq!(p.amount(), dec!(37));
+///
+/// let p = stats::percentile(&moneys, dec!(100), RoundingStrategy::HalfUp).unwrap();
+/// assert_eq!(p.amount(), dec!(40));
+///
+/// assert!(stats::percentile(&moneys, dec!(101), RoundingStrategy::HalfUp).is_none());
+/// ```
+pub fn percentile<C: Currency>(
+    moneys: &[Money<C>],
+    p: Decimal,
+    strategy: RoundingStrategy,
+) -> Option<Money<C>> {
+    if moneys.is_empty() || p < Decimal::ZERO || p > dec!(100) {
+        return None;
+    }
+
+    let mut amounts: Vec<Decimal> = moneys.iter().map(|m| m.amount()).collect();
+    amounts.sort();
+
+    let last = Decimal::from_usize(amounts.len().checked_sub(1)?)?;
+    let rank = p.checked_div(dec!(100))?.checked_mul(last)?;
+    let lower = rank.floor();
+    let lower_value = *amounts.get(lower.to_usize()?)?;
+    let upper_value = *amounts.get(rank.ceil().to_usize()?)?;
+
+    let weight = rank.checked_sub(lower)?;
+    let value = upper_value
+        .checked_sub(lower_value)?
+        .checked_mul(weight)?
+        .checked_add(lower_value)?;
+
+    Some(round(value, strategy))
+}
+
+// Decimal places of percentages returned by `breakdown`.
+const BREAKDOWN_SCALE: u32 = 2;
+
+/// Returns each part's share of the sum of `parts` as percentages with 2 decimal places, summing to exactly 100.00.
+///
+/// Percentages are floored, then the missing hundredths go to the parts with the largest floored-off remainders,
+/// earlier parts first on ties, so pie charts don't show 99.99% or 100.01%.
+///
+/// Returns `None` if `parts` is empty, any part is negative, the sum is zero, or the computation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{money, stats, macros::dec};
+///
+/// let parts = [money!(USD, 1), money!(USD, 1), money!(USD, 1)];
+/// assert_eq!(stats::breakdown(&parts).unwrap(), [dec!(33.34), dec!(33.33), dec!(33.33)]);
+///
+/// let parts = [money!(USD, 10), money!(USD, 30), money!(USD, 0)];
+/// assert_eq!(stats::breakdown(&parts).unwrap(), [dec!(25.00), dec!(75.00), dec!(0.00)]);
+/// ```
+pub fn breakdown<C: Currency>(parts: &[Money<C>]) -> Option<Vec<Decimal>> {
+    if parts
+        .iter()
+        .any(|part| part.amount().is_sign_negative() && !part.amount().is_zero())
+    {
+        return None;
+    }
+    let total = parts
+        .iter()
+        .try_fold(Decimal::ZERO, |acc, m| acc.checked_add(m.amount()))?;
+    if total.is_zero() {
+        return None;
+    }
+
+    let mut percentages = Vec::with_capacity(parts.len());
+    let mut remainders = Vec::with_capacity(parts.len());
+    for part in parts {
+        let share = part.amount().checked_mul(dec!(100))?.checked_div(total)?;
+        let mut floored =
+            share.round_dp_with_strategy(BREAKDOWN_SCALE, rust_decimal::RoundingStrategy::ToZero);
+        floored.rescale(BREAKDOWN_SCALE);
+        remainders.push(share.checked_sub(floored)?);
+        percentages.push(floored);
+    }
+
+    let floored_total = percentages
+        .iter()
+        .try_fold(Decimal::ZERO, |acc, pcn| acc.checked_add(*pcn))?;
+    let hundredth = Decimal::new(1, BREAKDOWN_SCALE);
+    let missing = dec!(100)
+        .checked_sub(floored_total)?
+        .checked_div(hundredth)?
+        .to_usize()?;
+
+    // stable, so earlier parts come first on ties.
+    let mut order: Vec<usize> = (0..parts.len()).collect();
+    order.sort_by(|&a, &b| remainders[b].cmp(&remainders[a]));
+    for &i in order.iter().take(missing) {
+        percentages[i] = percentages[i].checked_add(hundredth)?;
+    }
+
+    Some(percentages)
+}
+
+fn mean_decimal<C: Currency>(moneys: &[Money<C>]) -> Option<Decimal> {
+    if moneys.is_empty() {
+        return None;
+    }
+    let sum = moneys
+        .iter()
+        .try_fold(Decimal::ZERO, |acc, m| acc.checked_add(m.amount()))?;
+    sum.checked_div(Decimal::from_usize(moneys.len())?)
+}
+
+fn round<C: Currency>(amount: Decimal, strategy: RoundingStrategy) -> Money<C> {
+    Money::from_decimal(strategy.round_dp(amount, C::MINOR_UNIT.into()))
+}
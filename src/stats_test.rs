@@ -0,0 +1,182 @@
+use crate::iso::USD;
+use crate::macros::{dec, money};
+use crate::{BaseMoney, Money, RoundingStrategy, stats};
+
+#[test]
+fn test_empty() {
+    let moneys: [Money<USD>; 0] = [];
+    assert!(stats::mean(&moneys, RoundingStrategy::HalfUp).is_none());
+    assert!(stats::median(&moneys, RoundingStrategy::HalfUp).is_none());
+    assert!(stats::variance(&moneys).is_none());
+    assert!(stats::std_dev(&moneys, RoundingStrategy::HalfUp).is_none());
+    assert!(stats::percentile(&moneys, dec!(50), RoundingStrategy::HalfUp).is_none());
+}
+
+#[test]
+fn test_mean() {
+    let moneys = [money!(USD, 1), money!(USD, 2), money!(USD, 2)];
+    // 5 / 3 = 1.6666...
+    let mean = stats::mean(&moneys, RoundingStrategy::HalfUp).unwrap();
+    assert_eq!(mean.amount(), dec!(1.67));
+    let mean = stats::mean(&moneys, RoundingStrategy::Floor).unwrap();
+    assert_eq!(mean.amount(), dec!(1.66));
+
+    let moneys = [money!(USD, -1), money!(USD, -2), money!(USD, -2)];
+    let mean = stats::mean(&moneys, RoundingStrategy::Floor).unwrap();
+    assert_eq!(mean.amount(), dec!(-1.66));
+    let mean = stats::mean(&moneys, RoundingStrategy::Ceil).unwrap();
+    assert_eq!(mean.amount(), dec!(-1.67));
+
+    let moneys = [money!(JPY, 1), money!(JPY, 2)];
+    let mean = stats::mean(&moneys, RoundingStrategy::BankersRounding).unwrap();
+    assert_eq!(mean.amount(), dec!(2));
+    let mean = stats::mean(&moneys, RoundingStrategy::HalfDown).unwrap();
+    assert_eq!(mean.amount(), dec!(1));
+
+    let moneys = [money!(BHD, 1), money!(BHD, 2), money!(BHD, 2)];
+    let mean = stats::mean(&moneys, RoundingStrategy::HalfUp).unwrap();
+    assert_eq!(mean.amount(), dec!(1.667));
+}
+
+#[test]
+fn test_mean_overflow() {
+    let moneys = [
+        Money::<USD>::from_decimal(crate::Decimal::MAX),
+        Money::<USD>::from_decimal(crate::Decimal::MAX),
+    ];
+    assert!(stats::mean(&moneys, RoundingStrategy::HalfUp).is_none());
+    assert!(stats::variance(&moneys).is_none());
+}
+
+#[test]
+fn test_median() {
+    let moneys = [money!(USD, 5), money!(USD, 1), money!(USD, 3)];
+    let median = stats::median(&moneys, RoundingStrategy::HalfUp).unwrap();
+    assert_eq!(median.amount(), dec!(3));
+
+    let moneys = [
+        money!(USD, 4),
+        money!(USD, 1),
+        money!(USD, 3),
+        money!(USD, 2),
+    ];
+    let median = stats::median(&moneys, RoundingStrategy::HalfUp).unwrap();
+    assert_eq!(median.amount(), dec!(2.5));
+
+    let moneys = [money!(USD, 0.01), money!(USD, 0.04)];
+    let median = stats::median(&moneys, RoundingStrategy::HalfUp).unwrap();
+    assert_eq!(median.amount(), dec!(0.03));
+    let median = stats::median(&moneys, RoundingStrategy::HalfDown).unwrap();
+    assert_eq!(median.amount(), dec!(0.02));
+
+    let moneys = [money!(USD, 7)];
+    let median = stats::median(&moneys, RoundingStrategy::HalfUp).unwrap();
+    assert_eq!(median.amount(), dec!(7));
+}
+
+#[test]
+fn test_variance_and_std_dev() {
+    let moneys = [money!(USD, 10), money!(USD, 10), money!(USD, 10)];
+    assert_eq!(stats::variance(&moneys).unwrap(), dec!(0));
+    let std_dev = stats::std_dev(&moneys, RoundingStrategy::HalfUp).unwrap();
+    assert!(std_dev.is_zero());
+
+    let moneys = [
+        money!(USD, 1),
+        money!(USD, 2),
+        money!(USD, 3),
+        money!(USD, 4),
+    ];
+    // mean 2.5, squared diffs: 2.25 + 0.25 + 0.25 + 2.25 = 5, / 4
+    assert_eq!(stats::variance(&moneys).unwrap(), dec!(1.25));
+    // sqrt(1.25) = 1.1180339...
+    let std_dev = stats::std_dev(&moneys, RoundingStrategy::HalfUp).unwrap();
+    assert_eq!(std_dev.amount(), dec!(1.12));
+    let std_dev = stats::std_dev(&moneys, RoundingStrategy::Floor).unwrap();
+    assert_eq!(std_dev.amount(), dec!(1.11));
+}
+
+#[test]
+fn test_percentile() {
+    let moneys = [
+        money!(USD, 15),
+        money!(USD, 20),
+        money!(USD, 35),
+        money!(USD, 40),
+        money!(USD, 50),
+    ];
+    let p = |p| {
+        stats::percentile(&moneys, p, RoundingStrategy::HalfUp)
+            .unwrap()
+            .amount()
+    };
+    assert_eq!(p(dec!(0)), dec!(15));
+    assert_eq!(p(dec!(25)), dec!(20));
+    assert_eq!(p(dec!(40)), dec!(29));
+    assert_eq!(p(dec!(50)), dec!(35));
+    assert_eq!(p(dec!(100)), dec!(50));
+    assert_eq!(p(dec!(33.3)), dec!(24.98));
+
+    assert!(stats::percentile(&moneys, dec!(-0.1), RoundingStrategy::HalfUp).is_none());
+    assert!(stats::percentile(&moneys, dec!(100.1), RoundingStrategy::HalfUp).is_none());
+
+    let single = [money!(USD, 9.99)];
+    for q in [dec!(0), dec!(37.5), dec!(100)] {
+        let p = stats::percentile(&single, q, RoundingStrategy::HalfUp).unwrap();
+        assert_eq!(p.amount(), dec!(9.99));
+    }
+}
+
+#[test]
+fn test_breakdown() {
+    let parts = [money!(USD, 1), money!(USD, 2), money!(USD, 3)];
+    // 16.666.., 33.333.., 50
+    assert_eq!(
+        stats::breakdown(&parts).unwrap(),
+        [dec!(16.67), dec!(33.33), dec!(50.00)]
+    );
+
+    let parts = [money!(USD, 100)];
+    assert_eq!(stats::breakdown(&parts).unwrap(), [dec!(100.00)]);
+
+    // 14.2857.. each, 4 hundredths missing go to the earliest on ties.
+    let parts = [money!(USD, 1); 7];
+    let pcns = stats::breakdown(&parts).unwrap();
+    assert!(pcns[..4].iter().all(|p| *p == dec!(14.29)));
+    assert!(pcns[4..].iter().all(|p| *p == dec!(14.28)));
+    assert_eq!(pcns.iter().sum::<crate::Decimal>(), dec!(100));
+}
+
+#[test]
+fn test_breakdown_sums_to_hundred() {
+    let parts = [
+        money!(USD, 0.01),
+        money!(USD, 123.45),
+        money!(USD, 999999.99),
+        money!(USD, 7.77),
+        money!(USD, 0),
+        money!(USD, 42.42),
+    ];
+    let pcns = stats::breakdown(&parts).unwrap();
+    assert_eq!(pcns.len(), parts.len());
+    assert_eq!(pcns.iter().sum::<crate::Decimal>(), dec!(100.00));
+    assert!(pcns.iter().all(|p| p.scale() == 2));
+    assert_eq!(pcns[4], dec!(0));
+
+    // largest remainder gets the extra hundredth: 1/3 -> 33.333.., 2/3 -> 66.666..
+    let parts = [money!(USD, 1), money!(USD, 2)];
+    assert_eq!(
+        stats::breakdown(&parts).unwrap(),
+        [dec!(33.33), dec!(66.67)]
+    );
+}
+
+#[test]
+fn test_breakdown_invalid() {
+    let empty: [Money<USD>; 0] = [];
+    assert!(stats::breakdown(&empty).is_none());
+    assert!(stats::breakdown(&[money!(USD, 0), money!(USD, 0)]).is_none());
+    assert!(stats::breakdown(&[money!(USD, 10), money!(USD, -5)]).is_none());
+    let max = Money::<crate::iso::JPY>::max_representable();
+    assert!(stats::breakdown(&[max, max]).is_none());
+}
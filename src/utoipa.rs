@@ -0,0 +1,45 @@
+//! utoipa contains `ToSchema` implementations of Money and RawMoney, described as numbers.
+//!
+//! `ToSchema` derive requires generic arguments to be schemas too, which ISO currencies are not,
+//! so fields are declared through type aliases, e.g. `type Usd = Money<USD>;`, or `#[schema(value_type = Usd)]`.
+
+use std::borrow::Cow;
+
+use ::utoipa::openapi::RefOr;
+use ::utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use ::utoipa::{PartialSchema, ToSchema};
+
+use crate::{Currency, Money};
+
+fn number_schema<C: Currency>(kind: &str) -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .schema_type(Type::Number)
+        .description(Some(format!("{} amount of {}", kind, C::CODE)))
+        .into()
+}
+
+impl<C: Currency> PartialSchema for Money<C> {
+    fn schema() -> RefOr<Schema> {
+        number_schema::<C>("Money")
+    }
+}
+
+impl<C: Currency> ToSchema for Money<C> {
+    fn name() -> Cow<'static, str> {
+        format!("Money_{}", C::CODE).into()
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> PartialSchema for crate::RawMoney<C> {
+    fn schema() -> RefOr<Schema> {
+        number_schema::<C>("RawMoney")
+    }
+}
+
+#[cfg(feature = "raw_money")]
+impl<C: Currency> ToSchema for crate::RawMoney<C> {
+    fn name() -> Cow<'static, str> {
+        format!("RawMoney_{}", C::CODE).into()
+    }
+}
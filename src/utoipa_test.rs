@@ -0,0 +1,68 @@
+use serde_json::json;
+use utoipa::openapi::{RefOr, schema::Schema};
+use utoipa::{OpenApi, PartialSchema, ToSchema};
+
+use crate::iso::{EUR, JPY, USD};
+use crate::{Money, RawMoney};
+
+fn to_json(schema: RefOr<Schema>) -> serde_json::Value {
+    serde_json::to_value(schema).unwrap()
+}
+
+#[test]
+fn test_money_schema() {
+    assert_eq!(Money::<USD>::name(), "Money_USD");
+    assert_eq!(Money::<EUR>::name(), "Money_EUR");
+    assert_eq!(
+        to_json(Money::<USD>::schema()),
+        json!({ "type": "number", "description": "Money amount of USD" })
+    );
+}
+
+#[test]
+fn test_raw_money_schema() {
+    assert_eq!(RawMoney::<JPY>::name(), "RawMoney_JPY");
+    assert_eq!(
+        to_json(RawMoney::<JPY>::schema()),
+        json!({ "type": "number", "description": "RawMoney amount of JPY" })
+    );
+}
+
+type UsdMoney = Money<USD>;
+type EurRawMoney = RawMoney<EUR>;
+
+#[derive(::serde::Serialize, ToSchema)]
+#[allow(dead_code)]
+struct Transfer {
+    amount: UsdMoney,
+    fee: Option<UsdMoney>,
+    rate: EurRawMoney,
+    #[schema(value_type = UsdMoney)]
+    limit: Money<USD>,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(Transfer, UsdMoney)))]
+struct ApiDoc;
+
+#[test]
+fn test_openapi_components() {
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = &doc["components"]["schemas"];
+    let transfer = &schemas["Transfer"];
+    assert_eq!(
+        transfer["properties"]["amount"]["$ref"],
+        "#/components/schemas/Money_USD"
+    );
+    assert_eq!(
+        transfer["properties"]["rate"]["$ref"],
+        "#/components/schemas/RawMoney_EUR"
+    );
+    assert_eq!(
+        transfer["properties"]["limit"]["$ref"],
+        "#/components/schemas/Money_USD"
+    );
+    assert_eq!(transfer["required"], json!(["amount", "rate", "limit"]));
+    assert_eq!(schemas["Money_USD"]["type"], "number");
+    assert_eq!(schemas["RawMoney_EUR"]["type"], "number");
+}
@@ -0,0 +1,154 @@
+//! validator contains helpers for validating money fields with [validator](https://docs.rs/validator) crate.
+//!
+//! Helpers return [`ValidationError`] with formatted money in its message and params,
+//! so they can be used directly or wrapped inside functions passed to `#[validate(custom(function = ...))]`.
+//!
+//! # Examples
+//!
+//! ```
+//! use moneylib::{Money, BaseMoney, money, iso::USD, validator::{validate_positive, validate_range}};
+//! use validator::{Validate, ValidationError};
+//!
+//! fn validate_transfer_limit(amount: &Money<USD>) -> Result<(), ValidationError> {
+//!     validate_range(amount, money!(USD, 1), money!(USD, 10_000))
+//! }
+//!
+//! #[derive(Validate)]
+//! struct Transfer {
+//!     #[validate(custom(function = "validate_positive"))]
+//!     fee: Money<USD>,
+//!
+//!     #[validate(custom(function = "validate_transfer_limit"))]
+//!     amount: Money<USD>,
+//! }
+//!
+//! let transfer = Transfer { fee: money!(USD, 0.5), amount: money!(USD, 250) };
+//! assert!(transfer.validate().is_ok());
+//!
+//! let transfer = Transfer { fee: money!(USD, 0), amount: money!(USD, 25_000) };
+//! let errors = transfer.validate().unwrap_err();
+//! assert!(errors.field_errors().contains_key("fee"));
+//! assert!(errors.field_errors().contains_key("amount"));
+//! ```
+
+use std::borrow::Cow;
+
+use ::validator::ValidationError;
+
+use crate::{BaseMoney, Currency, Money, MoneyError, MoneyParser};
+
+/// Error code of [`validate_range`].
+pub const RANGE_CODE: &str = "money_range";
+
+/// Error code of [`validate_positive`].
+pub const POSITIVE_CODE: &str = "money_positive";
+
+/// Error code of [`validate_currency`].
+pub const CURRENCY_CODE: &str = "money_currency";
+
+/// Validates that `money` is within `min..=max`.
+///
+/// On failure, error has code [`RANGE_CODE`], params `value`, `min`, `max` formatted with currency code,
+/// and message like `"USD 0.50 is not within USD 1.00 and USD 100.00"`.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{money, validator::validate_range};
+///
+/// assert!(validate_range(&money!(USD, 50), money!(USD, 1), money!(USD, 100)).is_ok());
+/// assert!(validate_range(&money!(USD, 100), money!(USD, 1), money!(USD, 100)).is_ok());
+///
+/// let err = validate_range(&money!(USD, 0.5), money!(USD, 1), money!(USD, 100)).unwrap_err();
+/// assert_eq!(err.code, "money_range");
+/// assert_eq!(err.message.unwrap(), "USD 0.50 is not within USD 1.00 and USD 100.00");
+/// ```
+pub fn validate_range<M, C>(money: &M, min: M, max: M) -> Result<(), ValidationError>
+where
+    M: BaseMoney<C>,
+    C: Currency,
+{
+    if money.amount() >= min.amount() && money.amount() <= max.amount() {
+        return Ok(());
+    }
+
+    let value = money.format_code();
+    let min = min.format_code();
+    let max = max.format_code();
+    let message = format!("{} is not within {} and {}", value, min, max);
+
+    let mut err = ValidationError::new(RANGE_CODE).with_message(Cow::Owned(message));
+    err.add_param(Cow::Borrowed("value"), &value);
+    err.add_param(Cow::Borrowed("min"), &min);
+    err.add_param(Cow::Borrowed("max"), &max);
+    Err(err)
+}
+
+/// Validates that `money` is greater than zero.
+///
+/// On failure, error has code [`POSITIVE_CODE`], param `value` formatted with currency code,
+/// and message like `"USD -1.00 is not positive"`.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{money, validator::validate_positive};
+///
+/// assert!(validate_positive(&money!(USD, 0.01)).is_ok());
+///
+/// let err = validate_positive(&money!(USD, 0)).unwrap_err();
+/// assert_eq!(err.code, "money_positive");
+/// assert_eq!(err.message.unwrap(), "USD 0.00 is not positive");
+/// ```
+pub fn validate_positive<M, C>(money: &M) -> Result<(), ValidationError>
+where
+    M: BaseMoney<C>,
+    C: Currency,
+{
+    if money.is_positive() {
+        return Ok(());
+    }
+
+    let value = money.format_code();
+    let message = format!("{} is not positive", value);
+
+    let mut err = ValidationError::new(POSITIVE_CODE).with_message(Cow::Owned(message));
+    err.add_param(Cow::Borrowed("value"), &value);
+    Err(err)
+}
+
+/// Validates that `money_str` is money in currency `C` in `"<CODE> <AMOUNT>"` format, e.g. `"USD 1,234.56"`.
+///
+/// Useful for string fields received from forms before parsing them into [`Money`].
+///
+/// On failure, error has code [`CURRENCY_CODE`], params `value` and `expected`,
+/// and message describing the mismatch or parsing failure.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{iso::{EUR, USD}, validator::validate_currency};
+///
+/// assert!(validate_currency::<USD>("USD 1,234.56").is_ok());
+/// assert!(validate_currency::<EUR>("EUR 1.234,56").is_ok());
+///
+/// let err = validate_currency::<USD>("EUR 1.234,56").unwrap_err();
+/// assert_eq!(err.code, "money_currency");
+/// assert_eq!(err.message.unwrap(), "expected currency USD, got EUR");
+///
+/// assert!(validate_currency::<USD>("USD abc").is_err());
+/// ```
+pub fn validate_currency<C: Currency>(money_str: &str) -> Result<(), ValidationError> {
+    let message = match Money::<C>::from_str_code(money_str) {
+        Ok(_) => return Ok(()),
+        Err(MoneyError::CurrencyMismatchError(got, expected)) => {
+            format!("expected currency {}, got {}", expected, got)
+        }
+        Err(_) => format!("{} is not valid {} money", money_str.trim(), C::CODE),
+    };
+
+    let mut err = ValidationError::new(CURRENCY_CODE).with_message(Cow::Owned(message));
+    err.add_param(Cow::Borrowed("value"), &money_str);
+    err.add_param(Cow::Borrowed("expected"), &C::CODE);
+    Err(err)
+}
@@ -0,0 +1,127 @@
+use std::borrow::Cow;
+
+use ::validator::{Validate, ValidationError};
+
+use crate::iso::{BHD, USD};
+use crate::macros::{dec, money, raw};
+use crate::validator::{
+    CURRENCY_CODE, POSITIVE_CODE, RANGE_CODE, validate_currency, validate_positive, validate_range,
+};
+use crate::{BaseMoney, Money, RawMoney};
+
+fn param(err: &ValidationError, name: &'static str) -> String {
+    err.params[&Cow::Borrowed(name)]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_validate_range() {
+    let min = money!(USD, -10);
+    let max = money!(USD, 1_000);
+    assert!(validate_range(&money!(USD, -10), min, max).is_ok());
+    assert!(validate_range(&money!(USD, 0), min, max).is_ok());
+    assert!(validate_range(&money!(USD, 1_000), min, max).is_ok());
+
+    let err = validate_range(&money!(USD, 1_000.01), min, max).unwrap_err();
+    assert_eq!(err.code, RANGE_CODE);
+    assert_eq!(
+        err.message.as_deref(),
+        Some("USD 1,000.01 is not within USD -10.00 and USD 1,000.00")
+    );
+    assert_eq!(param(&err, "value"), "USD 1,000.01");
+    assert_eq!(param(&err, "min"), "USD -10.00");
+    assert_eq!(param(&err, "max"), "USD 1,000.00");
+
+    let err = validate_range(&money!(EUR, -10.01), money!(EUR, -10), money!(EUR, 0)).unwrap_err();
+    assert_eq!(
+        err.message.as_deref(),
+        Some("EUR -10,01 is not within EUR -10,00 and EUR 0,00")
+    );
+}
+
+#[test]
+fn test_validate_range_raw_money() {
+    let min = raw!(BHD, 0.0001);
+    let max = raw!(BHD, 1);
+    assert!(validate_range(&raw!(BHD, 0.0001), min, max).is_ok());
+    let err = validate_range(&RawMoney::<BHD>::from_decimal(dec!(0.00001)), min, max).unwrap_err();
+    assert_eq!(param(&err, "value"), "BHD 0.00001");
+    assert_eq!(param(&err, "min"), "BHD 0.0001");
+    assert_eq!(param(&err, "max"), "BHD 1.000");
+}
+
+#[test]
+fn test_validate_positive() {
+    assert!(validate_positive(&money!(USD, 0.01)).is_ok());
+    assert!(validate_positive(&raw!(USD, 0.001)).is_ok());
+
+    let err = validate_positive(&money!(USD, -1_234.5)).unwrap_err();
+    assert_eq!(err.code, POSITIVE_CODE);
+    assert_eq!(
+        err.message.as_deref(),
+        Some("USD -1,234.50 is not positive")
+    );
+    assert_eq!(param(&err, "value"), "USD -1,234.50");
+
+    assert!(validate_positive(&Money::<USD>::default()).is_err());
+}
+
+#[test]
+fn test_validate_currency() {
+    assert!(validate_currency::<USD>("USD 1,234.56").is_ok());
+    assert!(validate_currency::<USD>("  USD -1,234.56 ").is_ok());
+
+    let err = validate_currency::<USD>("EUR 1.234,56").unwrap_err();
+    assert_eq!(err.code, CURRENCY_CODE);
+    assert_eq!(
+        err.message.as_deref(),
+        Some("expected currency USD, got EUR")
+    );
+    assert_eq!(param(&err, "value"), "EUR 1.234,56");
+    assert_eq!(param(&err, "expected"), "USD");
+
+    let err = validate_currency::<USD>("USD 1.234,56").unwrap_err();
+    assert_eq!(
+        err.message.as_deref(),
+        Some("USD 1.234,56 is not valid USD money")
+    );
+}
+
+fn validate_limit(amount: &Money<USD>) -> Result<(), ValidationError> {
+    validate_range(amount, money!(USD, 1), money!(USD, 100))
+}
+
+#[derive(Validate)]
+struct Payment {
+    #[validate(custom(function = "validate_positive"))]
+    tip: Money<USD>,
+
+    #[validate(custom(function = "validate_limit"))]
+    amount: Money<USD>,
+
+    #[validate(custom(function = "validate_currency::<USD>"))]
+    input: String,
+}
+
+#[test]
+fn test_validate_derive() {
+    let payment = Payment {
+        tip: money!(USD, 1),
+        amount: money!(USD, 50),
+        input: "USD 50.00".into(),
+    };
+    assert!(payment.validate().is_ok());
+
+    let payment = Payment {
+        tip: money!(USD, 0),
+        amount: money!(USD, 100.01),
+        input: "EUR 50,00".into(),
+    };
+    let errors = payment.validate().unwrap_err();
+    let field_errors = errors.field_errors();
+    assert_eq!(field_errors["tip"][0].code, POSITIVE_CODE);
+    assert_eq!(field_errors["amount"][0].code, RANGE_CODE);
+    assert_eq!(field_errors["input"][0].code, CURRENCY_CODE);
+}
@@ -0,0 +1,149 @@
+//! waterfall contains priority waterfall distribution of money pool into capped tranches.
+//!
+//! Tranches are paid in priority order, each up to its cap, until the pool runs out.
+//! This is the standard structure in debt servicing, escrow release, and revenue-share agreements.
+
+use crate::{BaseMoney, Currency, Decimal, Money};
+
+/// A claim on the pool with a priority and a cap.
+///
+/// Lower `priority` is paid first. Tranches sharing the same priority are paid pro-rata to their caps
+/// when the pool is not enough to pay all of them in full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tranche<C: Currency> {
+    /// Seniority of the tranche, lower is paid first.
+    pub priority: u32,
+
+    /// Maximum amount paid to the tranche.
+    pub cap: Money<C>,
+}
+
+impl<C: Currency> Tranche<C> {
+    /// Creates tranche with `priority` and `cap`.
+    pub fn new(priority: u32, cap: Money<C>) -> Self {
+        Self { priority, cap }
+    }
+}
+
+/// Result of [`distribute`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distribution<C: Currency> {
+    /// Payouts in the same order as the input tranches.
+    pub payouts: Vec<Money<C>>,
+
+    /// Amount of the pool left after paying all tranches.
+    pub remainder: Money<C>,
+}
+
+/// Distributes `pool` into `tranches` in priority order, each up to its cap.
+///
+/// Tranches sharing the same priority are paid pro-rata to their caps if the pool is insufficient.
+/// Pro-rata shares are rounded down to the currency's minor unit, then leftover minor units are given
+/// one at a time following the input order, so the payouts plus remainder always sum back to `pool`
+/// and no payout ever exceeds its cap.
+///
+/// Returns `None` if `pool` or any cap is negative, or the computation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use moneylib::{money, waterfall::{Tranche, distribute}};
+///
+/// let tranches = [
+///     Tranche::new(1, money!(USD, 500)), // senior
+///     Tranche::new(2, money!(USD, 300)), // mezzanine
+///     Tranche::new(3, money!(USD, 400)), // junior
+/// ];
+///
+/// let distribution = distribute(money!(USD, 1_000), &tranches).unwrap();
+/// assert_eq!(
+///     distribution.payouts,
+///     vec![money!(USD, 500), money!(USD, 300), money!(USD, 200)]
+/// );
+/// assert_eq!(distribution.remainder, money!(USD, 0));
+///
+/// let distribution = distribute(money!(USD, 2_000), &tranches).unwrap();
+/// assert_eq!(distribution.remainder, money!(USD, 800));
+///
+/// // Same priority shares pro-rata.
+/// let tranches = [
+///     Tranche::new(1, money!(USD, 100)),
+///     Tranche::new(2, money!(USD, 200)),
+///     Tranche::new(2, money!(USD, 100)),
+/// ];
+/// let distribution = distribute(money!(USD, 250), &tranches).unwrap();
+/// assert_eq!(
+///     distribution.payouts,
+///     vec![money!(USD, 100), money!(USD, 100), money!(USD, 50)]
+/// );
+/// ```
+pub fn distribute<C: Currency>(pool: Money<C>, tranches: &[Tranche<C>]) -> Option<Distribution<C>> {
+    if pool.is_negative() || tranches.iter().any(|t| t.cap.is_negative()) {
+        return None;
+    }
+
+    let mut order: Vec<usize> = (0..tranches.len()).collect();
+    order.sort_by_key(|&i| tranches[i].priority);
+
+    let mut payouts = vec![Money::<C>::default(); tranches.len()];
+    let mut available = pool.amount();
+
+    for group in order.chunk_by(|&a, &b| tranches[a].priority == tranches[b].priority) {
+        let total_cap = group.iter().try_fold(Decimal::ZERO, |acc, &i| {
+            acc.checked_add(tranches[i].cap.amount())
+        })?;
+
+        if available >= total_cap {
+            for &i in group {
+                payouts[i] = tranches[i].cap.clone();
+            }
+            available = available.checked_sub(total_cap)?;
+            continue;
+        }
+
+        available = pay_pro_rata(available, total_cap, group, tranches, &mut payouts)?;
+        break;
+    }
+
+    Some(Distribution {
+        payouts,
+        remainder: Money::from_decimal(available),
+    })
+}
+
+// Pays `available`, which is less than `total_cap`, to tranches in `group` pro-rata to their caps.
+// Returns what's left, which is only non-zero if `available` has more precision than the currency's minor unit.
+fn pay_pro_rata<C: Currency>(
+    available: Decimal,
+    total_cap: Decimal,
+    group: &[usize],
+    tranches: &[Tranche<C>],
+    payouts: &mut [Money<C>],
+) -> Option<Decimal> {
+    let minor_unit: u32 = C::MINOR_UNIT.into();
+    let ulp = Decimal::new(1, minor_unit);
+
+    let mut leftover = available;
+    for &i in group {
+        // share < cap since available < total_cap, rounding down keeps it below cap.
+        let share = available
+            .checked_mul(tranches[i].cap.amount())?
+            .checked_div(total_cap)?
+            .trunc_with_scale(minor_unit);
+        payouts[i] = Money::from_decimal(share);
+        leftover = leftover.checked_sub(share)?;
+    }
+
+    // Leftover is less than one minor unit per tranche.
+    for &i in group {
+        if leftover < ulp {
+            break;
+        }
+        if payouts[i].amount() < tranches[i].cap.amount() {
+            payouts[i] = Money::from_decimal(payouts[i].amount().checked_add(ulp)?);
+            leftover = leftover.checked_sub(ulp)?;
+        }
+    }
+
+    Some(leftover)
+}
@@ -0,0 +1,99 @@
+use crate::iso::USD;
+use crate::macros::money;
+use crate::waterfall::{Distribution, Tranche, distribute};
+use crate::{BaseMoney, Money};
+
+fn total<C: crate::Currency>(d: &Distribution<C>) -> Money<C> {
+    d.payouts.iter().sum::<Money<C>>() + d.remainder.clone()
+}
+
+#[test]
+fn test_distribute_in_priority_order() {
+    // input order doesn't matter, priority does
+    let tranches = [
+        Tranche::new(3, money!(USD, 400)),
+        Tranche::new(1, money!(USD, 500)),
+        Tranche::new(2, money!(USD, 300)),
+    ];
+
+    let d = distribute(money!(USD, 650.25), &tranches).unwrap();
+    assert_eq!(
+        d.payouts,
+        vec![money!(USD, 0), money!(USD, 500), money!(USD, 150.25)]
+    );
+    assert!(d.remainder.is_zero());
+    assert_eq!(total(&d), money!(USD, 650.25));
+
+    let d = distribute(money!(USD, 1_500), &tranches).unwrap();
+    assert_eq!(
+        d.payouts,
+        vec![money!(USD, 400), money!(USD, 500), money!(USD, 300)]
+    );
+    assert_eq!(d.remainder, money!(USD, 300));
+}
+
+#[test]
+fn test_distribute_pro_rata_same_priority() {
+    let tranches = [
+        Tranche::new(1, money!(USD, 1)),
+        Tranche::new(1, money!(USD, 1)),
+        Tranche::new(1, money!(USD, 1)),
+        Tranche::new(2, money!(USD, 10)),
+    ];
+    let d = distribute(money!(USD, 1), &tranches).unwrap();
+    assert_eq!(
+        d.payouts,
+        vec![
+            money!(USD, 0.34),
+            money!(USD, 0.33),
+            money!(USD, 0.33),
+            money!(USD, 0)
+        ]
+    );
+    assert_eq!(total(&d), money!(USD, 1));
+
+    let tranches = [
+        Tranche::new(1, money!(JPY, 100)),
+        Tranche::new(1, money!(JPY, 200)),
+    ];
+    let d = distribute(money!(JPY, 100), &tranches).unwrap();
+    assert_eq!(d.payouts, vec![money!(JPY, 34), money!(JPY, 66)]);
+    for (payout, tranche) in d.payouts.iter().zip(&tranches) {
+        assert!(*payout <= tranche.cap);
+    }
+}
+
+#[test]
+fn test_distribute_zero_caps_and_empty() {
+    let tranches = [
+        Tranche::new(1, money!(USD, 0)),
+        Tranche::new(1, money!(USD, 0)),
+        Tranche::new(2, money!(USD, 5)),
+    ];
+    let d = distribute(money!(USD, 3), &tranches).unwrap();
+    assert_eq!(
+        d.payouts,
+        vec![money!(USD, 0), money!(USD, 0), money!(USD, 3)]
+    );
+
+    let d = distribute(money!(USD, 3), &[]).unwrap();
+    assert!(d.payouts.is_empty());
+    assert_eq!(d.remainder, money!(USD, 3));
+
+    let d = distribute(money!(USD, 0), &tranches).unwrap();
+    assert!(d.payouts.iter().all(|p| p.is_zero()));
+    assert!(d.remainder.is_zero());
+}
+
+#[test]
+fn test_distribute_invalid() {
+    let tranches = [Tranche::new(1, money!(USD, 10))];
+    assert!(distribute(money!(USD, -1), &tranches).is_none());
+
+    let tranches = [Tranche::new(1, money!(USD, -10))];
+    assert!(distribute(money!(USD, 1), &tranches).is_none());
+
+    let max = Money::<USD>::from_decimal(crate::Decimal::MAX);
+    let tranches = [Tranche::new(1, max), Tranche::new(1, max)];
+    assert!(distribute(money!(USD, 1), &tranches).is_none());
+}
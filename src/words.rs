@@ -0,0 +1,297 @@
+//! words contains rendering of money amounts in words, e.g. for cheque printing and legal documents.
+//!
+//! [`MoneyFormatter::to_words`](crate::MoneyFormatter::to_words) renders in [`English`].
+//! Other languages implement [`Language`] and are used with
+//! [`MoneyFormatter::to_words_in`](crate::MoneyFormatter::to_words_in).
+//!
+//! # Examples
+//!
+//! ```
+//! use moneylib::{BaseMoney, Money, MoneyFormatter, macros::dec, iso::{EUR, USD}};
+//! use moneylib::words::{Language, Parts};
+//!
+//! let money = Money::<USD>::from_decimal(dec!(1234.56));
+//! assert_eq!(money.to_words(), "one thousand two hundred thirty-four dollars and fifty-six cents");
+//!
+//! // Amounts in digits, e.g. for the numeric box of a cheque.
+//! struct Digits;
+//!
+//! impl Language for Digits {
+//!     fn amount_in_words(&self, parts: &Parts<'_>) -> String {
+//!         format!("{} {} {:02}/100", parts.code, parts.major, parts.minor)
+//!     }
+//! }
+//!
+//! let money = Money::<EUR>::from_decimal(dec!(1234.5));
+//! assert_eq!(money.to_words_in(&Digits), "EUR 1234 50/100");
+//! ```
+
+use std::fmt::Debug;
+
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+
+use crate::{BaseMoney, Currency, Decimal};
+
+/// Parts of money amount to render in words, rounded into the currency's minor unit.
+///
+/// `Debug` shows the amount like money's `Debug` does, so it is masked with `redact_debug` feature.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Parts<'a> {
+    /// Currency code, e.g. `"USD"`.
+    pub code: &'a str,
+
+    /// Currency name, e.g. `"United States dollar"`.
+    pub name: &'a str,
+
+    /// Name of the currency's minor unit, e.g. `"cent"`.
+    pub minor_unit_name: &'a str,
+
+    /// Number of digits of the currency's minor unit, e.g. `2` for USD.
+    pub minor_unit: u16,
+
+    /// `true` if the amount is negative.
+    pub is_negative: bool,
+
+    /// Whole units of the absolute amount, e.g. `1234` of `-1234.56`.
+    pub major: u128,
+
+    /// Minor units of the absolute amount, e.g. `56` of `-1234.56`.
+    pub minor: u128,
+}
+
+impl<'a> Parts<'a> {
+    /// Splits `money`'s amount, rounded into its minor unit with bankers rounding, into parts.
+    pub fn new<C: Currency>(money: &impl BaseMoney<C>) -> Self {
+        let amount = money.amount().round_dp(C::MINOR_UNIT.into());
+        let abs = amount.abs();
+        let major = abs.trunc();
+        // fraction scaled by 10^minor_unit is less than 10^28, and the integer part is at most `Decimal::MAX`.
+        let minor = crate::decimal::pow10(C::MINOR_UNIT.into())
+            .and_then(|factor| (abs - major).checked_mul(factor))
+            .and_then(|minor| minor.to_u128())
+            .unwrap_or_default();
+
+        Self {
+            code: C::CODE,
+            name: C::NAME,
+            minor_unit_name: C::MINOR_UNIT_NAME,
+            minor_unit: C::MINOR_UNIT,
+            is_negative: amount.is_sign_negative() && !amount.is_zero(),
+            major: major.to_u128().unwrap_or_default(),
+            minor,
+        }
+    }
+
+    // Signed amount of parts, in the minor unit's decimal places.
+    fn amount(&self) -> Decimal {
+        let minor_unit = u32::from(self.minor_unit);
+        let abs = 10u128
+            .checked_pow(minor_unit)
+            .and_then(|factor| self.major.checked_mul(factor)?.checked_add(self.minor))
+            .and_then(|mantissa| i128::try_from(mantissa).ok())
+            .and_then(|mantissa| Decimal::try_from_i128_with_scale(mantissa, minor_unit).ok())
+            .or_else(|| Decimal::from_u128(self.major))
+            .unwrap_or(Decimal::MAX);
+        if self.is_negative { -abs } else { abs }
+    }
+}
+
+impl Debug for Parts<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Parts")
+            .field("code", &self.code)
+            .field("name", &self.name)
+            .field("minor_unit_name", &self.minor_unit_name)
+            .field("minor_unit", &self.minor_unit)
+            .field(
+                "amount",
+                &format_args!(
+                    "{}",
+                    crate::fmt::debug_amount(self.amount(), self.minor_unit)
+                ),
+            )
+            .finish()
+    }
+}
+
+/// Language rendering money amounts in words.
+pub trait Language {
+    /// Returns money of `parts` in words.
+    fn amount_in_words(&self, parts: &Parts<'_>) -> String;
+}
+
+/// English in short scale, e.g. `"minus one thousand two hundred thirty-four dollars and fifty-six cents"`.
+///
+/// - Hyphenated tens, no "and" between hundreds and tens, as written on US cheques.
+/// - Minor units are omitted if zero, e.g. `"one hundred dollars"`.
+/// - Unit names come from the currency's name and minor unit name, e.g. `"dollar"` of `"United States dollar"`,
+///   in plural unless exactly one, e.g. `"pence"`, `"yen"`, and `"kronor"` for irregular plurals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct English;
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+// Short scale names of each power of thousand, up to beyond `u128::MAX`.
+const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+// Currencies whose unit isn't the last word of their name.
+const MAJOR_UNITS: &[(&str, &str)] = &[("GBP", "pound"), ("CNY", "yuan")];
+
+// Units whose plural isn't formed with "s".
+const PLURALS: &[(&str, &str)] = &[
+    ("penny", "pence"),
+    ("paisa", "paise"),
+    ("krona", "kronor"),
+    ("krone", "kroner"),
+    ("króna", "krónur"),
+    ("yen", "yen"),
+    ("won", "won"),
+    ("yuan", "yuan"),
+    ("fen", "fen"),
+    ("sen", "sen"),
+    ("fils", "fils"),
+    ("baht", "baht"),
+    ("rand", "rand"),
+];
+
+impl English {
+    /// Returns `n` in words, e.g. `"one thousand two hundred thirty-four"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moneylib::words::English;
+    ///
+    /// assert_eq!(English.number(0), "zero");
+    /// assert_eq!(English.number(1_000_015), "one million fifteen");
+    /// ```
+    pub fn number(&self, n: u128) -> String {
+        if n == 0 {
+            return ONES[0].to_string();
+        }
+
+        let mut groups: Vec<String> = vec![];
+        let mut rest = n;
+        let mut scale = 0;
+        while rest > 0 {
+            let group = rest % 1000;
+            if group > 0 {
+                let mut words = below_thousand(group);
+                if !SCALES[scale].is_empty() {
+                    words.push(' ');
+                    words.push_str(SCALES[scale]);
+                }
+                groups.push(words);
+            }
+            rest /= 1000;
+            scale += 1;
+        }
+        groups.reverse();
+        groups.join(" ")
+    }
+
+    fn unit(&self, unit: &str, count: u128) -> String {
+        if count == 1 {
+            return unit.to_string();
+        }
+        PLURALS
+            .iter()
+            .find(|(singular, _)| *singular == unit)
+            .map_or_else(|| format!("{}s", unit), |(_, plural)| plural.to_string())
+    }
+}
+
+// Words of 1..=999.
+fn below_thousand(n: u128) -> String {
+    // all indices are below 20 or 10.
+    let digit = |d: u128| usize::try_from(d).unwrap_or_default();
+
+    let mut words = String::new();
+    let hundreds = n / 100;
+    let rest = n % 100;
+    if hundreds > 0 {
+        words.push_str(ONES[digit(hundreds)]);
+        words.push_str(" hundred");
+        if rest > 0 {
+            words.push(' ');
+        }
+    }
+    if rest >= 20 {
+        words.push_str(TENS[digit(rest / 10)]);
+        if !rest.is_multiple_of(10) {
+            words.push('-');
+            words.push_str(ONES[digit(rest % 10)]);
+        }
+    } else if rest > 0 {
+        words.push_str(ONES[digit(rest)]);
+    }
+    words
+}
+
+impl Language for English {
+    fn amount_in_words(&self, parts: &Parts<'_>) -> String {
+        let major_unit = MAJOR_UNITS
+            .iter()
+            .find(|(code, _)| *code == parts.code)
+            .map(|(_, unit)| unit.to_string())
+            .or_else(|| parts.name.split_whitespace().last().map(str::to_lowercase))
+            .unwrap_or_else(|| parts.code.to_string());
+
+        let mut words = String::new();
+        if parts.is_negative {
+            words.push_str("minus ");
+        }
+        words.push_str(&self.number(parts.major));
+        words.push(' ');
+        words.push_str(&self.unit(&major_unit, parts.major));
+
+        if parts.minor_unit > 0 && parts.minor > 0 {
+            words.push_str(" and ");
+            words.push_str(&self.number(parts.minor));
+            words.push(' ');
+            words.push_str(&self.unit(&parts.minor_unit_name.to_lowercase(), parts.minor));
+        }
+
+        words
+    }
+}
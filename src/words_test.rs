@@ -0,0 +1,144 @@
+use crate::iso::{BHD, EUR, GBP, INR, JPY, USD};
+use crate::macros::dec;
+use crate::words::{English, Language, Parts};
+use crate::{BaseMoney, Decimal, Money, MoneyFormatter};
+
+#[test]
+fn test_english_number() {
+    let cases = [
+        (0, "zero"),
+        (7, "seven"),
+        (13, "thirteen"),
+        (20, "twenty"),
+        (42, "forty-two"),
+        (100, "one hundred"),
+        (101, "one hundred one"),
+        (999, "nine hundred ninety-nine"),
+        (1000, "one thousand"),
+        (1001, "one thousand one"),
+        (1234, "one thousand two hundred thirty-four"),
+        (1_000_000, "one million"),
+        (
+            12_000_345_678,
+            "twelve billion three hundred forty-five thousand six hundred seventy-eight",
+        ),
+    ];
+    for (n, words) in cases {
+        assert_eq!(English.number(n), words);
+    }
+
+    assert!(
+        English
+            .number(u128::MAX)
+            .starts_with("three hundred forty undecillion")
+    );
+}
+
+#[test]
+fn test_to_words() {
+    assert_eq!(
+        Money::<USD>::from_decimal(dec!(1234.56)).to_words(),
+        "one thousand two hundred thirty-four dollars and fifty-six cents"
+    );
+    assert_eq!(
+        Money::<USD>::from_decimal(dec!(1.01)).to_words(),
+        "one dollar and one cent"
+    );
+    assert_eq!(
+        Money::<USD>::from_decimal(dec!(100)).to_words(),
+        "one hundred dollars"
+    );
+    assert_eq!(
+        Money::<USD>::from_decimal(dec!(0.5)).to_words(),
+        "zero dollars and fifty cents"
+    );
+    assert_eq!(
+        Money::<USD>::from_decimal(dec!(0)).to_words(),
+        "zero dollars"
+    );
+    assert_eq!(
+        Money::<USD>::from_decimal(dec!(-2.02)).to_words(),
+        "minus two dollars and two cents"
+    );
+    assert_eq!(
+        Money::<EUR>::from_decimal(dec!(21.99)).to_words(),
+        "twenty-one euros and ninety-nine cents"
+    );
+    assert_eq!(
+        Money::<GBP>::from_decimal(dec!(2.02)).to_words(),
+        "two pounds and two pence"
+    );
+    assert_eq!(Money::<JPY>::from_decimal(dec!(1)).to_words(), "one yen");
+    assert_eq!(
+        Money::<BHD>::from_decimal(dec!(1.005)).to_words(),
+        "one dinar and five fils"
+    );
+    assert_eq!(
+        Money::<INR>::from_decimal(dec!(10.10)).to_words(),
+        "ten rupees and ten paise"
+    );
+
+    assert!(
+        Money::<USD>::from_decimal(Decimal::MAX)
+            .to_words()
+            .starts_with("seventy-nine octillion")
+    );
+}
+
+#[cfg(feature = "raw_money")]
+#[test]
+fn test_to_words_rounds_raw_money() {
+    use crate::RawMoney;
+
+    assert_eq!(
+        RawMoney::<USD>::from_decimal(dec!(1.005)).to_words(),
+        "one dollar"
+    );
+    assert_eq!(
+        RawMoney::<USD>::from_decimal(dec!(-0.015)).to_words(),
+        "minus zero dollars and two cents"
+    );
+}
+
+#[test]
+fn test_to_words_in() {
+    struct Cheque;
+
+    impl Language for Cheque {
+        fn amount_in_words(&self, parts: &Parts<'_>) -> String {
+            format!(
+                "{}{} and {:02}/100",
+                if parts.is_negative { "-" } else { "" },
+                English.number(parts.major),
+                parts.minor
+            )
+        }
+    }
+
+    assert_eq!(
+        Money::<USD>::from_decimal(dec!(-1234.05)).to_words_in(&Cheque),
+        "-one thousand two hundred thirty-four and 05/100"
+    );
+
+    let parts = Parts::new(&Money::<EUR>::from_decimal(dec!(-1234.5)));
+    assert_eq!(parts.code, "EUR");
+    assert_eq!(parts.minor_unit, 2);
+    assert!(parts.is_negative);
+    assert_eq!(parts.major, 1234);
+    assert_eq!(parts.minor, 50);
+}
+
+#[test]
+fn test_parts_debug() {
+    let parts = Parts::new(&Money::<EUR>::from_decimal(dec!(-1234.5)));
+    #[cfg(not(feature = "redact_debug"))]
+    assert_eq!(
+        format!("{:?}", parts),
+        r#"Parts { code: "EUR", name: "Euro", minor_unit_name: "cent", minor_unit: 2, amount: -1234.50 }"#
+    );
+    #[cfg(feature = "redact_debug")]
+    assert_eq!(
+        format!("{:?}", parts),
+        r#"Parts { code: "EUR", name: "Euro", minor_unit_name: "cent", minor_unit: 2, amount: ••••.50 }"#
+    );
+}